@@ -1078,6 +1078,7 @@ async fn run_acp_lifecycle_inner(
     // entire tree (including MCP servers) with a single group signal.
     let mut cmd = tokio::process::Command::new(&backend.command);
     cmd.args(&backend.args)
+        .envs(backend.env_vars.iter().map(|(k, v)| (k, v)))
         .stdin(std::process::Stdio::piped())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
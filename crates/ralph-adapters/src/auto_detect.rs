@@ -104,6 +104,22 @@ pub fn is_backend_available(backend: &str) -> bool {
     }
 }
 
+/// Returns the first line of `<command> --version` for a backend.
+///
+/// Returns `None` when the command is missing, fails, or prints nothing.
+pub fn backend_version(backend: &str) -> Option<String> {
+    let output = Command::new(detection_command(backend))
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(String::from)
+}
+
 /// Detects the first available backend from a priority list.
 ///
 /// # Arguments
@@ -293,6 +293,37 @@ impl CliBackend {
         }
     }
 
+    /// Applies a hat's sampling settings to this backend.
+    ///
+    /// Both settings are exported as `RALPH_TEMPERATURE` / `RALPH_MAX_TOKENS`
+    /// for custom backends. The Claude CLI also reads its output token limit
+    /// from `CLAUDE_CODE_MAX_OUTPUT_TOKENS`; no built-in backend accepts a
+    /// temperature.
+    pub fn apply_sampling(&mut self, temperature: Option<f32>, max_tokens: Option<u32>) {
+        if let Some(temperature) = temperature {
+            self.set_env("RALPH_TEMPERATURE", temperature.to_string());
+        }
+        if let Some(max_tokens) = max_tokens {
+            self.set_env("RALPH_MAX_TOKENS", max_tokens.to_string());
+            if self.command == "claude" {
+                self.set_env("CLAUDE_CODE_MAX_OUTPUT_TOKENS", max_tokens.to_string());
+            }
+        }
+    }
+
+    /// Sets environment variables on the spawned process, replacing any
+    /// earlier value for the same name (e.g. an adapter's configured `env`).
+    pub fn apply_env(&mut self, env: impl IntoIterator<Item = (String, String)>) {
+        for (key, value) in env {
+            self.set_env(&key, value);
+        }
+    }
+
+    fn set_env(&mut self, key: &str, value: String) {
+        self.env_vars.retain(|(existing, _)| existing != key);
+        self.env_vars.push((key.to_string(), value));
+    }
+
     /// Creates the Gemini backend.
     pub fn gemini() -> Self {
         Self {
@@ -875,6 +906,45 @@ impl CliBackend {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_sampling_exports_env() {
+        let mut claude = CliBackend::claude();
+        claude.apply_sampling(Some(0.2), Some(4096));
+        assert!(
+            claude
+                .env_vars
+                .contains(&("RALPH_TEMPERATURE".to_string(), "0.2".to_string()))
+        );
+        assert!(claude.env_vars.contains(&(
+            "CLAUDE_CODE_MAX_OUTPUT_TOKENS".to_string(),
+            "4096".to_string()
+        )));
+
+        let mut gemini = CliBackend::gemini();
+        gemini.apply_sampling(None, Some(1024));
+        assert_eq!(
+            gemini.env_vars,
+            vec![("RALPH_MAX_TOKENS".to_string(), "1024".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_apply_env_overrides_existing_values() {
+        let mut backend = CliBackend::gemini();
+        backend.apply_sampling(None, Some(1024));
+        backend.apply_env([
+            ("RALPH_MAX_TOKENS".to_string(), "2048".to_string()),
+            ("GEMINI_API_KEY".to_string(), "key".to_string()),
+        ]);
+        assert_eq!(
+            backend.env_vars,
+            vec![
+                ("RALPH_MAX_TOKENS".to_string(), "2048".to_string()),
+                ("GEMINI_API_KEY".to_string(), "key".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_claude_backend() {
         let backend = CliBackend::claude();
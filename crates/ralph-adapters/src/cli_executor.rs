@@ -1,7 +1,8 @@
 //! CLI executor for running prompts through backends.
 //!
 //! Executes prompts via CLI tools with real-time streaming output.
-//! Supports optional execution timeout with graceful SIGTERM termination.
+//! Supports an optional inactivity timeout and an optional total execution
+//! timeout, both with graceful SIGTERM termination.
 
 #[cfg(test)]
 use crate::cli_backend::PromptMode;
@@ -33,12 +34,27 @@ pub struct ExecutionResult {
     pub exit_code: Option<i32>,
     /// Whether the execution was terminated due to timeout.
     pub timed_out: bool,
+    /// Which timeout terminated the execution, if any.
+    pub timeout_kind: Option<TimeoutKind>,
+}
+
+/// The timeout that terminated an execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutKind {
+    /// No stdout/stderr activity for the inactivity timeout.
+    Idle,
+    /// The total execution timeout elapsed.
+    Total,
+    /// The process kept running past the grace period after emitting an event.
+    PostEvent,
 }
 
 /// Executor for running prompts through CLI backends.
 #[derive(Debug)]
 pub struct CliExecutor {
     backend: CliBackend,
+    total_timeout: Option<Duration>,
+    working_dir: Option<std::path::PathBuf>,
 }
 
 enum StreamEvent {
@@ -56,7 +72,25 @@ enum StreamKind {
 impl CliExecutor {
     /// Creates a new executor with the given backend.
     pub fn new(backend: CliBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            total_timeout: None,
+            working_dir: None,
+        }
+    }
+
+    /// Caps each execution's total wall-clock time, regardless of activity.
+    #[must_use]
+    pub fn with_total_timeout(mut self, total_timeout: Duration) -> Self {
+        self.total_timeout = Some(total_timeout);
+        self
+    }
+
+    /// Runs the backend in `dir` instead of the process's current directory.
+    #[must_use]
+    pub fn with_working_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.working_dir = Some(dir.into());
+        self
     }
 
     /// Executes a prompt and streams output to the provided writer.
@@ -64,7 +98,8 @@ impl CliExecutor {
     /// Output is streamed line-by-line to the writer while being accumulated
     /// for the return value. If `timeout` is provided and the execution produces
     /// no stdout/stderr activity for longer than that duration, the process
-    /// receives SIGTERM and the result indicates timeout.
+    /// receives SIGTERM and the result indicates timeout. A total timeout set
+    /// with [`CliExecutor::with_total_timeout`] applies on top of that.
     ///
     /// When `verbose` is true, stderr output is also written to the output writer
     /// with a `[stderr]` prefix. When false, stderr is captured but not displayed.
@@ -88,7 +123,9 @@ impl CliExecutor {
 
         // Set working directory to current directory (mirrors PTY executor behavior)
         // Use fallback to "." if current_dir fails (e.g., E2E test workspaces)
-        let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+        let cwd = self.working_dir.clone().unwrap_or_else(|| {
+            std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
+        });
         command.current_dir(&cwd);
         inject_ralph_runtime_env(&mut command, &cwd);
 
@@ -122,7 +159,10 @@ impl CliExecutor {
             drop(stdin); // Close stdin to signal EOF
         }
 
-        let mut timed_out = false;
+        let mut timeout_kind = None;
+        let total_deadline = self
+            .total_timeout
+            .map(|duration| tokio::time::Instant::now() + duration);
         let mut post_event_deadline: Option<tokio::time::Instant> = None;
         let mut terminated_status = None;
 
@@ -155,29 +195,37 @@ impl CliExecutor {
 
         while !stdout_done || !stderr_done {
             let now = tokio::time::Instant::now();
-            let effective_timeout = match (timeout, post_event_deadline) {
-                (_, Some(deadline)) if deadline <= now => Some(Duration::ZERO),
-                (Some(duration), Some(deadline)) => {
-                    Some(duration.min(deadline.saturating_duration_since(now)))
+            // The nearest of the three limits wins; ties go to the more specific one.
+            let mut effective_timeout = timeout.map(|duration| (duration, TimeoutKind::Idle));
+            for (deadline, kind) in [
+                (total_deadline, TimeoutKind::Total),
+                (post_event_deadline, TimeoutKind::PostEvent),
+            ] {
+                if let Some(deadline) = deadline {
+                    let remaining = deadline.saturating_duration_since(now);
+                    if effective_timeout.is_none_or(|(duration, _)| remaining <= duration) {
+                        effective_timeout = Some((remaining, kind));
+                    }
                 }
-                (None, Some(deadline)) => Some(deadline.saturating_duration_since(now)),
-                (Some(duration), None) => Some(duration),
-                (None, None) => None,
-            };
+            }
 
             let next_event = match effective_timeout {
-                Some(duration) => match tokio::time::timeout(duration, event_rx.recv()).await {
-                    Ok(event) => event,
-                    Err(_) => {
-                        warn!(
-                            timeout_secs = duration.as_secs(),
-                            "Execution inactivity timeout reached, sending SIGTERM"
-                        );
-                        timed_out = true;
-                        terminated_status = Some(Self::terminate_child_and_wait(&mut child).await?);
-                        break;
+                Some((duration, kind)) => {
+                    match tokio::time::timeout(duration, event_rx.recv()).await {
+                        Ok(event) => event,
+                        Err(_) => {
+                            warn!(
+                                timeout_secs = duration.as_secs(),
+                                ?kind,
+                                "Execution timeout reached, sending SIGTERM"
+                            );
+                            timeout_kind = Some(kind);
+                            terminated_status =
+                                Some(Self::terminate_child_and_wait(&mut child).await?);
+                            break;
+                        }
                     }
-                },
+                }
                 None => event_rx.recv().await,
             };
 
@@ -238,11 +286,13 @@ impl CliExecutor {
             handle.await.map_err(join_error_to_io)??;
         }
 
+        let timed_out = timeout_kind.is_some();
         Ok(ExecutionResult {
             output: accumulated_output,
             success: status.success() && !timed_out,
             exit_code: status.code(),
             timed_out,
+            timeout_kind,
         })
     }
 
@@ -358,6 +408,13 @@ fn inject_ralph_runtime_env(command: &mut Command, workspace_root: &std::path::P
         command.env("RALPH_EVENTS_FILE", &abs);
     }
 
+    // Propagate the run seed recorded in the reproducibility manifest
+    if let Some(seed) =
+        ralph_core::RunManifest::read_seed(&workspace_root.join(".ralph/agent/run-manifest.json"))
+    {
+        command.env("RALPH_SEED", seed.to_string());
+    }
+
     if std::path::Path::new("/var/tmp").is_dir() {
         command.env("TMPDIR", "/var/tmp");
         command.env("TMP", "/var/tmp");
@@ -394,6 +451,25 @@ mod tests {
         assert!(result.output.contains("hello world"));
     }
 
+    #[tokio::test]
+    async fn test_execute_runs_in_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = CliBackend {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "pwd".to_string()],
+            prompt_mode: PromptMode::Arg,
+            prompt_flag: None,
+            output_format: OutputFormat::Text,
+            env_vars: vec![],
+        };
+
+        let executor = CliExecutor::new(backend).with_working_dir(dir.path());
+        let result = executor.execute_capture("prompt").await.unwrap();
+
+        let expected = dir.path().canonicalize().unwrap();
+        assert_eq!(result.output.trim(), expected.to_string_lossy());
+    }
+
     #[tokio::test]
     async fn test_execute_stdin() {
         // Use cat to test stdin mode
@@ -456,12 +532,38 @@ mod tests {
             .unwrap();
 
         assert!(result.timed_out, "Expected execution to time out");
+        assert_eq!(result.timeout_kind, Some(TimeoutKind::Idle));
         assert!(
             !result.success,
             "Timed out execution should not be successful"
         );
     }
 
+    #[tokio::test]
+    async fn test_execute_total_timeout_despite_output_activity() {
+        let backend = CliBackend {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "while true; do printf 'tick\\n'; sleep 0.05; done".to_string(),
+            ],
+            prompt_mode: PromptMode::Stdin,
+            prompt_flag: None,
+            output_format: OutputFormat::Text,
+            env_vars: vec![],
+        };
+
+        let executor = CliExecutor::new(backend).with_total_timeout(Duration::from_millis(300));
+        let result = executor
+            .execute_capture_with_timeout("", Some(Duration::from_secs(5)))
+            .await
+            .unwrap();
+
+        assert!(result.timed_out, "Expected total timeout to fire");
+        assert_eq!(result.timeout_kind, Some(TimeoutKind::Total));
+        assert!(result.output.contains("tick"));
+    }
+
     #[tokio::test]
     async fn test_execute_timeout_resets_on_output_activity() {
         let backend = CliBackend {
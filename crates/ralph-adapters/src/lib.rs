@@ -41,14 +41,15 @@ pub mod tool_preview;
 
 pub use acp_executor::AcpExecutor;
 pub use auto_detect::{
-    DEFAULT_PRIORITY, NoBackendError, detect_backend, detect_backend_default, is_backend_available,
+    DEFAULT_PRIORITY, NoBackendError, backend_version, detect_backend, detect_backend_default,
+    is_backend_available,
 };
 pub use claude_stream::{
     AssistantMessage, ClaudeStreamEvent, ClaudeStreamParser, ContentBlock, Usage, UserContentBlock,
     UserMessage,
 };
 pub use cli_backend::{CliBackend, CustomBackendError, OutputFormat, PromptMode};
-pub use cli_executor::{CliExecutor, ExecutionResult};
+pub use cli_executor::{CliExecutor, ExecutionResult, TimeoutKind};
 pub use copilot_stream::{CopilotAssistantMessage, CopilotStreamEvent, CopilotStreamParser};
 pub use json_rpc_handler::{JsonRpcStreamHandler, stdout_json_rpc_handler};
 pub use pi_stream::{
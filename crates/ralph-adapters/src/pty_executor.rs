@@ -1799,6 +1799,13 @@ fn inject_ralph_runtime_env(cmd_builder: &mut CommandBuilder, workspace_root: &s
         cmd_builder.env("RALPH_EVENTS_FILE", abs);
     }
 
+    // Propagate the run seed recorded in the reproducibility manifest
+    if let Some(seed) =
+        ralph_core::RunManifest::read_seed(&workspace_root.join(".ralph/agent/run-manifest.json"))
+    {
+        cmd_builder.env("RALPH_SEED", seed.to_string());
+    }
+
     if std::path::Path::new("/var/tmp").is_dir() {
         cmd_builder.env("TMPDIR", "/var/tmp");
         cmd_builder.env("TMP", "/var/tmp");
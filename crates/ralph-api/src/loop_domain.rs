@@ -158,6 +158,7 @@ impl LoopDomain {
                 MergeState::Merging => "merging",
                 MergeState::Merged => "merged",
                 MergeState::NeedsReview => "needs-review",
+                MergeState::VerificationFailed => "verification-failed",
                 MergeState::Discarded => "discarded",
             };
 
@@ -253,9 +254,9 @@ impl LoopDomain {
             .map_err(map_merge_error)?
             .ok_or_else(|| loop_not_found_error(&params.id))?;
 
-        if entry.state != MergeState::NeedsReview {
+        if !entry.state.needs_steering() {
             return Err(ApiError::precondition_failed(format!(
-                "Loop '{}' is in state {:?}, can only retry 'needs-review' loops",
+                "Loop '{}' is in state {:?}, can only retry 'needs-review' or 'verification-failed' loops",
                 params.id, entry.state
             )));
         }
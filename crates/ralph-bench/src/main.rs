@@ -10,17 +10,20 @@
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
-use ralph_adapters::{CliBackend, CliExecutor, detect_backend};
+use ralph_adapters::{CliBackend, CliExecutor, TimeoutKind, detect_backend};
 use ralph_core::{
-    CleanupPolicy, CliCapture, EventLoop, PlayerConfig, RalphConfig, ReplayMode, SessionPlayer,
-    TaskSuite, TerminationReason, WorkspaceManager,
+    CaptureMode, CleanupPolicy, CliCapture, EventLoop, LoopContext, PlayerConfig, RalphConfig,
+    ReplayMode, SessionPlayer, StartPoint, TaskSuite, TerminationReason, VerificationFailure,
+    VerificationResult, WorkspaceManager, is_gzip_path,
 };
 use ralph_proto::FrameCapture;
 use std::fs::{self, File};
 use std::io::{self, BufReader, BufWriter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
-use tracing::{debug, info, warn};
+use tokio::task::JoinSet;
+use tracing::{Instrument, debug, info, info_span, warn};
 
 /// Ralph Benchmark Harness - Record, replay, and benchmark orchestration loops
 #[derive(Parser, Debug)]
@@ -41,7 +44,7 @@ enum Commands {
         /// Path to tasks.json file
         tasks: PathBuf,
 
-        /// Record session to JSONL file (single task mode)
+        /// Record session to JSONL file (single task mode); a `.gz` path is gzip-compressed
         #[arg(long)]
         record: Option<PathBuf>,
 
@@ -53,6 +56,11 @@ enum Commands {
         #[arg(long)]
         record_ux: bool,
 
+        /// Replay mode the UX recording is for: terminal keeps raw bytes,
+        /// text keeps only ANSI-stripped lines (smaller recordings)
+        #[arg(long, value_enum, default_value = "terminal", requires = "record_ux")]
+        ux_mode: UxMode,
+
         /// Write metrics summary to JSON file
         #[arg(long, short)]
         output: Option<PathBuf>,
@@ -68,6 +76,46 @@ enum Commands {
         /// Number of workspaces to keep when using rotate policy
         #[arg(long, default_value = "5")]
         keep_last_n: usize,
+
+        /// Evict the oldest workspaces when they exceed this many bytes in total
+        #[arg(long, value_name = "BYTES")]
+        max_workspace_bytes: Option<u64>,
+
+        /// Kill an iteration after this many seconds without output
+        /// (default: cli.idle_timeout_secs, 0 disables)
+        #[arg(long, value_name = "SECS")]
+        idle_timeout: Option<u32>,
+
+        /// Number of failing tasks to tolerate before exiting non-zero
+        #[arg(long, value_name = "N", default_value = "0")]
+        allow_failures: usize,
+
+        /// Also count tasks that overshoot their expected iterations as failures
+        #[arg(long)]
+        require_all: bool,
+
+        /// Iterations over expected allowed per task with --require-all
+        #[arg(long, value_name = "N", default_value = "0", requires = "require_all")]
+        iteration_tolerance: u32,
+
+        /// Run up to N tasks concurrently, each in its own workspace
+        #[arg(
+            long,
+            short,
+            value_name = "N",
+            default_value = "1",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        jobs: usize,
+
+        /// Run each task N times and report pass rate and iteration/duration spread
+        #[arg(
+            long,
+            value_name = "N",
+            default_value = "1",
+            value_parser = clap::builder::RangedU64ValueParser::<u32>::new().range(1..)
+        )]
+        repeat: u32,
     },
 
     /// Replay a recorded session
@@ -90,6 +138,24 @@ enum Commands {
         /// Filter to specific event types (comma-separated prefixes)
         #[arg(long)]
         filter: Option<String>,
+
+        /// Skip ahead to this iteration before playing
+        #[arg(long)]
+        from_iteration: Option<u32>,
+
+        /// Stop after this iteration
+        #[arg(long)]
+        to_iteration: Option<u32>,
+
+        /// Write the session as an asciinema v2 cast to this path instead of playing it
+        #[arg(long, value_name = "PATH")]
+        export_asciicast: Option<PathBuf>,
+    },
+
+    /// Print a recorded session's header and record count
+    Inspect {
+        /// Path to session JSONL file
+        session: PathBuf,
     },
 
     /// List recorded sessions or workspaces
@@ -145,22 +211,48 @@ async fn main() -> Result<()> {
             record,
             record_dir,
             record_ux,
+            ux_mode,
             output,
             task,
             cleanup,
             keep_last_n,
+            max_workspace_bytes,
+            idle_timeout,
+            allow_failures,
+            require_all,
+            iteration_tolerance,
+            jobs,
+            repeat,
         } => {
-            cmd_run(
+            let gate = FailureGate {
+                allow_failures,
+                require_all,
+                iteration_tolerance,
+            };
+            let failures = cmd_run(
                 tasks,
                 record,
                 record_dir,
-                record_ux,
+                record_ux.then(|| CaptureMode::from(ReplayMode::from(ux_mode))),
                 output,
                 task,
                 cleanup,
                 keep_last_n,
+                max_workspace_bytes,
+                idle_timeout,
+                jobs,
+                repeat,
+                &gate,
             )
-            .await
+            .await?;
+            if failures > gate.allow_failures {
+                warn!(
+                    "{} task(s) failed (allowed: {})",
+                    failures, gate.allow_failures
+                );
+                std::process::exit(1);
+            }
+            Ok(())
         }
         Commands::Replay {
             session,
@@ -168,22 +260,70 @@ async fn main() -> Result<()> {
             speed,
             step,
             filter,
-        } => cmd_replay(session, ux_mode, speed, step, filter),
+            from_iteration,
+            to_iteration,
+            export_asciicast,
+        } => cmd_replay(
+            session,
+            ux_mode,
+            speed,
+            step,
+            filter,
+            from_iteration,
+            to_iteration,
+            export_asciicast,
+        ),
+        Commands::Inspect { session } => cmd_inspect(&session),
         Commands::List { what, dir } => cmd_list(what, dir),
     }
 }
 
-/// Run benchmark tasks
+/// Decides which task results fail a benchmark run.
+struct FailureGate {
+    /// Failing tasks tolerated before the run fails.
+    allow_failures: usize,
+    /// Whether overshooting the expected iteration count is a failure.
+    require_all: bool,
+    /// Iterations over expected allowed with `require_all`.
+    iteration_tolerance: u32,
+}
+
+impl FailureGate {
+    /// Number of results that count as failures.
+    fn count_failures(&self, results: &[TaskResult]) -> usize {
+        results
+            .iter()
+            .filter(|result| {
+                !result.verification_passed
+                    || (self.require_all
+                        && result
+                            .iteration_delta
+                            .is_some_and(|delta| delta > self.iteration_tolerance as i32))
+            })
+            .count()
+    }
+}
+
+/// Run benchmark tasks.
+///
+/// Runs each task `repeat` times, up to `jobs` runs concurrently. Returns the
+/// number of failing runs per `gate`; results are written to `output` either
+/// way, in suite order.
 async fn cmd_run(
     tasks_path: PathBuf,
     record: Option<PathBuf>,
     record_dir: Option<PathBuf>,
-    record_ux: bool,
+    record_ux: Option<CaptureMode>,
     output: Option<PathBuf>,
     task_filter: Option<String>,
     cleanup_policy: String,
     keep_last_n: usize,
-) -> Result<()> {
+    max_workspace_bytes: Option<u64>,
+    idle_timeout: Option<u32>,
+    jobs: usize,
+    repeat: u32,
+    gate: &FailureGate,
+) -> Result<usize> {
     // Load task suite
     let suite = TaskSuite::from_file(&tasks_path)
         .with_context(|| format!("Failed to load tasks from {:?}", tasks_path))?;
@@ -208,7 +348,10 @@ async fn cmd_run(
     // Setup workspace manager
     let policy = CleanupPolicy::from_str(&cleanup_policy, Some(keep_last_n));
     let base_dir = std::env::temp_dir();
-    let manager = WorkspaceManager::new(&base_dir, policy);
+    let mut manager = WorkspaceManager::new(&base_dir, policy);
+    if let Some(max_total_bytes) = max_workspace_bytes {
+        manager = manager.with_max_total_bytes(max_total_bytes);
+    }
 
     // Get tasks directory (parent of tasks.json)
     let tasks_dir = tasks_path
@@ -222,84 +365,67 @@ async fn cmd_run(
             .with_context(|| format!("Failed to create record directory: {:?}", dir))?;
     }
 
-    // Run each task
-    let mut results = Vec::new();
-    for task in tasks_to_run {
-        info!("Running task: {}", task.name);
-
-        // Create workspace
-        let workspace = manager
-            .create_workspace(task)
-            .with_context(|| format!("Failed to create workspace for task '{}'", task.name))?;
-
-        // Setup workspace with task files
-        workspace
-            .setup(task, &tasks_dir)
-            .with_context(|| format!("Failed to setup workspace for task '{}'", task.name))?;
-
-        info!("Workspace created at: {}", workspace.path().display());
-
-        // Determine recording output
-        let record_path = if let Some(ref dir) = record_dir {
-            Some(dir.join(format!("{}.jsonl", task.name)))
-        } else {
-            record.clone()
-        };
-
-        // Track timing
-        let task_start = std::time::Instant::now();
-
-        // Run the orchestration loop for this task
-        let (iterations, termination_reason) =
-            run_task_loop(task, &workspace, record_path.as_ref(), record_ux)
-                .await
-                .with_context(|| format!("Failed to run task '{}'", task.name))?;
+    if record.is_some() && (repeat > 1 || (jobs > 1 && tasks_to_run.len() > 1)) {
+        anyhow::bail!("--record writes a single file; use --record-dir with --jobs or --repeat");
+    }
 
-        // Run verification command (this works even without full EventLoop integration)
-        let verification_result = workspace
-            .run_verification(&task.verification)
-            .with_context(|| format!("Failed to run verification for task '{}'", task.name))?;
+    let manager = Arc::new(manager);
+    let settings = Arc::new(TaskRunSettings {
+        tasks_dir,
+        record,
+        record_dir,
+        record_ux,
+        idle_timeout,
+        repeat,
+    });
 
-        if verification_result.passed {
-            info!(
-                "Task '{}' verification: {}",
-                task.name,
-                verification_result.summary()
-            );
-        } else {
-            tracing::warn!(
-                "Task '{}' verification: {}\nstderr: {}",
-                task.name,
-                verification_result.summary(),
-                verification_result.stderr.trim()
+    // Run up to `jobs` tasks at a time, each in its own workspace
+    let mut pending = tasks_to_run
+        .into_iter()
+        .flat_map(|task| (1..=repeat).map(move |run| (task.clone(), run)))
+        .enumerate();
+    let mut running = JoinSet::new();
+    let mut results = Vec::new();
+    loop {
+        while running.len() < jobs
+            && let Some((index, (task, run))) = pending.next()
+        {
+            let span = info_span!("task", name = %task.name, run);
+            let manager = Arc::clone(&manager);
+            let settings = Arc::clone(&settings);
+            running.spawn(
+                async move { (index, run_task(task, run, &manager, &settings).await) }
+                    .instrument(span),
             );
         }
+        let Some(joined) = running.join_next().await else {
+            break;
+        };
+        let (index, result) = joined.context("Benchmark task panicked")?;
+        results.push((index, result?));
+    }
 
-        let duration_secs = task_start.elapsed().as_secs_f64();
+    // Report in suite order, whatever order the tasks finished in
+    results.sort_by_key(|(index, _)| *index);
+    let results: Vec<TaskResult> = results.into_iter().map(|(_, result)| result).collect();
 
-        // Apply cleanup policy based on verification result
-        let mut workspace = workspace;
-        let cleaned_up = manager
-            .apply_cleanup(&mut workspace, verification_result.passed)
-            .with_context(|| format!("Failed to cleanup workspace for task '{}'", task.name))?;
+    let failures = gate.count_failures(&results);
+    info!("{} of {} task run(s) failed", failures, results.len());
 
-        if !cleaned_up {
+    let summaries = TaskSummary::from_results(&results);
+    if repeat > 1 {
+        for summary in &summaries {
             info!(
-                "Workspace retained for debugging: {}",
-                workspace.path().display()
+                "Task '{}': {}/{} passed{}, iterations {}-{} (median {})",
+                summary.name,
+                summary.passed,
+                summary.runs,
+                if summary.flaky { " (flaky)" } else { "" },
+                summary.iterations.min,
+                summary.iterations.max,
+                summary.iterations.median
             );
         }
-
-        // Record task result
-        results.push(TaskResult::new(
-            task.name.clone(),
-            iterations,
-            task.expected_iterations,
-            duration_secs,
-            termination_reason,
-            verification_result.passed,
-            workspace.path().to_string_lossy().to_string(),
-        ));
     }
 
     // Write results if output specified
@@ -307,7 +433,9 @@ async fn cmd_run(
         let results_json = BenchmarkResults {
             run_id: format!("bench-{}", chrono_timestamp()),
             timestamp: chrono_timestamp(),
+            repeat,
             tasks: results,
+            summaries,
         };
 
         let file = File::create(&output_path)
@@ -318,20 +446,129 @@ async fn cmd_run(
         info!("Results written to: {:?}", output_path);
     }
 
-    Ok(())
+    Ok(failures)
+}
+
+/// Settings shared by every task in a `ralph-bench run`.
+struct TaskRunSettings {
+    tasks_dir: PathBuf,
+    record: Option<PathBuf>,
+    record_dir: Option<PathBuf>,
+    record_ux: Option<CaptureMode>,
+    idle_timeout: Option<u32>,
+    repeat: u32,
+}
+
+/// Runs one benchmark task in a fresh workspace: loop, verification, cleanup.
+///
+/// `run` numbers the repetitions of a task from 1.
+async fn run_task(
+    task: ralph_core::TaskDefinition,
+    run: u32,
+    manager: &WorkspaceManager,
+    settings: &TaskRunSettings,
+) -> Result<TaskResult> {
+    info!("Running task: {}", task.name);
+
+    // Create workspace
+    let workspace = manager
+        .create_workspace(&task)
+        .with_context(|| format!("Failed to create workspace for task '{}'", task.name))?;
+
+    // Setup workspace with task files
+    workspace
+        .setup(&task, &settings.tasks_dir)
+        .with_context(|| format!("Failed to setup workspace for task '{}'", task.name))?;
+
+    info!("Workspace created at: {}", workspace.path().display());
+
+    // Determine recording output
+    let record_path = if let Some(ref dir) = settings.record_dir {
+        if settings.repeat > 1 {
+            Some(dir.join(format!("{}-{}.jsonl", task.name, run)))
+        } else {
+            Some(dir.join(format!("{}.jsonl", task.name)))
+        }
+    } else {
+        settings.record.clone()
+    };
+
+    // Track timing
+    let task_start = std::time::Instant::now();
+
+    // Run the orchestration loop for this task
+    let (iterations, termination_reason) = run_task_loop(
+        &task,
+        &workspace,
+        record_path.as_ref(),
+        settings.record_ux,
+        settings.idle_timeout,
+    )
+    .await
+    .with_context(|| format!("Failed to run task '{}'", task.name))?;
+
+    // Run verification command (this works even without full EventLoop integration).
+    // It blocks until the command exits, so keep it off the async workers.
+    let verification_result =
+        tokio::task::block_in_place(|| workspace.run_verification(&task.verification))
+            .with_context(|| format!("Failed to run verification for task '{}'", task.name))?;
+
+    if verification_result.passed {
+        info!(
+            "Task '{}' verification: {}",
+            task.name,
+            verification_result.summary()
+        );
+    } else {
+        tracing::warn!(
+            "Task '{}' verification: {}\nstderr: {}",
+            task.name,
+            verification_result.summary(),
+            verification_result.stderr.trim()
+        );
+    }
+
+    let duration_secs = task_start.elapsed().as_secs_f64();
+
+    // Apply cleanup policy based on verification result
+    let mut workspace = workspace;
+    let cleaned_up = manager
+        .apply_cleanup(&mut workspace, verification_result.passed)
+        .with_context(|| format!("Failed to cleanup workspace for task '{}'", task.name))?;
+
+    if !cleaned_up {
+        info!(
+            "Workspace retained for debugging: {}",
+            workspace.path().display()
+        );
+    }
+
+    Ok(TaskResult::new(
+        task.name.clone(),
+        iterations,
+        task.expected_iterations,
+        duration_secs,
+        termination_reason,
+        verification_result.passed,
+        workspace.path().to_string_lossy().to_string(),
+    )
+    .with_run(run)
+    .with_verification(&verification_result))
 }
 
 /// Run the orchestration loop for a single benchmark task.
 ///
-/// Returns (iterations, termination_reason) tuple.
+/// Returns (iterations, termination_reason) tuple. An iteration that hits the
+/// idle or total execution timeout ends the task with `IdleTimeout` or
+/// `ExecutionTimeout` rather than retrying a stuck backend.
 async fn run_task_loop(
     task: &ralph_core::TaskDefinition,
     workspace: &ralph_core::TaskWorkspace,
     record_path: Option<&PathBuf>,
-    record_ux: bool,
+    record_ux: Option<CaptureMode>,
+    idle_timeout: Option<u32>,
 ) -> Result<(u32, String)> {
-    use ralph_core::{Record, SessionRecorder};
-    use std::sync::Arc;
+    use ralph_core::{Record, SessionRecorder, SessionWriter};
 
     // Read the prompt file from the workspace (it was copied there during setup)
     let prompt_path = workspace.path().join("PROMPT.md");
@@ -343,6 +580,10 @@ async fn run_task_loop(
     config.event_loop.max_iterations = task.max_iterations;
     config.event_loop.completion_promise = task.completion_promise.clone();
     config.event_loop.max_runtime_seconds = task.timeout_seconds;
+    config.core.workspace_root = workspace.path().to_path_buf();
+    if let Some(idle_timeout) = idle_timeout {
+        config.cli.idle_timeout_secs = idle_timeout;
+    }
 
     // Auto-detect backend
     let priority = config.get_agent_priority();
@@ -363,19 +604,29 @@ async fn run_task_loop(
     }
 
     // Initialize event loop
-    let mut event_loop = EventLoop::new(config.clone());
+    // Resolve loop state against the workspace rather than the process cwd,
+    // which concurrent tasks share
+    let context = LoopContext::primary(workspace.path().to_path_buf());
+    let mut event_loop = EventLoop::with_context(config.clone(), context);
     event_loop.initialize(&prompt_content);
 
-    // Create CLI executor
-    let backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
-    let executor = CliExecutor::new(backend);
+    // Create CLI executor. The per-adapter timeout caps each iteration's total
+    // runtime; the idle timeout kills a backend that stops producing output.
+    let mut backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
+    backend.apply_env(config.adapter_env(&config.cli.backend));
+    let total_timeout = Duration::from_secs(config.adapter_settings(&config.cli.backend).timeout);
+    let executor = CliExecutor::new(backend)
+        .with_total_timeout(total_timeout)
+        .with_working_dir(workspace.path());
+    let idle_timeout = (config.cli.idle_timeout_secs > 0)
+        .then(|| Duration::from_secs(u64::from(config.cli.idle_timeout_secs)));
 
     // Setup session recording if requested
-    let recorder: Option<Arc<SessionRecorder<BufWriter<File>>>> =
+    let recorder: Option<Arc<SessionRecorder<SessionWriter>>> =
         if let Some(record_path) = record_path {
-            let file = File::create(record_path)
+            let recorder = SessionRecorder::create(record_path)
                 .with_context(|| format!("Failed to create recording file: {:?}", record_path))?;
-            let recorder = Arc::new(SessionRecorder::new(BufWriter::new(file)));
+            let recorder = Arc::new(recorder);
             recorder.record_meta(Record::meta_loop_start(
                 &config.event_loop.prompt_file,
                 config.event_loop.max_iterations,
@@ -392,26 +643,22 @@ async fn run_task_loop(
         };
 
     // Determine if we should capture UX events (requires both flag and recorder)
-    let should_capture_ux = record_ux && recorder.is_some();
+    let capture_mode = record_ux.filter(|_| recorder.is_some());
 
     info!(
         "Running task '{}' with max {} iterations",
         task.name, config.event_loop.max_iterations
     );
 
-    // Change to workspace directory for execution
-    let original_dir = std::env::current_dir()?;
-    std::env::set_current_dir(workspace.path())?;
-
     // Main orchestration loop
-    let termination_reason: TerminationReason;
+    let termination_reason: String;
     let mut consecutive_fallbacks: u32 = 0;
     const MAX_FALLBACK_ATTEMPTS: u32 = 3;
 
     loop {
         // Check termination before execution
         if let Some(reason) = event_loop.check_termination() {
-            termination_reason = reason;
+            termination_reason = format_termination_reason(&reason);
             break;
         }
 
@@ -431,7 +678,7 @@ async fn run_task_loop(
                         "Fallback recovery exhausted after {} attempts, terminating",
                         MAX_FALLBACK_ATTEMPTS
                     );
-                    termination_reason = TerminationReason::Stopped;
+                    termination_reason = format_termination_reason(&TerminationReason::Stopped);
                     break;
                 }
 
@@ -440,7 +687,7 @@ async fn run_task_loop(
                 }
 
                 warn!("No hats with pending events and fallback not available, terminating");
-                termination_reason = TerminationReason::Stopped;
+                termination_reason = format_termination_reason(&TerminationReason::Stopped);
                 break;
             }
         };
@@ -458,17 +705,13 @@ async fn run_task_loop(
         };
 
         // Execute the prompt (capture output but don't print to stdout)
-        // Get per-adapter timeout from config
-        let timeout_secs = config.adapter_settings(&config.cli.backend).timeout;
-        let timeout = Some(Duration::from_secs(timeout_secs));
-
         // Execute with optional UX capture
-        let result = if should_capture_ux {
+        let result = if let Some(mode) = capture_mode {
             // Wrap output buffer with CliCapture to record terminal output
             let mut output_buf = Vec::new();
-            let mut capture = CliCapture::new(&mut output_buf, true);
+            let mut capture = CliCapture::new(&mut output_buf, true).with_mode(mode);
             let result = executor
-                .execute(&prompt, &mut capture, timeout, false)
+                .execute(&prompt, &mut capture, idle_timeout, false)
                 .await?;
 
             // Extract and record UX events
@@ -481,13 +724,22 @@ async fn run_task_loop(
         } else {
             let mut output_buf = Vec::new();
             executor
-                .execute(&prompt, &mut output_buf, timeout, false)
+                .execute(&prompt, &mut output_buf, idle_timeout, false)
                 .await?
         };
 
+        if let Some(reason) = result.timeout_kind.and_then(format_timeout_reason) {
+            warn!(
+                "Task '{}' iteration {} ended by {}",
+                task.name, iteration, reason
+            );
+            termination_reason = reason.to_string();
+            break;
+        }
+
         // Process output
         if let Some(reason) = event_loop.process_output(&hat_id, &result.output, result.success) {
-            termination_reason = reason;
+            termination_reason = format_termination_reason(&reason);
             break;
         }
 
@@ -502,12 +754,9 @@ async fn run_task_loop(
         }
     }
 
-    // Restore original directory
-    std::env::set_current_dir(original_dir)?;
-
     let state = event_loop.state();
     let iterations = state.iteration;
-    let reason_str = format_termination_reason(&termination_reason);
+    let reason_str = termination_reason;
 
     info!(
         "Task '{}' completed: {} iterations, reason: {}",
@@ -533,6 +782,19 @@ fn format_termination_reason(reason: &TerminationReason) -> String {
         TerminationReason::RestartRequested => "RestartRequested".to_string(),
         TerminationReason::WorkspaceGone => "WorkspaceGone".to_string(),
         TerminationReason::Cancelled => "Cancelled".to_string(),
+        TerminationReason::ConfigError { .. } => "ConfigError".to_string(),
+    }
+}
+
+/// Termination reason for an iteration killed by a timeout.
+///
+/// The post-event grace timeout is not a failure: the backend already emitted
+/// its event, so the loop carries on.
+fn format_timeout_reason(kind: TimeoutKind) -> Option<&'static str> {
+    match kind {
+        TimeoutKind::Idle => Some("IdleTimeout"),
+        TimeoutKind::Total => Some("ExecutionTimeout"),
+        TimeoutKind::PostEvent => None,
     }
 }
 
@@ -543,14 +805,11 @@ fn cmd_replay(
     speed: f32,
     step: bool,
     filter: Option<String>,
+    from_iteration: Option<u32>,
+    to_iteration: Option<u32>,
+    export_asciicast: Option<PathBuf>,
 ) -> Result<()> {
-    // Open session file
-    let file = File::open(&session_path)
-        .with_context(|| format!("Failed to open session file: {:?}", session_path))?;
-
-    // Create player
-    let mut player = SessionPlayer::from_reader(BufReader::new(file))
-        .with_context(|| "Failed to parse session file")?;
+    let mut player = open_session(&session_path)?;
 
     info!(
         "Loaded {} records from {:?}",
@@ -563,6 +822,8 @@ fn cmd_replay(
     config.replay_mode = ux_mode.into();
     config.speed = speed;
     config.step_mode = step;
+    config.start_at = from_iteration.map(StartPoint::Iteration);
+    config.end_at = to_iteration.map(StartPoint::Iteration);
 
     if let Some(f) = filter {
         config.event_filter = f.split(',').map(|s| s.trim().to_string()).collect();
@@ -570,6 +831,16 @@ fn cmd_replay(
 
     player = player.with_config(config);
 
+    if let Some(cast_path) = export_asciicast {
+        let file = File::create(&cast_path)
+            .with_context(|| format!("Failed to create cast file: {:?}", cast_path))?;
+        player
+            .export_asciicast(&mut BufWriter::new(file))
+            .with_context(|| "Failed to export asciicast")?;
+        info!("Exported asciicast to {:?}", cast_path);
+        return Ok(());
+    }
+
     // Replay to stdout
     let mut stdout = io::stdout();
     player
@@ -579,13 +850,54 @@ fn cmd_replay(
     Ok(())
 }
 
+/// Opens and parses a recorded session.
+fn open_session(session_path: &Path) -> Result<SessionPlayer> {
+    let file = File::open(session_path)
+        .with_context(|| format!("Failed to open session file: {:?}", session_path))?;
+    SessionPlayer::from_reader(BufReader::new(file))
+        .with_context(|| format!("Failed to parse session file: {:?}", session_path))
+}
+
+/// Print a recorded session's header
+fn cmd_inspect(session_path: &Path) -> Result<()> {
+    let player = open_session(session_path)?;
+    let header = player.header();
+    let unknown = || "unknown".to_string();
+
+    println!("Session: {}", session_path.display());
+    println!("  Format version: {}", player.format_version());
+    println!(
+        "  Prompt file:    {}",
+        header
+            .as_ref()
+            .and_then(|h| h.prompt_file.clone())
+            .unwrap_or_else(unknown)
+    );
+    println!(
+        "  Max iterations: {}",
+        header
+            .as_ref()
+            .and_then(|h| h.max_iterations)
+            .map_or_else(unknown, |n| n.to_string())
+    );
+    println!(
+        "  UX mode:        {}",
+        header
+            .as_ref()
+            .and_then(|h| h.ux_mode.clone())
+            .unwrap_or_else(unknown)
+    );
+    println!("  Records:        {}", player.record_count());
+    Ok(())
+}
+
 /// List sessions or workspaces
 fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
     let search_dir = dir.unwrap_or_else(|| PathBuf::from("."));
 
     match what {
         ListTarget::Sessions => {
-            // List .jsonl files
+            // List .jsonl and .jsonl.gz files
             if !search_dir.exists() {
                 println!("Directory does not exist: {:?}", search_dir);
                 return Ok(());
@@ -593,7 +905,7 @@ fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
 
             let mut sessions: Vec<_> = fs::read_dir(&search_dir)?
                 .filter_map(|e| e.ok())
-                .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+                .filter(|e| is_session_file(&e.path()))
                 .collect();
 
             sessions.sort_by_key(|e| e.file_name());
@@ -606,10 +918,12 @@ fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
                     let path = entry.path();
                     let metadata = entry.metadata().ok();
                     let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+                    let compressed = if is_gzip_path(&path) { ", gzip" } else { "" };
                     println!(
-                        "  {} ({} bytes)",
+                        "  {} ({} bytes{})",
                         path.file_name().unwrap_or_default().to_string_lossy(),
-                        size
+                        size,
+                        compressed
                     );
                 }
             }
@@ -638,10 +952,18 @@ fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Returns true for recorded session files, compressed (`.jsonl.gz`) or not.
+fn is_session_file(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.ends_with(".jsonl") || name.ends_with(".jsonl.gz")
+}
+
 /// Task execution result
 #[derive(Debug, serde::Serialize)]
 struct TaskResult {
     name: String,
+    /// Which repetition of the task this is, from 1 (see `--repeat`).
+    run: u32,
     iterations: u32,
     expected_iterations: Option<u32>,
     /// Difference between actual and expected iterations (iterations - expected).
@@ -650,6 +972,14 @@ struct TaskResult {
     duration_secs: f64,
     termination_reason: String,
     verification_passed: bool,
+    /// Line coverage percentage from `verification.coverage_report`; `null`
+    /// when none is configured or it could not be read.
+    coverage: Option<f64>,
+    /// Why verification failed (`timed_out`, `command_not_found`, ...);
+    /// `null` when it passed.
+    failure_kind: Option<VerificationFailure>,
+    /// The stdout assertion that failed, when `failure_kind` is `output_mismatch`.
+    failed_assertion: Option<String>,
     workspace_path: String,
 }
 
@@ -669,15 +999,109 @@ impl TaskResult {
 
         Self {
             name,
+            run: 1,
             iterations,
             expected_iterations,
             iteration_delta,
             duration_secs,
             termination_reason,
             verification_passed,
+            coverage: None,
+            failure_kind: None,
+            failed_assertion: None,
             workspace_path,
         }
     }
+
+    /// Sets which repetition of the task this is.
+    fn with_run(mut self, run: u32) -> Self {
+        self.run = run;
+        self
+    }
+
+    /// Records the line coverage and failure kind from verification.
+    fn with_verification(mut self, result: &VerificationResult) -> Self {
+        self.coverage = result.coverage;
+        self.failure_kind = result.failure_kind;
+        self.failed_assertion = result.failed_assertion.clone();
+        self
+    }
+}
+
+/// Pass rate and spread across the repeated runs of one task.
+#[derive(Debug, serde::Serialize)]
+struct TaskSummary {
+    name: String,
+    runs: usize,
+    passed: usize,
+    pass_rate: f64,
+    /// The task both passed and failed across its runs.
+    flaky: bool,
+    iterations: Distribution,
+    duration_secs: Distribution,
+}
+
+impl TaskSummary {
+    /// Summarizes `results` per task, in order of each task's first run.
+    fn from_results(results: &[TaskResult]) -> Vec<Self> {
+        let mut names: Vec<&str> = Vec::new();
+        for result in results {
+            if !names.contains(&result.name.as_str()) {
+                names.push(&result.name);
+            }
+        }
+
+        names
+            .into_iter()
+            .map(|name| {
+                let runs: Vec<&TaskResult> = results.iter().filter(|r| r.name == name).collect();
+                let passed = runs.iter().filter(|r| r.verification_passed).count();
+                let pass_rate = passed as f64 / runs.len() as f64;
+                Self {
+                    name: name.to_string(),
+                    runs: runs.len(),
+                    passed,
+                    pass_rate,
+                    flaky: pass_rate > 0.0 && pass_rate < 1.0,
+                    iterations: Distribution::of(runs.iter().map(|r| f64::from(r.iterations))),
+                    duration_secs: Distribution::of(runs.iter().map(|r| r.duration_secs)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Min, median, max and mean of a set of samples.
+#[derive(Debug, Default, PartialEq, serde::Serialize)]
+struct Distribution {
+    min: f64,
+    median: f64,
+    max: f64,
+    mean: f64,
+}
+
+impl Distribution {
+    /// Computes the distribution; all zero when there are no samples.
+    fn of(samples: impl Iterator<Item = f64>) -> Self {
+        let mut samples: Vec<f64> = samples.collect();
+        if samples.is_empty() {
+            return Self::default();
+        }
+        samples.sort_by(f64::total_cmp);
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            f64::midpoint(samples[len / 2 - 1], samples[len / 2])
+        } else {
+            samples[len / 2]
+        };
+        Self {
+            min: samples[0],
+            median,
+            max: samples[len - 1],
+            mean: samples.iter().sum::<f64>() / len as f64,
+        }
+    }
 }
 
 /// Benchmark results output
@@ -685,7 +1109,12 @@ impl TaskResult {
 struct BenchmarkResults {
     run_id: String,
     timestamp: String,
+    /// Runs per task (`--repeat`).
+    repeat: u32,
+    /// One result per task run, in suite order.
     tasks: Vec<TaskResult>,
+    /// Per-task pass rate and spread across its runs.
+    summaries: Vec<TaskSummary>,
 }
 
 /// Generate a timestamp string
@@ -748,6 +1177,14 @@ fn chrono_timestamp() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_session_file_accepts_compressed_sessions() {
+        assert!(is_session_file(Path::new("runs/task.jsonl")));
+        assert!(is_session_file(Path::new("runs/task.jsonl.gz")));
+        assert!(!is_session_file(Path::new("runs/results.json")));
+        assert!(!is_session_file(Path::new("runs/archive.tar.gz")));
+    }
+
     #[test]
     fn test_chrono_timestamp_format() {
         let ts = chrono_timestamp();
@@ -761,4 +1198,163 @@ mod tests {
         assert_eq!(ReplayMode::from(UxMode::Terminal), ReplayMode::Terminal);
         assert_eq!(ReplayMode::from(UxMode::Text), ReplayMode::Text);
     }
+
+    #[test]
+    fn test_run_ux_mode_requires_record_ux() {
+        let args = Args::try_parse_from(["ralph-bench", "run", "tasks.json"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Commands::Run {
+                record_ux: false,
+                ux_mode: UxMode::Terminal,
+                ..
+            }
+        ));
+
+        let args = Args::try_parse_from([
+            "ralph-bench",
+            "run",
+            "tasks.json",
+            "--record-ux",
+            "--ux-mode",
+            "text",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Commands::Run {
+                ux_mode: UxMode::Text,
+                ..
+            }
+        ));
+
+        assert!(
+            Args::try_parse_from(["ralph-bench", "run", "tasks.json", "--ux-mode", "text"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_run_jobs_defaults_to_one_and_rejects_zero() {
+        let args = Args::try_parse_from(["ralph-bench", "run", "tasks.json"]).unwrap();
+        assert!(matches!(args.command, Commands::Run { jobs: 1, .. }));
+
+        let args =
+            Args::try_parse_from(["ralph-bench", "run", "tasks.json", "--jobs", "4"]).unwrap();
+        assert!(matches!(args.command, Commands::Run { jobs: 4, .. }));
+
+        assert!(Args::try_parse_from(["ralph-bench", "run", "tasks.json", "--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_task_summary_reports_pass_rate_and_spread() {
+        let result = |name: &str, iterations, duration_secs, passed| {
+            TaskResult::new(
+                name.to_string(),
+                iterations,
+                None,
+                duration_secs,
+                "CompletionPromise".to_string(),
+                passed,
+                String::new(),
+            )
+        };
+        let results = vec![
+            result("steady", 2, 10.0, true),
+            result("flaky", 3, 1.0, true),
+            result("steady", 4, 30.0, true),
+            result("flaky", 9, 5.0, false),
+            result("flaky", 5, 3.0, false),
+        ];
+
+        let summaries = TaskSummary::from_results(&results);
+        let names: Vec<&str> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["steady", "flaky"]);
+
+        let steady = &summaries[0];
+        assert_eq!((steady.runs, steady.passed), (2, 2));
+        assert!(!steady.flaky);
+        assert_eq!(
+            steady.iterations,
+            Distribution {
+                min: 2.0,
+                median: 3.0,
+                max: 4.0,
+                mean: 3.0
+            }
+        );
+        assert_eq!(
+            steady.duration_secs,
+            Distribution {
+                min: 10.0,
+                median: 20.0,
+                max: 30.0,
+                mean: 20.0
+            }
+        );
+
+        let flaky = &summaries[1];
+        assert_eq!((flaky.runs, flaky.passed), (3, 1));
+        assert!(flaky.flaky);
+        assert!((flaky.pass_rate - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(
+            flaky.duration_secs,
+            Distribution {
+                min: 1.0,
+                median: 3.0,
+                max: 5.0,
+                mean: 3.0
+            }
+        );
+        assert_eq!(
+            Distribution::of(std::iter::empty()),
+            Distribution::default()
+        );
+    }
+
+    #[test]
+    fn test_timeout_reasons_distinguish_idle_and_total() {
+        assert_eq!(
+            format_timeout_reason(TimeoutKind::Idle),
+            Some("IdleTimeout")
+        );
+        assert_eq!(
+            format_timeout_reason(TimeoutKind::Total),
+            Some("ExecutionTimeout")
+        );
+        assert_eq!(format_timeout_reason(TimeoutKind::PostEvent), None);
+    }
+
+    #[test]
+    fn test_failure_gate_counts_failed_verification_and_overshoot() {
+        let result = |name: &str, iterations, passed| {
+            TaskResult::new(
+                name.to_string(),
+                iterations,
+                Some(3),
+                1.0,
+                "CompletionPromise".to_string(),
+                passed,
+                String::new(),
+            )
+        };
+        let results = vec![
+            result("passes", 3, true),
+            result("fails", 3, false),
+            result("slow", 5, true),
+        ];
+
+        let mut gate = FailureGate {
+            allow_failures: 0,
+            require_all: false,
+            iteration_tolerance: 0,
+        };
+        assert_eq!(gate.count_failures(&results), 1);
+
+        gate.require_all = true;
+        assert_eq!(gate.count_failures(&results), 2);
+
+        gate.iteration_tolerance = 2;
+        assert_eq!(gate.count_failures(&results), 1);
+    }
 }
@@ -0,0 +1,223 @@
+//! CLI command for `ralph backends`.
+//!
+//! Shows, for each backend in auto-detection priority order, whether its CLI
+//! is installed, whether it is enabled in config, and which command it runs,
+//! so it is clear why `cli.backend: auto` picks what it picks.
+
+use anyhow::Result;
+use clap::{Parser, ValueEnum};
+use ralph_adapters::{CliBackend, is_backend_available};
+use ralph_core::RalphConfig;
+use serde::Serialize;
+use std::io::Write;
+
+use crate::ConfigSource;
+use crate::display::colors;
+
+/// Output format for `ralph backends`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum BackendsFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+/// Arguments for `ralph backends`.
+#[derive(Parser, Debug)]
+pub struct BackendsArgs {
+    /// Output format (table, json)
+    #[arg(long, value_enum, default_value_t = BackendsFormat::Table)]
+    pub format: BackendsFormat,
+}
+
+/// Detection state of one backend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackendStatus {
+    /// 1-based position in the detection priority order.
+    pub priority: usize,
+    pub name: String,
+    /// Whether the backend's CLI answered `--version`.
+    pub installed: bool,
+    /// Whether auto-detection may pick it (`adapters.<name>.enabled`).
+    pub enabled: bool,
+    /// Command the backend runs.
+    pub command: String,
+    /// Whether `auto` would select this backend.
+    pub selected: bool,
+}
+
+pub fn execute(
+    config_sources: &[ConfigSource],
+    args: BackendsArgs,
+    use_colors: bool,
+) -> Result<()> {
+    let config = crate::load_config_with_overrides(config_sources)?;
+    let statuses = backend_statuses(&config, is_backend_available);
+
+    let mut stdout = std::io::stdout();
+    match args.format {
+        BackendsFormat::Table => write_table(&mut stdout, &config, &statuses, use_colors),
+        BackendsFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &statuses)?;
+            writeln!(stdout)?;
+            Ok(())
+        }
+    }
+}
+
+/// Checks each backend in the config's detection priority order.
+///
+/// `is_installed` is the detection probe (normally `is_backend_available`).
+/// Disabled backends are still probed so the table shows whether they are
+/// installed; like `detect_backend`, only enabled ones can be selected.
+pub fn backend_statuses<F>(config: &RalphConfig, is_installed: F) -> Vec<BackendStatus>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut selected_found = false;
+    config
+        .get_agent_priority()
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let installed = is_installed(name);
+            let enabled = config.adapter_settings(name).enabled;
+            let selected = !selected_found && installed && enabled;
+            selected_found |= selected;
+            BackendStatus {
+                priority: index + 1,
+                name: name.to_string(),
+                installed,
+                enabled,
+                command: CliBackend::from_name(name)
+                    .map(|backend| backend.command)
+                    .unwrap_or_else(|_| "-".to_string()),
+                selected,
+            }
+        })
+        .collect()
+}
+
+fn write_table<W: Write>(
+    writer: &mut W,
+    config: &RalphConfig,
+    statuses: &[BackendStatus],
+    use_colors: bool,
+) -> Result<()> {
+    let (green, dim, reset) = if use_colors {
+        (colors::GREEN, colors::DIM, colors::RESET)
+    } else {
+        ("", "", "")
+    };
+    let yes_no = |value: bool| if value { "yes" } else { "no" };
+
+    let source = if config.agent_priority.is_empty() {
+        "built-in default"
+    } else {
+        "agent_priority"
+    };
+    writeln!(writer, "Detection order ({source}):")?;
+    writeln!(
+        writer,
+        "  {:<3} {:<10} {:<10} {:<8} COMMAND",
+        "#", "BACKEND", "INSTALLED", "ENABLED"
+    )?;
+    writeln!(writer, "{}", "-".repeat(54))?;
+    for status in statuses {
+        let marker = if status.selected { "*" } else { " " };
+        let row = format!(
+            "{marker} {:<3} {:<10} {:<10} {:<8} {}",
+            status.priority,
+            status.name,
+            yes_no(status.installed),
+            yes_no(status.enabled),
+            status.command
+        );
+        if status.selected {
+            writeln!(writer, "{green}{row}{reset}")?;
+        } else if !status.installed || !status.enabled {
+            writeln!(writer, "{dim}{row}{reset}")?;
+        } else {
+            writeln!(writer, "{row}")?;
+        }
+    }
+    writeln!(writer)?;
+
+    let auto_pick = statuses
+        .iter()
+        .find(|s| s.selected)
+        .map(|s| s.name.as_str());
+    match (config.cli.backend.as_str(), auto_pick) {
+        ("auto", Some(name)) => writeln!(writer, "cli.backend: auto → {name} (marked *)")?,
+        ("auto", None) => writeln!(
+            writer,
+            "cli.backend: auto → no enabled backend is installed"
+        )?,
+        (configured, _) => writeln!(
+            writer,
+            "cli.backend: {configured} (auto-detection not used; * marks what auto would pick)"
+        )?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(yaml: &str) -> RalphConfig {
+        RalphConfig::parse_yaml(yaml).unwrap()
+    }
+
+    #[test]
+    fn statuses_reflect_fake_detection_and_config() {
+        let config = config(
+            "cli:\n  backend: auto\nagent_priority: [claude, gemini, codex, kiro]\nadapters:\n  gemini:\n    enabled: false\n",
+        );
+        let installed = ["gemini", "codex", "kiro"];
+
+        let statuses = backend_statuses(&config, |name| installed.contains(&name));
+        let summary: Vec<(&str, bool, bool, bool)> = statuses
+            .iter()
+            .map(|s| (s.name.as_str(), s.installed, s.enabled, s.selected))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("claude", false, true, false),
+                // Installed but disabled: skipped by auto
+                ("gemini", true, false, false),
+                ("codex", true, true, true),
+                ("kiro", true, true, false),
+            ]
+        );
+        assert_eq!(statuses[3].command, "kiro-cli");
+
+        let mut out = Vec::new();
+        write_table(&mut out, &config, &statuses, false).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        assert!(
+            table.contains("Detection order (agent_priority):"),
+            "{table}"
+        );
+        assert!(table.contains("* 3   codex      yes        yes"), "{table}");
+        assert!(table.contains("  2   gemini     yes        no"), "{table}");
+        assert!(table.contains("cli.backend: auto → codex"), "{table}");
+    }
+
+    #[test]
+    fn nothing_installed_selects_nothing() {
+        let config = config("cli:\n  backend: claude\n");
+        let statuses = backend_statuses(&config, |_| false);
+        assert!(statuses.iter().all(|s| !s.selected));
+
+        let mut out = Vec::new();
+        write_table(&mut out, &config, &statuses, false).unwrap();
+        let table = String::from_utf8(out).unwrap();
+        assert!(
+            table.contains("Detection order (built-in default):"),
+            "{table}"
+        );
+        assert!(table.contains("cli.backend: claude"), "{table}");
+    }
+}
@@ -0,0 +1,395 @@
+//! CLI command for `ralph bundle`.
+//!
+//! Packs the artifacts of the current run — config, prompt, event logs,
+//! scratchpad, run manifest, the latest diagnostics session and any session
+//! recordings — into one zip archive that can be attached to a bug report.
+//! Text entries are passed through [`redact_secrets`] on the way in so tokens
+//! and API keys do not leave the machine.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use regex::Regex;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use zip::write::SimpleFileOptions;
+
+use crate::ConfigSource;
+use crate::display::colors;
+
+/// Entries larger than this are reported as large (and dropped with `--exclude-large`).
+const DEFAULT_LARGE_THRESHOLD_MB: u64 = 10;
+
+/// Replacement written in place of a redacted value.
+const REDACTED: &str = "[REDACTED]";
+
+/// Arguments for `ralph bundle`.
+#[derive(Parser, Debug)]
+pub struct BundleArgs {
+    /// Path of the archive to write
+    #[arg(short, long, default_value = "ralph-bundle.zip")]
+    pub output: PathBuf,
+
+    /// Session recording to include (from `ralph run --record-session`; repeatable)
+    #[arg(long = "recording", value_name = "FILE")]
+    pub recordings: Vec<PathBuf>,
+
+    /// Leave out entries larger than `--large-threshold-mb` instead of only warning
+    #[arg(long)]
+    pub exclude_large: bool,
+
+    /// Size in MiB above which an entry counts as large
+    #[arg(long, default_value_t = DEFAULT_LARGE_THRESHOLD_MB, value_name = "MB")]
+    pub large_threshold_mb: u64,
+}
+
+/// A file to put into the archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleEntry {
+    /// Path inside the archive.
+    pub name: String,
+    /// Path on disk.
+    pub source: PathBuf,
+}
+
+/// What ended up in the archive.
+#[derive(Debug, Default)]
+pub struct BundleSummary {
+    /// Archive paths that were written.
+    pub written: Vec<String>,
+    /// Archive paths left out because they exceeded the large threshold.
+    pub excluded: Vec<String>,
+}
+
+pub fn execute(config_sources: &[ConfigSource], args: BundleArgs, use_colors: bool) -> Result<()> {
+    let workspace_root = crate::resolve_workspace_root(None);
+    let config = crate::load_config_with_overrides(config_sources).unwrap_or_else(|err| {
+        eprintln!("Warning: could not load config, bundling default artifact paths: {err:#}");
+        ralph_core::RalphConfig::default()
+    });
+
+    let config_file = config_sources
+        .iter()
+        .find_map(|source| match source {
+            ConfigSource::File(path) => Some(path.clone()),
+            _ => None,
+        })
+        .or_else(|| {
+            config_sources
+                .iter()
+                .all(|source| matches!(source, ConfigSource::Override { .. }))
+                .then(crate::default_config_path)
+        });
+
+    let mut entries = collect_entries(
+        &workspace_root,
+        config_file.as_deref(),
+        &config.event_loop.prompt_file,
+        &config.core.scratchpad.path,
+    );
+    for recording in &args.recordings {
+        if !recording.is_file() {
+            anyhow::bail!("Recording not found: {}", recording.display());
+        }
+        entries.push(BundleEntry {
+            name: format!("recordings/{}", file_name(recording)),
+            source: recording.clone(),
+        });
+    }
+
+    if entries.is_empty() {
+        anyhow::bail!(
+            "Nothing to bundle: no Ralph artifacts found under {}",
+            workspace_root.display()
+        );
+    }
+
+    let threshold = args.large_threshold_mb.saturating_mul(1024 * 1024);
+    let summary = write_bundle(&args.output, &entries, threshold, args.exclude_large)?;
+
+    let (green, dim, reset) = if use_colors {
+        (colors::GREEN, colors::DIM, colors::RESET)
+    } else {
+        ("", "", "")
+    };
+    let mut stdout = std::io::stdout();
+    for name in &summary.written {
+        writeln!(stdout, "  {dim}+{reset} {name}")?;
+    }
+    for name in &summary.excluded {
+        writeln!(stdout, "  {dim}- {name} (excluded: large){reset}")?;
+    }
+    writeln!(
+        stdout,
+        "{green}✓{reset} Wrote {} ({} entries, secrets redacted)",
+        args.output.display(),
+        summary.written.len()
+    )?;
+    Ok(())
+}
+
+/// Lists the run artifacts that exist under `workspace_root`.
+///
+/// Relative `config_file`, `prompt_file` and `scratchpad` paths are resolved
+/// against the workspace root; missing files are skipped.
+pub fn collect_entries(
+    workspace_root: &Path,
+    config_file: Option<&Path>,
+    prompt_file: &str,
+    scratchpad: &str,
+) -> Vec<BundleEntry> {
+    let mut entries = Vec::new();
+    let mut push = |name: String, source: PathBuf| {
+        if source.is_file() {
+            entries.push(BundleEntry { name, source });
+        }
+    };
+
+    if let Some(config_file) = config_file {
+        let path = workspace_root.join(config_file);
+        push(format!("config/{}", file_name(&path)), path);
+    }
+    if !prompt_file.is_empty() {
+        let path = workspace_root.join(prompt_file);
+        push(format!("prompt/{}", file_name(&path)), path);
+    }
+
+    let events = crate::resolve_events_path(workspace_root, None);
+    let system_events = ralph_core::system_events_path(&events);
+    push(format!("events/{}", file_name(&events)), events);
+    push(
+        format!("events/{}", file_name(&system_events)),
+        system_events,
+    );
+
+    push(
+        "agent/scratchpad.md".to_string(),
+        workspace_root.join(scratchpad),
+    );
+    push(
+        "agent/run-manifest.json".to_string(),
+        workspace_root.join(".ralph/agent/run-manifest.json"),
+    );
+
+    if let Some(session) =
+        crate::diagnostics_cli::latest_session(&workspace_root.join(".ralph/diagnostics"))
+    {
+        let session_name = file_name(&session);
+        let mut files: Vec<PathBuf> = fs::read_dir(&session)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        files.sort();
+        for file in files {
+            push(
+                format!("diagnostics/{session_name}/{}", file_name(&file)),
+                file,
+            );
+        }
+    }
+
+    entries
+}
+
+/// Writes `entries` to a zip archive at `output`, redacting text entries.
+///
+/// Entries larger than `large_threshold` bytes trigger a warning and are left
+/// out when `exclude_large` is set.
+pub fn write_bundle(
+    output: &Path,
+    entries: &[BundleEntry],
+    large_threshold: u64,
+    exclude_large: bool,
+) -> Result<BundleSummary> {
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let file = fs::File::create(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut summary = BundleSummary::default();
+    for entry in entries {
+        let size = fs::metadata(&entry.source).map_or(0, |meta| meta.len());
+        if size > large_threshold {
+            if exclude_large {
+                eprintln!(
+                    "Warning: excluding {} from bundle: {size} bytes exceeds the large threshold",
+                    entry.source.display()
+                );
+                summary.excluded.push(entry.name.clone());
+                continue;
+            }
+            eprintln!(
+                "Warning: {} is large ({size} bytes); pass --exclude-large to leave it out",
+                entry.source.display()
+            );
+        }
+
+        let bytes = fs::read(&entry.source)
+            .with_context(|| format!("Failed to read {}", entry.source.display()))?;
+        let bytes = match String::from_utf8(bytes) {
+            Ok(text) => redact_secrets(&text).into_bytes(),
+            Err(err) => err.into_bytes(),
+        };
+
+        zip.start_file(entry.name.as_str(), options)?;
+        zip.write_all(&bytes)?;
+        summary.written.push(entry.name.clone());
+    }
+    zip.finish()?;
+    Ok(summary)
+}
+
+/// Values of keys that look like credentials (`bot_token: ...`, `"api_key": "..."`).
+static SECRET_ASSIGNMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)("?[\w.-]*(?:token|secret|password|passwd|api[_-]?key|authorization)"?\s*[:=]\s*)("[^"]*"|'[^']*'|[^\s,}\]]+)"#,
+    )
+    .expect("secret assignment regex")
+});
+
+/// Well-known credential shapes that may appear outside a key/value pair.
+static SECRET_VALUE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(?:sk-[A-Za-z0-9_-]{20,}|gh[pousr]_[A-Za-z0-9]{30,}|xox[abpr]-[A-Za-z0-9-]{10,}|AKIA[0-9A-Z]{16}|\d{8,10}:[A-Za-z0-9_-]{35})\b",
+    )
+    .expect("secret value regex")
+});
+
+/// Replaces credential-looking values in `text` with `[REDACTED]`.
+///
+/// Catches values assigned to keys named like tokens, secrets, passwords or
+/// API keys, plus common token formats (OpenAI/Anthropic `sk-`, GitHub,
+/// Slack, AWS access keys, Telegram bot tokens) wherever they appear.
+pub fn redact_secrets(text: &str) -> String {
+    let text = SECRET_ASSIGNMENT.replace_all(text, |caps: &regex::Captures<'_>| {
+        let value = &caps[2];
+        let quote = match value.chars().next() {
+            Some(q @ ('"' | '\'')) => q.to_string(),
+            _ => String::new(),
+        };
+        format!("{}{quote}{REDACTED}{quote}", &caps[1])
+    });
+    SECRET_VALUE.replace_all(&text, REDACTED).into_owned()
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    const BOT_TOKEN: &str = "123456789:AAHdqTcvCH1vGWJxfSeofSAs0K5PALDsawA";
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn read_archive(path: &Path) -> Vec<(String, String)> {
+        let mut archive = zip::ZipArchive::new(fs::File::open(path).unwrap()).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut content = String::new();
+                file.read_to_string(&mut content).unwrap();
+                (file.name().to_string(), content)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bundle_collects_run_artifacts_and_redacts_secrets() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            "ralph.yml",
+            &format!(
+                "cli:\n  backend: claude\nevent_loop:\n  max_tokens: 4000\nRObot:\n  telegram:\n    bot_token: \"{BOT_TOKEN}\"\n"
+            ),
+        );
+        write(root, "PROMPT.md", "Fix the login bug\n");
+        write(
+            root,
+            ".ralph/current-events",
+            ".ralph/events-20260101-000000.jsonl",
+        );
+        write(
+            root,
+            ".ralph/events-20260101-000000.jsonl",
+            "{\"topic\":\"build.done\",\"payload\":\"export OPENAI_API_KEY=sk-abcdefghijklmnopqrstuvwxyz123456\"}\n",
+        );
+        write(root, ".ralph/agent/scratchpad.md", "- [ ] task\n");
+        write(root, ".ralph/agent/run-manifest.json", "{\"seed\":7}\n");
+        write(
+            root,
+            ".ralph/diagnostics/2026-01-01T00-00-00/errors.jsonl",
+            "{}\n",
+        );
+        write(
+            root,
+            ".ralph/diagnostics/2026-01-02T00-00-00/orchestration.jsonl",
+            "{}\n",
+        );
+        write(root, "session.jsonl", &"x".repeat(2048));
+
+        let mut entries = collect_entries(
+            root,
+            Some(Path::new("ralph.yml")),
+            "PROMPT.md",
+            ".ralph/agent/scratchpad.md",
+        );
+        entries.push(BundleEntry {
+            name: "recordings/session.jsonl".to_string(),
+            source: root.join("session.jsonl"),
+        });
+
+        let output = root.join("out/repro.zip");
+        let summary = write_bundle(&output, &entries, 1024, true).unwrap();
+        assert_eq!(summary.excluded, vec!["recordings/session.jsonl"]);
+
+        let archive = read_archive(&output);
+        let names: Vec<&str> = archive.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "config/ralph.yml",
+                "prompt/PROMPT.md",
+                "events/events-20260101-000000.jsonl",
+                "agent/scratchpad.md",
+                "agent/run-manifest.json",
+                "diagnostics/2026-01-02T00-00-00/orchestration.jsonl",
+            ]
+        );
+
+        let all: String = archive
+            .iter()
+            .map(|(_, content)| content.as_str())
+            .collect();
+        assert!(!all.contains(BOT_TOKEN), "telegram token leaked: {all}");
+        assert!(!all.contains("sk-abcdefghij"), "api key leaked: {all}");
+        assert!(all.contains("bot_token: \"[REDACTED]\""));
+        assert!(
+            all.contains("max_tokens: 4000"),
+            "non-secret redacted: {all}"
+        );
+        assert!(all.contains("Fix the login bug"));
+    }
+}
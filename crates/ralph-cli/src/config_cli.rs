@@ -0,0 +1,124 @@
+//! CLI commands for the `ralph config` namespace.
+//!
+//! Subcommands:
+//! - `migrate`: Rewrite v1 flat config fields into the canonical v2 nested form
+
+use anyhow::{Context, Result, bail};
+use clap::{Parser, Subcommand};
+use ralph_core::migrate_v1_config;
+use similar::{ChangeTag, TextDiff};
+use std::path::PathBuf;
+
+use crate::display::colors;
+use crate::{ConfigSource, config_resolution, default_config_path};
+
+/// Inspect and maintain configuration files.
+#[derive(Parser, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Migrate v1 flat fields (agent, max_iterations, ...) to v2 nested fields
+    Migrate(MigrateArgs),
+}
+
+/// Arguments for `ralph config migrate`.
+#[derive(Parser, Debug)]
+pub struct MigrateArgs {
+    /// Rewrite the config file in place (default: print a diff)
+    #[arg(long)]
+    pub write: bool,
+}
+
+pub fn execute(config_sources: &[ConfigSource], args: ConfigArgs, use_colors: bool) -> Result<()> {
+    match args.command {
+        ConfigCommands::Migrate(args) => migrate_command(config_sources, &args, use_colors),
+    }
+}
+
+fn migrate_command(
+    config_sources: &[ConfigSource],
+    args: &MigrateArgs,
+    use_colors: bool,
+) -> Result<()> {
+    let path = config_file_path(config_sources)?;
+    let label = path.display().to_string();
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read config from {label}"))?;
+    let migration = migrate_v1_config(&content)
+        .with_context(|| format!("Failed to migrate config from {label}"))?;
+
+    if migration.is_noop() {
+        println!("{label} has no v1 fields; nothing to migrate.");
+        return Ok(());
+    }
+
+    if !migration.preserved_formatting {
+        eprintln!(
+            "Note: {label} could not be rewritten in place; the migrated file is re-serialized and comments are dropped."
+        );
+    }
+
+    if args.write {
+        std::fs::write(&path, &migration.migrated)
+            .with_context(|| format!("Failed to write {label}"))?;
+        println!("Migrated {label}:");
+        for change in &migration.changes {
+            println!("  - {change}");
+        }
+    } else {
+        print_diff(&label, &content, &migration.migrated, use_colors);
+        println!();
+        println!("Run `ralph config migrate --write` to apply these changes.");
+    }
+
+    Ok(())
+}
+
+/// Resolves the local config file to migrate from the `-c` sources.
+fn config_file_path(config_sources: &[ConfigSource]) -> Result<PathBuf> {
+    let (primary_sources, _) = config_resolution::split_config_sources(config_sources);
+    let path = match primary_sources.into_iter().next() {
+        Some(ConfigSource::File(path)) => path,
+        Some(other) => bail!(
+            "`ralph config migrate` only works on local config files, not {}",
+            other.to_cli_string()
+        ),
+        None => default_config_path(),
+    };
+    if !path.exists() {
+        bail!("Config file {} not found", path.display());
+    }
+    Ok(path)
+}
+
+fn print_diff(label: &str, old: &str, new: &str, use_colors: bool) {
+    let diff = TextDiff::from_lines(old, new);
+    let (red, green, cyan, reset) = if use_colors {
+        (colors::RED, colors::GREEN, colors::CYAN, colors::RESET)
+    } else {
+        ("", "", "", "")
+    };
+
+    println!("--- {label}");
+    println!("+++ {label} (migrated)");
+    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+        println!("{cyan}{}{reset}", hunk.header());
+        for change in hunk.iter_changes() {
+            let (sign, color) = match change.tag() {
+                ChangeTag::Delete => ("-", red),
+                ChangeTag::Insert => ("+", green),
+                ChangeTag::Equal => (" ", ""),
+            };
+            let line = change.value().trim_end_matches('\n');
+            if color.is_empty() {
+                println!("{sign}{line}");
+            } else {
+                println!("{color}{sign}{line}{reset}");
+            }
+        }
+    }
+}
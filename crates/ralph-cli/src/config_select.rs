@@ -0,0 +1,215 @@
+//! Choosing between conflicting primary config sources.
+//!
+//! Passing more than one primary `-c` source (a file or URL, as opposed to a
+//! `core.*` override) is ambiguous. On a terminal the user picks one source or
+//! aborts; otherwise the sources are left alone, and config loading warns and
+//! uses the first one so scripts keep working.
+
+use crate::ConfigSource;
+use anyhow::{Result, bail};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+use std::io::{IsTerminal, Write};
+
+/// Reduces `config_sources` to one primary source plus its overrides,
+/// prompting the user when several primary sources were given interactively.
+pub(crate) fn resolve_conflicting_sources(
+    config_sources: Vec<ConfigSource>,
+) -> Result<Vec<ConfigSource>> {
+    let labels: Vec<String> = config_sources
+        .iter()
+        .filter(|source| is_primary(source))
+        .map(ConfigSource::to_cli_string)
+        .collect();
+    if labels.len() <= 1 || !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        return Ok(config_sources);
+    }
+
+    match prompt_for_source(&labels)? {
+        Some(choice) => Ok(keep_primary(config_sources, choice)),
+        None => bail!("Aborted: no config source selected"),
+    }
+}
+
+fn is_primary(source: &ConfigSource) -> bool {
+    !matches!(source, ConfigSource::Override { .. })
+}
+
+/// Drops every primary source except the `choice`-th, keeping overrides.
+fn keep_primary(config_sources: Vec<ConfigSource>, choice: usize) -> Vec<ConfigSource> {
+    let mut primary_index = 0;
+    config_sources
+        .into_iter()
+        .filter(|source| {
+            if !is_primary(source) {
+                return true;
+            }
+            primary_index += 1;
+            primary_index - 1 == choice
+        })
+        .collect()
+}
+
+/// What a key press does in the source picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PickerAction {
+    /// The highlighted source changed; redraw.
+    Moved,
+    Select(usize),
+    Abort,
+    Ignore,
+}
+
+/// Highlighted entry of a list of `len` sources.
+struct SourcePicker {
+    selected: usize,
+    len: usize,
+}
+
+impl SourcePicker {
+    fn new(len: usize) -> Self {
+        Self { selected: 0, len }
+    }
+
+    /// Arrow keys (or `j`/`k`) move, Enter or a digit selects, and Esc, `q`
+    /// or Ctrl-C abort.
+    fn handle_key(&mut self, key: KeyEvent) -> PickerAction {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                PickerAction::Abort
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.checked_sub(1).unwrap_or(self.len - 1);
+                PickerAction::Moved
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % self.len;
+                PickerAction::Moved
+            }
+            KeyCode::Enter => PickerAction::Select(self.selected),
+            KeyCode::Esc | KeyCode::Char('q') => PickerAction::Abort,
+            KeyCode::Char(c) => match c.to_digit(10).map(|n| n as usize) {
+                Some(n) if (1..=self.len).contains(&n) => PickerAction::Select(n - 1),
+                _ => PickerAction::Ignore,
+            },
+            _ => PickerAction::Ignore,
+        }
+    }
+}
+
+/// Shows the picker on stderr. Returns the chosen index, or `None` on abort.
+fn prompt_for_source(labels: &[String]) -> Result<Option<usize>> {
+    let mut stderr = std::io::stderr();
+    writeln!(
+        stderr,
+        "Multiple config sources given; choose one (↑/↓ then Enter, or 1-{}; Esc aborts):",
+        labels.len()
+    )?;
+
+    terminal::enable_raw_mode()?;
+    let choice = run_picker(&mut stderr, labels);
+    terminal::disable_raw_mode()?;
+    choice
+}
+
+fn run_picker(out: &mut impl Write, labels: &[String]) -> Result<Option<usize>> {
+    let mut picker = SourcePicker::new(labels.len());
+    render(out, labels, picker.selected)?;
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match picker.handle_key(key) {
+            PickerAction::Moved => {
+                execute!(
+                    out,
+                    cursor::MoveUp(labels.len() as u16),
+                    terminal::Clear(terminal::ClearType::FromCursorDown)
+                )?;
+                render(out, labels, picker.selected)?;
+            }
+            PickerAction::Select(choice) => return Ok(Some(choice)),
+            PickerAction::Abort => return Ok(None),
+            PickerAction::Ignore => {}
+        }
+    }
+}
+
+fn render(out: &mut impl Write, labels: &[String], selected: usize) -> Result<()> {
+    for (index, label) in labels.iter().enumerate() {
+        let marker = if index == selected { '>' } else { ' ' };
+        // Raw mode: newlines do not return the carriage
+        write!(out, "{marker} {}. {label}\r\n", index + 1)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn picker_wraps_and_selects() {
+        let mut picker = SourcePicker::new(3);
+        assert_eq!(picker.handle_key(key(KeyCode::Up)), PickerAction::Moved);
+        assert_eq!(picker.selected, 2);
+        assert_eq!(picker.handle_key(key(KeyCode::Down)), PickerAction::Moved);
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Char('j'))),
+            PickerAction::Moved
+        );
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            PickerAction::Select(1)
+        );
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Char('3'))),
+            PickerAction::Select(2)
+        );
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Char('4'))),
+            PickerAction::Ignore
+        );
+    }
+
+    #[test]
+    fn picker_aborts_on_escape_q_and_ctrl_c() {
+        let mut picker = SourcePicker::new(2);
+        assert_eq!(picker.handle_key(key(KeyCode::Esc)), PickerAction::Abort);
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Char('q'))),
+            PickerAction::Abort
+        );
+        assert_eq!(
+            picker.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            PickerAction::Abort
+        );
+    }
+
+    #[test]
+    fn keep_primary_drops_other_primaries_but_keeps_overrides() {
+        let sources = vec![
+            ConfigSource::File(PathBuf::from("a.yml")),
+            ConfigSource::Override {
+                key: "core.specs_dir".to_string(),
+                value: "specs".to_string(),
+            },
+            ConfigSource::Remote("https://example.com/b.yml".to_string()),
+        ];
+
+        let kept: Vec<String> = keep_primary(sources, 1)
+            .iter()
+            .map(ConfigSource::to_cli_string)
+            .collect();
+        assert_eq!(kept, ["core.specs_dir=specs", "https://example.com/b.yml"]);
+    }
+}
@@ -0,0 +1,173 @@
+//! CLI commands for the `ralph diagnostics` namespace.
+//!
+//! Subcommands:
+//! - `show`: Pretty-print a session's `summary.json`
+
+use anyhow::{Context, Result, bail};
+use clap::{Parser, Subcommand};
+use ralph_core::diagnostics::{RunSummary, SUMMARY_FILE};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::display::colors;
+
+/// Inspect diagnostics sessions (`RALPH_DIAGNOSTICS=1`).
+#[derive(Parser, Debug)]
+pub struct DiagnosticsArgs {
+    #[command(subcommand)]
+    pub command: DiagnosticsCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DiagnosticsCommands {
+    /// Show the run summary of a diagnostics session
+    Show(ShowArgs),
+}
+
+/// Arguments for `ralph diagnostics show`.
+#[derive(Parser, Debug)]
+pub struct ShowArgs {
+    /// Session directory name under .ralph/diagnostics (default: latest)
+    pub session: Option<String>,
+
+    /// Print the raw summary JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub fn execute(args: DiagnosticsArgs, use_colors: bool) -> Result<()> {
+    match args.command {
+        DiagnosticsCommands::Show(args) => show_command(&args, use_colors),
+    }
+}
+
+fn show_command(args: &ShowArgs, use_colors: bool) -> Result<()> {
+    let workspace_root = crate::resolve_workspace_root(None);
+    let diagnostics_dir = workspace_root.join(".ralph/diagnostics");
+    let session_dir = match &args.session {
+        Some(session) => diagnostics_dir.join(session),
+        None => latest_session(&diagnostics_dir).with_context(|| {
+            format!(
+                "No diagnostics sessions in {} (run with RALPH_DIAGNOSTICS=1)",
+                diagnostics_dir.display()
+            )
+        })?,
+    };
+    if !session_dir.is_dir() {
+        bail!("Diagnostics session {} not found", session_dir.display());
+    }
+    let summary = RunSummary::load(&session_dir).with_context(|| {
+        format!(
+            "No readable {SUMMARY_FILE} in {} (the run may still be in progress)",
+            session_dir.display()
+        )
+    })?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        let session = session_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        print!("{}", format_summary(&session, &summary, use_colors));
+    }
+    Ok(())
+}
+
+/// Most recent `<timestamp>` session directory under `diagnostics_dir`.
+///
+/// Session names are `YYYY-MM-DDTHH-MM-SS`, so the greatest name is the newest.
+pub(crate) fn latest_session(diagnostics_dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(diagnostics_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_dir())
+        .max()
+}
+
+/// Renders a summary as an aligned, human-readable report.
+fn format_summary(session: &str, summary: &RunSummary, use_colors: bool) -> String {
+    let (bold, dim, reset) = if use_colors {
+        (colors::BOLD, colors::DIM, colors::RESET)
+    } else {
+        ("", "", "")
+    };
+    let mut out = String::new();
+    let _ = writeln!(out, "{bold}Diagnostics session {session}{reset}");
+    let _ = writeln!(out, "  Iterations:            {}", summary.iterations);
+    let _ = writeln!(
+        out,
+        "  Termination:           {}",
+        summary.termination_reason.as_deref().unwrap_or("-")
+    );
+    let _ = writeln!(
+        out,
+        "  Backpressure triggers: {}",
+        summary.backpressure_triggers
+    );
+
+    for (title, counts) in [
+        ("Hat activations", &summary.hat_activations),
+        ("Events by topic", &summary.topic_counts),
+    ] {
+        let _ = writeln!(out, "\n{bold}{title}{reset}");
+        if counts.is_empty() {
+            let _ = writeln!(out, "  {dim}(none){reset}");
+            continue;
+        }
+        let width = counts.keys().map(String::len).max().unwrap_or(0);
+        let mut rows: Vec<_> = counts.iter().collect();
+        rows.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        for (name, count) in rows {
+            let _ = writeln!(out, "  {name:<width$}  {count}");
+        }
+    }
+
+    let _ = writeln!(out, "\n{bold}Abandoned tasks{reset}");
+    if summary.abandoned_tasks.is_empty() {
+        let _ = writeln!(out, "  {dim}(none){reset}");
+    }
+    for reason in &summary.abandoned_tasks {
+        let _ = writeln!(out, "  - {reason}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_summary_lists_counts_by_frequency() {
+        let mut summary = RunSummary {
+            iterations: 4,
+            backpressure_triggers: 1,
+            termination_reason: Some("completed".to_string()),
+            abandoned_tasks: vec!["blocked 3 times".to_string()],
+            ..RunSummary::default()
+        };
+        summary.hat_activations.insert("ralph".to_string(), 1);
+        summary.hat_activations.insert("builder".to_string(), 3);
+
+        let out = format_summary("2026-01-01T00-00-00", &summary, false);
+
+        assert!(out.contains("Iterations:            4"));
+        assert!(out.contains("Termination:           completed"));
+        assert!(out.contains("  builder  3\n  ralph    1\n"), "{out}");
+        assert!(out.contains("Events by topic\n  (none)"));
+        assert!(out.contains("  - blocked 3 times"));
+    }
+
+    #[test]
+    fn test_latest_session_picks_newest_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        for name in ["2026-01-01T00-00-00", "2026-01-02T00-00-00"] {
+            std::fs::create_dir_all(temp.path().join(name)).unwrap();
+        }
+        std::fs::write(temp.path().join("zzz.txt"), "").unwrap();
+
+        let latest = latest_session(temp.path()).unwrap();
+        assert_eq!(latest.file_name().unwrap(), "2026-01-02T00-00-00");
+    }
+}
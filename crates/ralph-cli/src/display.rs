@@ -6,7 +6,8 @@
 
 use ralph_core::{EventRecord, TerminationReason, floor_char_boundary, truncate_with_ellipsis};
 use ralph_proto::HatId;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::time::Duration;
 
@@ -252,6 +253,7 @@ pub fn print_termination(
         TerminationReason::RestartRequested => (CYAN, "↻", "Restarting by human request"),
         TerminationReason::WorkspaceGone => (RED, "?", "Workspace directory removed"),
         TerminationReason::Cancelled => (CYAN, "⏹", "Cancelled gracefully"),
+        TerminationReason::ConfigError { .. } => (RED, "?", "Configuration error"),
     };
 
     let separator = "-".repeat(58);
@@ -323,7 +325,24 @@ pub fn get_topic_color(topic: &str) -> &'static str {
 pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
     use colors::*;
 
-    // Header
+    print_events_table_header(use_colors);
+
+    for (i, record) in records.iter().enumerate() {
+        print_event_row(i + 1, record, use_colors);
+    }
+
+    // Footer
+    if use_colors {
+        println!("\n{DIM}Total: {} events{RESET}", records.len());
+    } else {
+        println!("\nTotal: {} events", records.len());
+    }
+}
+
+/// Prints the column header used by the events table and `ralph events --follow`.
+pub fn print_events_table_header(use_colors: bool) {
+    use colors::*;
+
     if use_colors {
         println!(
             "{BOLD}{DIM}  # | Time     | Iteration | Hat           | Topic              | Triggered      | Payload{RESET}"
@@ -339,62 +358,193 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
             "----|----------|-----------|---------------|--------------------|-----------------|-----------------"
         );
     }
+}
 
-    for (i, record) in records.iter().enumerate() {
-        let topic_color = get_topic_color(&record.topic);
-        let triggered = record.triggered.as_deref().unwrap_or("-");
-        let payload_one_line = record.payload.replace('\n', " ");
-        let payload_preview = truncate_with_ellipsis(&payload_one_line, 40);
-
-        // Extract time portion (HH:MM:SS) from ISO 8601 timestamp
-        let time = record
-            .ts
-            .find('T')
-            .and_then(|t_pos| {
-                let after_t = &record.ts[t_pos + 1..];
-                // Find end of time (before timezone indicator or end of string)
-                let end = after_t
-                    .find(|c| c == 'Z' || c == '+' || c == '-')
-                    .unwrap_or(after_t.len());
-                let time_str = &after_t[..end];
-                // Take only HH:MM:SS (usually ASCII), but still ensure we slice on a valid UTF-8
-                // boundary for robustness. Otherwise, an unexpected `ts` (e.g. CJK/emoji) can make
-                // `&s[..N]` panic.
-                let boundary = floor_char_boundary(time_str, 8);
-                Some(&time_str[..boundary])
-            })
-            .unwrap_or("-");
+/// Prints a single events table row with the given 1-based row number.
+pub fn print_event_row(number: usize, record: &EventRecord, use_colors: bool) {
+    use colors::*;
 
-        if use_colors {
-            println!(
-                "{DIM}{:>3}{RESET} | {:<8} | {:>9} | {:<13} | {topic_color}{:<18}{RESET} | {:<14} | {DIM}{}{RESET}",
-                i + 1,
-                time,
-                record.iteration,
-                truncate(&record.hat, 13),
-                truncate(&record.topic, 18),
-                truncate(triggered, 14),
-                payload_preview
-            );
-        } else {
-            println!(
-                "{:>3} | {:<8} | {:>9} | {:<13} | {:<18} | {:<14} | {}",
-                i + 1,
-                time,
-                record.iteration,
-                truncate(&record.hat, 13),
-                truncate(&record.topic, 18),
-                truncate(triggered, 14),
-                payload_preview
-            );
+    let topic_color = get_topic_color(&record.topic);
+    let triggered = record.triggered.as_deref().unwrap_or("-");
+    let payload_one_line = record.payload.replace('\n', " ");
+    let payload_preview = truncate_with_ellipsis(&payload_one_line, 40);
+
+    // Extract time portion (HH:MM:SS) from ISO 8601 timestamp
+    let time = record
+        .ts
+        .find('T')
+        .and_then(|t_pos| {
+            let after_t = &record.ts[t_pos + 1..];
+            // Find end of time (before timezone indicator or end of string)
+            let end = after_t
+                .find(|c| c == 'Z' || c == '+' || c == '-')
+                .unwrap_or(after_t.len());
+            let time_str = &after_t[..end];
+            // Take only HH:MM:SS (usually ASCII), but still ensure we slice on a valid UTF-8
+            // boundary for robustness. Otherwise, an unexpected `ts` (e.g. CJK/emoji) can make
+            // `&s[..N]` panic.
+            let boundary = floor_char_boundary(time_str, 8);
+            Some(&time_str[..boundary])
+        })
+        .unwrap_or("-");
+
+    if use_colors {
+        println!(
+            "{DIM}{:>3}{RESET} | {:<8} | {:>9} | {:<13} | {topic_color}{:<18}{RESET} | {:<14} | {DIM}{}{RESET}",
+            number,
+            time,
+            record.iteration,
+            truncate(&record.hat, 13),
+            truncate(&record.topic, 18),
+            truncate(triggered, 14),
+            payload_preview
+        );
+    } else {
+        println!(
+            "{:>3} | {:<8} | {:>9} | {:<13} | {:<18} | {:<14} | {}",
+            number,
+            time,
+            record.iteration,
+            truncate(&record.hat, 13),
+            truncate(&record.topic, 18),
+            truncate(triggered, 14),
+            payload_preview
+        );
+    }
+}
+
+/// Header row for CSV event export.
+pub const EVENTS_CSV_HEADER: &str = "ts,iteration,topic,payload";
+
+/// Prints event records as CSV (RFC 4180) with a header row.
+pub fn print_events_csv(records: &[EventRecord]) {
+    println!("{EVENTS_CSV_HEADER}");
+    for record in records {
+        println!("{}", format_event_csv_row(record));
+    }
+}
+
+/// Formats a single event record as a CSV row.
+///
+/// The payload column holds the payload as a serialized JSON value: structured
+/// payloads (objects/arrays) are emitted as-is, anything else as a JSON string,
+/// so nested data survives a round trip through a spreadsheet or CSV parser.
+pub fn format_event_csv_row(record: &EventRecord) -> String {
+    let payload = match serde_json::from_str::<serde_json::Value>(&record.payload) {
+        Ok(value @ (serde_json::Value::Object(_) | serde_json::Value::Array(_))) => value,
+        _ => serde_json::Value::String(record.payload.clone()),
+    };
+
+    [
+        csv_field(&record.ts),
+        record.iteration.to_string(),
+        csv_field(&record.topic),
+        csv_field(&payload.to_string()),
+    ]
+    .join(",")
+}
+
+/// Quotes a CSV field per RFC 4180 when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Distribution of a set of event records, for `ralph events --stats`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventStats {
+    pub total: usize,
+    pub topics: BTreeMap<String, usize>,
+    pub iterations: BTreeMap<u32, usize>,
+    /// Earliest and latest parseable timestamps.
+    pub first_ts: Option<String>,
+    pub last_ts: Option<String>,
+    /// Seconds between `first_ts` and `last_ts`.
+    pub span_secs: Option<f64>,
+}
+
+impl EventStats {
+    /// Counts `records` per topic and per iteration and measures their time span.
+    ///
+    /// Records with unparseable timestamps are counted but don't affect the span.
+    pub fn from_records(records: &[EventRecord]) -> Self {
+        let mut topics = BTreeMap::new();
+        let mut iterations = BTreeMap::new();
+        let mut first: Option<(chrono::DateTime<chrono::FixedOffset>, &str)> = None;
+        let mut last = first;
+
+        for record in records {
+            *topics.entry(record.topic.clone()).or_default() += 1;
+            *iterations.entry(record.iteration).or_default() += 1;
+
+            let Ok(ts) = chrono::DateTime::parse_from_rfc3339(&record.ts) else {
+                continue;
+            };
+            if first.is_none_or(|(earliest, _)| ts < earliest) {
+                first = Some((ts, &record.ts));
+            }
+            if last.is_none_or(|(latest, _)| ts > latest) {
+                last = Some((ts, &record.ts));
+            }
+        }
+
+        let span_secs = first
+            .zip(last)
+            .map(|((start, _), (end, _))| (end - start).num_milliseconds() as f64 / 1000.0);
+        Self {
+            total: records.len(),
+            topics,
+            iterations,
+            first_ts: first.map(|(_, raw)| raw.to_string()),
+            last_ts: last.map(|(_, raw)| raw.to_string()),
+            span_secs,
         }
     }
+}
 
-    // Footer
-    if use_colors {
-        println!("\n{DIM}Total: {} events{RESET}", records.len());
+/// Prints event counts per topic (most frequent first) and per iteration,
+/// followed by the total and time span.
+pub fn print_event_stats(stats: &EventStats, use_colors: bool) {
+    use colors::*;
+
+    let (bold, dim, reset) = if use_colors {
+        (BOLD, DIM, RESET)
     } else {
-        println!("\nTotal: {} events", records.len());
+        ("", "", "")
+    };
+
+    let mut topics: Vec<_> = stats.topics.iter().collect();
+    topics.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    println!("{bold}Topic                          | Count{reset}");
+    println!("{dim}-------------------------------+------{reset}");
+    for (topic, count) in topics {
+        let topic_color = if use_colors {
+            get_topic_color(topic)
+        } else {
+            ""
+        };
+        println!(
+            "{topic_color}{:<30}{reset} | {count:>5}",
+            truncate(topic, 30)
+        );
+    }
+
+    println!("\n{bold}Iteration | Count{reset}");
+    println!("{dim}----------+------{reset}");
+    for (iteration, count) in &stats.iterations {
+        println!("{iteration:>9} | {count:>5}");
+    }
+
+    let span = stats
+        .span_secs
+        .map(|secs| format_elapsed(Duration::from_secs_f64(secs.max(0.0))))
+        .unwrap_or_else(|| "-".to_string());
+    println!("\n{dim}Total: {} events over {span}{reset}", stats.total);
+    if let (Some(first), Some(last)) = (&stats.first_ts, &stats.last_ts) {
+        println!("{dim}From {first} to {last}{reset}");
     }
 }
 
@@ -547,6 +697,54 @@ mod tests {
         assert_eq!(format_elapsed(d), "45s");
     }
 
+    fn event(ts: &str, iteration: u32, topic: &str) -> EventRecord {
+        EventRecord {
+            ts: ts.to_string(),
+            iteration,
+            hat: String::new(),
+            topic: topic.to_string(),
+            triggered: None,
+            payload: String::new(),
+            blocked_count: None,
+            wave_id: None,
+            wave_index: None,
+            wave_total: None,
+        }
+    }
+
+    #[test]
+    fn test_event_stats_counts_topics_iterations_and_span() {
+        let records = [
+            event("2026-01-01T00:00:05Z", 1, "build.task"),
+            event("2026-01-01T00:00:00Z", 1, "task.start"),
+            event("not a timestamp", 2, "build.task"),
+            event("2026-01-01T00:01:30.500+00:00", 2, "build.done"),
+        ];
+
+        let stats = EventStats::from_records(&records);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.topics["build.task"], 2);
+        assert_eq!(stats.topics["build.done"], 1);
+        assert_eq!(stats.iterations[&1], 2);
+        assert_eq!(stats.iterations[&2], 2);
+        assert_eq!(stats.first_ts.as_deref(), Some("2026-01-01T00:00:00Z"));
+        assert_eq!(
+            stats.last_ts.as_deref(),
+            Some("2026-01-01T00:01:30.500+00:00")
+        );
+        assert_eq!(stats.span_secs, Some(90.5));
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["iterations"]["2"], 2);
+    }
+
+    #[test]
+    fn test_event_stats_without_timestamps_has_no_span() {
+        let stats = EventStats::from_records(&[event("", 0, "build.task")]);
+        assert_eq!(stats.span_secs, None);
+        assert_eq!(stats.first_ts, None);
+    }
+
     #[test]
     fn test_resume_hint_skipped_for_completion_promise() {
         assert!(resume_hint_for(&TerminationReason::CompletionPromise, "abc").is_none());
@@ -925,4 +1123,47 @@ hats:
             "Wildcard-only patterns should produce empty hat map"
         );
     }
+
+    fn csv_record(payload: &str) -> EventRecord {
+        EventRecord {
+            ts: "2024-01-01T00:00:00Z".to_string(),
+            iteration: 3,
+            hat: "builder".to_string(),
+            topic: "build.done".to_string(),
+            triggered: None,
+            payload: payload.to_string(),
+            blocked_count: None,
+            wave_id: None,
+            wave_index: None,
+            wave_total: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_row_quotes_json_encoded_payload() {
+        let row = format_event_csv_row(&csv_record("tests pass, lint clean\nnext"));
+        assert_eq!(
+            row,
+            r#"2024-01-01T00:00:00Z,3,build.done,"""tests pass, lint clean\nnext""""#
+        );
+    }
+
+    #[test]
+    fn test_csv_row_keeps_structured_payload_as_json() {
+        let row = format_event_csv_row(&csv_record(r#"{"status":"ok","files":["a.rs"]}"#));
+        let payload_field = row.splitn(4, ',').nth(3).unwrap();
+        let unquoted = payload_field
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .unwrap()
+            .replace("\"\"", "\"");
+        let value: serde_json::Value = serde_json::from_str(&unquoted).unwrap();
+        assert_eq!(value["files"][0], "a.rs");
+    }
+
+    #[test]
+    fn test_csv_field_plain_values_unquoted() {
+        assert_eq!(csv_field("build.done"), "build.done");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
 }
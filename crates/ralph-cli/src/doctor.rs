@@ -466,7 +466,7 @@ fn command_version_ok(command: &str) -> bool {
         .unwrap_or(false)
 }
 
-fn command_exists(command: &str) -> bool {
+pub(crate) fn command_exists(command: &str) -> bool {
     let path = Path::new(command);
     if path.components().count() > 1 {
         return path.is_file();
@@ -623,10 +623,15 @@ mod tests {
             publishes: vec![],
             instructions: String::new(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend,
             default_publishes: None,
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -0,0 +1,163 @@
+//! Machine-readable error reporting for `--json-errors`.
+//!
+//! When enabled (via `--json-errors` or `RALPH_JSON_ERRORS=1`), a top-level
+//! error is printed to stderr as a single JSON object instead of anyhow's
+//! human-readable chain. Success paths are unaffected.
+
+use ralph_adapters::NoBackendError;
+use ralph_core::{ConfigError, LockError};
+use serde::Serialize;
+
+/// Environment variable that enables JSON error output.
+pub const JSON_ERRORS_ENV: &str = "RALPH_JSON_ERRORS";
+
+/// Broad class of a top-level error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    Config,
+    Backend,
+    Lock,
+    Io,
+    Other,
+}
+
+/// JSON shape printed to stderr for a top-level error.
+#[derive(Debug, Serialize)]
+pub struct JsonError {
+    /// Stable, machine-matchable error code (e.g. `config_parse`, `lock_held`).
+    pub code: &'static str,
+    pub category: ErrorCategory,
+    /// Outermost error message.
+    pub message: String,
+    /// Remaining causes, outermost first.
+    pub context: Vec<String>,
+}
+
+/// Returns true when JSON errors are requested by flag or environment.
+pub fn json_errors_enabled(flag: bool) -> bool {
+    flag || std::env::var(JSON_ERRORS_ENV).is_ok_and(|v| v == "1")
+}
+
+/// Classifies an error by walking its cause chain.
+///
+/// Typed config, lock, and backend errors take precedence over plain IO
+/// errors, since those are usually the underlying cause of the former.
+pub fn classify(err: &anyhow::Error) -> JsonError {
+    let mut classified = None;
+    let mut io_fallback = None;
+
+    for cause in err.chain() {
+        if let Some(config_err) = cause.downcast_ref::<ConfigError>() {
+            let code = match config_err {
+                ConfigError::Yaml(_) => "config_parse",
+                ConfigError::Io(_) => "config_io",
+                _ => "config_invalid",
+            };
+            classified = Some((code, ErrorCategory::Config));
+        } else if cause.downcast_ref::<serde_yaml::Error>().is_some() {
+            classified = Some(("config_parse", ErrorCategory::Config));
+        } else if let Some(lock_err) = cause.downcast_ref::<LockError>() {
+            let code = match lock_err {
+                LockError::AlreadyLocked(_) => "lock_held",
+                _ => "lock_failed",
+            };
+            classified = Some((code, ErrorCategory::Lock));
+        } else if cause.downcast_ref::<NoBackendError>().is_some() {
+            classified = Some(("backend_not_found", ErrorCategory::Backend));
+        } else if io_fallback.is_none() && cause.downcast_ref::<std::io::Error>().is_some() {
+            io_fallback = Some(("io_error", ErrorCategory::Io));
+        }
+
+        if classified.is_some() {
+            break;
+        }
+    }
+
+    let (code, category) = classified
+        .or(io_fallback)
+        .unwrap_or(("internal_error", ErrorCategory::Other));
+
+    JsonError {
+        code,
+        category,
+        message: err.to_string(),
+        context: err.chain().skip(1).map(ToString::to_string).collect(),
+    }
+}
+
+/// Prints the JSON form of `err` to stderr.
+pub fn print_json_error(err: &anyhow::Error) {
+    let report = classify(err);
+    match serde_json::to_string(&report) {
+        Ok(json) => eprintln!("{json}"),
+        Err(_) => eprintln!("Error: {err:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use ralph_core::LockMetadata;
+
+    #[test]
+    fn config_parse_failure_is_classified_as_config() {
+        let err = serde_yaml::from_str::<serde_yaml::Value>("event_loop: [unclosed")
+            .context("Failed to parse YAML from ralph.yml")
+            .unwrap_err();
+
+        let report = classify(&err);
+        assert_eq!(report.code, "config_parse");
+        assert_eq!(report.category, ErrorCategory::Config);
+        assert_eq!(report.message, "Failed to parse YAML from ralph.yml");
+        assert_eq!(report.context.len(), 1);
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["category"], "config");
+        assert_eq!(json["code"], "config_parse");
+        assert!(json["context"].is_array());
+    }
+
+    #[test]
+    fn lock_error_is_classified_as_lock() {
+        let metadata = LockMetadata {
+            pid: 4242,
+            started: chrono::Utc::now(),
+            prompt: "build the thing".to_string(),
+        };
+        let err = anyhow::Error::new(LockError::AlreadyLocked(metadata))
+            .context("Another loop is already running");
+
+        let report = classify(&err);
+        assert_eq!(report.code, "lock_held");
+        assert_eq!(report.category, ErrorCategory::Lock);
+        assert_eq!(report.message, "Another loop is already running");
+        assert_eq!(report.context, vec!["Lock already held by PID 4242"]);
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["category"], "lock");
+    }
+
+    #[test]
+    fn typed_error_wins_over_underlying_io_error() {
+        let err = anyhow::Error::new(LockError::Io(std::io::Error::other("disk gone")))
+            .context("Failed to acquire loop lock");
+
+        let report = classify(&err);
+        assert_eq!(report.category, ErrorCategory::Lock);
+        assert_eq!(report.code, "lock_failed");
+    }
+
+    #[test]
+    fn plain_io_and_unknown_errors() {
+        let io_err = anyhow::Error::new(std::io::Error::other("boom")).context("Failed to write");
+        assert_eq!(classify(&io_err).category, ErrorCategory::Io);
+
+        let other = anyhow::anyhow!("something odd");
+        let report = classify(&other);
+        assert_eq!(report.category, ErrorCategory::Other);
+        assert_eq!(report.code, "internal_error");
+        assert!(report.context.is_empty());
+    }
+}
@@ -5,6 +5,8 @@
 //! Subcommands:
 //! - `list`: Show all configured hats (Name, Description)
 //! - `show`: Show detailed configuration for a specific hat
+//! - `validate`: Report unreachable hats, dead-end events and cycles
+//! - `diagram`: Write the topology as Mermaid or Graphviz DOT
 
 use crate::backend_support;
 use crate::display::colors;
@@ -14,7 +16,7 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use ralph_adapters::{CliBackend, detect_backend_default};
-use ralph_core::{HatRegistry, RalphConfig, truncate_with_ellipsis};
+use ralph_core::{HatRegistry, HatTopology, RalphConfig, truncate_with_ellipsis};
 use std::collections::{BTreeMap, HashSet};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -31,7 +33,14 @@ pub struct HatsArgs {
 #[derive(Subcommand, Debug)]
 pub enum HatsCommands {
     /// Validate hat topology and report issues
-    Validate,
+    ///
+    /// Exits non-zero when a hat is unreachable or the starting event has no
+    /// subscriber.
+    Validate {
+        /// Also print the topology as a Mermaid diagram
+        #[arg(long)]
+        mermaid: bool,
+    },
     /// Display hat topology graph
     Graph {
         /// Output format (unicode, ascii, compact, mermaid)
@@ -41,6 +50,8 @@ pub enum HatsCommands {
         #[arg(short = 'b', long = "backend")]
         backend: Option<String>,
     },
+    /// Write the hat topology diagram used in Ralph's prompt
+    Diagram(DiagramArgs),
     /// List all configured hats (default if no subcommand)
     List {
         /// Output format (table, json)
@@ -81,6 +92,25 @@ pub enum GraphFormat {
     Mermaid,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+pub enum DiagramFormat {
+    /// Mermaid flowchart source
+    #[default]
+    Mermaid,
+    /// Graphviz DOT
+    Dot,
+}
+
+#[derive(Parser, Debug)]
+pub struct DiagramArgs {
+    /// Diagram format
+    #[arg(long, default_value = "mermaid")]
+    pub format: DiagramFormat,
+    /// Write to this file instead of stdout (e.g. topology.mmd)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(ValueEnum, Clone, Debug, Default)]
 pub enum ListFormat {
     #[default]
@@ -131,7 +161,32 @@ pub async fn execute(
         Some(HatsCommands::Show(show_args)) => {
             show_hat(&mut stdout, &registry, &show_args.name, use_colors)
         }
-        Some(HatsCommands::Validate) => validate_hats(&mut stdout, &config, &registry, use_colors),
+        Some(HatsCommands::Validate { mermaid }) => {
+            let result = validate_hats(&mut stdout, &config, &registry, use_colors);
+            if mermaid && !registry.is_empty() {
+                writeln!(stdout)?;
+                writeln!(stdout, "```mermaid")?;
+                write!(
+                    stdout,
+                    "{}",
+                    HatTopology::from_registry(&registry).mermaid_diagram()
+                )?;
+                writeln!(stdout, "```")?;
+            }
+            result
+        }
+        Some(HatsCommands::Diagram(diagram_args)) => {
+            let diagram = render_diagram(&config, &registry, diagram_args.format);
+            match diagram_args.output {
+                Some(path) => {
+                    std::fs::write(&path, diagram)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    eprintln!("Wrote hat topology diagram to {}", path.display());
+                    Ok(())
+                }
+                None => Ok(write!(stdout, "{}", diagram)?),
+            }
+        }
         Some(HatsCommands::Graph { format, backend }) => {
             graph_hats(&mut stdout, &config, &registry, format, backend.as_deref())
         }
@@ -467,32 +522,59 @@ fn validate_hats<W: Write>(
         }
     }
 
-    // 2. Orphan event detection (published but no subscribers)
-    for hat in registry.all() {
-        for pub_event in &hat.publishes {
-            let topic = pub_event.as_str();
-            // Ignore loop completion promise
-            if topic == config.event_loop.completion_promise {
-                continue;
-            }
-            // Ignore if Ralph subscribes (task.start, etc - though Ralph usually PUBLISHES task.start)
-            // Ralph conceptually subscribes to everything as fallback, but we want to warn if no SPECIFIC hat handles it.
-            if !registry.has_subscriber(topic) {
-                print_check(
-                    writer,
-                    CheckResult::Warn,
-                    &format!(
-                        "Event '{}' published by '{}' has no hat subscribers",
-                        topic, hat.name
-                    ),
-                    use_colors,
-                )?;
-                warnings += 1;
-            }
-        }
+    let report = HatTopology::from_registry(registry).analyze(
+        config.event_loop.starting_event.as_deref(),
+        &config.event_loop.completion_promise,
+    );
+
+    // 2. Unreachable hats (no trigger is ever published)
+    for name in &report.unreachable_hats {
+        print_check(
+            writer,
+            CheckResult::Error,
+            &format!(
+                "Hat '{}' is unreachable: none of its triggers is ever published",
+                name
+            ),
+            use_colors,
+        )?;
+        errors += 1;
+    }
+
+    // 3. Orphan event detection (published but no subscribers; only Ralph's fallback sees them)
+    for dead_end in &report.dead_end_events {
+        print_check(
+            writer,
+            CheckResult::Warn,
+            &format!(
+                "Event '{}' published by '{}' has no hat subscribers",
+                dead_end.topic, dead_end.hat
+            ),
+            use_colors,
+        )?;
+        warnings += 1;
+    }
+
+    // 4. Cycles (legitimate for build/review loops, but each needs an exit)
+    for cycle in &report.cycles {
+        let message = if let [hat] = cycle.as_slice() {
+            format!("Hat '{}' triggers itself", hat)
+        } else {
+            format!("Hats {} trigger each other in a cycle", cycle.join(", "))
+        };
+        print_check(
+            writer,
+            CheckResult::Warn,
+            &format!(
+                "{} (make sure one of them publishes an exit event)",
+                message
+            ),
+            use_colors,
+        )?;
+        warnings += 1;
     }
 
-    // 3. Dead end detection
+    // 5. Dead end detection
     let mut dead_ends = 0;
     for hat in registry.all() {
         if hat.publishes.is_empty() {
@@ -587,6 +669,23 @@ fn graph_hats<W: Write>(
     Ok(())
 }
 
+/// Renders the prompt's topology diagram, noting the configured starting
+/// event as a comment since the diagram itself always enters at task.start.
+fn render_diagram(config: &RalphConfig, registry: &HatRegistry, format: DiagramFormat) -> String {
+    let topology = HatTopology::from_registry(registry);
+    let (comment, body) = match format {
+        DiagramFormat::Mermaid => ("%%", topology.mermaid_diagram()),
+        DiagramFormat::Dot => ("//", topology.dot_diagram()),
+    };
+    match &config.event_loop.starting_event {
+        Some(start) => format!(
+            "{} Entry: task.start -> Ralph -> {} (starting_event)\n{}",
+            comment, start, body
+        ),
+        None => body,
+    }
+}
+
 /// Render hat topology as ASCII DAG by calling an AI backend.
 ///
 /// Shows the logical flow: task.start -> Ralph -> Hats
@@ -1005,7 +1104,8 @@ mod tests {
         // Builder publishes build.done, but no one listens
         registry.register(mock_hat("Builder", &["build.task"], &["build.done"]));
 
-        let config = RalphConfig::default();
+        let mut config = RalphConfig::default();
+        config.event_loop.starting_event = Some("build.task".to_string());
         let mut buf = Vec::new();
 
         // Validation might exit process on error, so we test warning scenario
@@ -1019,6 +1119,25 @@ mod tests {
         assert!(output.contains("Result: Valid (1 warnings)"));
     }
 
+    #[test]
+    fn test_validate_hats_unreachable_and_cycle() {
+        let mut registry = HatRegistry::new();
+        registry.register(mock_hat("Builder", &["build.task"], &["build.done"]));
+        registry.register(mock_hat("Reviewer", &["build.done"], &["build.task"]));
+        registry.register(mock_hat("Orphan", &[], &[]));
+
+        let config = RalphConfig::default();
+        let mut buf = Vec::new();
+
+        let err = validate_hats(&mut buf, &config, &registry, false).unwrap_err();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(err.to_string().contains("1 errors"));
+        assert!(output.contains("[err] Hat 'Orphan' is unreachable"));
+        assert!(output.contains("[warn] Hats Builder, Reviewer trigger each other in a cycle"));
+        assert!(output.contains("Result: Invalid (1 errors, 1 warnings)"));
+    }
+
     #[test]
     fn test_graph_hats_compact() {
         let mut registry = HatRegistry::new();
@@ -1087,6 +1206,26 @@ mod tests {
         assert!(output.contains("A -.->|mid| B"));
     }
 
+    #[test]
+    fn test_render_diagram_formats() {
+        let mut registry = HatRegistry::new();
+        registry.register(mock_hat("Builder", &["build.task"], &["build.done"]));
+        registry.register(mock_hat("Reviewer", &["build.done"], &[]));
+
+        let mut config = RalphConfig::default();
+        let mermaid = render_diagram(&config, &registry, DiagramFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart LR\n"), "{mermaid}");
+        assert!(mermaid.contains("Ralph -->|build.task| Builder"));
+        assert!(mermaid.contains("Builder -->|build.done| Reviewer"));
+        assert!(!mermaid.contains("```"));
+
+        config.event_loop.starting_event = Some("build.task".to_string());
+        let dot = render_diagram(&config, &registry, DiagramFormat::Dot);
+        assert!(dot.starts_with("// Entry: task.start -> Ralph -> build.task"));
+        assert!(dot.contains("digraph hats {"));
+        assert!(dot.contains("\"Builder\" -> \"Reviewer\" [label=\"build.done\"];"));
+    }
+
     #[test]
     fn test_show_hat_found() {
         let mut registry = HatRegistry::new();
@@ -1132,7 +1271,8 @@ mod tests {
         registry.register(mock_hat("A", &["start"], &["mid"]));
         registry.register(mock_hat("B", &["mid"], &[]));
 
-        let config = RalphConfig::default();
+        let mut config = RalphConfig::default();
+        config.event_loop.starting_event = Some("start".to_string());
         let mut buf = Vec::new();
 
         validate_hats(&mut buf, &config, &registry, false).unwrap();
@@ -1141,6 +1281,23 @@ mod tests {
         assert!(output.contains("No dead-end hats") || output.contains("Result: Valid"));
     }
 
+    #[test]
+    fn test_validate_hats_trigger_nobody_publishes() {
+        let mut registry = HatRegistry::new();
+        registry.register(mock_hat("Builder", &["task.start"], &["LOOP_COMPLETE"]));
+        registry.register(mock_hat("Deployer", &["deploy.start"], &["LOOP_COMPLETE"]));
+
+        let config = RalphConfig::default();
+        let mut buf = Vec::new();
+
+        let err = validate_hats(&mut buf, &config, &registry, false).unwrap_err();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(err.to_string().contains("1 errors"));
+        assert!(output.contains("[err] Hat 'Deployer' is unreachable"));
+        assert!(!output.contains("Hat 'Builder' is unreachable"));
+    }
+
     #[test]
     fn test_list_hats_json() {
         let mut registry = HatRegistry::new();
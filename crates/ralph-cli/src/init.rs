@@ -4,9 +4,9 @@
 //! a minimal backend template or from an embedded preset.
 
 use crate::backend_support;
-use crate::presets::list_presets;
 #[cfg(test)]
 use crate::presets::{get_preset, preset_names};
+use crate::presets::{list_presets, user_presets};
 use std::fs;
 use std::path::Path;
 
@@ -30,6 +30,9 @@ pub enum InitError {
     #[error("Failed to write ralph.yml: {0}")]
     WriteError(#[from] std::io::Error),
 
+    #[error("Failed to write PROMPT.md: {0}")]
+    PromptWriteError(std::io::Error),
+
     #[cfg(test)]
     #[error("Failed to parse/generate YAML: {0}")]
     YamlError(String),
@@ -74,11 +77,38 @@ event_loop:
 #     triggers: ["review.request"]
 #     publishes: ["review.approved", "review.changes_requested"]
 
-# Create PROMPT.md with your task, then run: ralph run
+# Describe your task in PROMPT.md, then run: ralph run
 "#
     )
 }
 
+/// Starter PROMPT.md written next to ralph.yml.
+const PROMPT_TEMPLATE: &str = r"# Task
+
+<!--
+Describe what Ralph should accomplish: the goal, any constraints, and how
+to tell when the work is done. Replace this comment with your objective.
+
+Example:
+
+Add a `--json` flag to the `status` command that prints the same fields
+as a JSON object. Cover the new flag with tests and document it in the
+README.
+-->
+";
+
+/// Writes the PROMPT.md template unless one already exists (or `force` is set).
+///
+/// Returns whether the file was written.
+fn write_prompt_template(force: bool) -> Result<bool, InitError> {
+    let path = Path::new("PROMPT.md");
+    if path.exists() && !force {
+        return Ok(false);
+    }
+    fs::write(path, PROMPT_TEMPLATE).map_err(InitError::PromptWriteError)?;
+    Ok(true)
+}
+
 /// Checks if ralph.yml exists and handles the force flag.
 fn check_file_exists(force: bool) -> Result<(), InitError> {
     let path = Path::new("ralph.yml");
@@ -88,28 +118,42 @@ fn check_file_exists(force: bool) -> Result<(), InitError> {
     Ok(())
 }
 
-/// Initializes ralph.yml from a minimal backend template.
-///
-/// # Arguments
-/// * `backend` - The backend name (claude, kiro, gemini, codex, forge, amp, copilot, opencode, pi, custom)
-/// * `force` - If true, overwrite existing ralph.yml
+/// Renders the minimal config for a backend without touching the filesystem.
 ///
 /// # Errors
-/// Returns error if file exists (without force) or backend is invalid.
-pub fn init_from_backend(backend: &str, force: bool) -> Result<(), InitError> {
-    // Validate backend
+/// Returns error if the backend is invalid.
+pub fn render_backend_config(backend: &str) -> Result<String, InitError> {
     if !VALID_BACKENDS.contains(&backend) {
         return Err(InitError::UnknownBackend(
             backend_support::unknown_backend_message(backend),
         ));
     }
+    Ok(generate_template(backend))
+}
+
+/// Initializes ralph.yml from a minimal backend template.
+///
+/// # Arguments
+/// * `backend` - The backend name (claude, kiro, gemini, codex, forge, amp, copilot, opencode, pi, custom)
+/// * `force` - If true, overwrite existing ralph.yml (and PROMPT.md)
+/// * `with_prompt` - If true, also scaffold PROMPT.md when it doesn't exist
+///
+/// Returns whether PROMPT.md was written.
+///
+/// # Errors
+/// Returns error if file exists (without force) or backend is invalid.
+pub fn init_from_backend(backend: &str, force: bool, with_prompt: bool) -> Result<bool, InitError> {
+    let content = render_backend_config(backend)?;
 
     check_file_exists(force)?;
 
-    let content = generate_template(backend);
     fs::write("ralph.yml", content)?;
 
-    Ok(())
+    if with_prompt {
+        write_prompt_template(force)
+    } else {
+        Ok(false)
+    }
 }
 
 /// Initializes ralph.yml from an embedded preset.
@@ -117,7 +161,10 @@ pub fn init_from_backend(backend: &str, force: bool) -> Result<(), InitError> {
 /// # Arguments
 /// * `preset_name` - The name of the preset to use
 /// * `backend_override` - Optional backend to override the preset's backend
-/// * `force` - If true, overwrite existing ralph.yml
+/// * `force` - If true, overwrite existing ralph.yml (and PROMPT.md)
+/// * `with_prompt` - If true, also scaffold PROMPT.md when it doesn't exist
+///
+/// Returns whether PROMPT.md was written.
 ///
 /// # Errors
 /// Returns error if file exists (without force) or preset doesn't exist.
@@ -126,7 +173,8 @@ pub fn init_from_preset(
     preset_name: &str,
     backend_override: Option<&str>,
     force: bool,
-) -> Result<(), InitError> {
+    with_prompt: bool,
+) -> Result<bool, InitError> {
     let preset = get_preset(preset_name).ok_or_else(|| {
         let available = preset_names().join(", ");
         InitError::UnknownPreset(preset_name.to_string(), available)
@@ -151,7 +199,11 @@ pub fn init_from_preset(
 
     fs::write("ralph.yml", content)?;
 
-    Ok(())
+    if with_prompt {
+        write_prompt_template(force)
+    } else {
+        Ok(false)
+    }
 }
 
 /// Overrides the backend field in YAML content using regex for surgical replacement.
@@ -199,11 +251,29 @@ fn override_backend_in_yaml(content: &str, backend: &str) -> Result<String, Init
 }
 
 /// Formats the list of presets for display.
+///
+/// User presets shadow embedded ones of the same name, so each name is listed
+/// once with the source `builtin:<name>` resolves to.
 pub fn format_preset_list() -> String {
+    let user = user_presets();
     let mut output = String::from("Available hat collections:\n\n");
 
     for preset in list_presets() {
-        output.push_str(&format!("  {:<25} {}\n", preset.name, preset.description));
+        if user.iter().any(|(name, _)| name == preset.name) {
+            continue;
+        }
+        output.push_str(&format!(
+            "  {:<25} {:<9} {}\n",
+            preset.name, "embedded", preset.description
+        ));
+    }
+    for (name, path) in &user {
+        output.push_str(&format!(
+            "  {:<25} {:<9} {}\n",
+            name,
+            "user",
+            path.display()
+        ));
     }
 
     output.push_str("\nUsage:\n");
@@ -270,26 +340,70 @@ mod tests {
         let temp_dir = TempDir::new().expect("create temp dir");
         let _cwd = CwdGuard::set(temp_dir.path());
 
-        init_from_preset("code-assist", None, false).expect("init_from_preset succeeds");
+        init_from_preset("code-assist", None, false, false).expect("init_from_preset succeeds");
 
         let content = fs::read_to_string("ralph.yml").expect("read ralph.yml");
         assert!(
             content.contains("build.start") && content.contains("LOOP_COMPLETE"),
             "expected event loop configuration in generated config"
         );
+        assert!(!Path::new("PROMPT.md").exists());
+    }
+
+    #[test]
+    fn test_init_from_backend_scaffolds_prompt() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let wrote = init_from_backend("claude", false, true).expect("init succeeds");
+
+        assert!(wrote);
+        let prompt = fs::read_to_string("PROMPT.md").expect("read PROMPT.md");
+        assert_eq!(prompt, PROMPT_TEMPLATE);
+    }
+
+    #[test]
+    fn test_init_keeps_existing_prompt_unless_forced() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+        fs::write("PROMPT.md", "my task").expect("write PROMPT.md");
+
+        let wrote = init_from_backend("claude", false, true).expect("init succeeds");
+        assert!(!wrote);
+        assert_eq!(fs::read_to_string("PROMPT.md").unwrap(), "my task");
+
+        let wrote = init_from_backend("claude", true, true).expect("forced init succeeds");
+        assert!(wrote);
+        assert_eq!(fs::read_to_string("PROMPT.md").unwrap(), PROMPT_TEMPLATE);
+    }
+
+    #[test]
+    fn test_render_backend_config_writes_nothing() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let content = render_backend_config("codex").expect("render succeeds");
+
+        assert_eq!(content, generate_template("codex"));
+        assert!(!Path::new("ralph.yml").exists());
+        assert!(!Path::new("PROMPT.md").exists());
+        assert!(matches!(
+            render_backend_config("invalid-backend"),
+            Err(InitError::UnknownBackend(_))
+        ));
     }
 
     #[test]
     fn test_unknown_backend_error() {
         // We can't actually test file operations without filesystem mocking,
         // but we can test the validation logic
-        let result = init_from_backend("invalid-backend", false);
+        let result = init_from_backend("invalid-backend", false, true);
         assert!(matches!(result, Err(InitError::UnknownBackend(_))));
     }
 
     #[test]
     fn test_unknown_backend_message_actionable() {
-        let result = init_from_backend("invalid-backend", false);
+        let result = init_from_backend("invalid-backend", false, true);
         assert!(result.is_err());
 
         let err = result.expect_err("expected init error");
@@ -1,4 +1,8 @@
 use anyhow::{Context, Result};
+use ralph_core::{
+    LoopEntry, LoopRegistry, MergeQueue, Worktree, has_uncommitted_changes, has_unmerged_commits,
+    list_ralph_worktrees, remove_worktree,
+};
 use std::fs;
 use std::path::Path;
 
@@ -7,6 +11,7 @@ mod colors {
     pub const RESET: &str = "\x1b[0m";
     pub const CYAN: &str = "\x1b[36m";
     pub const GREEN: &str = "\x1b[32m";
+    pub const YELLOW: &str = "\x1b[33m";
 }
 
 /// Clean diagnostic logs from .ralph/diagnostics directory
@@ -79,6 +84,178 @@ pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool)
     Ok(())
 }
 
+/// Why a loop worktree is kept by [`clean_worktrees`], or `None` if it can go.
+fn worktree_keep_reason(
+    workspace_root: &Path,
+    worktree: &Worktree,
+    registry: &LoopRegistry,
+    loops: &[LoopEntry],
+    merge_queue: &MergeQueue,
+    force: bool,
+) -> Result<Option<&'static str>> {
+    let loop_id = worktree.branch.trim_start_matches("ralph/");
+    let live = loops.iter().any(|entry| {
+        let same_worktree = entry
+            .worktree_path
+            .as_deref()
+            .is_some_and(|path| Path::new(path) == worktree.path);
+        (entry.id == loop_id || same_worktree) && registry.is_alive(entry)
+    });
+    if live {
+        return Ok(Some("in use by a running loop"));
+    }
+
+    // Removal deletes the loop branch, which a pending merge still needs
+    let pending_merge = merge_queue
+        .get_entry(loop_id)
+        .with_context(|| format!("Failed to read merge queue entry for '{loop_id}'"))?
+        .is_some_and(|entry| !entry.state.is_terminal());
+    if pending_merge {
+        return Ok(Some("waiting in the merge queue"));
+    }
+
+    if force {
+        return Ok(None);
+    }
+
+    // If git cannot tell, assume there is work to lose
+    if has_uncommitted_changes(&worktree.path).unwrap_or(true) {
+        return Ok(Some(
+            "has uncommitted changes (use --force to remove anyway)",
+        ));
+    }
+    if has_unmerged_commits(workspace_root, &worktree.branch).unwrap_or(true) {
+        return Ok(Some("has unmerged commits (use --force to remove anyway)"));
+    }
+    Ok(None)
+}
+
+/// Remove loop worktrees that no running loop or pending merge uses.
+///
+/// Worktrees with uncommitted changes, or whose branch has commits the
+/// checked-out branch does not, are kept unless `force` is set. Prints
+/// each decision and the disk space reclaimed (or, with `dry_run`, the space
+/// that would be).
+pub fn clean_worktrees(
+    workspace_root: &Path,
+    use_colors: bool,
+    dry_run: bool,
+    force: bool,
+) -> Result<()> {
+    let worktrees = list_ralph_worktrees(workspace_root)
+        .with_context(|| format!("Failed to list worktrees in '{}'", workspace_root.display()))?;
+    if worktrees.is_empty() {
+        if use_colors {
+            println!(
+                "{}Nothing to clean:{} No loop worktrees",
+                colors::DIM,
+                colors::RESET
+            );
+        } else {
+            println!("Nothing to clean: No loop worktrees");
+        }
+        return Ok(());
+    }
+
+    let registry = LoopRegistry::new(workspace_root);
+    let loops = registry
+        .list()
+        .context("Failed to read the loop registry")?;
+    let merge_queue = MergeQueue::new(workspace_root);
+    let (dim, yellow, green, reset) = if use_colors {
+        (colors::DIM, colors::YELLOW, colors::GREEN, colors::RESET)
+    } else {
+        ("", "", "", "")
+    };
+
+    let mut removed = 0;
+    let mut reclaimed = 0;
+    for worktree in &worktrees {
+        if let Some(reason) = worktree_keep_reason(
+            workspace_root,
+            worktree,
+            &registry,
+            &loops,
+            &merge_queue,
+            force,
+        )? {
+            println!(
+                "{yellow}Keeping{reset} {} {dim}({}: {reason}){reset}",
+                worktree.path.display(),
+                worktree.branch
+            );
+            continue;
+        }
+
+        let size = dir_size(&worktree.path);
+        if dry_run {
+            println!(
+                "Would remove {} {dim}({}, {}){reset}",
+                worktree.path.display(),
+                worktree.branch,
+                format_size(size)
+            );
+        } else {
+            remove_worktree(workspace_root, &worktree.path).with_context(|| {
+                format!("Failed to remove worktree '{}'", worktree.path.display())
+            })?;
+            println!(
+                "{green}✓{reset} Removed {} {dim}({}, {}){reset}",
+                worktree.path.display(),
+                worktree.branch,
+                format_size(size)
+            );
+        }
+        removed += 1;
+        reclaimed += size;
+    }
+
+    if dry_run {
+        println!(
+            "Dry run: would remove {removed} of {} worktree(s), reclaiming {}",
+            worktrees.len(),
+            format_size(reclaimed)
+        );
+    } else {
+        println!(
+            "Removed {removed} of {} worktree(s), reclaimed {}",
+            worktrees.len(),
+            format_size(reclaimed)
+        );
+    }
+    Ok(())
+}
+
+/// Returns the total size of the files under `path`, without following symlinks.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 MiB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +289,66 @@ mod tests {
         clean_diagnostics(temp_dir.path(), false, false).expect("clean diagnostics");
         assert!(!diagnostics_dir.exists());
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("run git")
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn format_size_picks_binary_unit() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn clean_worktrees_removes_dead_and_keeps_dirty_unless_forced() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let root = temp_dir.path();
+        git(root, &["init", "--initial-branch=main"]);
+        git(root, &["config", "user.email", "test@test.local"]);
+        git(root, &["config", "user.name", "Test User"]);
+        std::fs::write(root.join("README.md"), "# Test").expect("write readme");
+        git(root, &["add", "README.md"]);
+        git(root, &["commit", "-m", "init"]);
+        git(
+            root,
+            &["worktree", "add", "-b", "ralph/dead", ".worktrees/dead"],
+        );
+        git(
+            root,
+            &["worktree", "add", "-b", "ralph/dirty", ".worktrees/dirty"],
+        );
+        std::fs::write(root.join(".worktrees/dirty/notes.txt"), "wip").expect("write notes");
+        git(
+            root,
+            &["worktree", "add", "-b", "ralph/ahead", ".worktrees/ahead"],
+        );
+        let ahead = root.join(".worktrees/ahead");
+        std::fs::write(ahead.join("work.txt"), "done").expect("write work");
+        git(&ahead, &["add", "work.txt"]);
+        git(&ahead, &["commit", "-m", "loop work"]);
+
+        clean_worktrees(root, false, true, false).expect("dry run");
+        assert!(root.join(".worktrees/dead").exists());
+
+        clean_worktrees(root, false, false, false).expect("clean");
+        assert!(!root.join(".worktrees/dead").exists());
+        assert!(root.join(".worktrees/dirty").exists());
+        assert!(
+            ahead.exists(),
+            "unmerged commits must survive a plain clean"
+        );
+
+        clean_worktrees(root, false, false, true).expect("forced clean");
+        assert!(!root.join(".worktrees/dirty").exists());
+        assert!(!ahead.exists());
+        assert!(list_ralph_worktrees(root).expect("list").is_empty());
+    }
 }
@@ -14,18 +14,19 @@ use ralph_adapters::{
 };
 use ralph_core::diagnostics::{HookDisposition, HookRunTelemetryEntry};
 use ralph_core::{
-    CompletionAction, EventLogger, EventLoop, EventParser, EventRecord, HookEngine, HookExecutor,
-    HookExecutorContract, HookMutationConfig, HookOnError, HookPayloadBuilderInput,
+    CompletionAction, EventBuilder, EventLogger, EventLoop, EventParser, EventRecord, HookEngine,
+    HookExecutor, HookExecutorContract, HookMutationConfig, HookOnError, HookPayloadBuilderInput,
     HookPayloadContextInput, HookPhaseEvent, HookRunRequest, HookRunResult, HookSuspendMode,
-    LoopCompletionHandler, LoopContext, LoopHistory, LoopRegistry, MergeQueue, RalphConfig, Record,
-    SessionRecorder, SummaryWriter, SuspendStateRecord, SuspendStateStore, TerminationReason,
-    UrgentSteerStore, resolve_context_window_for_backend,
+    LoopCompletionHandler, LoopContext, LoopHistory, LoopRegistry, MergeQueue, MergeStrategy,
+    RalphConfig, Record, SessionRecorder, SessionWriter, ShutdownPhase, ShutdownSignal,
+    SummaryWriter, SuspendStateRecord, SuspendStateStore, TerminationReason, UrgentSteerStore,
+    merge_loop_env, resolve_context_window_for_backend, squash_history_loss,
 };
 use ralph_proto::{Event, GuidanceTarget, HatId, RpcEvent, RpcState, RpcTaskCounts};
 use ralph_tui::Tui;
 use std::ffi::OsStr;
 use std::fs::{self, File};
-use std::io::{BufWriter, IsTerminal, stdin, stdout};
+use std::io::{IsTerminal, stdin, stdout};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::Arc;
@@ -63,6 +64,28 @@ fn context_tokens_from_pty_result(pty_result: &ralph_adapters::PtyExecutionResul
     pty_result.input_tokens
 }
 
+/// Returns the iteration cost, falling back to the backend's configured cost
+/// markers when the adapter itself reported none.
+fn iteration_cost_usd(
+    config: &RalphConfig,
+    backend_name: &str,
+    reported_cost_usd: f64,
+    output: &str,
+) -> f64 {
+    if reported_cost_usd > 0.0 {
+        return reported_cost_usd;
+    }
+    let parsed = match config
+        .adapter_settings(backend_name)
+        .cost_markers
+        .as_deref()
+    {
+        Some(markers) => EventParser::parse_cost_with_markers(output, markers),
+        None => EventParser::parse_cost(output, backend_name),
+    };
+    parsed.unwrap_or(reported_cost_usd)
+}
+
 fn context_window_for_backend(config: &RalphConfig, backend_name: &str) -> u64 {
     resolve_context_window_for_backend(config, backend_name)
 }
@@ -116,6 +139,11 @@ fn resolve_loop_id(
 ///   (equivalent to `--no-auto-merge`). If `None`, uses `config.features.auto_merge`.
 /// * `resume_loop_id` - Explicit loop ID to use when resuming (`--loop-id`).
 ///   If `None` and `resume` is true, reuses the existing `current-loop-id` marker.
+/// * `rescan_events` - When resuming, ignore the persisted events offset and
+///   replay every event after the last `loop.terminate` (`--rescan-events`).
+/// * `seed` - Run seed restored from a manifest (`--from-manifest`). If `None`,
+///   a fresh seed is generated. Either way it is recorded in the run manifest.
+#[allow(clippy::fn_params_excessive_bools)]
 pub async fn run_loop_impl(
     config: RalphConfig,
     color_mode: ColorMode,
@@ -128,6 +156,44 @@ pub async fn run_loop_impl(
     custom_args: Vec<String>,
     auto_merge_override: Option<bool>,
     resume_loop_id: Option<String>,
+    rescan_events: bool,
+    seed: Option<u64>,
+) -> Result<TerminationReason> {
+    // The loop future holds the whole EventLoop; boxing it once here keeps
+    // every caller's future small.
+    Box::pin(run_loop_impl_inner(
+        config,
+        color_mode,
+        resume,
+        enable_tui,
+        enable_rpc,
+        verbosity,
+        record_session,
+        loop_context,
+        custom_args,
+        auto_merge_override,
+        resume_loop_id,
+        rescan_events,
+        seed,
+    ))
+    .await
+}
+
+#[allow(clippy::fn_params_excessive_bools)]
+async fn run_loop_impl_inner(
+    config: RalphConfig,
+    color_mode: ColorMode,
+    resume: bool,
+    enable_tui: bool,
+    enable_rpc: bool,
+    verbosity: Verbosity,
+    record_session: Option<PathBuf>,
+    loop_context: Option<LoopContext>,
+    custom_args: Vec<String>,
+    auto_merge_override: Option<bool>,
+    resume_loop_id: Option<String>,
+    rescan_events: bool,
+    seed: Option<u64>,
 ) -> Result<TerminationReason> {
     // Set up process group leadership per spec
     // "The orchestrator must run as a process group leader"
@@ -156,9 +222,11 @@ pub async fn run_loop_impl(
 
     // Set up interrupt channel for signal handling
     // Per spec:
-    // - SIGINT (Ctrl+C): Immediately terminate child process (SIGTERM -> 5s grace -> SIGKILL), exit with code 130
-    // - SIGTERM: Same as SIGINT
-    // - SIGHUP: Same as SIGINT
+    // - SIGINT (Ctrl+C): Two-phase. The first finishes the current iteration and then stops
+    //   (loop.terminate still fires); a second within a few seconds terminates the child
+    //   immediately (SIGTERM -> 5s grace -> SIGKILL). Both exit with code 130
+    // - SIGTERM: Immediate, like a second SIGINT
+    // - SIGHUP: Immediate, like a second SIGINT
     //
     // Use watch channel for interrupt notification so we can race execution vs interrupt
     // Note: Signal handlers are spawned AFTER TUI initialization to avoid deadlock
@@ -170,7 +238,15 @@ pub async fn run_loop_impl(
     // 3. Config prompt (inline text)
     // 4. Config prompt_file (file path)
     // 5. Default PROMPT.md
-    let prompt_content = resolve_prompt_content(&config.event_loop)?;
+    //
+    // A resumed loop already has its objective in the scratchpad and events, so
+    // a prompt file that has since disappeared ends the loop with a config
+    // error instead of failing before the loop can record why it stopped.
+    let (prompt_content, prompt_config_error) = match resolve_prompt_content(&config.event_loop) {
+        Ok(content) => (content, None),
+        Err(e) if resume => (String::new(), Some(format!("{e:#}"))),
+        Err(e) => return Err(e),
+    };
 
     // Create or use provided loop context for path resolution
     // This ensures events are written to the correct location for worktree loops
@@ -198,6 +274,34 @@ pub async fn run_loop_impl(
     fs::write(&loop_id_marker, &loop_id).context("Failed to write current-loop-id marker")?;
     debug!(loop_id = %loop_id, marker = ?loop_id_marker, "Wrote loop ID marker file");
 
+    // Record everything that affects this run so it can be reproduced with
+    // `ralph run --from-manifest`. A failure here never blocks the loop. The
+    // manifest is written before the first iteration, since the executors
+    // (`RALPH_SEED`) and `ralph emit` read it; the backend `--version` probe
+    // then fills in `backend_version` off the startup path.
+    let seed = seed.unwrap_or_else(ralph_core::generate_seed);
+    match ralph_core::RunManifest::capture(&config, &loop_id, seed, &prompt_content) {
+        Ok(mut manifest) => {
+            let manifest_path = ctx.run_manifest_path();
+            match manifest.write(&manifest_path) {
+                Ok(()) => {
+                    debug!(seed, path = ?manifest_path, "Wrote run manifest");
+                    let backend = config.cli.backend.clone();
+                    tokio::task::spawn_blocking(move || {
+                        manifest.backend_version = ralph_adapters::backend_version(&backend);
+                        if manifest.backend_version.is_some()
+                            && let Err(e) = manifest.write(&manifest_path)
+                        {
+                            warn!("Failed to record backend version in run manifest: {}", e);
+                        }
+                    });
+                }
+                Err(e) => warn!("Failed to write run manifest: {}", e),
+            }
+        }
+        Err(e) => warn!("Failed to capture run manifest: {}", e),
+    }
+
     // For fresh runs (not resume), generate a unique timestamped events file
     // This prevents stale events from previous runs polluting new runs (issue #82)
     // The marker file `.ralph/current-events` coordinates path between Ralph and agents
@@ -243,10 +347,14 @@ pub async fn run_loop_impl(
 
     // Initialize event loop with context for proper path resolution
     let mut event_loop = EventLoop::with_context(config.clone(), ctx.clone());
+    if let Some(detail) = prompt_config_error {
+        event_loop.report_config_error(detail);
+    }
     let loop_state_path = event_loop.loop_state_path();
     if resume {
-        if let Err(e) = event_loop.restore_loop_state(&loop_state_path) {
-            warn!("Failed to restore persisted loop state: {}", e);
+        event_loop.restore_loop_state(&loop_state_path);
+        if rescan_events {
+            event_loop.clear_resume_events_offset();
         }
     } else if let Err(e) = EventLoop::clear_loop_state(&loop_state_path) {
         warn!("Failed to clear stale persisted loop state: {}", e);
@@ -263,6 +371,10 @@ pub async fn run_loop_impl(
     // Capture the robot service shutdown flag so signal handlers can interrupt wait_for_response()
     let robot_shutdown = event_loop.robot_shutdown_flag();
 
+    // Shared Ctrl-C phase: signal handlers and the TUI advance it, the event loop drains on it
+    let shutdown = ShutdownSignal::new();
+    event_loop.set_shutdown_signal(shutdown.clone());
+
     let hooks_dispatch_enabled = config.hooks.enabled && !config.hooks.events.is_empty();
     let hook_engine = HookEngine::new(&config.hooks);
     let hook_executor = HookExecutor::new();
@@ -331,12 +443,12 @@ pub async fn run_loop_impl(
 
     // Set up session recording if requested
     // This records all events to a JSONL file for replay testing
-    let _session_recorder: Option<Arc<SessionRecorder<BufWriter<File>>>> =
+    let _session_recorder: Option<Arc<SessionRecorder<SessionWriter>>> =
         if let Some(record_path) = record_session {
-            let file = File::create(&record_path).with_context(|| {
+            let recorder = SessionRecorder::create(&record_path).with_context(|| {
                 format!("Failed to create session recording file: {:?}", record_path)
             })?;
-            let recorder = Arc::new(SessionRecorder::new(BufWriter::new(file)));
+            let recorder = Arc::new(recorder);
 
             // Record metadata for the session
             recorder.record_meta(Record::meta_loop_start(
@@ -349,19 +461,31 @@ pub async fn run_loop_impl(
             let observer = SessionRecorder::make_observer(Arc::clone(&recorder));
             event_loop.add_observer(observer);
 
+            let marker_target = std::path::absolute(&record_path).unwrap_or(record_path.clone());
+            if let Err(e) = fs::write(
+                ctx.current_recording_marker(),
+                marker_target.to_string_lossy().as_bytes(),
+            ) {
+                warn!("Failed to write current-recording marker: {}", e);
+            }
+
             info!("Session recording enabled: {:?}", record_path);
             Some(recorder)
         } else {
+            // Don't let attach follow a previous run's recording
+            let _ = fs::remove_file(ctx.current_recording_marker());
             None
         };
 
     // Initialize event logger for debugging (uses context for path resolution)
-    let mut event_logger = EventLogger::from_context(&ctx);
+    let mut event_logger =
+        EventLogger::from_context(&ctx).with_system_events(config.event_loop.system_events);
 
     // On --continue, hydrate runtime state from the existing events file before
     // appending this process's task.resume record. This preserves historical
     // seen_topics and republishes watchdog/human resume events appended after
     // the last loop.terminate without double-delivering the fresh task.resume.
+    // Events before the persisted consumed offset are never republished.
     if resume && let Err(e) = event_loop.replay_resume_events_from_jsonl() {
         warn!("Failed to replay resume events from JSONL: {}", e);
     }
@@ -388,6 +512,7 @@ pub async fn run_loop_impl(
     // Create backend from config - TUI mode uses the same backend as non-TUI
     // The TUI is an observation layer that displays output, not a different mode
     let mut backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
+    backend.apply_env(config.adapter_env(&config.cli.backend));
 
     // Append custom args from CLI if provided (e.g., `ralph run -b opencode -- --model="some-model"`)
     if !custom_args.is_empty() {
@@ -552,7 +677,8 @@ pub async fn run_loop_impl(
             .with_termination_signal(terminated_rx)
             .with_export_workspace_root(ctx.workspace().to_path_buf())
             .with_events_path(resolve_current_events_path(&ctx))
-            .with_urgent_steer_path(urgent_steer_path.clone());
+            .with_urgent_steer_path(urgent_steer_path.clone())
+            .with_shutdown_signal(shutdown.clone());
 
         // Get shared state and guidance queue before spawning (for content streaming)
         let state = tui.state();
@@ -608,13 +734,27 @@ pub async fn run_loop_impl(
     // Spawn task to listen for SIGINT (Ctrl+C)
     let interrupt_tx_sigint = interrupt_tx.clone();
     let robot_shutdown_sigint = robot_shutdown.clone();
+    let shutdown_sigint = shutdown.clone();
+    // The TUI shows the drain status itself; printing would corrupt its screen
+    let announce_drain = tui_handle.is_none();
     tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            debug!("Interrupt received (SIGINT), terminating immediately...");
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if shutdown_sigint.request() == ShutdownPhase::Draining {
+                debug!("Interrupt received (SIGINT), finishing current iteration...");
+                if announce_drain {
+                    eprintln!(
+                        "\nralph: {}",
+                        ShutdownPhase::Draining.status_message().unwrap_or_default()
+                    );
+                }
+                continue;
+            }
+            debug!("Second interrupt received (SIGINT), terminating immediately...");
             if let Some(ref flag) = robot_shutdown_sigint {
                 flag.store(true, std::sync::atomic::Ordering::Relaxed);
             }
             let _ = interrupt_tx_sigint.send(true);
+            break;
         }
     });
 
@@ -623,12 +763,14 @@ pub async fn run_loop_impl(
     {
         let interrupt_tx_sigterm = interrupt_tx.clone();
         let robot_shutdown_sigterm = robot_shutdown.clone();
+        let shutdown_sigterm = shutdown.clone();
         tokio::spawn(async move {
             let mut sigterm =
                 tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
                     .expect("Failed to register SIGTERM handler");
             sigterm.recv().await;
             debug!("SIGTERM received, terminating immediately...");
+            shutdown_sigterm.force_quit();
             if let Some(ref flag) = robot_shutdown_sigterm {
                 flag.store(true, std::sync::atomic::Ordering::Relaxed);
             }
@@ -641,11 +783,13 @@ pub async fn run_loop_impl(
     {
         let interrupt_tx_sighup = interrupt_tx.clone();
         let robot_shutdown_sighup = robot_shutdown.clone();
+        let shutdown_sighup = shutdown.clone();
         tokio::spawn(async move {
             let mut sighup = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
                 .expect("Failed to register SIGHUP handler");
             sighup.recv().await;
             warn!("SIGHUP received (terminal closed), terminating immediately...");
+            shutdown_sighup.force_quit();
             if let Some(ref flag) = robot_shutdown_sighup {
                 flag.store(true, std::sync::atomic::Ordering::Relaxed);
             }
@@ -720,7 +864,8 @@ pub async fn run_loop_impl(
                               auto_merge: bool,
                               prompt: &str| {
         // Per spec: Write summary file on termination
-        let summary_writer = SummaryWriter::default();
+        let summary_writer =
+            SummaryWriter::default().with_formatting(config.core.formatting.clone());
         let scratchpad_path = std::path::Path::new(scratchpad);
         let scratchpad_opt = if scratchpad_path.exists() {
             Some(scratchpad_path)
@@ -752,6 +897,7 @@ pub async fn run_loop_impl(
                 TerminationReason::RestartRequested => "restart_requested",
                 TerminationReason::WorkspaceGone => "workspace_gone",
                 TerminationReason::Cancelled => "cancelled",
+                TerminationReason::ConfigError { .. } => "config_error",
             };
 
             if matches!(reason, TerminationReason::Interrupted) {
@@ -823,6 +969,7 @@ pub async fn run_loop_impl(
                     TerminationReason::RestartRequested => "restart requested",
                     TerminationReason::WorkspaceGone => "workspace directory removed",
                     TerminationReason::Cancelled => "cancelled by human",
+                    TerminationReason::ConfigError { .. } => "configuration error",
                 };
                 if let Err(e) = queue.mark_needs_review(loop_id, reason_str) {
                     warn!(loop_id = %loop_id, error = %e, "Failed to mark merge as needs-review");
@@ -836,7 +983,9 @@ pub async fn run_loop_impl(
         // Per spec: merge loops do NOT enqueue themselves, even if run in worktree context
         if let Some(ctx) = context {
             if merge_loop_id.is_none() && matches!(reason, TerminationReason::CompletionPromise) {
-                let handler = LoopCompletionHandler::new(auto_merge);
+                let handler = LoopCompletionHandler::new(auto_merge)
+                    .with_merge_strategy(config.merge.strategy)
+                    .with_formatting(config.core.formatting.clone());
                 match handler.handle_completion(ctx, prompt) {
                     Ok(CompletionAction::None) => {
                         debug!("Loop completed, no action needed");
@@ -890,7 +1039,10 @@ pub async fn run_loop_impl(
 
             // Handle merge queue processing for primary loop completion
             if ctx.is_primary() && matches!(reason, TerminationReason::CompletionPromise) {
-                process_pending_merges(ctx.repo_root());
+                process_pending_merges(
+                    ctx.repo_root(),
+                    config.merge.pre_merge_verification().as_ref(),
+                );
             }
 
             // Always deregister from registry — process is exiting regardless of reason.
@@ -1022,6 +1174,22 @@ pub async fn run_loop_impl(
         );
     }
 
+    // In verbose mode, print what changed after each iteration. The baseline
+    // snapshot makes the first iteration's delta meaningful too.
+    let delta_scratchpad_path = ctx.workspace().join(&config.core.scratchpad.path);
+    let mut delta_writer = (verbosity == Verbosity::Verbose).then(|| {
+        let mut writer = SummaryWriter::from_context(&ctx)
+            .with_events_path(resolve_current_events_path(&ctx))
+            .with_formatting(config.core.formatting.clone());
+        let baseline = writer.snapshot(
+            event_loop.state(),
+            Some(&delta_scratchpad_path),
+            ctx.workspace(),
+        );
+        writer.record_iteration(baseline);
+        writer
+    });
+
     // Main orchestration loop
     loop {
         // Check for interrupt signal at start of each iteration
@@ -1029,16 +1197,10 @@ pub async fn run_loop_impl(
         if *interrupt_rx.borrow() {
             #[cfg(unix)]
             {
-                use nix::sys::signal::{Signal, killpg};
-                use nix::unistd::getpgrp;
-                let pgid = getpgrp();
-                debug!(
-                    "Interrupt detected at loop start, sending SIGTERM to process group {}",
-                    pgid
-                );
-                let _ = killpg(pgid, Signal::SIGTERM);
-                tokio::time::sleep(Duration::from_millis(250)).await;
-                let _ = killpg(pgid, Signal::SIGKILL);
+                debug!("Interrupt detected at loop start, terminating process group");
+                if let Some((pids, killed)) = terminate_process_group().await {
+                    event_loop.log_orphaned_processes(&pids, killed);
+                }
             }
             let reason = dispatch_pre_loop_termination_hooks(
                 &event_loop,
@@ -1094,6 +1256,19 @@ pub async fn run_loop_impl(
         // Handle both TUI guidance queue and RPC guidance channel.
         let mut guidance_messages: Vec<String> = Vec::new();
 
+        // A steer file is persisted as urgent steer (so the agent's next emit
+        // is held back) and prepended to the next coordinator prompt as a
+        // user.steer event.
+        let steer_file = ctx.steer_file_path();
+        match urgent_steer_store.absorb_steer_file(&steer_file) {
+            Ok(Some(message)) => {
+                info!(path = ?steer_file, "Queued steering from steer file");
+                event_loop.inject_steer([message]);
+            }
+            Ok(None) => {}
+            Err(e) => warn!(error = %e, path = ?steer_file, "Failed to read steer file"),
+        }
+
         // Drain TUI guidance queue
         if let Some(ref queue) = guidance_next_queue {
             let messages: Vec<String> = {
@@ -1697,11 +1872,24 @@ pub async fn run_loop_impl(
         let hat_config_opt = event_loop.registry().get_config(&display_hat);
         let hat_backend_opt = hat_config_opt.and_then(|c| c.backend.as_ref());
         let hat_backend_args = hat_config_opt.and_then(|c| c.backend_args.clone());
+        let hat_temperature = hat_config_opt.and_then(|c| c.temperature);
+        let hat_max_tokens = hat_config_opt.and_then(|c| c.max_tokens);
 
         // Step 2: Resolve effective backend and determine backend name for timeout
         // Note: backend_name_for_timeout is owned String to avoid lifetime issues with hat_backend reference
         let (mut effective_backend, backend_name_for_timeout): (CliBackend, String) =
             match hat_backend_opt {
+                Some(ralph_core::HatBackend::Custom { command, .. })
+                    if !crate::doctor::command_exists(
+                        command.split_whitespace().next().unwrap_or(command),
+                    ) =>
+                {
+                    event_loop.report_config_error(format!(
+                        "Hat '{}' backend command '{}' was not found",
+                        display_hat, command
+                    ));
+                    continue;
+                }
                 Some(hat_backend) => {
                     // Hat has custom backend configuration
                     match CliBackend::from_hat_backend(hat_backend) {
@@ -1761,10 +1949,13 @@ pub async fn run_loop_impl(
                 }
             };
 
-        // Step 2.5: Apply custom hat backend args if configured
+        // Step 2.5: Apply the backend's adapter env, then custom hat backend
+        // args and sampling settings if configured
+        effective_backend.apply_env(config.adapter_env(&backend_name_for_timeout));
         if let Some(args) = hat_backend_args {
             effective_backend.args.extend(args);
         }
+        effective_backend.apply_sampling(hat_temperature, hat_max_tokens);
 
         // Step 3: Get timeout from config based on actual backend being used
         let timeout_secs = config.adapter_settings(&backend_name_for_timeout).timeout;
@@ -1793,75 +1984,84 @@ pub async fn run_loop_impl(
         let interrupt_rx_for_pty = interrupt_rx.clone();
         let tui_lines_for_pty = tui_lines.clone();
         let rpc_stdout_for_pty = rpc_stdout.clone();
+        let retry_policy = BackendRetryPolicy::from_config(&config.cli);
+        let mut backend_retries: Vec<BackendRetry> = Vec::new();
+        let backend_retries_for_exec = &mut backend_retries;
         let execute_future = async {
-            if effective_backend.output_format == BackendOutputFormat::Acp {
-                execute_acp(
-                    &effective_backend,
-                    &config,
-                    &prompt,
-                    verbosity,
-                    tui_lines_for_pty,
-                    rpc_stdout_for_pty,
-                    iteration,
-                    display_hat.as_str(),
-                    &backend_name_for_timeout,
-                )
-                .await
-            } else if use_pty {
-                execute_pty(
-                    pty_executor.as_mut(),
-                    &effective_backend,
-                    &config,
-                    &prompt,
-                    user_interactive,
-                    interrupt_rx_for_pty,
-                    verbosity,
-                    tui_lines_for_pty,
-                    rpc_stdout_for_pty,
-                    iteration,
-                    display_hat.as_str(),
-                    &backend_name_for_timeout,
-                )
-                .await
-            } else {
-                let executor = CliExecutor::new(effective_backend.clone());
-                let result = executor
-                    .execute(&prompt, stdout(), timeout, verbosity == Verbosity::Verbose)
-                    .await?;
-                Ok(ExecutionOutcome {
-                    output: normalize_cli_output_for_parsing(
-                        effective_backend.output_format,
-                        &result.output,
-                    ),
-                    success: result.success,
-                    termination: None,
-                    total_cost_usd: 0.0,
-                    input_tokens: 0,
-                    output_tokens: 0,
-                    cache_read_tokens: 0,
-                    cache_write_tokens: 0,
-                    context_window: context_window_for_backend(&config, &backend_name_for_timeout),
-                    context_tokens: 0,
-                    num_turns: 0,
-                })
+            loop {
+                let result = if effective_backend.output_format == BackendOutputFormat::Acp {
+                    execute_acp(
+                        &effective_backend,
+                        &config,
+                        &prompt,
+                        verbosity,
+                        tui_lines_for_pty.clone(),
+                        rpc_stdout_for_pty.clone(),
+                        iteration,
+                        display_hat.as_str(),
+                        &backend_name_for_timeout,
+                    )
+                    .await
+                } else if use_pty {
+                    execute_pty(
+                        pty_executor.as_mut(),
+                        &effective_backend,
+                        &config,
+                        &prompt,
+                        user_interactive,
+                        interrupt_rx_for_pty.clone(),
+                        verbosity,
+                        tui_lines_for_pty.clone(),
+                        rpc_stdout_for_pty.clone(),
+                        iteration,
+                        display_hat.as_str(),
+                        &backend_name_for_timeout,
+                    )
+                    .await
+                } else {
+                    execute_cli(
+                        &effective_backend,
+                        &config,
+                        &prompt,
+                        timeout,
+                        verbosity,
+                        &backend_name_for_timeout,
+                    )
+                    .await
+                };
+
+                // Interactive sessions are driven by the user; never replay them.
+                let retry_reason = if user_interactive {
+                    None
+                } else {
+                    retryable_backend_failure(&result)
+                };
+                let attempt = backend_retries_for_exec.len() as u32 + 1;
+                let Some(reason) = retry_reason.filter(|_| attempt <= retry_policy.max_retries)
+                else {
+                    return result;
+                };
+                let delay = retry_policy.backoff(attempt);
+                warn!(
+                    "Backend '{}' failed transiently ({}); retry {}/{} in {:?}",
+                    backend_name_for_timeout, reason, attempt, retry_policy.max_retries, delay
+                );
+                backend_retries_for_exec.push(BackendRetry {
+                    attempt,
+                    delay,
+                    reason,
+                });
+                tokio::time::sleep(delay).await;
             }
         };
 
-        let outcome = tokio::select! {
+        let mut outcome = tokio::select! {
             result = execute_future => result?,
             _ = interrupt_rx_clone.changed() => {
                 // Immediately terminate children via process group signal
                 #[cfg(unix)]
-                {
-                    use nix::sys::signal::{killpg, Signal};
-                    use nix::unistd::getpgrp;
-                    let pgid = getpgrp();
-                    debug!("Sending SIGTERM to process group {}", pgid);
-                    let _ = killpg(pgid, Signal::SIGTERM);
-
-                    // Wait briefly for graceful exit, then SIGKILL
-                    tokio::time::sleep(Duration::from_millis(250)).await;
-                    let _ = killpg(pgid, Signal::SIGKILL);
+                if let Some((pids, killed)) = terminate_process_group().await {
+                    event_loop.log_orphaned_processes(&pids, killed);
                 }
 
                 let reason = dispatch_pre_loop_termination_hooks(
@@ -1902,6 +2102,16 @@ pub async fn run_loop_impl(
             }
         };
 
+        for retry in &backend_retries {
+            event_loop.log_backend_retry(
+                hat_id.as_str(),
+                retry.attempt,
+                retry_policy.max_retries,
+                retry.delay.as_millis() as u64,
+                &retry.reason,
+            );
+        }
+
         if let Some(reason) = outcome.termination {
             let reason = dispatch_pre_loop_termination_hooks(
                 &event_loop,
@@ -1954,6 +2164,15 @@ pub async fn run_loop_impl(
             return Ok(reason);
         }
 
+        // Backends without native cost reporting print it as text; recover it
+        // so max_cost_usd and the footer see real numbers.
+        outcome.total_cost_usd = iteration_cost_usd(
+            &config,
+            &backend_name_for_timeout,
+            outcome.total_cost_usd,
+            &outcome.output,
+        );
+
         let iteration_duration_ms = iteration_started_at.elapsed().as_millis() as u64;
         let summary_metrics = IterationSummaryMetrics {
             duration_ms: iteration_duration_ms,
@@ -1967,7 +2186,8 @@ pub async fn run_loop_impl(
             context_tokens: outcome.context_tokens,
         };
 
-        let output = outcome.output;
+        // Enforce the hat's max_output_bytes before anything ingests the output
+        let output = event_loop.cap_hat_output(&hat_id, outcome.output);
         let success = outcome.success;
 
         // Note: TUI lines are now written directly to IterationBuffer during streaming,
@@ -2038,6 +2258,16 @@ pub async fn run_loop_impl(
         if let Err(e) = event_loop.save_loop_state(&loop_state_path) {
             warn!("Failed to persist loop state: {}", e);
         }
+        if let Some(writer) = delta_writer.as_mut() {
+            let snapshot = writer.snapshot(
+                event_loop.state(),
+                Some(&delta_scratchpad_path),
+                ctx.workspace(),
+            );
+            if let Some(delta) = writer.record_iteration(snapshot) {
+                eprintln!("\n{delta}");
+            }
+        }
         if let Some(reason) = termination {
             // Per spec: Log "All done! {promise} detected." when completion promise found
             if reason == TerminationReason::CompletionPromise {
@@ -2301,6 +2531,10 @@ pub async fn run_loop_impl(
                     (None, Vec::new())
                 }
             };
+        // Persist the consumed events offset so --continue won't replay these
+        if let Err(e) = event_loop.save_loop_state(&loop_state_path) {
+            warn!("Failed to persist loop state: {}", e);
+        }
 
         if let Some(human_interact_context) = processed_events
             .as_ref()
@@ -2726,6 +2960,52 @@ enum LateEventRecovery {
     Terminate(TerminationReason),
 }
 
+/// How long children get to exit after a termination signal before the
+/// orchestrator checks for survivors.
+const ORPHAN_CHECK_GRACE: Duration = Duration::from_millis(250);
+
+/// Sends SIGTERM to the process group, then verifies nothing outlived it.
+///
+/// Children that ignore the group signal (or left the group) are warned
+/// about, sent SIGTERM directly, and finally SIGKILLed if still alive. The
+/// surviving PIDs are returned, with whether SIGKILL was needed, so the caller
+/// can record them in diagnostics.
+#[cfg(unix)]
+async fn terminate_process_group() -> Option<(Vec<u32>, bool)> {
+    use crate::process_management::{signal_each, surviving_processes};
+    use nix::sys::signal::{Signal, killpg};
+    use nix::unistd::getpgrp;
+
+    let pgid = getpgrp();
+    debug!("Sending SIGTERM to process group {}", pgid);
+    let _ = killpg(pgid, Signal::SIGTERM);
+    tokio::time::sleep(ORPHAN_CHECK_GRACE).await;
+
+    let survivors = surviving_processes();
+    if survivors.is_empty() {
+        return None;
+    }
+    warn!(
+        "{} child process(es) still alive after SIGTERM to process group {}: {:?}",
+        survivors.len(),
+        pgid,
+        survivors
+    );
+    signal_each(&survivors, Signal::SIGTERM);
+    tokio::time::sleep(ORPHAN_CHECK_GRACE).await;
+
+    let stubborn: Vec<u32> = surviving_processes()
+        .into_iter()
+        .filter(|pid| survivors.contains(pid))
+        .collect();
+    if !stubborn.is_empty() {
+        warn!("Escalating to SIGKILL for {:?}", stubborn);
+        signal_each(&stubborn, Signal::SIGKILL);
+    }
+    let killed = !stubborn.is_empty();
+    Some((survivors, killed))
+}
+
 const LATE_EVENT_RECOVERY_MAX_POLLS: u32 = 5;
 const LATE_EVENT_RECOVERY_POLL_INTERVAL_MS: u64 = 50;
 const EMIT_RECOVERY_MAX_POLLS: u32 = 20;
@@ -4270,6 +4550,122 @@ fn prepare_tui_iteration(
     state.latest_iteration_lines_handle()
 }
 
+/// Execute a prompt through the plain (non-PTY) CLI executor.
+///
+/// Output is streamed to stdout and normalized for event parsing. This path
+/// reports no usage, so cost and token counts are zero.
+async fn execute_cli(
+    backend: &CliBackend,
+    config: &RalphConfig,
+    prompt: &str,
+    timeout: Option<Duration>,
+    verbosity: Verbosity,
+    backend_name: &str,
+) -> Result<ExecutionOutcome> {
+    let executor = CliExecutor::new(backend.clone());
+    let result = executor
+        .execute(prompt, stdout(), timeout, verbosity == Verbosity::Verbose)
+        .await?;
+    Ok(ExecutionOutcome {
+        output: normalize_cli_output_for_parsing(backend.output_format, &result.output),
+        success: result.success,
+        termination: None,
+        total_cost_usd: 0.0,
+        input_tokens: 0,
+        output_tokens: 0,
+        cache_read_tokens: 0,
+        cache_write_tokens: 0,
+        context_window: context_window_for_backend(config, backend_name),
+        context_tokens: 0,
+        num_turns: 0,
+    })
+}
+
+/// `cli.max_retries` / `cli.retry_backoff_ms` for transient backend failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BackendRetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl BackendRetryPolicy {
+    fn from_config(cli: &ralph_core::CliConfig) -> Self {
+        Self {
+            max_retries: cli.max_retries,
+            initial_backoff: Duration::from_millis(cli.retry_backoff_ms),
+        }
+    }
+
+    /// Delay before retry number `attempt` (1-based), doubling each time.
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+/// A retried backend invocation, logged to diagnostics once execution ends.
+struct BackendRetry {
+    attempt: u32,
+    delay: Duration,
+    reason: String,
+}
+
+/// Output fragments that mark a failed run as transient rather than a real failure.
+const TRANSIENT_FAILURE_MARKERS: &[&str] = &[
+    "rate limit",
+    "rate_limit",
+    "too many requests",
+    "overloaded",
+    "service unavailable",
+    "connection reset",
+    "connection refused",
+    "econnreset",
+    "etimedout",
+    "network error",
+    "temporarily unavailable",
+];
+
+/// How much of the end of a failed run's output is searched for transient markers.
+const TRANSIENT_FAILURE_TAIL_BYTES: usize = 4096;
+
+/// Returns why a backend invocation is worth retrying, or `None` if it is not.
+///
+/// Retryable: I/O errors while spawning or talking to the backend (other than
+/// a missing binary, denied permission or invalid input, which a retry cannot
+/// fix) and failed runs whose output ends with a rate-limit or connectivity
+/// message. Other errors — bad arguments, config problems — and ordinary
+/// failed runs are not retried.
+fn retryable_backend_failure(result: &Result<ExecutionOutcome>) -> Option<String> {
+    match result {
+        Err(error) => {
+            let io_error = error
+                .chain()
+                .find_map(|cause| cause.downcast_ref::<std::io::Error>())?;
+            let permanent = matches!(
+                io_error.kind(),
+                std::io::ErrorKind::NotFound
+                    | std::io::ErrorKind::PermissionDenied
+                    | std::io::ErrorKind::InvalidInput
+                    | std::io::ErrorKind::Unsupported
+            );
+            (!permanent).then(|| format!("{error:#}"))
+        }
+        Ok(outcome) if !outcome.success && outcome.termination.is_none() => {
+            let output = &outcome.output;
+            let mut start = output.len().saturating_sub(TRANSIENT_FAILURE_TAIL_BYTES);
+            while !output.is_char_boundary(start) {
+                start += 1;
+            }
+            let tail = output[start..].to_lowercase();
+            TRANSIENT_FAILURE_MARKERS
+                .iter()
+                .find(|marker| tail.contains(*marker))
+                .map(|marker| format!("output reported '{marker}'"))
+        }
+        Ok(_) => None,
+    }
+}
+
 /// Execute a prompt via ACP (Agent Client Protocol) for kiro-acp backend.
 async fn execute_acp(
     backend: &CliBackend,
@@ -4544,7 +4940,7 @@ fn log_events_from_output(
                 "Event has no subscriber - logging event.orphaned"
             );
 
-            let orphan_event = Event::new(
+            let orphan_event = EventBuilder::system(
                 "event.orphaned",
                 format!(
                     "Event '{}' has no subscriber hat. Valid events to publish: {:?}",
@@ -4624,7 +5020,7 @@ fn log_iteration_summary(
         "context_tokens": metrics.context_tokens,
         "context_pct": context_pct,
     });
-    let event = Event::new(
+    let event = EventBuilder::system(
         "iteration.summary",
         serde_json::to_string(&payload).unwrap_or_default(),
     );
@@ -4663,7 +5059,9 @@ fn get_last_commit_info() -> Option<String> {
 /// 5. Default PROMPT.md
 ///
 /// Note: CLI overrides are already applied to config before this function is called.
-fn resolve_prompt_content(event_loop_config: &ralph_core::EventLoopConfig) -> Result<String> {
+pub(crate) fn resolve_prompt_content(
+    event_loop_config: &ralph_core::EventLoopConfig,
+) -> Result<String> {
     debug!(
         inline_prompt = ?event_loop_config.prompt.as_ref().map(|s| format!("{}...", &s[..s.len().min(50)])),
         prompt_file = %event_loop_config.prompt_file,
@@ -4782,7 +5180,7 @@ fn check_planning_session_responses_for_session(
         }
 
         // Publish as user.response event
-        let event = Event::new(
+        let event = EventBuilder::system(
             "user.response",
             format!("[id: {}] {}", entry.id, entry.text),
         );
@@ -4804,8 +5202,14 @@ fn check_planning_session_responses_for_session(
 /// Processes pending merges from the merge queue.
 ///
 /// Called when the primary loop completes successfully. Spawns merge-ralph
-/// processes for each queued loop in FIFO order.
-fn process_pending_merges_with_command(repo_root: &Path, ralph_cmd: &OsStr) {
+/// processes for each queued loop in FIFO order. When `pre_merge` is set, it
+/// must pass in the loop's worktree first; otherwise the loop is marked
+/// `verification-failed` and skipped.
+fn process_pending_merges_with_command(
+    repo_root: &Path,
+    ralph_cmd: &OsStr,
+    pre_merge: Option<&ralph_core::Verification>,
+) {
     let queue = MergeQueue::new(repo_root);
 
     // Get all pending merges
@@ -4879,7 +5283,33 @@ fn process_pending_merges_with_command(repo_root: &Path, ralph_cmd: &OsStr) {
     for entry in pending {
         let loop_id = &entry.loop_id;
 
-        info!(loop_id = %loop_id, "Spawning merge-ralph process");
+        if let Some(verification) = pre_merge
+            && !pre_merge_verification_passed(&queue, repo_root, loop_id, verification)
+        {
+            continue;
+        }
+
+        // A squash that would flatten merges or multiple authors needs a human.
+        if entry.strategy == MergeStrategy::Squash {
+            match squash_history_loss(repo_root, loop_id) {
+                Ok(Some(reason)) => {
+                    warn!(loop_id = %loop_id, reason = %reason, "Squash merge needs review");
+                    if let Err(e) = queue
+                        .mark_merging(loop_id, std::process::id())
+                        .and_then(|()| queue.mark_needs_review(loop_id, &reason))
+                    {
+                        warn!(loop_id = %loop_id, error = %e, "Failed to mark merge for review");
+                    }
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(loop_id = %loop_id, error = %e, "Failed to inspect branch history");
+                }
+            }
+        }
+
+        info!(loop_id = %loop_id, strategy = %entry.strategy, "Spawning merge-ralph process");
 
         // Redirect subprocess stdio to a log file to prevent TUI corruption.
         // If log file creation fails, fall back to Stdio::null rather than
@@ -4920,7 +5350,7 @@ fn process_pending_merges_with_command(repo_root: &Path, ralph_cmd: &OsStr) {
                 "-p",
                 &format!("Merge loop {} from branch ralph/{}", loop_id, loop_id),
             ])
-            .env("RALPH_MERGE_LOOP_ID", loop_id)
+            .envs(merge_loop_env(repo_root, loop_id, entry.strategy))
             .stdout(stdout_stdio)
             .stderr(stderr_stdio)
             .spawn()
@@ -4974,15 +5404,72 @@ fn create_merge_subprocess_log_file(
     Ok((file, log_path))
 }
 
-fn process_pending_merges(repo_root: &Path) {
-    process_pending_merges_with_command(repo_root, OsStr::new("ralph"));
+/// Runs `command` in the worktree of `loop_id`, recording a failure in the queue.
+///
+/// Returns false if the command failed (or could not run) and the merge
+/// should be skipped. A loop without a worktree is not verified.
+fn pre_merge_verification_passed(
+    queue: &MergeQueue,
+    repo_root: &Path,
+    loop_id: &str,
+    verification: &ralph_core::Verification,
+) -> bool {
+    let command = verification.command.as_str();
+    let branch = format!("ralph/{}", loop_id);
+    let worktree = match ralph_core::worktree::list_ralph_worktrees(repo_root) {
+        Ok(worktrees) => worktrees.into_iter().find(|wt| wt.branch == branch),
+        Err(e) => {
+            warn!(loop_id = %loop_id, error = %e, "Failed to list worktrees for pre-merge verification");
+            None
+        }
+    };
+
+    let result = match worktree {
+        Some(worktree) => {
+            info!(loop_id = %loop_id, command = %command, "Running pre-merge verification");
+            ralph_core::workspace::run_verification_in(&worktree.path, verification)
+                .unwrap_or_else(|e| unrun_verification(e.to_string()))
+        }
+        None => unrun_verification(format!("No worktree found for branch {branch}")),
+    };
+    if result.passed {
+        return true;
+    }
+
+    warn!(
+        loop_id = %loop_id,
+        result = %result.summary(),
+        "Pre-merge verification failed, skipping merge"
+    );
+    if let Err(e) = queue.mark_verification_failed(loop_id, command, &result) {
+        warn!(loop_id = %loop_id, error = %e, "Failed to record verification failure");
+    }
+    false
+}
+
+/// Failed verification result for a pre-merge command that could not run.
+fn unrun_verification(reason: String) -> ralph_core::workspace::VerificationResult {
+    ralph_core::workspace::VerificationResult {
+        passed: false,
+        exit_code: -1,
+        expected_exit_code: 0,
+        stdout: String::new(),
+        stderr: reason,
+        coverage: None,
+        failure_kind: Some(ralph_core::VerificationFailure::NotRun),
+        failed_assertion: None,
+    }
+}
+
+fn process_pending_merges(repo_root: &Path, pre_merge: Option<&ralph_core::Verification>) {
+    process_pending_merges_with_command(repo_root, OsStr::new("ralph"), pre_merge);
 }
 
 /// Public wrapper for CLI invocation of process_pending_merges.
 ///
 /// Called by `ralph loops process` command to process the merge queue.
-pub fn process_pending_merges_cli(repo_root: &Path) {
-    process_pending_merges(repo_root);
+pub fn process_pending_merges_cli(repo_root: &Path, pre_merge: Option<&ralph_core::Verification>) {
+    process_pending_merges(repo_root, pre_merge);
 }
 
 /// Start a loop from an external caller (e.g., the bot daemon).
@@ -5069,6 +5556,8 @@ pub async fn start_loop(
         Vec::new(),         // no custom args
         None,               // default auto-merge
         None,               // no explicit loop ID
+        false,              // not resuming, nothing to rescan
+        None,               // fresh seed
     )
     .await
 }
@@ -5264,9 +5753,17 @@ async fn handle_wave_events(
     }
 
     let main_events_file = resolve_current_events_path(ctx);
+    // A hat-level backend gets its own adapter's env rather than the global one
+    let hat_adapter_env = detected
+        .hat_config
+        .backend
+        .as_ref()
+        .map(|b| event_loop.config().adapter_env(&b.to_cli_backend()))
+        .unwrap_or_default();
     let wave_result = execute_wave(
         &detected,
         backend,
+        &hat_adapter_env,
         &main_events_file,
         out.show_cli,
         out.use_colors,
@@ -5365,9 +5862,11 @@ async fn handle_wave_events(
 ///
 /// Creates per-worker event files, spawns workers with concurrency-limited
 /// semaphore, collects results, and returns a `CompletedWave`.
+#[allow(clippy::too_many_arguments)]
 async fn execute_wave(
     wave: &ralph_core::DetectedWave,
     global_backend: &CliBackend,
+    hat_adapter_env: &[(String, String)],
     main_events_file: &Path,
     show_progress: bool,
     use_colors: bool,
@@ -5425,7 +5924,13 @@ async fn execute_wave(
 
         // Resolve backend for this worker
         let mut worker_backend = if let Some(ref hat_backend) = hat_config.backend {
-            CliBackend::from_hat_backend(hat_backend).unwrap_or_else(|_| global_backend.clone())
+            CliBackend::from_hat_backend(hat_backend).map_or_else(
+                |_| global_backend.clone(),
+                |mut backend| {
+                    backend.apply_env(hat_adapter_env.iter().cloned());
+                    backend
+                },
+            )
         } else {
             global_backend.clone()
         };
@@ -5456,10 +5961,11 @@ async fn execute_wave(
             ),
         ]);
 
-        // Apply hat backend args
+        // Apply hat backend args and sampling settings
         if let Some(ref args) = hat_config.backend_args {
             worker_backend.args.extend(args.iter().cloned());
         }
+        worker_backend.apply_sampling(hat_config.temperature, hat_config.max_tokens);
 
         let worker_events_path = worker_events_file.clone();
         let tx = progress_tx.clone();
@@ -6376,6 +6882,26 @@ mod tests {
         assert_eq!(context_window_for_backend(&config, "claude"), 200_000);
     }
 
+    #[test]
+    fn test_iteration_cost_usd_falls_back_to_adapter_cost_markers() {
+        let config = RalphConfig::parse_yaml(
+            "adapters:\n  gemini:\n    cost_markers: [\"spend=\"]\n  codex:\n    cost_markers: []\n",
+        )
+        .unwrap();
+        let output = "work done\nspend=0.75\nTotal cost: $9.00\n{\"total_cost_usd\":0.40}\n";
+
+        // Native cost from the adapter wins
+        assert!((iteration_cost_usd(&config, "claude", 0.05, output) - 0.05).abs() < f64::EPSILON);
+        // Built-in markers
+        assert!((iteration_cost_usd(&config, "claude", 0.0, output) - 0.40).abs() < f64::EPSILON);
+        // No built-in markers for other backends
+        assert!(iteration_cost_usd(&config, "kiro", 0.0, output).abs() < f64::EPSILON);
+        // Configured markers replace the built-in ones
+        assert!((iteration_cost_usd(&config, "gemini", 0.0, output) - 0.75).abs() < f64::EPSILON);
+        // Empty list disables parsing
+        assert!(iteration_cost_usd(&config, "codex", 0.0, output).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_resolve_loop_id_fresh_generates_new() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -9932,6 +10458,66 @@ hats:
         );
     }
 
+    fn failed_outcome(output: &str) -> ExecutionOutcome {
+        ExecutionOutcome {
+            output: output.to_string(),
+            success: false,
+            termination: None,
+            total_cost_usd: 0.0,
+            input_tokens: 0,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_write_tokens: 0,
+            context_window: 0,
+            context_tokens: 0,
+            num_turns: 0,
+        }
+    }
+
+    #[test]
+    fn test_retryable_backend_failure_classifies_errors() {
+        let io_error = |kind| -> Result<ExecutionOutcome> {
+            Err(anyhow::Error::new(std::io::Error::new(kind, "boom")).context("Failed to spawn"))
+        };
+        assert!(retryable_backend_failure(&io_error(std::io::ErrorKind::BrokenPipe)).is_some());
+        assert!(retryable_backend_failure(&io_error(std::io::ErrorKind::WouldBlock)).is_some());
+        // A missing binary or bad arguments will not fix themselves
+        assert!(retryable_backend_failure(&io_error(std::io::ErrorKind::NotFound)).is_none());
+        assert!(retryable_backend_failure(&io_error(std::io::ErrorKind::InvalidInput)).is_none());
+        assert!(retryable_backend_failure(&Err(anyhow::anyhow!("unknown flag --foo"))).is_none());
+
+        let rate_limited = failed_outcome("Error: 429 Too Many Requests");
+        assert_eq!(
+            retryable_backend_failure(&Ok(rate_limited)).as_deref(),
+            Some("output reported 'too many requests'")
+        );
+        assert!(retryable_backend_failure(&Ok(failed_outcome("tests failed"))).is_none());
+
+        let mut succeeded = failed_outcome("rate limit mentioned in passing");
+        succeeded.success = true;
+        assert!(retryable_backend_failure(&Ok(succeeded)).is_none());
+    }
+
+    #[test]
+    fn test_backend_retries_are_opt_in() {
+        let policy = BackendRetryPolicy::from_config(&ralph_core::CliConfig::default());
+        assert_eq!(policy.max_retries, 0);
+    }
+
+    #[test]
+    fn test_backend_retry_policy_backoff_doubles() {
+        let cli = ralph_core::CliConfig {
+            max_retries: 3,
+            retry_backoff_ms: 250,
+            ..Default::default()
+        };
+        let policy = BackendRetryPolicy::from_config(&cli);
+
+        assert_eq!(policy.max_retries, 3);
+        assert_eq!(policy.backoff(1), Duration::from_millis(250));
+        assert_eq!(policy.backoff(3), Duration::from_secs(1));
+    }
+
     #[test]
     fn test_wave_worker_execution_mode_supports_all_backend_formats() {
         assert_eq!(
@@ -10164,10 +10750,15 @@ hats:
                 publishes,
                 instructions: "Emit review.done when finished.".to_string(),
                 extra_instructions: vec![],
+                instructions_file: None,
+                extends: None,
                 backend: None,
                 backend_args: None,
+                temperature: None,
+                max_tokens: None,
                 default_publishes: None,
                 max_activations: None,
+                max_output_bytes: None,
                 disallowed_tools: vec![],
                 timeout: Some(timeout_secs),
                 concurrency: 1,
@@ -10222,7 +10813,7 @@ hats:
 
         let events_file = temp_dir.path().join("events.jsonl");
         let wave = make_test_wave_with_timeout(vec!["review.done".to_string()], timeout_secs);
-        execute_wave(&wave, &backend, &events_file, false, false, None, None)
+        execute_wave(&wave, &backend, &[], &events_file, false, false, None, None)
             .await
             .expect("wave execution")
     }
@@ -10251,7 +10842,7 @@ hats:
 
         let events_file = temp_dir.path().join("events.jsonl");
         let wave = make_test_wave(vec!["review.done".to_string()]);
-        execute_wave(&wave, &backend, &events_file, false, false, None, None)
+        execute_wave(&wave, &backend, &[], &events_file, false, false, None, None)
             .await
             .expect("wave execution")
     }
@@ -10314,7 +10905,7 @@ hats:
             30,
             task_payload.to_string(),
         );
-        let completed = execute_wave(&wave, &backend, &events_file, false, false, None, None)
+        let completed = execute_wave(&wave, &backend, &[], &events_file, false, false, None, None)
             .await
             .expect("wave execution");
         let captured: CapturedWaveInvocation = serde_json::from_str(
@@ -10367,6 +10958,7 @@ hats:
         execute_wave(
             &wave,
             &global_backend,
+            &[],
             &events_file,
             false,
             false,
@@ -10410,6 +11002,7 @@ hats:
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &[],
             &events_file,
             false,
             false,
@@ -10450,7 +11043,7 @@ hats:
         wave.hat_config.backend_args = backend_args;
         let backend = CliBackend::from_name("kiro-acp").expect("named ACP backend");
 
-        let completed = execute_wave(&wave, &backend, &events_file, false, false, None, None)
+        let completed = execute_wave(&wave, &backend, &[], &events_file, false, false, None, None)
             .await
             .expect("wave execution");
         let captured: CapturedAcpWaveInvocation = serde_json::from_str(
@@ -10500,6 +11093,7 @@ hats:
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &[],
             &events_file,
             false,
             false,
@@ -12292,6 +12886,7 @@ EOF"#,
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &[],
             &events_file,
             false,
             false,
@@ -12693,7 +13288,7 @@ EOF"#,
         let repo_root = temp_dir.path();
         std::fs::create_dir_all(repo_root.join(".ralph/merge-queue")).expect("queue dir");
 
-        process_pending_merges(repo_root);
+        process_pending_merges(repo_root, None);
     }
 
     #[cfg(unix)]
@@ -12714,7 +13309,147 @@ EOF"#,
         std::fs::create_dir_all(&bin_dir).expect("bin dir");
         let ralph_path = write_fake_executable(&bin_dir, "ralph", "exit 0");
 
-        process_pending_merges_with_command(repo_root, ralph_path.as_os_str());
+        process_pending_merges_with_command(repo_root, ralph_path.as_os_str(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_pending_merges_skips_loop_failing_pre_merge_command() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let repo_root = temp_dir.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(repo_root)
+                .output()
+                .expect("git");
+        };
+        git(&["init", "-q", "--initial-branch=main"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "Initial commit"]);
+        git(&[
+            "worktree",
+            "add",
+            "-q",
+            "-b",
+            "ralph/loop-v",
+            ".worktrees/loop-v",
+        ]);
+
+        let queue = ralph_core::merge_queue::MergeQueue::new(repo_root);
+        queue.enqueue("loop-v", "merge prompt").expect("enqueue");
+
+        let bin_dir = repo_root.join("bin");
+        std::fs::create_dir_all(&bin_dir).expect("bin dir");
+        let marker = repo_root.join("spawned");
+        let ralph_path =
+            write_fake_executable(&bin_dir, "ralph", &format!("touch {}", marker.display()));
+
+        process_pending_merges_with_command(
+            repo_root,
+            ralph_path.as_os_str(),
+            Some(&ralph_core::Verification::new(
+                "echo broken build && exit 3",
+            )),
+        );
+
+        let entry = queue.get_entry("loop-v").expect("read").expect("entry");
+        assert_eq!(
+            entry.state,
+            ralph_core::merge_queue::MergeState::VerificationFailed
+        );
+        assert!(
+            entry.failure_reason.unwrap().contains("exit code 3"),
+            "failure reason should carry the exit code"
+        );
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(!marker.exists(), "merge-ralph must not be spawned");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_pending_merges_times_out_pre_merge_command() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let repo_root = temp_dir.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(repo_root)
+                .output()
+                .expect("git");
+        };
+        git(&["init", "-q", "--initial-branch=main"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "Initial commit"]);
+        git(&[
+            "worktree",
+            "add",
+            "-q",
+            "-b",
+            "ralph/loop-slow",
+            ".worktrees/loop-slow",
+        ]);
+
+        let queue = ralph_core::merge_queue::MergeQueue::new(repo_root);
+        queue.enqueue("loop-slow", "merge prompt").expect("enqueue");
+
+        let merge = ralph_core::MergeConfig {
+            pre_merge_command: Some("sleep 30".to_string()),
+            pre_merge_timeout_secs: 1,
+            ..ralph_core::MergeConfig::default()
+        };
+        let started = std::time::Instant::now();
+        process_pending_merges_with_command(
+            repo_root,
+            OsStr::new("ralph-command-missing-12345"),
+            merge.pre_merge_verification().as_ref(),
+        );
+        assert!(started.elapsed() < Duration::from_secs(20));
+
+        let entry = queue.get_entry("loop-slow").expect("read").expect("entry");
+        assert_eq!(
+            entry.state,
+            ralph_core::merge_queue::MergeState::VerificationFailed
+        );
+        assert_eq!(
+            entry.failure_reason.as_deref(),
+            Some("Pre-merge command `sleep 30` timed out")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_pending_merges_blocks_loop_without_worktree() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let repo_root = temp_dir.path();
+        let queue = ralph_core::merge_queue::MergeQueue::new(repo_root);
+        queue.enqueue("loop-gone", "merge prompt").expect("enqueue");
+
+        let bin_dir = repo_root.join("bin");
+        std::fs::create_dir_all(&bin_dir).expect("bin dir");
+        let marker = repo_root.join("spawned");
+        let ralph_path =
+            write_fake_executable(&bin_dir, "ralph", &format!("touch {}", marker.display()));
+
+        process_pending_merges_with_command(
+            repo_root,
+            ralph_path.as_os_str(),
+            Some(&ralph_core::Verification::new("true")),
+        );
+
+        let entry = queue.get_entry("loop-gone").expect("read").expect("entry");
+        assert_eq!(
+            entry.state,
+            ralph_core::merge_queue::MergeState::VerificationFailed
+        );
+        assert_eq!(
+            entry.failure_reason.as_deref(),
+            Some(
+                "Pre-merge command `true` did not run: No worktree found for branch ralph/loop-gone"
+            )
+        );
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(!marker.exists(), "merge-ralph must not be spawned");
     }
 
     #[test]
@@ -12724,7 +13459,11 @@ EOF"#,
         let queue = ralph_core::merge_queue::MergeQueue::new(repo_root);
         queue.enqueue("loop-9999", "merge prompt").expect("enqueue");
 
-        process_pending_merges_with_command(repo_root, OsStr::new("ralph-command-missing-12345"));
+        process_pending_merges_with_command(
+            repo_root,
+            OsStr::new("ralph-command-missing-12345"),
+            None,
+        );
 
         let config_path = repo_root.join(".ralph/merge-loop-config.yml");
         assert!(config_path.exists());
@@ -12744,7 +13483,7 @@ EOF"#,
         let config_path = repo_root.join(".ralph/merge-loop-config.yml");
         assert!(!config_path.exists());
 
-        process_pending_merges_with_command(repo_root, OsStr::new("ralph"));
+        process_pending_merges_with_command(repo_root, OsStr::new("ralph"), None);
 
         assert!(!config_path.exists());
     }
@@ -12768,7 +13507,7 @@ EOF"#,
             "echo 'stdout output' && echo 'stderr output' >&2 && sleep 0.1",
         );
 
-        process_pending_merges_with_command(repo_root, ralph_path.as_os_str());
+        process_pending_merges_with_command(repo_root, ralph_path.as_os_str(), None);
 
         // Wait for subprocess to finish writing
         std::thread::sleep(std::time::Duration::from_millis(500));
@@ -12820,7 +13559,7 @@ EOF"#,
         let ralph_path = write_fake_executable(&bin_dir, "ralph", "exit 0");
 
         // Should not panic even though log file creation fails
-        process_pending_merges_with_command(repo_root, ralph_path.as_os_str());
+        process_pending_merges_with_command(repo_root, ralph_path.as_os_str(), None);
     }
 
     #[test]
@@ -11,12 +11,14 @@
 //! - `stop`: Terminate running loop
 //! - `resume`: Resume a suspended loop
 //! - `prune`: Clean up stale loops
-//! - `attach`: Open shell in worktree
+//! - `attach`: Stream a loop's live output (or open a shell in its worktree)
 //! - `diff`: Show changes from merge-base
 //! - `publish-review`: Push a loop branch for remote review
 //! - `rebase`: Rebase loop branches onto a base branch without merging
+//! - `summary`: Roll up finished loops with their outcome and merge status
 
 use std::collections::BTreeSet;
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::{Duration, Instant};
@@ -24,10 +26,17 @@ use std::time::{Duration, Instant};
 use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
 
+use crate::ConfigSource;
+use crate::hats::DiagramFormat;
+
 use ralph_core::worktree::{list_ralph_worktrees, remove_worktree};
+use ralph_proto::UxEvent;
+
+use chrono::{DateTime, Utc};
 use ralph_core::{
-    LoopRegistry, MergeButtonState, MergeQueue, MergeState, SuspendStateStore, merge_button_state,
-    truncate_with_ellipsis,
+    EventHistory, HistoryCounts, HistoryEvent, HistoryFilter, LoopHistory, LoopNameGenerator,
+    LoopNameTheme, LoopRegistry, MergeButtonState, MergeQueue, MergeState, Record,
+    SuspendStateStore, TerminationReason, is_gzip_path, merge_button_state, truncate_with_ellipsis,
 };
 
 /// Manage parallel loops.
@@ -63,7 +72,7 @@ pub enum LoopsCommands {
     /// Clean up stale loops (crashed processes)
     Prune,
 
-    /// Open shell in loop's worktree
+    /// Stream a running loop's output live (--shell opens a shell in its worktree)
     Attach(AttachArgs),
 
     /// Show diff of loop's changes from merge-base
@@ -83,6 +92,15 @@ pub enum LoopsCommands {
 
     /// Get merge button state for a loop (JSON output for web API)
     MergeButtonState(MergeButtonStateArgs),
+
+    /// Show parent/child relationships between loops as a Mermaid or DOT graph
+    Graph(GraphArgs),
+
+    /// Preview loop names generated with the configured (or given) theme
+    Name(NameArgs),
+
+    /// Roll up finished loops: objective, outcome, iterations and merge status
+    Summary(SummaryArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -108,12 +126,53 @@ pub struct LogsArgs {
 
 #[derive(Parser, Debug)]
 pub struct HistoryArgs {
-    /// Loop ID
-    pub loop_id: String,
+    /// Loop ID (default: the primary loop and every worktree loop)
+    pub loop_id: Option<String>,
 
     /// Output raw JSONL instead of formatted table
     #[arg(long)]
     pub json: bool,
+
+    /// Only events since this time: a relative age (`30m`, `12h`, `7d`, `2w`),
+    /// a date (`2026-01-31`) or an RFC 3339 timestamp
+    #[arg(long, value_parser = parse_time_filter)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only events before this time (same formats as --since)
+    #[arg(long, value_parser = parse_time_filter)]
+    pub until: Option<DateTime<Utc>>,
+
+    /// Only run outcomes with this termination reason (e.g. `completed`,
+    /// `max_iterations`, `interrupted`)
+    #[arg(long)]
+    pub reason: Option<TerminationReason>,
+}
+
+/// Parses a `--since`/`--until` value into an absolute time.
+///
+/// Accepts RFC 3339, `YYYY-MM-DD`, or an age relative to now (`30m`, `1h`,
+/// `7d`, `2w`).
+pub(crate) fn parse_time_filter(value: &str) -> std::result::Result<DateTime<Utc>, String> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+
+    let split = value.char_indices().last().map_or(0, |(index, _)| index);
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("invalid time '{value}' (expected e.g. 7d, 2026-01-31)"))?;
+    let age = match unit {
+        "m" => chrono::Duration::minutes(amount),
+        "h" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        "w" => chrono::Duration::weeks(amount),
+        _ => return Err(format!("invalid time unit in '{value}' (use m, h, d or w)")),
+    };
+    Ok(Utc::now() - age)
 }
 
 #[derive(Parser, Debug)]
@@ -153,6 +212,14 @@ pub struct ResumeArgs {
 pub struct AttachArgs {
     /// Loop ID
     pub loop_id: String,
+
+    /// Stream only the loop's events, not its session recording
+    #[arg(long, conflicts_with = "shell")]
+    pub events_only: bool,
+
+    /// Open a shell in the loop's worktree instead of streaming its output
+    #[arg(long)]
+    pub shell: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -225,8 +292,41 @@ pub struct MergeButtonStateArgs {
     pub loop_id: String,
 }
 
+#[derive(Parser, Debug)]
+pub struct GraphArgs {
+    /// Graph format
+    #[arg(long, default_value = "mermaid")]
+    pub format: DiagramFormat,
+
+    /// Write to this file instead of stdout (e.g. loops.mmd)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+pub struct SummaryArgs {
+    /// Output JSON instead of table
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct NameArgs {
+    /// Word theme (default: features.loop_naming.theme)
+    #[arg(long, value_name = "THEME")]
+    pub theme: Option<LoopNameTheme>,
+
+    /// Number of sample names to print
+    #[arg(short = 'n', long, default_value_t = 1)]
+    pub count: usize,
+}
+
 /// Execute a loops command.
-pub fn execute(args: LoopsArgs, use_colors: bool) -> Result<()> {
+pub async fn execute(
+    config_sources: &[ConfigSource],
+    args: LoopsArgs,
+    use_colors: bool,
+) -> Result<()> {
     match args.command {
         None => list_loops(
             ListArgs {
@@ -243,22 +343,57 @@ pub fn execute(args: LoopsArgs, use_colors: bool) -> Result<()> {
         Some(LoopsCommands::Stop(stop_args)) => stop_loop(stop_args),
         Some(LoopsCommands::Resume(resume_args)) => resume_loop(resume_args),
         Some(LoopsCommands::Prune) => prune_stale(),
-        Some(LoopsCommands::Attach(attach_args)) => attach_to_loop(attach_args),
+        Some(LoopsCommands::Attach(attach_args)) if attach_args.shell => {
+            shell_into_loop(attach_args)
+        }
+        Some(LoopsCommands::Attach(attach_args)) => attach_to_loop(attach_args, use_colors).await,
         Some(LoopsCommands::Diff(diff_args)) => show_diff(diff_args),
         Some(LoopsCommands::PublishReview(args)) => publish_review(args),
         Some(LoopsCommands::Rebase(args)) => rebase_loops(args),
         Some(LoopsCommands::Merge(merge_args)) => merge_loop(merge_args),
-        Some(LoopsCommands::Process) => process_queue(),
+        Some(LoopsCommands::Process) => process_queue(config_sources),
         Some(LoopsCommands::MergeButtonState(args)) => get_merge_button_state(args),
+        Some(LoopsCommands::Graph(args)) => graph_loops(args),
+        Some(LoopsCommands::Name(args)) => preview_names(config_sources, &args),
+        Some(LoopsCommands::Summary(args)) => summarize_finished_loops(&args),
     }
 }
 
+/// Print sample loop names, unique against registered loops and each other.
+fn preview_names(config_sources: &[ConfigSource], args: &NameArgs) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let mut naming = crate::load_config_with_overrides(config_sources)
+        .map(|config| config.features.loop_naming)
+        .unwrap_or_default();
+    if let Some(theme) = args.theme {
+        naming.theme = theme;
+    }
+
+    let mut taken: Vec<String> = LoopRegistry::new(&cwd)
+        .list()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| entry.id)
+        .collect();
+    let generator = LoopNameGenerator::new(naming);
+    for _ in 0..args.count {
+        let name = generator.generate_memorable_unique(|name| taken.iter().any(|id| id == name));
+        println!("{name}");
+        taken.push(name);
+    }
+    Ok(())
+}
+
 /// Process pending merge queue entries.
-fn process_queue() -> Result<()> {
+fn process_queue(config_sources: &[ConfigSource]) -> Result<()> {
     let cwd = std::env::current_dir()?;
+    let pre_merge = crate::load_config_with_overrides(config_sources)
+        .context("Failed to load config for merge queue processing")?
+        .merge
+        .pre_merge_verification();
 
     // Delegate to the loop_runner's process_pending_merges function
-    crate::loop_runner::process_pending_merges_cli(&cwd);
+    crate::loop_runner::process_pending_merges_cli(&cwd, pre_merge.as_ref());
 
     Ok(())
 }
@@ -311,20 +446,28 @@ fn format_age(duration: chrono::Duration) -> String {
     }
 }
 
-/// List all loops with their status.
-fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
+/// Loops shown by `ralph loops list`.
+struct LoopListing {
+    rows: Vec<LoopRow>,
+    /// Merged/discarded entries left out because `--all` was not given.
+    hidden_terminal_count: usize,
+    has_needs_review: bool,
+}
+
+/// Collects the primary loop, registered loops, merge queue entries and
+/// orphan worktrees under `cwd` into list rows.
+fn collect_loop_rows(cwd: &Path, all: bool) -> LoopListing {
     use ralph_core::LoopLock;
 
-    let cwd = std::env::current_dir()?;
-    let registry = LoopRegistry::new(&cwd);
-    let merge_queue = MergeQueue::new(&cwd);
+    let registry = LoopRegistry::new(cwd);
+    let merge_queue = MergeQueue::new(cwd);
     let now = chrono::Utc::now();
 
     // Get loops from registry
     let loop_entries = registry.list().unwrap_or_default();
 
     // Get worktrees for additional info
-    let worktrees = list_ralph_worktrees(&cwd).unwrap_or_default();
+    let worktrees = list_ralph_worktrees(cwd).unwrap_or_default();
 
     // Get merge queue entries
     let merge_entries = merge_queue.list().unwrap_or_default();
@@ -335,24 +478,29 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
     let mut hidden_terminal_count = 0;
 
     // Check for primary loop holding the lock (not in a worktree)
-    if let Ok(true) = LoopLock::is_locked(&cwd) {
+    if let Ok(true) = LoopLock::is_locked(cwd) {
         // Only show primary loop if it's not already tracked in the registry
         // (Registry entries with no worktree_path are primary loops)
         let primary_in_registry = loop_entries
             .iter()
             .any(|e| e.worktree_path.is_none() && e.is_alive());
 
-        if !primary_in_registry && let Ok(Some(metadata)) = LoopLock::read_existing(&cwd) {
+        if !primary_in_registry && let Ok(Some(metadata)) = LoopLock::read_existing(cwd) {
             // Verify the process is actually alive
             let is_alive = is_process_alive(metadata.pid);
             if is_alive {
+                // Primary loop doesn't have merge state
                 rows.push(LoopRow {
-                    id: "(primary)".to_string(),
-                    status: "running".to_string(),
-                    location: "(in-place)".to_string(),
-                    prompt: truncate(&metadata.prompt, 40),
-                    age: None,   // Primary loop age not easily available
-                    merge: None, // Primary loop doesn't have merge state
+                    age: Some(format_age(now.signed_duration_since(metadata.started))),
+                    pid: Some(metadata.pid),
+                    pid_alive: Some(true),
+                    started: Some(metadata.started),
+                    ..LoopRow::new(
+                        "(primary)".to_string(),
+                        "running",
+                        "(in-place)".to_string(),
+                        metadata.prompt,
+                    )
                 });
             }
         }
@@ -360,9 +508,13 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
 
     // Add running loops from registry
     for entry in &loop_entries {
-        let status = if entry.is_alive() {
+        let pid_alive = entry.is_pid_alive();
+        let status = if registry.is_alive(entry) {
             "running"
-        } else if entry.is_pid_alive() {
+        } else if entry.is_alive() {
+            // Process is up but has stopped heartbeating (hung, or PID reused)
+            "possibly-dead"
+        } else if pid_alive {
             // PID alive but is_alive() false → worktree removed externally
             "orphan"
         } else {
@@ -375,13 +527,22 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             .map(|p| shorten_path(p))
             .unwrap_or_else(|| "(in-place)".to_string());
 
+        let branch = entry.worktree_path.as_ref().map(|wt_path| {
+            worktrees
+                .iter()
+                .find(|wt| wt.path == Path::new(wt_path))
+                .map_or_else(|| format!("ralph/{}", entry.id), |wt| wt.branch.clone())
+        });
+
         rows.push(LoopRow {
-            id: entry.id.clone(),
-            status: status.to_string(),
-            location,
-            prompt: truncate(&entry.prompt, 40),
-            age: None, // Registry doesn't track start time
-            merge: None,
+            age: Some(format_age(now.signed_duration_since(entry.started))),
+            pid: Some(entry.pid),
+            pid_alive: Some(pid_alive),
+            branch,
+            worktree_path: entry.worktree_path.clone(),
+            started: Some(entry.started),
+            last_seen: entry.last_seen,
+            ..LoopRow::new(entry.id.clone(), status, location, entry.prompt.clone())
         });
     }
 
@@ -390,21 +551,13 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
         let already_listed = rows.iter().any(|r| r.id.ends_with(&entry.loop_id));
         if !already_listed {
             // Skip terminal merge states unless --all is specified
-            if entry.state.is_terminal() && !args.all {
+            if entry.state.is_terminal() && !all {
                 hidden_terminal_count += 1;
                 continue;
             }
 
-            let status = match entry.state {
-                MergeState::Queued => "queued",
-                MergeState::Merging => "merging",
-                MergeState::Merged => "merged",
-                MergeState::NeedsReview => {
-                    has_needs_review = true;
-                    "needs-review"
-                }
-                MergeState::Discarded => "discarded",
-            };
+            let status = merge_state_label(entry.state);
+            has_needs_review |= entry.state.needs_steering();
 
             // Calculate age from entry timestamp
             let age = Some(format_age(now.signed_duration_since(entry.queued_at)));
@@ -418,7 +571,7 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
 
             // Get merge button state for queued entries
             let merge_status = if entry.state == MergeState::Queued {
-                match merge_button_state(&cwd, &entry.loop_id) {
+                match merge_button_state(cwd, &entry.loop_id) {
                     Ok(MergeButtonState::Active) => Some("ready".to_string()),
                     Ok(MergeButtonState::Blocked { .. }) => Some("blocked".to_string()),
                     Err(_) => None,
@@ -428,12 +581,16 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             };
 
             rows.push(LoopRow {
-                id: entry.loop_id.clone(),
-                status: status.to_string(),
-                location,
-                prompt: truncate(&entry.prompt, 40),
                 age,
                 merge: merge_status,
+                branch: Some(format!("ralph/{}", entry.loop_id)),
+                started: Some(entry.queued_at),
+                ..LoopRow::new(
+                    entry.loop_id.clone(),
+                    status,
+                    location,
+                    entry.prompt.clone(),
+                )
             });
         }
     }
@@ -445,17 +602,47 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             let already_listed = rows.iter().any(|r| r.id.contains(loop_id));
             if !already_listed {
                 rows.push(LoopRow {
-                    id: loop_id.to_string(),
-                    status: "orphan".to_string(),
-                    location: shorten_path(&wt.path.to_string_lossy()),
-                    prompt: String::new(),
-                    age: None,
-                    merge: None,
+                    branch: Some(wt.branch.clone()),
+                    worktree_path: Some(wt.path.display().to_string()),
+                    ..LoopRow::new(
+                        loop_id.to_string(),
+                        "orphan",
+                        shorten_path(&wt.path.to_string_lossy()),
+                        String::new(),
+                    )
                 });
             }
         }
     }
 
+    LoopListing {
+        rows,
+        hidden_terminal_count,
+        has_needs_review,
+    }
+}
+
+/// Status label for a merge queue state, as shown by `ralph loops list`.
+fn merge_state_label(state: MergeState) -> &'static str {
+    match state {
+        MergeState::Queued => "queued",
+        MergeState::Merging => "merging",
+        MergeState::Merged => "merged",
+        MergeState::NeedsReview => "needs-review",
+        MergeState::VerificationFailed => "verification-failed",
+        MergeState::Discarded => "discarded",
+    }
+}
+
+/// List all loops with their status.
+fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let LoopListing {
+        rows,
+        hidden_terminal_count,
+        has_needs_review,
+    } = collect_loop_rows(&cwd, args.all);
+
     if rows.is_empty() {
         if args.json {
             println!("[]");
@@ -481,9 +668,11 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
     // Print summary header
     let summary_parts: Vec<String> = [
         "running",
+        "possibly-dead",
         "queued",
         "merging",
         "needs-review",
+        "verification-failed",
         "merged",
         "discarded",
         "crashed",
@@ -526,7 +715,7 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             merge_display,
             age_display,
             truncate(&row.location, 20),
-            row.prompt
+            truncate(&row.prompt, 40)
         );
     }
 
@@ -551,11 +740,204 @@ struct LoopRow {
     id: String,
     status: String,
     location: String,
+    /// Full prompt; the table truncates it when rendering.
     prompt: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     age: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     merge: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<u32>,
+    /// Whether `pid` is a running process (only set when `pid` is known).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid_alive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worktree_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started: Option<chrono::DateTime<chrono::Utc>>,
+    /// Last registry heartbeat, for registered loops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_seen: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl LoopRow {
+    /// A row with only the table columns filled in.
+    fn new(id: String, status: &str, location: String, prompt: String) -> Self {
+        Self {
+            id,
+            status: status.to_string(),
+            location,
+            prompt,
+            age: None,
+            merge: None,
+            pid: None,
+            pid_alive: None,
+            branch: None,
+            worktree_path: None,
+            started: None,
+            last_seen: None,
+        }
+    }
+}
+
+/// A loop as drawn by `ralph loops graph`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LoopNode {
+    id: String,
+    /// Worktree branch, or `None` for in-place loops.
+    branch: Option<String>,
+    parent_id: Option<String>,
+    /// The loop's process is gone (or its worktree was removed).
+    stale: bool,
+}
+
+/// Collects the primary loop and all registered loops with their branches.
+///
+/// Parents referenced by a child but no longer running are added as stale
+/// nodes so every edge has both ends.
+fn collect_loop_nodes(cwd: &Path) -> Vec<LoopNode> {
+    use ralph_core::LoopLock;
+
+    let entries = LoopRegistry::new(cwd).list().unwrap_or_default();
+    let worktrees = list_ralph_worktrees(cwd).unwrap_or_default();
+    let mut nodes: Vec<LoopNode> = Vec::new();
+
+    let primary_in_registry = entries
+        .iter()
+        .any(|e| e.worktree_path.is_none() && e.is_alive());
+    if !primary_in_registry
+        && let Ok(true) = LoopLock::is_locked(cwd)
+        && let Ok(Some(metadata)) = LoopLock::read_existing(cwd)
+        && is_process_alive(metadata.pid)
+    {
+        let id = std::fs::read_to_string(cwd.join(".ralph/current-loop-id"))
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "(primary)".to_string());
+        nodes.push(LoopNode {
+            id,
+            branch: None,
+            parent_id: None,
+            stale: false,
+        });
+    }
+
+    for entry in &entries {
+        let branch = entry.worktree_path.as_ref().map(|wt_path| {
+            worktrees
+                .iter()
+                .find(|wt| wt.path == Path::new(wt_path))
+                .map_or_else(|| format!("ralph/{}", entry.id), |wt| wt.branch.clone())
+        });
+        nodes.push(LoopNode {
+            id: entry.id.clone(),
+            branch,
+            parent_id: entry.parent_id.clone(),
+            stale: !entry.is_alive(),
+        });
+    }
+
+    let missing_parents: BTreeSet<String> = nodes
+        .iter()
+        .filter_map(|n| n.parent_id.clone())
+        .filter(|parent| !nodes.iter().any(|n| &n.id == parent))
+        .collect();
+    nodes.extend(missing_parents.into_iter().map(|id| LoopNode {
+        id,
+        branch: None,
+        parent_id: None,
+        stale: true,
+    }));
+
+    nodes
+}
+
+/// Renders loops as a Mermaid flowchart or DOT digraph with parent -> child edges.
+fn render_loop_graph(nodes: &[LoopNode], format: DiagramFormat) -> String {
+    let location = |node: &LoopNode| node.branch.as_deref().unwrap_or("(in-place)").to_string();
+
+    match format {
+        DiagramFormat::Mermaid => {
+            // Sanitized Mermaid node IDs (loop IDs contain dashes)
+            let node_id = |id: &str| -> String {
+                id.chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                    .collect()
+            };
+
+            let mut diagram = String::from("flowchart TD\n");
+            for node in nodes {
+                diagram.push_str(&format!(
+                    "    {}[\"{}<br/>{}\"]\n",
+                    node_id(&node.id),
+                    node.id,
+                    location(node)
+                ));
+            }
+            for node in nodes {
+                if let Some(parent) = &node.parent_id {
+                    diagram.push_str(&format!(
+                        "    {} --> {}\n",
+                        node_id(parent),
+                        node_id(&node.id)
+                    ));
+                }
+            }
+            let stale: Vec<String> = nodes
+                .iter()
+                .filter(|n| n.stale)
+                .map(|n| node_id(&n.id))
+                .collect();
+            if !stale.is_empty() {
+                diagram.push_str("    classDef stale stroke-dasharray: 5 5,color:#888\n");
+                diagram.push_str(&format!("    class {} stale\n", stale.join(",")));
+            }
+            diagram
+        }
+        DiagramFormat::Dot => {
+            let quote =
+                |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+
+            let mut diagram = String::from("digraph loops {\n    rankdir=TB;\n");
+            for node in nodes {
+                let style = if node.stale {
+                    ", style=dashed, color=gray"
+                } else {
+                    ""
+                };
+                diagram.push_str(&format!(
+                    "    {} [label={}{}];\n",
+                    quote(&node.id),
+                    quote(&format!("{}\\n{}", node.id, location(node))),
+                    style
+                ));
+            }
+            for node in nodes {
+                if let Some(parent) = &node.parent_id {
+                    diagram.push_str(&format!("    {} -> {};\n", quote(parent), quote(&node.id)));
+                }
+            }
+            diagram.push_str("}\n");
+            diagram
+        }
+    }
+}
+
+fn graph_loops(args: GraphArgs) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let graph = render_loop_graph(&collect_loop_nodes(&cwd), args.format);
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, graph)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            eprintln!("Wrote loop graph to {}", path.display());
+        }
+        None => print!("{}", graph),
+    }
+    Ok(())
 }
 
 fn colorize_status(status: &str) -> String {
@@ -564,7 +946,9 @@ fn colorize_status(status: &str) -> String {
         "merging" => format!("\x1b[33m{}\x1b[0m", status), // yellow
         "merged" => format!("\x1b[34m{}\x1b[0m", status),  // blue
         "needs-review" => format!("\x1b[31m{}\x1b[0m", status), // red
+        "verification-failed" => format!("\x1b[31m{}\x1b[0m", status), // red
         "crashed" => format!("\x1b[31m{}\x1b[0m", status), // red
+        "possibly-dead" => format!("\x1b[33m{}\x1b[0m", status), // yellow
         "orphan" => format!("\x1b[90m{}\x1b[0m", status),  // gray
         "queued" => format!("\x1b[36m{}\x1b[0m", status),  // cyan
         "discarded" => format!("\x1b[90m{}\x1b[0m", status), // gray
@@ -644,48 +1028,111 @@ fn show_logs(args: LogsArgs) -> Result<()> {
 /// Show history for a loop.
 fn show_history(args: HistoryArgs) -> Result<()> {
     let cwd = std::env::current_dir()?;
-    let (loop_id, worktree_path) = resolve_loop(&cwd, &args.loop_id)?;
+    let mut filter = HistoryFilter {
+        since: args.since,
+        until: args.until,
+        reason: args.reason.clone(),
+        loop_name: None,
+    };
+
+    let histories = if let Some(id) = &args.loop_id {
+        let (loop_id, worktree_path) = resolve_loop(&cwd, id)?;
+        let history_path = if let Some(wt_path) = worktree_path {
+            PathBuf::from(wt_path).join(".ralph/history.jsonl")
+        } else {
+            cwd.join(".ralph/history.jsonl")
+        };
 
-    let history_path = if let Some(wt_path) = worktree_path {
-        PathBuf::from(wt_path).join(".ralph/history.jsonl")
+        if !history_path.exists() {
+            bail!("No history file found for loop '{}'", loop_id);
+        }
+        filter.loop_name = Some(loop_id.clone());
+        vec![LoopHistory::new(history_path).with_loop_name(loop_id)]
     } else {
-        cwd.join(".ralph/history.jsonl")
+        LoopHistory::for_workspace(&cwd)
     };
 
-    if !history_path.exists() {
-        bail!("No history file found for loop '{}'", loop_id);
+    let mut rows = Vec::new();
+    for history in &histories {
+        let name = history.loop_name().unwrap_or("primary");
+        for event in history
+            .query(&filter)
+            .with_context(|| format!("Failed to read {}", history.path().display()))?
+        {
+            rows.push((name.to_string(), event));
+        }
     }
-
-    let contents = std::fs::read_to_string(&history_path).context("Failed to read history file")?;
+    rows.sort_by_key(|(_, event)| event.timestamp);
 
     if args.json {
-        print!("{}", contents);
-    } else {
-        // Format as table
-        println!("{:<25} {:<20} DATA", "TIMESTAMP", "TYPE");
-        println!("{}", "-".repeat(80));
-
-        for line in contents.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
-            if let Ok(event) = serde_json::from_str::<serde_json::Value>(line) {
-                let ts = event.get("ts").and_then(|v| v.as_str()).unwrap_or("-");
-                let event_type = event.get("type").and_then(|v| v.as_str()).unwrap_or("-");
-                let data = event.get("data").map(|v| v.to_string()).unwrap_or_default();
-                println!(
-                    "{:<25} {:<20} {}",
-                    truncate(ts, 25),
-                    event_type,
-                    truncate(&data, 35)
-                );
-            }
+        for (_, event) in &rows {
+            println!("{}", serde_json::to_string(event)?);
         }
+        return Ok(());
+    }
+
+    println!("{:<20} {:<24} {:<20} DATA", "TIMESTAMP", "LOOP", "TYPE");
+    println!("{}", "-".repeat(90));
+    for (name, event) in &rows {
+        let (kind, detail) = history_event_columns(event);
+        println!(
+            "{:<20} {:<24} {:<20} {}",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            truncate(name, 24),
+            kind,
+            truncate(&detail, 35)
+        );
+    }
+
+    let counts = HistoryCounts::from_events(rows.iter().map(|(_, event)| event));
+    println!();
+    println!(
+        "{} events, {} runs started, {} runs ended",
+        counts.events,
+        counts.runs_started,
+        counts.runs_ended()
+    );
+    if !counts.outcomes.is_empty() {
+        let outcomes: Vec<String> = counts
+            .outcomes
+            .iter()
+            .map(|(reason, count)| format!("{reason}: {count}"))
+            .collect();
+        println!("Outcomes: {}", outcomes.join(", "));
     }
 
     Ok(())
 }
 
+/// Splits an event into its kind and a compact rendering of its fields.
+fn history_event_columns(event: &HistoryEvent) -> (String, String) {
+    let mut fields = serde_json::to_value(&event.event_type).unwrap_or_default();
+    let kind = fields
+        .as_object_mut()
+        .and_then(|fields| fields.remove("kind"))
+        .and_then(|kind| kind.as_str().map(str::to_string))
+        .unwrap_or_else(|| "-".to_string());
+
+    let mut detail = match fields.as_object() {
+        Some(fields) if !fields.is_empty() => fields
+            .iter()
+            .map(|(key, value)| match value.as_str() {
+                Some(text) => format!("{key}={text}"),
+                None => format!("{key}={value}"),
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => String::new(),
+    };
+    if let Some(data) = &event.data {
+        if !detail.is_empty() {
+            detail.push(' ');
+        }
+        detail.push_str(&data.to_string());
+    }
+    (kind, detail)
+}
+
 /// Retry merge for a failed loop.
 fn retry_merge(args: RetryArgs) -> Result<()> {
     let cwd = std::env::current_dir()?;
@@ -695,9 +1142,9 @@ fn retry_merge(args: RetryArgs) -> Result<()> {
         .get_entry(&args.loop_id)?
         .context(format!("Loop '{}' not found in merge queue", args.loop_id))?;
 
-    if entry.state != MergeState::NeedsReview {
+    if !entry.state.needs_steering() {
         bail!(
-            "Loop '{}' is in state {:?}, can only retry 'needs-review' loops",
+            "Loop '{}' is in state {:?}, can only retry 'needs-review' or 'verification-failed' loops",
             args.loop_id,
             entry.state
         );
@@ -1005,8 +1452,282 @@ fn prune_stale() -> Result<()> {
     Ok(())
 }
 
-/// Attach to a loop's worktree.
-fn attach_to_loop(args: AttachArgs) -> Result<()> {
+/// Streams a running loop's events (and session recording) until it exits.
+///
+/// Loops that are no longer running get their final summary from
+/// `.ralph/history.jsonl` instead.
+async fn attach_to_loop(args: AttachArgs, use_colors: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let (loop_id, worktree_path) = resolve_loop(&cwd, &args.loop_id)?;
+    let workspace = worktree_path.map_or_else(|| cwd.clone(), PathBuf::from);
+
+    let Some(pid) = LoopRegistry::new(&cwd)
+        .get(&loop_id)
+        .ok()
+        .flatten()
+        .map(|entry| entry.pid)
+    else {
+        println!("Loop '{}' is not running.", loop_id);
+        return print_loop_summary(&loop_id, &workspace);
+    };
+
+    let events_path = crate::resolve_events_path(&workspace, None);
+    let (backlog, offset) = EventHistory::new(&events_path).read_from(0)?;
+    let mut recording = if args.events_only {
+        None
+    } else {
+        let recording = RecordingTail::for_workspace(&workspace);
+        match &recording {
+            None => eprintln!(
+                "Note: loop '{}' has no session recording (start it with --record-session); streaming events only",
+                loop_id
+            ),
+            Some(tail) if tail.compressed => eprintln!(
+                "Note: loop '{}' records to a gzip file ({}), which can't be followed live; streaming events only",
+                loop_id,
+                tail.path.display()
+            ),
+            Some(_) => {}
+        }
+        recording
+    };
+
+    eprintln!(
+        "Attached to loop '{}' at {} (Ctrl-C to detach)",
+        loop_id,
+        workspace.display()
+    );
+    crate::display::print_events_table_header(use_colors);
+    let mut printed = 0;
+    let skip = backlog.len().saturating_sub(ATTACH_BACKLOG_EVENTS);
+    for record in &backlog[skip..] {
+        printed += 1;
+        crate::display::print_event_row(printed, record, use_colors);
+    }
+
+    let mut finished = false;
+    crate::poll_new_events(&workspace, None, events_path, offset, |records| {
+        for record in &records {
+            printed += 1;
+            crate::display::print_event_row(printed, record, use_colors);
+        }
+        if let Some(recording) = recording.as_mut() {
+            recording.print_new_output()?;
+        }
+        if is_process_alive(pid) {
+            return Ok(ControlFlow::Continue(()));
+        }
+        finished = true;
+        Ok(ControlFlow::Break(()))
+    })
+    .await?;
+
+    if finished {
+        println!("\nLoop '{}' has exited.", loop_id);
+        print_loop_summary(&loop_id, &workspace)?;
+    }
+    Ok(())
+}
+
+/// Events from before attaching that are shown for context.
+const ATTACH_BACKLOG_EVENTS: usize = 10;
+
+/// Follows the terminal output captured in a loop's session recording.
+struct RecordingTail {
+    path: PathBuf,
+    offset: u64,
+    /// Gzip recordings (`*.gz`) are only complete once the loop exits, so
+    /// their output is not followed.
+    compressed: bool,
+}
+
+impl RecordingTail {
+    /// The recording named by `.ralph/current-recording`, if the loop has one.
+    fn for_workspace(workspace: &Path) -> Option<Self> {
+        let marker = std::fs::read_to_string(workspace.join(".ralph/current-recording")).ok()?;
+        let path = PathBuf::from(marker.trim());
+        let path = if path.is_absolute() {
+            path
+        } else {
+            workspace.join(path)
+        };
+        path.exists().then(|| Self {
+            compressed: is_gzip_path(&path),
+            path,
+            offset: 0,
+        })
+    }
+
+    /// Prints terminal writes appended since the last call.
+    ///
+    /// Bus events in the recording are skipped; they are already streamed from
+    /// the events file.
+    fn print_new_output(&mut self) -> Result<()> {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        if self.compressed {
+            return Ok(());
+        }
+
+        let mut file = std::fs::File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut appended = String::new();
+        file.read_to_string(&mut appended)?;
+        // Leave a partially written last line for the next poll
+        let complete = appended.rfind('\n').map_or(0, |idx| idx + 1);
+        self.offset += complete as u64;
+
+        let mut stdout = std::io::stdout();
+        for line in appended[..complete].lines() {
+            let Ok(record) = serde_json::from_str::<Record>(line) else {
+                continue;
+            };
+            if let Ok(UxEvent::TerminalWrite(write)) = serde_json::from_value(record.data)
+                && let Ok(bytes) = write.decode_bytes()
+            {
+                stdout.write_all(&bytes)?;
+            }
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+/// One loop in `ralph loops summary --json`.
+#[derive(serde::Serialize)]
+struct LoopSummaryRow {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    objective: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    termination_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iterations: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_commit: Option<String>,
+}
+
+/// Prints a single-screen overview of every finished loop.
+fn summarize_finished_loops(args: &SummaryArgs) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let rows: Vec<LoopSummaryRow> = ralph_core::summarize_loops(&cwd)
+        .into_iter()
+        .map(|overview| LoopSummaryRow {
+            name: overview.name,
+            objective: overview.objective,
+            termination_reason: overview.termination_reason,
+            iterations: overview.iterations,
+            merge: overview.merge_state.map(merge_state_label),
+            merge_commit: overview.merge_commit,
+        })
+        .collect();
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+    if rows.is_empty() {
+        println!("No finished loops found.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<20} {:<20} {:>5}  {:<20} OBJECTIVE",
+        "LOOP", "OUTCOME", "ITERS", "MERGE"
+    );
+    println!("{}", "-".repeat(100));
+    for row in &rows {
+        let iterations = row
+            .iterations
+            .map_or_else(|| "-".to_string(), |n| n.to_string());
+        let merge = match (row.merge, &row.merge_commit) {
+            (Some(state), Some(commit)) => format!("{state} {}", truncate(commit, 7)),
+            (Some(state), None) => state.to_string(),
+            (None, _) => "-".to_string(),
+        };
+        println!(
+            "{:<20} {:<20} {:>5}  {:<20} {}",
+            truncate(&row.name, 20),
+            truncate(row.termination_reason.as_deref().unwrap_or("-"), 20),
+            iterations,
+            merge,
+            truncate(row.objective.as_deref().unwrap_or(""), 30)
+        );
+    }
+
+    let merged = rows
+        .iter()
+        .filter(|row| row.merge == Some("merged"))
+        .count();
+    let pending = rows
+        .iter()
+        .filter(|row| matches!(row.merge, Some("queued" | "merging")))
+        .count();
+    let attention = rows
+        .iter()
+        .filter(|row| matches!(row.merge, Some("needs-review" | "verification-failed")))
+        .count();
+    println!();
+    println!(
+        "{} loops: {} merged, {} waiting to merge, {} need review",
+        rows.len(),
+        merged,
+        pending,
+        attention
+    );
+
+    Ok(())
+}
+
+/// Prints the final state of a loop from its `.ralph/history.jsonl`.
+fn print_loop_summary(loop_id: &str, workspace: &Path) -> Result<()> {
+    let history = LoopHistory::new(workspace.join(".ralph/history.jsonl"));
+    if !history.path().exists() {
+        bail!("No history file found for loop '{}'", loop_id);
+    }
+    let summary = history.summary().context("Failed to read loop history")?;
+
+    let outcome = if summary.completed {
+        format!(
+            "completed ({})",
+            summary.completion_reason.as_deref().unwrap_or("-")
+        )
+    } else if summary.terminated {
+        format!(
+            "terminated ({})",
+            summary.termination_signal.as_deref().unwrap_or("-")
+        )
+    } else {
+        "exited without recording an outcome".to_string()
+    };
+    println!("  Outcome:    {}", outcome);
+    if let Some(prompt) = &summary.prompt {
+        println!("  Prompt:     {}", truncate_with_ellipsis(prompt, 60));
+    }
+    println!(
+        "  Iterations: {} ({} failed)",
+        summary.iterations_completed, summary.iterations_failed
+    );
+    println!("  Events:     {}", summary.events_published);
+    if let (Some(started), Some(ended)) = (summary.started_at, summary.ended_at) {
+        let secs = (ended - started).num_seconds().max(0);
+        println!("  Duration:   {}m {}s", secs / 60, secs % 60);
+    }
+    if let Some(commit) = &summary.merge_commit {
+        println!("  Merged:     {}", commit);
+    } else if summary.merge_failed {
+        println!(
+            "  Merge:      failed ({})",
+            summary.merge_failure_reason.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(())
+}
+
+/// Opens a shell in a loop's worktree.
+fn shell_into_loop(args: AttachArgs) -> Result<()> {
     let cwd = std::env::current_dir()?;
     let (loop_id, worktree_path) = resolve_loop(&cwd, &args.loop_id)?;
 
@@ -1198,7 +1919,7 @@ fn collect_reviewable_loops(cwd: &Path) -> Result<Vec<ReviewableLoop>> {
     let queue = MergeQueue::new(cwd);
     if let Ok(entries) = queue.list() {
         for entry in entries {
-            if matches!(entry.state, MergeState::Queued | MergeState::NeedsReview) {
+            if entry.state == MergeState::Queued || entry.state.needs_steering() {
                 let worktree_path = find_loop_worktree_path(cwd, &entry.loop_id);
                 add_reviewable_loop(cwd, &entry.loop_id, worktree_path, &mut targets, &mut seen);
             }
@@ -1684,7 +2405,7 @@ fn merge_loop(args: MergeArgs) -> Result<()> {
                 }
                 println!("Force-merging loop '{}'...", loop_id);
             }
-            MergeState::Queued | MergeState::NeedsReview => {
+            MergeState::Queued | MergeState::NeedsReview | MergeState::VerificationFailed => {
                 println!("Merging loop '{}'...", loop_id);
             }
         }
@@ -1744,8 +2465,16 @@ fn spawn_merge_ralph(cwd: &std::path::Path, loop_id: &str) -> Result<()> {
     let config_path = cwd.join(".ralph/merge-loop-config.yml");
     std::fs::write(&config_path, core_yaml).context("Failed to write merge config file")?;
 
+    let strategy = MergeQueue::new(cwd)
+        .get_entry(loop_id)?
+        .map(|entry| entry.strategy)
+        .unwrap_or_default();
+
     // Spawn merge-ralph
-    println!("Spawning merge-ralph for loop '{}'...", loop_id);
+    println!(
+        "Spawning merge-ralph for loop '{}' ({} strategy)...",
+        loop_id, strategy
+    );
 
     let status = Command::new("ralph")
         .args([
@@ -1758,7 +2487,7 @@ fn spawn_merge_ralph(cwd: &std::path::Path, loop_id: &str) -> Result<()> {
             "-p",
             &format!("Merge loop {} from branch ralph/{}", loop_id, loop_id),
         ])
-        .env("RALPH_MERGE_LOOP_ID", loop_id)
+        .envs(ralph_core::merge_loop_env(cwd, loop_id, strategy))
         .status()
         .context("Failed to spawn merge-ralph")?;
 
@@ -1954,6 +2683,66 @@ mod tests {
         .expect("list loops");
     }
 
+    #[test]
+    fn test_collect_loop_rows_json_includes_registry_details() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let prompt = "a prompt long enough that the table would truncate it to forty chars";
+
+        let registry = LoopRegistry::new(temp_dir.path());
+        let entry = LoopEntry::with_id(
+            "loop-test-5678",
+            prompt,
+            Some("worktrees/loop-test-5678"),
+            temp_dir.path().display().to_string(),
+        );
+        let started = entry.started;
+        registry.register(entry).expect("register loop");
+
+        let listing = collect_loop_rows(temp_dir.path(), true);
+        let json = serde_json::to_value(&listing.rows).expect("serialize rows");
+        let row = &json[0];
+
+        assert_eq!(row["id"], "loop-test-5678");
+        assert_eq!(row["pid"], std::process::id());
+        assert_eq!(row["pid_alive"], true);
+        assert_eq!(row["branch"], "ralph/loop-test-5678");
+        assert_eq!(row["worktree_path"], "worktrees/loop-test-5678");
+        assert_eq!(row["prompt"], prompt);
+        assert_eq!(
+            row["started"],
+            serde_json::to_value(started).expect("serialize started")
+        );
+    }
+
+    #[test]
+    fn test_collect_loop_rows_flags_stale_heartbeat_as_possibly_dead() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let registry = LoopRegistry::new(temp_dir.path());
+        registry
+            .register(LoopEntry::with_id(
+                "loop-test-hung",
+                "hung loop",
+                None::<String>,
+                temp_dir.path().display().to_string(),
+            ))
+            .expect("register loop");
+
+        let rows = collect_loop_rows(temp_dir.path(), true).rows;
+        assert_eq!(rows[0].status, "running");
+
+        // Backdate the heartbeat past the staleness window
+        let registry_path = temp_dir.path().join(LoopRegistry::REGISTRY_FILE);
+        let mut data: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&registry_path).unwrap()).unwrap();
+        data["loops"][0]["last_seen"] =
+            serde_json::to_value(chrono::Utc::now() - chrono::Duration::minutes(5)).unwrap();
+        std::fs::write(&registry_path, data.to_string()).unwrap();
+
+        let rows = collect_loop_rows(temp_dir.path(), true).rows;
+        assert_eq!(rows[0].status, "possibly-dead");
+        assert_eq!(rows[0].pid_alive, Some(true));
+    }
+
     #[test]
     fn test_resolve_loop_exact_match_registry() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -2140,12 +2929,35 @@ mod tests {
         registry.register(entry).expect("register loop");
 
         show_history(HistoryArgs {
-            loop_id: "loop-hist-5678".to_string(),
+            loop_id: Some("loop-hist-5678".to_string()),
             json: false,
+            since: None,
+            until: None,
+            reason: None,
         })
         .expect("show history");
     }
 
+    #[test]
+    fn test_parse_time_filter_accepts_relative_ages_and_dates() {
+        let week_ago = parse_time_filter("7d").expect("relative");
+        let age = Utc::now() - week_ago;
+        assert!((age - chrono::Duration::days(7)).num_seconds().abs() < 5);
+
+        assert_eq!(
+            parse_time_filter("2026-01-31").expect("date").to_rfc3339(),
+            "2026-01-31T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_time_filter("2026-01-31T10:00:00+02:00")
+                .expect("rfc3339")
+                .to_rfc3339(),
+            "2026-01-31T08:00:00+00:00"
+        );
+        assert!(parse_time_filter("7y").is_err());
+        assert!(parse_time_filter("soon").is_err());
+    }
+
     #[test]
     fn test_retry_merge_rejects_non_needs_review_state() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -2488,7 +3300,69 @@ mod tests {
     }
 
     #[test]
-    fn test_attach_to_loop_requires_worktree() {
+    fn test_recording_tail_reads_complete_lines_only() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let recording = temp_dir.path().join("session.jsonl");
+        std::fs::create_dir_all(temp_dir.path().join(".ralph")).unwrap();
+        std::fs::write(
+            temp_dir.path().join(".ralph/current-recording"),
+            "session.jsonl",
+        )
+        .unwrap();
+
+        let write = Record::from_ux_event(&UxEvent::TerminalWrite(
+            ralph_proto::TerminalWrite::new(b"building...\n", true, 5),
+        ));
+        let complete = format!("{}\n", serde_json::to_string(&write).unwrap());
+        std::fs::write(&recording, format!("{complete}{{\"ts\":1,\"ev")).unwrap();
+
+        let mut tail = RecordingTail::for_workspace(temp_dir.path()).expect("recording found");
+        tail.print_new_output().expect("tail recording");
+        assert_eq!(tail.offset, complete.len() as u64);
+
+        // The partial line is picked up once it is finished
+        std::fs::write(&recording, format!("{complete}{complete}")).unwrap();
+        tail.print_new_output().expect("tail recording");
+        assert_eq!(tail.offset, 2 * complete.len() as u64);
+    }
+
+    #[test]
+    fn test_recording_tail_skips_gzip_recording() {
+        use std::io::Write;
+
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let recording = temp_dir.path().join("session.jsonl.gz");
+        std::fs::create_dir_all(temp_dir.path().join(".ralph")).unwrap();
+        std::fs::write(
+            temp_dir.path().join(".ralph/current-recording"),
+            "session.jsonl.gz",
+        )
+        .unwrap();
+        let mut writer = ralph_core::SessionWriter::create(&recording).unwrap();
+        writer.write_all(b"{\"ts\":0}\n").unwrap();
+        drop(writer);
+
+        let mut tail = RecordingTail::for_workspace(temp_dir.path()).expect("recording found");
+        assert!(tail.compressed);
+        tail.print_new_output().expect("gzip recording is skipped");
+        assert_eq!(tail.offset, 0);
+    }
+
+    #[test]
+    fn test_print_loop_summary_requires_history() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let err = print_loop_summary("loop-1", temp_dir.path()).expect_err("no history");
+        assert!(err.to_string().contains("No history file"));
+
+        let history = LoopHistory::new(temp_dir.path().join(".ralph/history.jsonl"));
+        std::fs::create_dir_all(temp_dir.path().join(".ralph")).unwrap();
+        history.record_started("Add OAuth").unwrap();
+        history.record_completed("completion_promise").unwrap();
+        print_loop_summary("loop-1", temp_dir.path()).expect("summary from history");
+    }
+
+    #[test]
+    fn test_attach_shell_requires_worktree() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
         let _cwd = CwdGuard::set(temp_dir.path());
 
@@ -2501,10 +3375,12 @@ mod tests {
         );
         registry.register(entry).expect("register loop");
 
-        let err = attach_to_loop(AttachArgs {
+        let err = shell_into_loop(AttachArgs {
             loop_id: "loop-inplace-1".to_string(),
+            events_only: false,
+            shell: true,
         })
-        .expect_err("attach should fail for in-place loop");
+        .expect_err("attach --shell should fail for in-place loop");
 
         assert!(err.to_string().contains("not a worktree-based loop"));
     }
@@ -2619,12 +3495,51 @@ mod tests {
         assert_eq!(default_diff_base_branch(temp_dir.path()), "master");
     }
 
+    #[tokio::test]
+    async fn test_execute_defaults_to_list_when_no_subcommand() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        execute(&[], LoopsArgs { command: None }, false)
+            .await
+            .expect("execute default");
+    }
+
     #[test]
-    fn test_execute_defaults_to_list_when_no_subcommand() {
+    #[cfg(unix)]
+    fn test_graph_loops_draws_parent_child_edges() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
         let _cwd = CwdGuard::set(temp_dir.path());
+        let workspace = temp_dir.path().display().to_string();
 
-        execute(LoopsArgs { command: None }, false).expect("execute default");
+        let registry = LoopRegistry::new(temp_dir.path());
+        let mut parent = LoopEntry::with_id("primary-1", "parent", None::<String>, &workspace);
+        // Registration replaces entries with the same PID; use another live one.
+        parent.pid = std::os::unix::process::parent_id();
+        registry.register(parent).expect("register parent");
+        // The worktree directory doesn't exist, so the child is stale.
+        let child = LoopEntry::with_id(
+            "loop-child-1",
+            "child",
+            Some("worktrees/loop-child-1"),
+            &workspace,
+        )
+        .with_parent("primary-1");
+        registry.register(child).expect("register child");
+
+        let nodes = collect_loop_nodes(temp_dir.path());
+
+        let mermaid = render_loop_graph(&nodes, DiagramFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart TD\n"), "{mermaid}");
+        assert!(mermaid.contains("primary_1[\"primary-1<br/>(in-place)\"]"));
+        assert!(mermaid.contains("loop_child_1[\"loop-child-1<br/>ralph/loop-child-1\"]"));
+        assert!(mermaid.contains("    primary_1 --> loop_child_1\n"));
+        assert!(mermaid.contains("    class loop_child_1 stale\n"));
+
+        let dot = render_loop_graph(&nodes, DiagramFormat::Dot);
+        assert!(dot.contains("digraph loops {"));
+        assert!(dot.contains("    \"primary-1\" -> \"loop-child-1\";\n"));
+        assert!(dot.contains("style=dashed"));
     }
 
     #[test]
@@ -18,10 +18,16 @@
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 mod backend_support;
+mod backends;
 mod bot;
+mod bundle;
+mod config_cli;
 mod config_resolution;
+mod config_select;
+mod diagnostics_cli;
 mod display;
 mod doctor;
+mod error_report;
 mod hats;
 mod hooks;
 mod init;
@@ -32,6 +38,8 @@ mod mcp;
 mod memory;
 mod preflight;
 mod presets;
+mod remote_fetch;
+mod resume;
 mod rpc_stdin;
 mod skill_cli;
 mod sop_runner;
@@ -44,17 +52,19 @@ mod web;
 mod web_robot_service;
 
 use anyhow::{Context, Result};
-use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap::{ArgAction, ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
 use ralph_adapters::detect_backend;
 use ralph_core::{
-    CheckStatus, EventHistory, LockError, LoopContext, LoopEntry, LoopLock, LoopRegistry,
-    PreflightReport, PreflightRunner, RalphConfig, TerminationReason, UrgentSteerStore,
-    truncate_with_ellipsis,
-    worktree::{WorktreeConfig, create_worktree, ensure_gitignore, remove_worktree},
+    CheckStatus, EventHistory, EventRecord, LockError, LoopContext, LoopEntry, LoopLock,
+    LoopRegistry, PreflightReport, PreflightRunner, RalphConfig, RunManifest, TerminationReason,
+    UrgentSteerStore, truncate_with_ellipsis,
+    worktree::{create_worktree, ensure_gitignore, list_ralph_worktrees, remove_worktree},
 };
 use std::fs;
 use std::io::{IsTerminal, Write, stdout};
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 // Unix-specific process management for process group leadership
@@ -116,6 +126,127 @@ mod process_management {
 
         false
     }
+
+    /// A process-table entry, reduced to what orphan detection needs.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) struct ProcessInfo {
+        pub pid: u32,
+        pub ppid: u32,
+        pub pgid: u32,
+    }
+
+    /// Returns live processes, other than this one and its ancestors, that are
+    /// in this process group or descend from this process.
+    ///
+    /// These are the processes a termination signal to the group is meant to
+    /// stop, so any still listed afterwards have leaked (typically a backend
+    /// that moved itself into its own session or group). Ancestors are skipped
+    /// because a wrapper such as `npx` may share our group.
+    pub fn surviving_processes() -> Vec<u32> {
+        let me = std::process::id();
+        let pgid = getpgrp().as_raw().unsigned_abs();
+        survivors_in(&process_table(), me, pgid)
+    }
+
+    pub(crate) fn survivors_in(table: &[ProcessInfo], me: u32, pgid: u32) -> Vec<u32> {
+        let mut ancestors = vec![me];
+        let mut cursor = me;
+        while let Some(parent) = table.iter().find(|p| p.pid == cursor).map(|p| p.ppid) {
+            if parent == 0 || ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            cursor = parent;
+        }
+
+        let mut found: Vec<u32> = table
+            .iter()
+            .filter(|p| !ancestors.contains(&p.pid) && p.pgid == pgid)
+            .map(|p| p.pid)
+            .collect();
+
+        let mut frontier = vec![me];
+        while let Some(parent) = frontier.pop() {
+            for child in table.iter().filter(|p| p.ppid == parent) {
+                if !found.contains(&child.pid) {
+                    found.push(child.pid);
+                }
+                frontier.push(child.pid);
+            }
+        }
+
+        found.sort_unstable();
+        found
+    }
+
+    /// Sends `signal` to each PID, ignoring processes that already exited.
+    pub fn signal_each(pids: &[u32], signal: nix::sys::signal::Signal) {
+        for &pid in pids {
+            if let Ok(pid) = i32::try_from(pid) {
+                let _ = nix::sys::signal::kill(Pid::from_raw(pid), signal);
+            }
+        }
+    }
+
+    /// Reads the process table from `/proc`, skipping zombies.
+    #[cfg(target_os = "linux")]
+    fn process_table() -> Vec<ProcessInfo> {
+        let Ok(entries) = std::fs::read_dir("/proc") else {
+            return Vec::new();
+        };
+        entries
+            .filter_map(|entry| {
+                let pid: u32 = entry.ok()?.file_name().to_str()?.parse().ok()?;
+                let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+                parse_proc_stat(pid, &stat)
+            })
+            .collect()
+    }
+
+    /// Parses `/proc/<pid>/stat`. The command name is parenthesized and may
+    /// itself contain spaces or parentheses, so fields are read after the last `)`.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn parse_proc_stat(pid: u32, stat: &str) -> Option<ProcessInfo> {
+        let mut fields = stat[stat.rfind(')')? + 1..].split_whitespace();
+        let state = fields.next()?;
+        if matches!(state, "Z" | "X") {
+            return None;
+        }
+        Some(ProcessInfo {
+            pid,
+            ppid: fields.next()?.parse().ok()?,
+            pgid: fields.next()?.parse().ok()?,
+        })
+    }
+
+    /// Reads the process table via `ps`, skipping zombies and `ps` itself.
+    #[cfg(not(target_os = "linux"))]
+    fn process_table() -> Vec<ProcessInfo> {
+        let Ok(child) = std::process::Command::new("ps")
+            .args(["-A", "-o", "pid=,ppid=,pgid=,stat="])
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+        else {
+            return Vec::new();
+        };
+        let ps_pid = child.id();
+        let Ok(output) = child.wait_with_output() else {
+            return Vec::new();
+        };
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let info = ProcessInfo {
+                    pid: fields.next()?.parse().ok()?,
+                    ppid: fields.next()?.parse().ok()?,
+                    pgid: fields.next()?.parse().ok()?,
+                };
+                let zombie = fields.next()?.starts_with('Z');
+                (!zombie && info.pid != ps_pid).then_some(info)
+            })
+            .collect()
+    }
 }
 
 #[cfg(not(unix))]
@@ -298,6 +429,8 @@ pub enum OutputFormat {
     Table,
     /// JSON format for programmatic access
     Json,
+    /// CSV format (ts,iteration,topic,payload) for spreadsheets and dashboards
+    Csv,
 }
 
 // Re-export colors and truncate from display module for use in this file
@@ -633,6 +766,14 @@ struct Cli {
     /// Color output mode (auto, always, never)
     #[arg(long, value_enum, default_value_t = ColorMode::Auto, global = true)]
     color: ColorMode,
+
+    /// Print top-level errors as a JSON object on stderr (also: RALPH_JSON_ERRORS=1)
+    #[arg(long, global = true)]
+    json_errors: bool,
+
+    /// Always fetch remote config sources, bypassing `.ralph/cache/remote-configs/`
+    #[arg(long, global = true)]
+    no_cache: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -649,6 +790,12 @@ enum Commands {
     /// Run first-run diagnostics and environment checks
     Doctor(doctor::DoctorArgs),
 
+    /// Show which backends are installed and enabled, and which `auto` picks
+    Backends(backends::BackendsArgs),
+
+    /// Pack the current run's artifacts into a redacted zip for bug reports
+    Bundle(bundle::BundleArgs),
+
     /// Interactive walkthrough of hats, hat collections, and workflow
     Tutorial(TutorialArgs),
 
@@ -663,6 +810,12 @@ enum Commands {
     /// Initialize a new ralph.yml configuration file
     Init(InitArgs),
 
+    /// Inspect and migrate configuration files
+    Config(config_cli::ConfigArgs),
+
+    /// Inspect diagnostics sessions (run summaries)
+    Diagnostics(diagnostics_cli::DiagnosticsArgs),
+
     /// Clean up Ralph artifacts from `.ralph/agent`.
     Clean(CleanArgs),
 
@@ -727,13 +880,23 @@ struct InitArgs {
     #[arg(long, conflicts_with = "backend", conflicts_with = "preset")]
     list_presets: bool,
 
-    /// Overwrite existing ralph.yml if present
+    /// Overwrite existing ralph.yml (and PROMPT.md) if present
     #[arg(long)]
     force: bool,
+
+    /// Don't scaffold a PROMPT.md template next to ralph.yml
+    #[arg(long)]
+    no_prompt: bool,
+
+    /// Print the generated config to stdout instead of writing any files
+    #[arg(long, conflicts_with = "list_presets")]
+    stdout: bool,
 }
 
 /// Arguments for the run subcommand.
 #[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("resume_mode").args(["continue_mode", "resume_latest"])))]
+#[command(group(ArgGroup::new("waiting").args(["exclusive", "wait_for_slot"]).multiple(true)))]
 struct RunArgs {
     /// Inline prompt text (mutually exclusive with -P/--prompt-file)
     #[arg(short = 'p', long = "prompt", conflicts_with = "prompt_file")]
@@ -765,12 +928,23 @@ struct RunArgs {
     #[arg(long = "continue")]
     continue_mode: bool,
 
+    /// Resume the most recently interrupted loop, searching the primary
+    /// workspace and all worktrees for one that did not complete.
+    #[arg(long)]
+    resume_latest: bool,
+
     /// Explicit loop ID to use with --continue.
     /// Reuses tasks from the specified loop instead of generating a new ID.
     /// If omitted with --continue, reuses the existing current-loop-id marker.
-    #[arg(long, requires = "continue_mode")]
+    /// With --resume-latest, selects which interrupted loop to resume.
+    #[arg(long, requires = "resume_mode")]
     loop_id: Option<String>,
 
+    /// With --continue, replay every event after the last loop.terminate
+    /// instead of only those the previous session had not yet consumed.
+    #[arg(long, requires = "resume_mode")]
+    rescan_events: bool,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Execution Mode Options
     // ─────────────────────────────────────────────────────────────────────────
@@ -808,6 +982,16 @@ struct RunArgs {
     #[arg(long)]
     exclusive: bool,
 
+    /// When `features.max_parallel_loops` is reached, wait for a loop to
+    /// finish instead of failing.
+    #[arg(long)]
+    wait_for_slot: bool,
+
+    /// Give up waiting for the primary loop slot (or, with `--wait-for-slot`,
+    /// a parallel loop slot) after this many seconds.
+    #[arg(long, value_name = "SECS", requires = "waiting")]
+    wait_timeout: Option<u64>,
+
     /// Skip automatic merge after loop completes (keep worktree for manual handling).
     /// Only relevant for parallel loops running in worktrees.
     #[arg(long)]
@@ -836,6 +1020,33 @@ struct RunArgs {
     #[arg(long, value_name = "FILE")]
     record_session: Option<PathBuf>,
 
+    /// Log system events (`event.*`, `iteration.summary`, `<hat>.exhausted`) to a
+    /// separate `system-events*.jsonl` file, keeping the events file to agent
+    /// topics. Overrides `event_loop.system_events`.
+    #[arg(long)]
+    quiet_events: bool,
+
+    /// Don't inject the memories skill into prompts (memories are still
+    /// injected). Overrides `memories.inject_skill`.
+    #[arg(long)]
+    no_memories_skill: bool,
+
+    /// Reproduce a previous run from its run manifest
+    /// (`.ralph/agent/run-manifest.json`): restores its resolved config and
+    /// seed, and warns when HEAD differs from the recorded git SHA. Cannot be
+    /// combined with -c/-H, since the manifest already fixes the config.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["resume_mode", "config", "hats"]
+    )]
+    from_manifest: Option<PathBuf>,
+
+    /// Drive the loop from a recorded events file instead of a backend, to
+    /// reproduce routing and backpressure behavior from a real run.
+    #[arg(long, value_name = "FILE", hide = true, conflicts_with = "resume_mode")]
+    replay_events: Option<PathBuf>,
+
     /// Custom backend command and arguments (use after --)
     #[arg(last = true)]
     custom_args: Vec<String>,
@@ -895,6 +1106,15 @@ struct EventsArgs {
     #[arg(long)]
     iteration: Option<u32>,
 
+    /// Show events at or after this time (RFC 3339, YYYY-MM-DD, or an age
+    /// like 10m, 1h, 7d)
+    #[arg(long, value_parser = loops::parse_time_filter)]
+    since: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Show events at or before this time (same formats as --since)
+    #[arg(long, value_parser = loops::parse_time_filter)]
+    until: Option<chrono::DateTime<chrono::Utc>>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     format: OutputFormat,
@@ -906,6 +1126,20 @@ struct EventsArgs {
     /// Clear the event history
     #[arg(long)]
     clear: bool,
+
+    /// Keep running and print new events as they are appended (like `tail -f`).
+    /// Combine with `--last N` to print the N most recent events first.
+    #[arg(short = 'f', long, conflicts_with = "clear")]
+    follow: bool,
+
+    /// Include system events logged separately (`event_loop.system_events: separate`)
+    #[arg(long, conflicts_with = "follow")]
+    system: bool,
+
+    /// Print counts per topic and per iteration and the time span instead of
+    /// the events themselves (table or json format)
+    #[arg(long, conflicts_with_all = ["follow", "clear"])]
+    stats: bool,
 }
 
 /// Arguments for the clean subcommand.
@@ -918,6 +1152,15 @@ struct CleanArgs {
     /// Clean diagnostic logs instead of `.ralph/` directory
     #[arg(long)]
     diagnostics: bool,
+
+    /// Remove loop worktrees that no running loop or pending merge uses,
+    /// instead of `.ralph/` directory
+    #[arg(long, conflicts_with = "diagnostics")]
+    worktrees: bool,
+
+    /// With --worktrees, also remove worktrees with uncommitted changes
+    #[arg(long, requires = "worktrees")]
+    force: bool,
 }
 
 /// Arguments for the emit subcommand.
@@ -934,13 +1177,17 @@ struct EmitArgs {
     #[arg(long, short)]
     pub json: bool,
 
-    /// Custom ISO 8601 timestamp (defaults to current time)
+    /// Custom RFC 3339 timestamp, stored in UTC (defaults to current time)
     #[arg(long)]
     pub ts: Option<String>,
 
     /// Path to events file (defaults to .ralph/events.jsonl)
     #[arg(long, default_value = ".ralph/events.jsonl")]
     pub file: PathBuf,
+
+    /// Reject topics no hat triggers on or publishes (overrides event_loop.emit_topic_check)
+    #[arg(long)]
+    pub strict: bool,
 }
 
 /// Arguments for the tutorial subcommand.
@@ -970,6 +1217,18 @@ struct PlanArgs {
     #[arg(long)]
     teams: bool,
 
+    /// Kill the session after this many seconds (default: no limit)
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<u64>,
+
+    /// Write a planning session transcript to FILE as markdown instead of starting a session
+    #[arg(long, value_name = "FILE")]
+    export: Option<PathBuf>,
+
+    /// Planning session to export (default: most recent)
+    #[arg(long, value_name = "ID", requires = "export")]
+    session: Option<String>,
+
     /// Custom backend command and arguments (use after --)
     #[arg(last = true)]
     custom_args: Vec<String>,
@@ -994,6 +1253,10 @@ struct CodeTaskArgs {
     #[arg(long)]
     teams: bool,
 
+    /// Kill the session after this many seconds (default: no limit)
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<u64>,
+
     /// Custom backend command and arguments (use after --)
     #[arg(last = true)]
     custom_args: Vec<String>,
@@ -1072,6 +1335,21 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    let json_errors = error_report::json_errors_enabled(cli.json_errors);
+    match run_cli(cli).await {
+        Err(err) if json_errors => {
+            error_report::print_json_error(&err);
+            std::process::exit(1);
+        }
+        result => result,
+    }
+}
+
+async fn run_cli(cli: Cli) -> Result<()> {
+    if cli.no_cache {
+        remote_fetch::disable_cache();
+    }
+
     // Detect if TUI mode is requested - TUI owns the terminal, so logs must not go to stdout
     // TUI is enabled by default unless --no-tui, --autonomous, or --rpc is specified
     // RPC mode also suppresses stdout logging (JSON-only output)
@@ -1185,10 +1463,12 @@ async fn main() -> Result<()> {
         .iter()
         .map(|s| ConfigSource::parse(s))
         .collect();
+    let config_sources = config_select::resolve_conflicting_sources(config_sources)?;
     let hats_source = cli.hats.as_deref().map(HatsSource::parse);
 
     match cli.command {
         Some(Commands::Run(args)) => {
+            reject_manifest_config_overrides(&cli.config, cli.hats.as_deref(), &args)?;
             run_command(
                 &config_sources,
                 hats_source.as_ref(),
@@ -1225,6 +1505,12 @@ async fn main() -> Result<()> {
             )
             .await
         }
+        Some(Commands::Backends(args)) => {
+            backends::execute(&config_sources, args, cli.color.should_use_colors())
+        }
+        Some(Commands::Bundle(args)) => {
+            bundle::execute(&config_sources, args, cli.color.should_use_colors())
+        }
         Some(Commands::Tutorial(args)) => tutorial_command(cli.color, args),
         Some(Commands::Resume(args)) => {
             resume_command(
@@ -1236,10 +1522,22 @@ async fn main() -> Result<()> {
             )
             .await
         }
-        Some(Commands::Events(args)) => events_command(cli.color, args),
+        Some(Commands::Events(args)) => {
+            if args.follow {
+                follow_events_command(cli.color, args).await
+            } else {
+                events_command(cli.color, args)
+            }
+        }
         Some(Commands::Init(args)) => init_command(cli.color, args),
+        Some(Commands::Config(args)) => {
+            config_cli::execute(&config_sources, args, cli.color.should_use_colors())
+        }
+        Some(Commands::Diagnostics(args)) => {
+            diagnostics_cli::execute(args, cli.color.should_use_colors())
+        }
         Some(Commands::Clean(args)) => clean_command(&config_sources, cli.color, args),
-        Some(Commands::Emit(args)) => emit_command(cli.color, args),
+        Some(Commands::Emit(args)) => emit_command(&config_sources, cli.color, args),
         Some(Commands::Plan(args)) => {
             plan_command(&config_sources, hats_source.as_ref(), cli.color, args).await
         }
@@ -1251,7 +1549,9 @@ async fn main() -> Result<()> {
         }
         Some(Commands::Tools(args)) => tools::execute(args, cli.color.should_use_colors()).await,
         Some(Commands::Wave(args)) => wave::execute(args, cli.color.should_use_colors()),
-        Some(Commands::Loops(args)) => loops::execute(args, cli.color.should_use_colors()),
+        Some(Commands::Loops(args)) => {
+            loops::execute(&config_sources, args, cli.color.should_use_colors()).await
+        }
         Some(Commands::Hats(args)) => {
             hats::execute(
                 &config_sources,
@@ -1284,18 +1584,26 @@ async fn main() -> Result<()> {
                 completion_promise: None,
                 dry_run: false,
                 continue_mode: false,
+                resume_latest: false,
                 loop_id: None,
+                rescan_events: false,
                 no_tui: false, // TUI enabled by default
                 autonomous: false,
                 rpc: false,
                 legacy_tui: false,
                 idle_timeout: None,
                 exclusive: false,
+                wait_for_slot: false,
+                wait_timeout: None,
                 no_auto_merge: false,
                 skip_preflight: false,
                 verbose: false,
                 quiet: false,
                 record_session: None,
+                quiet_events: false,
+                no_memories_skill: false,
+                from_manifest: None,
+                replay_events: None,
                 custom_args: Vec::new(),
             };
             run_command(
@@ -1465,14 +1773,105 @@ fn print_preflight_summary(
     }
 }
 
+/// Rejects `-c`/`-H` alongside `--from-manifest`, whose recorded config would
+/// otherwise silently win. Clap only catches these when they follow `run`.
+fn reject_manifest_config_overrides(
+    config: &[String],
+    hats: Option<&str>,
+    args: &RunArgs,
+) -> Result<()> {
+    if args.from_manifest.is_some() && (!config.is_empty() || hats.is_some()) {
+        anyhow::bail!(
+            "--from-manifest cannot be combined with -c/--config or -H/--hats: \
+             the manifest already records the resolved config"
+        );
+    }
+    Ok(())
+}
+
+/// Loads a run manifest for `ralph run --from-manifest`.
+fn load_run_manifest(path: &Path) -> Result<RunManifest> {
+    RunManifest::load(path)
+        .with_context(|| format!("Failed to load run manifest {}", path.display()))
+}
+
+/// Warns when HEAD differs from the SHA the manifest recorded. Checking out
+/// the recorded SHA is left to the user.
+fn warn_on_manifest_sha_mismatch(manifest: &RunManifest, workspace_root: &Path) {
+    if let Some(recorded) = &manifest.git_sha {
+        match ralph_core::get_head_sha(workspace_root) {
+            Ok(head) if &head == recorded => {}
+            Ok(head) => eprintln!(
+                "Warning: run manifest was recorded at {recorded} but HEAD is {head}. \
+                 Run `git checkout {recorded}` first to reproduce exactly."
+            ),
+            Err(_) => eprintln!(
+                "Warning: run manifest was recorded at {recorded} but HEAD could not be read."
+            ),
+        }
+    }
+}
+
 async fn run_command(
     config_sources: &[ConfigSource],
     hats_source: Option<&HatsSource>,
     verbose: bool,
     color_mode: ColorMode,
-    args: RunArgs,
+    mut args: RunArgs,
 ) -> Result<()> {
-    let mut config = preflight::load_config_for_preflight(config_sources, hats_source).await?;
+    let manifest = args
+        .from_manifest
+        .as_deref()
+        .map(load_run_manifest)
+        .transpose()?;
+    let manifest_seed = manifest.as_ref().map(|manifest| manifest.seed);
+    let mut config = match &manifest {
+        Some(manifest) => {
+            let mut config = manifest
+                .restore_config()
+                .context("Failed to restore config from run manifest")?;
+            config.core.workspace_root =
+                std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+            warn_on_manifest_sha_mismatch(manifest, &config.core.workspace_root);
+            config
+        }
+        None => preflight::load_config_for_preflight(config_sources, hats_source).await?,
+    };
+
+    // Handle --resume-latest: find the newest interrupted loop and continue it
+    // from its own workspace, as if `ralph run --continue` were run there.
+    if args.resume_latest {
+        let repo_root = config.core.workspace_root.clone();
+        let worktrees = list_ralph_worktrees(&repo_root).unwrap_or_default();
+        let candidates = resume::find_resume_candidates(&repo_root, &worktrees)?;
+        let selected = resume::select_candidate(candidates, args.loop_id.as_deref())?;
+
+        let progress = selected
+            .last_iteration
+            .map_or_else(String::new, |n| format!(", {n} iterations completed"));
+        eprintln!(
+            "Resuming loop '{}' in {} (last active {}{})",
+            selected.id,
+            selected.workspace.display(),
+            selected.last_activity.format("%Y-%m-%d %H:%M:%S UTC"),
+            progress
+        );
+
+        if !selected.is_primary {
+            let context = selected.context(&repo_root);
+            std::env::set_current_dir(&selected.workspace).with_context(|| {
+                format!("Failed to enter worktree {}", selected.workspace.display())
+            })?;
+            config.core.workspace_root = selected.workspace.clone();
+            config.core.scratchpad.path = context.scratchpad_path().to_string_lossy().to_string();
+        }
+        if args.prompt_text.is_none() && args.prompt_file.is_none() && !selected.prompt.is_empty() {
+            args.prompt_text = Some(selected.prompt);
+        }
+        args.continue_mode = true;
+        // --loop-id was only the selector; the resumed loop's own marker is reused
+        args.loop_id = None;
+    }
 
     // Handle --continue mode: check scratchpad exists before proceeding
     let resume = args.continue_mode;
@@ -1530,6 +1929,19 @@ async fn run_command(
     if let Some(backend) = args.backend {
         config.cli.backend = backend;
     }
+    if args.quiet_events {
+        config.event_loop.system_events = ralph_core::SystemEventsMode::Separate;
+    }
+    if args.no_memories_skill {
+        config.memories.inject_skill = false;
+    }
+
+    if let Some(manifest) = &manifest
+        && let Ok(prompt) = loop_runner::resolve_prompt_content(&config.event_loop)
+        && ralph_core::content_hash(&prompt) != manifest.prompt_hash
+    {
+        eprintln!("Warning: prompt differs from the one recorded in the run manifest.");
+    }
 
     // Validate configuration and emit warnings
     let warnings = config
@@ -1539,6 +1951,10 @@ async fn run_command(
         eprintln!("{warning}");
     }
 
+    if let Some(path) = &args.replay_events {
+        return replay_recorded_events(config, path);
+    }
+
     // Handle auto-detection if backend is "auto"
     if config.cli.backend == "auto" {
         let priority = config.get_agent_priority();
@@ -1674,20 +2090,43 @@ async fn run_command(
                         "Loop lock held by PID {} (started {}), waiting for lock (--exclusive mode)...",
                         existing.pid, existing.started
                     );
-                    let guard = LoopLock::acquire_blocking(workspace_root, &prompt_summary)
-                        .context("Failed to acquire loop lock in exclusive mode")?;
+                    let guard = match args.wait_timeout {
+                        Some(secs) => LoopLock::acquire_blocking_timeout(
+                            workspace_root,
+                            &prompt_summary,
+                            Duration::from_secs(secs),
+                        ),
+                        None => LoopLock::acquire_blocking(workspace_root, &prompt_summary),
+                    }
+                    .context("Failed to acquire loop lock in exclusive mode")?;
                     debug!("Acquired loop lock after waiting");
                     let context = LoopContext::primary(workspace_root.clone());
                     (context, Some(guard))
                 } else if !config.features.parallel {
                     // Parallel loops disabled via config - error out
-                    anyhow::bail!(
+                    let message = format!(
                         "Another loop is already running (PID {}, prompt: \"{}\"). \
                     Parallel loops are disabled in config (features.parallel: false). \
                     Use --exclusive to wait for the lock, or enable parallel loops.",
                         existing.pid,
                         existing.prompt.chars().take(50).collect::<String>()
                     );
+                    return Err(
+                        anyhow::Error::new(LockError::AlreadyLocked(existing)).context(message)
+                    );
+                } else if let Some(guard) = wait_for_parallel_slot(
+                    workspace_root,
+                    &prompt_summary,
+                    config.features.max_parallel_loops,
+                    args.wait_for_slot,
+                    args.wait_timeout.map(Duration::from_secs),
+                )
+                .await?
+                {
+                    // The primary loop finished while we waited for a slot
+                    debug!("Acquired loop lock while waiting for a parallel loop slot");
+                    let context = LoopContext::primary(workspace_root.clone());
+                    (context, Some(guard))
                 } else {
                     // Auto-spawn into worktree
                     info!(
@@ -1696,14 +2135,22 @@ async fn run_command(
                         existing.prompt.chars().take(50).collect::<String>()
                     );
 
-                    let worktree_config = WorktreeConfig::default();
+                    let worktree_config = config.features.worktree.clone();
 
                     // Generate memorable loop ID (adjective-noun only, no prompt keywords)
                     // This ID will be used consistently for: registry ID, worktree path, and branch name
                     let name_generator =
                         ralph_core::LoopNameGenerator::from_config(&config.features.loop_naming);
+                    let registered: Vec<String> = LoopRegistry::new(workspace_root)
+                        .list()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|entry| entry.id)
+                        .collect();
                     let loop_id = name_generator.generate_memorable_unique(|name| {
-                        ralph_core::worktree_exists(workspace_root, name, &worktree_config)
+                        registered.iter().any(|id| id == name)
+                            || ralph_core::worktree_exists(workspace_root, name, &worktree_config)
+                            || ralph_core::loop_branch_exists(workspace_root, name)
                     });
 
                     // Ensure worktree directory is in .gitignore
@@ -1739,12 +2186,19 @@ async fn run_command(
 
                     // Register this loop after preflight succeeds so failed runs
                     // don't leave stale registry entries behind.
-                    let entry = LoopEntry::with_id(
+                    let mut entry = LoopEntry::with_id(
                         &loop_id,
                         &prompt_summary,
                         Some(worktree.path.to_string_lossy().to_string()),
                         worktree.path.to_string_lossy().to_string(),
                     );
+                    // The primary loop holding the lock is this loop's parent
+                    let primary_marker = workspace_root.join(".ralph/current-loop-id");
+                    if let Ok(primary_id) = std::fs::read_to_string(&primary_marker)
+                        && !primary_id.trim().is_empty()
+                    {
+                        entry = entry.with_parent(primary_id.trim());
+                    }
                     pending_worktree_registration = Some(entry);
 
                     // Update config to use worktree paths
@@ -1806,9 +2260,10 @@ async fn run_command(
 
     if let Some(entry) = pending_worktree_registration {
         let registry = LoopRegistry::new(loop_context.repo_root());
-        registry
+        let loop_id = registry
             .register(entry)
             .context("Failed to register loop in registry")?;
+        spawn_registry_heartbeat(loop_context.repo_root().to_path_buf(), loop_id);
     }
 
     // Run the orchestration loop and exit with proper exit code
@@ -1850,6 +2305,8 @@ async fn run_command(
             custom_args,
             auto_merge_override,
             args.loop_id,
+            args.rescan_events,
+            manifest_seed,
         )
         .await?
     };
@@ -1892,6 +2349,94 @@ async fn run_command(
     Ok(())
 }
 
+/// Refreshes this loop's registry heartbeat every
+/// [`LoopRegistry::HEARTBEAT_INTERVAL`] until the entry is deregistered.
+fn spawn_registry_heartbeat(repo_root: PathBuf, loop_id: String) {
+    let spawned = std::thread::Builder::new()
+        .name("loop-heartbeat".to_string())
+        .spawn(move || {
+            let registry = LoopRegistry::new(&repo_root);
+            loop {
+                std::thread::sleep(LoopRegistry::HEARTBEAT_INTERVAL);
+                match registry.heartbeat(&loop_id) {
+                    Ok(()) => {}
+                    Err(ralph_core::RegistryError::NotFound(_)) => break,
+                    Err(e) => debug!("Failed to update loop heartbeat: {}", e),
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start loop heartbeat thread: {}", e);
+    }
+}
+
+/// Poll interval while `ralph run --wait-for-slot` waits for a parallel loop slot.
+const PARALLEL_SLOT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Counts the loops running in `workspace_root`: the primary loop holding the
+/// lock plus live worktree loops in the loop registry. Worktree loops with a
+/// stale heartbeat are not counted, since their PID may have been reused.
+fn running_loop_count(workspace_root: &Path) -> usize {
+    let registry = LoopRegistry::new(workspace_root);
+    let worktree_loops = registry
+        .list()
+        .unwrap_or_default()
+        .iter()
+        .filter(|entry| entry.worktree_path.is_some() && registry.is_alive(entry))
+        .count();
+    worktree_loops + 1
+}
+
+/// Checks `features.max_parallel_loops` before a worktree loop is spawned.
+///
+/// Returns `Ok(None)` when there is room for another loop. At capacity, fails
+/// with the running count unless `wait` is set, in which case it polls until a
+/// loop finishes or `timeout` elapses. If the primary lock frees up meanwhile,
+/// it is acquired and returned so the caller runs in place instead.
+async fn wait_for_parallel_slot(
+    workspace_root: &Path,
+    prompt_summary: &str,
+    limit: usize,
+    wait: bool,
+    timeout: Option<Duration>,
+) -> Result<Option<ralph_core::LockGuard>> {
+    if limit == 0 {
+        return Ok(None);
+    }
+
+    let deadline = timeout.map(|t| std::time::Instant::now() + t);
+    let mut announced = false;
+    loop {
+        let running = running_loop_count(workspace_root);
+        if running < limit {
+            return Ok(None);
+        }
+        if !wait {
+            anyhow::bail!(
+                "Parallel loop limit reached: {running} of {limit} loops already running \
+                 (features.max_parallel_loops: {limit}). Wait for a loop to finish \
+                 (see `ralph loops`), pass --wait-for-slot, or raise the limit."
+            );
+        }
+        if deadline.is_some_and(|d| std::time::Instant::now() >= d) {
+            anyhow::bail!(
+                "Timed out waiting for a parallel loop slot: {running} of {limit} loops still running"
+            );
+        }
+        if !announced {
+            info!(
+                "Parallel loop limit reached ({running} of {limit} running), waiting for a slot..."
+            );
+            announced = true;
+        }
+
+        tokio::time::sleep(PARALLEL_SLOT_POLL_INTERVAL).await;
+        if let Ok(guard) = LoopLock::try_acquire(workspace_root, prompt_summary) {
+            return Ok(Some(guard));
+        }
+    }
+}
+
 fn required_restart_command(pid: u32) -> String {
     format!("kill {pid} && RALPH_DIAGNOSTICS=1 cargo run --bin ralph -- resume -c ralph.test.yml")
 }
@@ -1912,11 +2457,17 @@ struct SubprocessTuiArgs {
     completion_promise: Option<String>,
     continue_mode: bool,
     loop_id: Option<String>,
+    rescan_events: bool,
     idle_timeout: Option<u32>,
     verbose: bool,
     quiet: bool,
     record_session: Option<PathBuf>,
+    quiet_events: bool,
+    no_memories_skill: bool,
+    from_manifest: Option<PathBuf>,
     exclusive: bool,
+    wait_for_slot: bool,
+    wait_timeout: Option<u64>,
     no_auto_merge: bool,
     skip_preflight: bool,
     /// Config sources to forward to child process (-c args)
@@ -1940,11 +2491,17 @@ impl SubprocessTuiArgs {
             completion_promise: args.completion_promise.clone(),
             continue_mode: args.continue_mode,
             loop_id: args.loop_id.clone(),
+            rescan_events: args.rescan_events,
             idle_timeout: args.idle_timeout,
             verbose: args.verbose,
             quiet: args.quiet,
             record_session: args.record_session.clone(),
+            quiet_events: args.quiet_events,
+            no_memories_skill: args.no_memories_skill,
+            from_manifest: args.from_manifest.clone(),
             exclusive: args.exclusive,
+            wait_for_slot: args.wait_for_slot,
+            wait_timeout: args.wait_timeout,
             no_auto_merge: args.no_auto_merge,
             skip_preflight: args.skip_preflight,
             config_sources: config_sources.iter().map(|s| s.to_cli_string()).collect(),
@@ -1982,6 +2539,10 @@ async fn run_subprocess_tui(
         child_args.push(hats.clone());
     }
 
+    if remote_fetch::cache_disabled() {
+        child_args.push("--no-cache".to_string());
+    }
+
     // Add subcommand and mode
     child_args.push("run".to_string());
     child_args.push("--rpc".to_string());
@@ -2022,6 +2583,9 @@ async fn run_subprocess_tui(
         child_args.push("--loop-id".to_string());
         child_args.push(loop_id.clone());
     }
+    if args.rescan_events {
+        child_args.push("--rescan-events".to_string());
+    }
 
     // Forward idle timeout
     if let Some(timeout) = args.idle_timeout {
@@ -2043,10 +2607,30 @@ async fn run_subprocess_tui(
         child_args.push(path.to_string_lossy().to_string());
     }
 
+    if args.quiet_events {
+        child_args.push("--quiet-events".to_string());
+    }
+    if args.no_memories_skill {
+        child_args.push("--no-memories-skill".to_string());
+    }
+
+    // Forward run manifest
+    if let Some(ref path) = args.from_manifest {
+        child_args.push("--from-manifest".to_string());
+        child_args.push(path.to_string_lossy().to_string());
+    }
+
     // Forward multi-loop options
     if args.exclusive {
         child_args.push("--exclusive".to_string());
     }
+    if args.wait_for_slot {
+        child_args.push("--wait-for-slot".to_string());
+    }
+    if let Some(secs) = args.wait_timeout {
+        child_args.push("--wait-timeout".to_string());
+        child_args.push(secs.to_string());
+    }
     if args.no_auto_merge {
         child_args.push("--no-auto-merge".to_string());
     }
@@ -2253,6 +2837,8 @@ async fn resume_command(
         Vec::new(), // Resume command doesn't support custom args
         None,       // Use config.features.auto_merge (deprecated command)
         None,       // Deprecated resume command doesn't support --loop-id
+        false,      // Deprecated resume command doesn't support --rescan-events
+        None,       // Fresh seed
     )
     .await?;
     let exit_code = reason.exit_code();
@@ -2282,8 +2868,19 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
 
     // Handle --backend alone (minimal config)
     if let Some(backend) = args.backend {
-        match init::init_from_backend(&backend, args.force) {
-            Ok(()) => {
+        if args.stdout {
+            let content =
+                init::render_backend_config(&backend).map_err(|e| anyhow::anyhow!("{e}"))?;
+            print!("{}", content);
+            return Ok(());
+        }
+        match init::init_from_backend(&backend, args.force, !args.no_prompt) {
+            Ok(wrote_prompt) => {
+                let prompt_step = if wrote_prompt {
+                    "Describe your task in PROMPT.md"
+                } else {
+                    "Create PROMPT.md with your task"
+                };
                 if use_colors {
                     println!(
                         "{}✓{} Created ralph.yml with {} backend",
@@ -2291,15 +2888,23 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
                         colors::RESET,
                         backend
                     );
+                    if wrote_prompt {
+                        println!("{}✓{} Created PROMPT.md", colors::GREEN, colors::RESET);
+                    }
                     println!(
-                        "\n{}Next steps:{}\n  1. Create PROMPT.md with your task\n  2. Run core-only: ralph run -c ralph.yml\n  3. Or with hats:  ralph run -c ralph.yml -H builtin:code-assist",
+                        "\n{}Next steps:{}\n  1. {}\n  2. Run core-only: ralph run -c ralph.yml\n  3. Or with hats:  ralph run -c ralph.yml -H builtin:code-assist",
                         colors::DIM,
-                        colors::RESET
+                        colors::RESET,
+                        prompt_step
                     );
                 } else {
                     println!("Created ralph.yml with {} backend", backend);
+                    if wrote_prompt {
+                        println!("Created PROMPT.md");
+                    }
                     println!(
-                        "\nNext steps:\n  1. Create PROMPT.md with your task\n  2. Run core-only: ralph run -c ralph.yml\n  3. Or with hats:  ralph run -c ralph.yml -H builtin:code-assist"
+                        "\nNext steps:\n  1. {}\n  2. Run core-only: ralph run -c ralph.yml\n  3. Or with hats:  ralph run -c ralph.yml -H builtin:code-assist",
+                        prompt_step
                     );
                 }
                 return Ok(());
@@ -2314,6 +2919,8 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
     println!("Initialize a new ralph.yml configuration file.\n");
     println!("Usage:");
     println!("  ralph init --backend <backend>   Generate core config (ralph.yml)");
+    println!("  ralph init --backend <backend> --stdout");
+    println!("                                   Print the config without writing files");
     println!("  ralph init --list-presets        Show builtin hat collections\n");
     println!("Backends: {}", backend_support::VALID_BACKENDS_LABEL);
     println!("\nThen run with hats, e.g.: ralph run -c ralph.yml -H builtin:code-assist");
@@ -2321,23 +2928,64 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
     Ok(())
 }
 
+/// Resolves the events file for `ralph events`.
+///
+/// Reads the events path from the `.ralph/current-events` marker file and falls
+/// back to the default if the marker doesn't exist. This ensures `ralph events`
+/// reads from the same events file as the active run.
+fn resolve_events_path(workspace_root: &Path, file: Option<&PathBuf>) -> PathBuf {
+    match file {
+        Some(path) => path.clone(),
+        None => fs::read_to_string(workspace_root.join(".ralph/current-events"))
+            .map(|s| resolve_marker_target(workspace_root, &s))
+            .unwrap_or_else(|_| workspace_root.join(".ralph/events.jsonl")),
+    }
+}
+
+/// Returns true if the record passes the `--topic` / `--iteration` filters.
+fn event_matches_filters(record: &EventRecord, args: &EventsArgs) -> bool {
+    args.topic
+        .as_ref()
+        .is_none_or(|topic| record.topic == *topic)
+        && args
+            .iteration
+            .is_none_or(|iteration| record.iteration == iteration)
+        && event_in_time_window(record, args.since, args.until)
+}
+
+/// Returns true if `record` falls within `--since`/`--until`.
+///
+/// Records whose timestamp cannot be parsed only match when neither bound is set.
+fn event_in_time_window(
+    record: &EventRecord,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+) -> bool {
+    if since.is_none() && until.is_none() {
+        return true;
+    }
+    let Ok(ts) = chrono::DateTime::parse_from_rfc3339(&record.ts) else {
+        return false;
+    };
+    since.is_none_or(|since| ts >= since) && until.is_none_or(|until| ts <= until)
+}
+
 fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
+    if args.stats && matches!(args.format, OutputFormat::Csv) {
+        anyhow::bail!("--stats supports only table and json formats");
+    }
     let use_colors = color_mode.should_use_colors();
     let workspace_root = resolve_workspace_root(None);
-    let current_events_marker = workspace_root.join(".ralph/current-events");
+    let history = EventHistory::new(resolve_events_path(&workspace_root, args.file.as_ref()));
 
-    // Read events path from marker file, fall back to default if marker doesn't exist
-    // This ensures `ralph events` reads from the same events file as the active run
-    let history = match args.file {
-        Some(path) => EventHistory::new(path),
-        None => fs::read_to_string(&current_events_marker)
-            .map(|s| EventHistory::new(resolve_marker_target(&workspace_root, &s)))
-            .unwrap_or_else(|_| EventHistory::new(workspace_root.join(".ralph/events.jsonl"))),
-    };
+    let system_history = EventHistory::new(ralph_core::system_events_path(history.path()));
 
     // Handle clear command
     if args.clear {
         history.clear()?;
+        if system_history.exists() {
+            system_history.clear()?;
+        }
         if use_colors {
             println!("{}✓{} Event history cleared", colors::GREEN, colors::RESET);
         } else {
@@ -2361,15 +3009,12 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
 
     // Read and filter events
     let mut records = history.read_all()?;
-
-    // Apply filters in sequence
-    if let Some(ref topic) = args.topic {
-        records.retain(|r| r.topic == *topic);
-    }
-
-    if let Some(iteration) = args.iteration {
-        records.retain(|r| r.iteration == iteration);
+    if args.system && system_history.exists() {
+        records.extend(system_history.read_all()?);
+        // Stable sort keeps file order for events logged in the same instant
+        records.sort_by(|a, b| a.ts.cmp(&b.ts));
     }
+    records.retain(|r| event_matches_filters(r, &args));
 
     // Apply 'last' filter after other filters (to get last N of filtered results)
     if let Some(n) = args.last
@@ -2387,11 +3032,24 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
         return Ok(());
     }
 
+    if args.stats {
+        let stats = display::EventStats::from_records(&records);
+        if matches!(args.format, OutputFormat::Json) {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        } else {
+            display::print_event_stats(&stats, use_colors);
+        }
+        return Ok(());
+    }
+
     match args.format {
         OutputFormat::Json => {
             let json = serde_json::to_string_pretty(&records)?;
             println!("{json}");
         }
+        OutputFormat::Csv => {
+            display::print_events_csv(&records);
+        }
         OutputFormat::Table => {
             display::print_events_table(&records, use_colors);
         }
@@ -2400,6 +3058,124 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     Ok(())
 }
 
+/// Poll interval for `ralph events --follow`.
+const EVENTS_FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Streams events as they are appended, until Ctrl-C.
+///
+/// The events file is re-resolved on every poll (unless `--file` is given), so
+/// following survives a new run rotating `.ralph/current-events` or the file
+/// being created after the command starts.
+async fn follow_events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
+    let use_colors = color_mode.should_use_colors();
+    let workspace_root = resolve_workspace_root(None);
+
+    let path = resolve_events_path(&workspace_root, args.file.as_ref());
+    let (existing, offset) = EventHistory::new(&path).read_from(0)?;
+    let mut backlog: Vec<EventRecord> = existing
+        .into_iter()
+        .filter(|r| event_matches_filters(r, &args))
+        .collect();
+    let skip = backlog.len().saturating_sub(args.last.unwrap_or(0));
+    backlog.drain(..skip);
+
+    eprintln!(
+        "Following {} (Ctrl-C to stop)",
+        path.strip_prefix(&workspace_root)
+            .unwrap_or(&path)
+            .display()
+    );
+    match args.format {
+        OutputFormat::Table => display::print_events_table_header(use_colors),
+        OutputFormat::Csv => println!("{}", display::EVENTS_CSV_HEADER),
+        OutputFormat::Json => {}
+    }
+
+    let mut printed = 0;
+    let mut print_record = |record: &EventRecord| -> Result<()> {
+        printed += 1;
+        match args.format {
+            OutputFormat::Json => println!("{}", serde_json::to_string(record)?),
+            OutputFormat::Csv => println!("{}", display::format_event_csv_row(record)),
+            OutputFormat::Table => display::print_event_row(printed, record, use_colors),
+        }
+        Ok(())
+    };
+
+    for record in &backlog {
+        print_record(record)?;
+    }
+    stdout().flush()?;
+
+    poll_new_events(
+        &workspace_root,
+        args.file.as_ref(),
+        path,
+        offset,
+        |records| {
+            for record in records.iter().filter(|r| event_matches_filters(r, &args)) {
+                print_record(record)?;
+            }
+            Ok(ControlFlow::Continue(()))
+        },
+    )
+    .await
+}
+
+/// Polls the events file and passes newly appended records to `on_records`
+/// until Ctrl-C or `on_records` returns [`ControlFlow::Break`].
+///
+/// `path` and `offset` are where reading resumes. The events file is
+/// re-resolved on every poll (unless `file` is given), so following survives a
+/// new run rotating `.ralph/current-events` or the file being created late.
+/// `on_records` is called on every poll, with an empty batch if nothing new
+/// arrived.
+pub(crate) async fn poll_new_events(
+    workspace_root: &Path,
+    file: Option<&PathBuf>,
+    mut path: PathBuf,
+    mut offset: u64,
+    mut on_records: impl FnMut(Vec<EventRecord>) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let mut history = EventHistory::new(&path);
+    let mut ticker = tokio::time::interval(EVENTS_FOLLOW_POLL_INTERVAL);
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = ticker.tick() => {}
+        }
+
+        let current = resolve_events_path(workspace_root, file);
+        if current != path {
+            debug!("Events file changed to {}", current.display());
+            path = current;
+            history = EventHistory::new(&path);
+            offset = 0;
+        }
+
+        let records = match history.read_from(offset) {
+            Ok((records, next)) => {
+                offset = next;
+                records
+            }
+            // The file may disappear between polls (cleared or rotated)
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let flow = on_records(records)?;
+        stdout().flush()?;
+        if flow.is_break() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
 fn clean_command(
     config_sources: &[ConfigSource],
     color_mode: ColorMode,
@@ -2413,6 +3189,11 @@ fn clean_command(
         return ralph_cli::clean_diagnostics(&workspace_root, use_colors, args.dry_run);
     }
 
+    if args.worktrees {
+        let workspace_root = std::env::current_dir().context("Failed to get current directory")?;
+        return ralph_cli::clean_worktrees(&workspace_root, use_colors, args.dry_run, args.force);
+    }
+
     // Load config with overrides applied
     let config = load_config_with_overrides(config_sources)?;
 
@@ -2497,11 +3278,25 @@ fn clean_command(
 ///
 /// Events are written to the path specified in `.ralph/current-events` marker file
 /// (created by `ralph run`), or falls back to `.ralph/events.jsonl` if no marker exists.
-fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
-    emit_command_with_root(color_mode, args, None)
+fn emit_command(
+    config_sources: &[ConfigSource],
+    color_mode: ColorMode,
+    args: EmitArgs,
+) -> Result<()> {
+    emit_command_with_root(config_sources, color_mode, args, None)
+}
+
+/// Parses an RFC 3339 timestamp and re-renders it in UTC, the same form
+/// `ralph emit` writes when no timestamp is given.
+fn normalize_emit_timestamp(raw: &str) -> Result<String> {
+    let parsed = chrono::DateTime::parse_from_rfc3339(raw.trim()).with_context(|| {
+        format!("Invalid --ts value '{raw}': expected RFC 3339, e.g. 2026-03-09T12:00:00Z")
+    })?;
+    Ok(parsed.with_timezone(&chrono::Utc).to_rfc3339())
 }
 
 fn emit_command_with_root(
+    config_sources: &[ConfigSource],
     color_mode: ColorMode,
     args: EmitArgs,
     root: Option<&PathBuf>,
@@ -2510,6 +3305,12 @@ fn emit_command_with_root(
     let workspace_root = resolve_workspace_root(root);
     let current_events_marker = workspace_root.join(".ralph/current-events");
 
+    // Normalize to UTC RFC 3339, or generate a timestamp if not provided
+    let ts = match args.ts.as_deref() {
+        Some(raw) => normalize_emit_timestamp(raw)?,
+        None => chrono::Utc::now().to_rfc3339(),
+    };
+
     if std::env::var("RALPH_WAVE_ID").is_err() {
         let urgent_steer_store = UrgentSteerStore::new(urgent_steer_path_from_workspace(root));
         if let Some(record) = urgent_steer_store
@@ -2533,8 +3334,11 @@ fn emit_command_with_root(
         }
     }
 
-    // Generate timestamp if not provided
-    let ts = args.ts.unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
+    let config = load_emit_config(config_sources, &workspace_root);
+    let max_payload_chars = config.as_ref().map_or_else(
+        |_| ralph_core::EventLoopConfig::default().max_event_payload_chars,
+        |config| config.event_loop.max_event_payload_chars,
+    );
 
     // Validate JSON payload if --json flag is set
     let payload = if args.json && !args.payload.is_empty() {
@@ -2544,6 +3348,7 @@ fn emit_command_with_root(
     } else {
         args.payload
     };
+    let payload = cap_emit_payload(payload, args.json, max_payload_chars, use_colors)?;
 
     // Build the event record
     // We use serde_json directly to ensure proper escaping
@@ -2556,18 +3361,25 @@ fn emit_command_with_root(
         serde_json::Value::String(payload)
     };
 
-    let mut record = serde_json::json!({
-        "topic": args.topic,
-        "payload": payload_value,
-        "ts": ts
-    });
-
-    // Auto-tag with wave metadata from env vars (set by loop runner on wave workers)
+    // Validate the topic and auto-tag with wave metadata from env vars (set by
+    // loop runner on wave workers)
+    let mut builder = ralph_core::EventBuilder::new(args.topic);
     if let (Ok(wave_id), Ok(wave_index_str)) = (
         std::env::var("RALPH_WAVE_ID"),
         std::env::var("RALPH_WAVE_INDEX"),
     ) && let Ok(wave_index) = wave_index_str.parse::<u32>()
     {
+        builder = builder.wave(wave_id, wave_index);
+    }
+    let event = builder.build()?;
+    check_emit_topic(config, event.topic.as_str(), args.strict, use_colors)?;
+
+    let mut record = serde_json::json!({
+        "topic": event.topic.as_str(),
+        "payload": payload_value,
+        "ts": ts
+    });
+    if let (Some(wave_id), Some(wave_index)) = (event.wave_id, event.wave_index) {
         record["wave_id"] = serde_json::Value::String(wave_id);
         record["wave_index"] = serde_json::Value::Number(wave_index.into());
     }
@@ -2609,12 +3421,104 @@ fn emit_command_with_root(
             "{}✓{} Event emitted: {}",
             colors::GREEN,
             colors::RESET,
-            args.topic
+            event.topic
+        );
+    } else {
+        println!("Event emitted: {}", event.topic);
+    }
+
+    Ok(())
+}
+
+/// Loads the config `ralph emit` checks against.
+///
+/// Prefers the resolved config in the active run's manifest, which includes
+/// hats loaded with `-H`; falls back to the config sources otherwise.
+fn load_emit_config(config_sources: &[ConfigSource], workspace_root: &Path) -> Result<RalphConfig> {
+    let manifest_path = LoopContext::primary(workspace_root.to_path_buf()).run_manifest_path();
+    if manifest_path.exists() {
+        ralph_core::RunManifest::load(&manifest_path)
+            .and_then(|manifest| manifest.restore_config())
+            .map_err(anyhow::Error::from)
+    } else {
+        load_config_with_overrides(config_sources)
+    }
+}
+
+/// Applies `event_loop.max_event_payload_chars` to an emitted payload.
+///
+/// String payloads are truncated with a warning; JSON payloads cannot be cut
+/// without breaking them, so they are rejected.
+fn cap_emit_payload(
+    payload: String,
+    json: bool,
+    max_chars: usize,
+    use_colors: bool,
+) -> Result<String> {
+    let chars = payload.chars().count();
+    if chars <= max_chars {
+        return Ok(payload);
+    }
+    let advice = "Events are routing signals, not data transport: write the details to the \
+                  scratchpad (or a file) and reference them in the payload.";
+    if json {
+        anyhow::bail!(
+            "JSON payload is {chars} characters, over the {max_chars}-character limit \
+             (event_loop.max_event_payload_chars). {advice}"
         );
+    }
+    let notice = format!(
+        "payload is {chars} characters; truncated to {max_chars} \
+         (event_loop.max_event_payload_chars). {advice}"
+    );
+    if use_colors {
+        eprintln!("{}warning:{} {notice}", colors::YELLOW, colors::RESET);
     } else {
-        println!("Event emitted: {}", args.topic);
+        eprintln!("warning: {notice}");
+    }
+    Ok(ralph_core::truncate_with_ellipsis(&payload, max_chars))
+}
+
+/// Applies `event_loop.emit_topic_check` (or `--strict`) to an emitted topic.
+fn check_emit_topic(
+    config: Result<RalphConfig>,
+    topic: &str,
+    strict: bool,
+    use_colors: bool,
+) -> Result<()> {
+    use ralph_core::TopicCheckMode;
+    use ralph_core::topic_check::{check_topic, known_topics};
+
+    let config = match config {
+        Ok(config) => config,
+        Err(e) if strict => return Err(e.context("Failed to load config for --strict topic check")),
+        Err(e) => {
+            debug!("Skipping emit topic check: {e:#}");
+            return Ok(());
+        }
+    };
+
+    let mode = if strict {
+        TopicCheckMode::Error
+    } else {
+        config.event_loop.emit_topic_check
+    };
+    // Without hats every topic goes to Ralph, so nothing can be orphaned.
+    if mode == TopicCheckMode::Off || config.hats.is_empty() {
+        return Ok(());
     }
 
+    let Err(unknown) = check_topic(topic, &known_topics(&config)) else {
+        return Ok(());
+    };
+    if mode == TopicCheckMode::Error {
+        return Err(unknown.into());
+    }
+    if use_colors {
+        eprintln!("{}warning:{} {unknown}", colors::YELLOW, colors::RESET);
+    } else {
+        eprintln!("warning: {unknown}");
+    }
     Ok(())
 }
 
@@ -2769,6 +3673,10 @@ async fn plan_command(
 
     let use_colors = color_mode.should_use_colors();
 
+    if let Some(output) = &args.export {
+        return export_planning_transcript(args.session.as_deref(), output, use_colors);
+    }
+
     // Show what we're starting
     if use_colors {
         println!(
@@ -2795,15 +3703,93 @@ async fn plan_command(
             Some(args.custom_args)
         },
         agent_teams: args.teams,
+        timeout: args.timeout.map(Duration::from_secs),
     };
 
     sop_runner::run_sop(config).map_err(|e| match e {
         SopRunError::NoBackend(no_backend) => anyhow::Error::new(no_backend),
         SopRunError::UnknownBackend(msg) => anyhow::anyhow!("{}", msg),
         SopRunError::SpawnError(io_err) => anyhow::anyhow!("Failed to spawn backend: {}", io_err),
+        timed_out @ SopRunError::TimedOut(_) => anyhow::Error::new(timed_out),
     })
 }
 
+/// Runs the event loop against a recorded events file instead of a backend.
+///
+/// Prints every event the replayed loop publishes, then how the loop ended.
+fn replay_recorded_events(config: RalphConfig, path: &Path) -> Result<()> {
+    let mut event_loop = ralph_core::EventLoop::new(config);
+    event_loop.add_observer(|event| match &event.target {
+        Some(target) => println!("  {} -> {}", event.topic, target),
+        None => println!("  {}", event.topic),
+    });
+
+    println!("Replaying events from {}", path.display());
+    let outcome = event_loop
+        .replay_events(path)
+        .with_context(|| format!("Failed to replay events from {}", path.display()))?;
+    match outcome.termination {
+        Some(reason) => println!(
+            "Loop terminated after {} iteration(s): {}",
+            outcome.iterations,
+            reason.as_str()
+        ),
+        None => println!(
+            "Recording ended after {} iteration(s) without terminating the loop",
+            outcome.iterations
+        ),
+    }
+    Ok(())
+}
+
+/// Writes a planning session's conversation to `output` as markdown.
+///
+/// Exports `session_id`, or the most recent session under
+/// `.ralph/planning-sessions` when none is given.
+fn export_planning_transcript(
+    session_id: Option<&str>,
+    output: &Path,
+    use_colors: bool,
+) -> Result<()> {
+    use ralph_core::planning_session::PlanningSession;
+
+    let context = LoopContext::primary(resolve_workspace_root(None));
+    let session_id = match session_id {
+        Some(id) => id.to_string(),
+        None => {
+            let sessions_dir = context.planning_sessions_dir();
+            diagnostics_cli::latest_session(&sessions_dir)
+                .and_then(|dir| dir.file_name().map(|n| n.to_string_lossy().into_owned()))
+                .with_context(|| {
+                    format!("No planning sessions found in {}", sessions_dir.display())
+                })?
+        }
+    };
+
+    let session = PlanningSession::load(&session_id, &context)
+        .with_context(|| format!("Failed to load planning session {session_id}"))?;
+    let transcript = session.export_markdown()?;
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(output, transcript)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    let (green, reset) = if use_colors {
+        (colors::GREEN, colors::RESET)
+    } else {
+        ("", "")
+    };
+    println!(
+        "{green}✓{reset} Exported planning session {session_id} to {}",
+        output.display()
+    );
+    Ok(())
+}
+
 /// Starts a code-task-generator session.
 ///
 /// This is a thin wrapper that bypasses Ralph's event loop entirely.
@@ -2844,12 +3830,14 @@ async fn code_task_command(
             Some(args.custom_args)
         },
         agent_teams: args.teams,
+        timeout: args.timeout.map(Duration::from_secs),
     };
 
     sop_runner::run_sop(config).map_err(|e| match e {
         SopRunError::NoBackend(no_backend) => anyhow::Error::new(no_backend),
         SopRunError::UnknownBackend(msg) => anyhow::anyhow!("{}", msg),
         SopRunError::SpawnError(io_err) => anyhow::anyhow!("Failed to spawn backend: {}", io_err),
+        timed_out @ SopRunError::TimedOut(_) => anyhow::Error::new(timed_out),
     })
 }
 
@@ -2899,6 +3887,53 @@ mod tests {
     use ralph_core::{HookMutationConfig, HookOnError, HookPhaseEvent, HookSpec};
     use std::path::PathBuf;
     use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_survivors_include_group_members_and_descendants() {
+        use process_management::{ProcessInfo, survivors_in};
+        let p = |pid, ppid, pgid| ProcessInfo { pid, ppid, pgid };
+        let table = [
+            p(1, 0, 1),
+            p(90, 1, 100),    // wrapper that launched us, same group
+            p(100, 90, 100),  // orchestrator
+            p(101, 100, 100), // backend in the group
+            p(102, 100, 102), // backend that moved to its own group
+            p(103, 102, 102), // its child
+            p(104, 1, 100),   // reparented group member
+            p(200, 1, 200),   // unrelated
+        ];
+        assert_eq!(survivors_in(&table, 100, 100), vec![101, 102, 103, 104]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_proc_stat_handles_parens_and_zombies() {
+        use process_management::{ProcessInfo, parse_proc_stat};
+        assert_eq!(
+            parse_proc_stat(42, "42 (my (odd) cmd) S 7 42 42 0 -1"),
+            Some(ProcessInfo {
+                pid: 42,
+                ppid: 7,
+                pgid: 42
+            })
+        );
+        assert_eq!(parse_proc_stat(43, "43 (defunct) Z 7 42 42 0 -1"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_surviving_processes_sees_spawned_child() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .expect("spawn sleep");
+        let found = process_management::surviving_processes().contains(&child.id());
+        let _ = child.kill();
+        let _ = child.wait();
+        assert!(found, "live child should be reported");
+        assert!(!process_management::surviving_processes().contains(&child.id()));
+    }
     #[test]
     fn test_required_restart_command_matches_contract() {
         let command = required_restart_command(4242);
@@ -3051,6 +4086,61 @@ mod tests {
         assert_eq!(cli.hats.as_deref(), Some("builtin:code-assist"));
     }
 
+    #[test]
+    fn test_cli_parses_from_manifest_and_rejects_continue() {
+        let cli = Cli::try_parse_from(["ralph", "run", "--from-manifest", "m.json"])
+            .expect("CLI parse failed");
+        match cli.command {
+            Some(Commands::Run(args)) => {
+                assert_eq!(args.from_manifest, Some(PathBuf::from("m.json")));
+            }
+            other => panic!("Expected run command, got {:?}", other),
+        }
+        assert!(
+            Cli::try_parse_from(["ralph", "run", "--from-manifest", "m.json", "--continue"])
+                .is_err()
+        );
+        // The manifest's resolved config would silently win over -c/-H
+        for flag in [["-c", "other.yml"], ["-H", "builtin:code-assist"]] {
+            let mut args = vec!["ralph", "run", "--from-manifest", "m.json"];
+            args.extend(flag);
+            assert!(Cli::try_parse_from(&args).is_err(), "{flag:?}");
+            let mut args = vec!["ralph"];
+            args.extend(flag);
+            args.extend(["run", "--from-manifest", "m.json"]);
+            let cli = Cli::try_parse_from(&args).expect("CLI parse failed");
+            let Some(Commands::Run(run)) = &cli.command else {
+                panic!("Expected run command");
+            };
+            assert!(
+                reject_manifest_config_overrides(&cli.config, cli.hats.as_deref(), run).is_err(),
+                "{flag:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_run_manifest_restores_seed_and_config() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut config = RalphConfig::default();
+        config.event_loop.max_iterations = 9;
+        let path = tmp.path().join("run-manifest.json");
+        RunManifest::capture(&config, "primary-1", 7, "prompt")
+            .unwrap()
+            .write(&path)
+            .unwrap();
+
+        let manifest = load_run_manifest(&path).unwrap();
+
+        assert_eq!(manifest.seed, 7);
+        assert_eq!(
+            manifest.restore_config().unwrap().event_loop.max_iterations,
+            9
+        );
+        let err = load_run_manifest(&tmp.path().join("missing.json")).unwrap_err();
+        assert!(err.to_string().contains("missing.json"), "{err}");
+    }
+
     #[test]
     fn test_config_source_parse_remote_https() {
         let source = ConfigSource::parse("https://example.com/preset.yml");
@@ -3210,6 +4300,7 @@ mod tests {
         .expect("write marker");
 
         emit_command_with_root(
+            &[],
             ColorMode::Never,
             EmitArgs {
                 topic: "debug.step".to_string(),
@@ -3217,6 +4308,7 @@ mod tests {
                 json: false,
                 ts: Some("2026-03-09T00:00:00Z".to_string()),
                 file: PathBuf::from(".ralph/events.jsonl"),
+                strict: false,
             },
             Some(&workspace),
         )
@@ -3228,6 +4320,129 @@ mod tests {
         assert!(events.contains("task_id=demo"));
     }
 
+    #[test]
+    fn test_emit_command_rejects_invalid_topic() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let workspace = temp_dir.path().to_path_buf();
+
+        let err = emit_command_with_root(
+            &[],
+            ColorMode::Never,
+            EmitArgs {
+                topic: "build done".to_string(),
+                payload: "tests: pass".to_string(),
+                json: false,
+                ts: None,
+                file: PathBuf::from(".ralph/events.jsonl"),
+                strict: false,
+            },
+            Some(&workspace),
+        )
+        .expect_err("invalid topic");
+
+        assert!(err.to_string().contains("invalid event topic"), "{err}");
+        assert!(!workspace.join(".ralph/events.jsonl").exists());
+    }
+
+    #[test]
+    fn test_cap_emit_payload_truncates_strings_and_rejects_json() {
+        let short = cap_emit_payload("ok".to_string(), false, 10, false).expect("short payload");
+        assert_eq!(short, "ok");
+
+        let long = "y".repeat(50);
+        let capped = cap_emit_payload(long.clone(), false, 10, false).expect("string payload");
+        assert_eq!(capped, "yyyyyyy...");
+
+        let json = format!(r#"{{"detail":"{long}"}}"#);
+        let err = cap_emit_payload(json, true, 10, false).expect_err("json payload");
+        assert!(err.to_string().contains("scratchpad"), "{err}");
+    }
+
+    #[test]
+    fn test_emit_command_rejects_invalid_timestamp() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let workspace = temp_dir.path().to_path_buf();
+
+        let err = emit_command_with_root(
+            &[],
+            ColorMode::Never,
+            EmitArgs {
+                topic: "build.done".to_string(),
+                payload: String::new(),
+                json: false,
+                ts: Some("2026-13-09 25:00".to_string()),
+                file: PathBuf::from(".ralph/events.jsonl"),
+                strict: false,
+            },
+            Some(&workspace),
+        )
+        .expect_err("invalid timestamp");
+
+        assert!(err.to_string().contains("Invalid --ts value"), "{err}");
+        assert!(!workspace.join(".ralph/events.jsonl").exists());
+    }
+
+    #[test]
+    fn test_emit_command_checks_topic_against_run_manifest_hats() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let workspace = temp_dir.path().to_path_buf();
+        let config = RalphConfig::parse_yaml(
+            r#"
+event_loop:
+  emit_topic_check: error
+hats:
+  builder:
+    name: Builder
+    description: Builds
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#,
+        )
+        .expect("parse config");
+        ralph_core::RunManifest::capture(&config, "primary", 1, "prompt")
+            .expect("capture manifest")
+            .write(&LoopContext::primary(workspace.clone()).run_manifest_path())
+            .expect("write manifest");
+
+        let emit = |topic: &str| {
+            emit_command_with_root(
+                &[],
+                ColorMode::Never,
+                EmitArgs {
+                    topic: topic.to_string(),
+                    payload: String::new(),
+                    json: false,
+                    ts: None,
+                    file: workspace.join(".ralph/events.jsonl"),
+                    strict: false,
+                },
+                Some(&workspace),
+            )
+        };
+
+        let err = emit("buld.done").expect_err("unknown topic");
+        assert!(
+            err.to_string().contains("did you mean 'build.done'"),
+            "{err}"
+        );
+        assert!(!workspace.join(".ralph/events.jsonl").exists());
+
+        emit("build.done").expect("known topic");
+        assert!(workspace.join(".ralph/events.jsonl").exists());
+    }
+
+    #[test]
+    fn test_normalize_emit_timestamp_converts_to_utc() {
+        assert_eq!(
+            normalize_emit_timestamp("2026-03-09T14:30:00+02:00").unwrap(),
+            "2026-03-09T12:30:00+00:00"
+        );
+        assert_eq!(
+            normalize_emit_timestamp("2026-03-09T00:00:00Z").unwrap(),
+            "2026-03-09T00:00:00+00:00"
+        );
+    }
+
     #[test]
     fn test_emit_command_blocks_once_when_urgent_steer_pending() {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -3238,6 +4453,7 @@ mod tests {
             .expect("write urgent steer");
 
         let err = emit_command_with_root(
+            &[],
             ColorMode::Never,
             EmitArgs {
                 topic: "debug.step".to_string(),
@@ -3245,6 +4461,7 @@ mod tests {
                 json: false,
                 ts: Some("2026-03-09T00:00:00Z".to_string()),
                 file: PathBuf::from(".ralph/events.jsonl"),
+                strict: false,
             },
             Some(&workspace),
         )
@@ -3727,6 +4944,27 @@ core:
         );
     }
 
+    #[test]
+    fn test_export_planning_transcript_writes_latest_session() {
+        use ralph_core::planning_session::PlanningSession;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+        let context = LoopContext::primary(temp_dir.path().to_path_buf());
+        let session = PlanningSession::new("Add OAuth login", &context, None).unwrap();
+        session.append_prompt("q1", "Which providers?").unwrap();
+
+        let output = temp_dir.path().join("docs/transcript.md");
+        export_planning_transcript(None, &output, false).unwrap();
+
+        let transcript = std::fs::read_to_string(&output).unwrap();
+        assert!(transcript.contains(&format!("# Planning session {}", session.id())));
+        assert!(transcript.contains("## Agent prompt (q1)"));
+
+        let err = export_planning_transcript(Some("missing"), &output, false).unwrap_err();
+        assert!(err.to_string().contains("missing"), "{err:#}");
+    }
+
     #[test]
     fn test_load_config_with_overrides_applies_override_sources() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -3834,18 +5072,26 @@ core:
             completion_promise: None,
             dry_run: false,
             continue_mode: false,
+            resume_latest: false,
             loop_id: None,
+            rescan_events: false,
             no_tui: true,
             autonomous: false,
             rpc: false,
             legacy_tui: false,
             idle_timeout: None,
             exclusive: false,
+            wait_for_slot: false,
+            wait_timeout: None,
             no_auto_merge: false,
             skip_preflight: true,
             verbose: false,
             quiet: false,
             record_session: None,
+            quiet_events: false,
+            no_memories_skill: false,
+            from_manifest: None,
+            replay_events: None,
             custom_args: Vec::new(),
         }
     }
@@ -3864,6 +5110,44 @@ core:
         assert!(err.to_string().contains("scratchpad not found"));
     }
 
+    #[tokio::test]
+    async fn test_run_command_resume_latest_without_interrupted_loop_returns_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let mut args = default_run_args();
+        args.resume_latest = true;
+
+        let err = run_command(&[], None, false, ColorMode::Never, args)
+            .await
+            .expect_err("expected no interrupted loops error");
+        assert!(err.to_string().contains("No interrupted loops found"));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_resume_latest_continues_interrupted_primary_loop() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let history = ralph_core::LoopHistory::new(temp_dir.path().join(".ralph/history.jsonl"));
+        std::fs::create_dir_all(temp_dir.path().join(".ralph/agent")).unwrap();
+        history.record_started("Interrupted task").unwrap();
+        history.record_terminated("SIGTERM").unwrap();
+        std::fs::write(
+            temp_dir.path().join(".ralph/agent/scratchpad.md"),
+            "# Scratchpad\n",
+        )
+        .unwrap();
+
+        let mut args = default_run_args();
+        args.resume_latest = true;
+        args.dry_run = true;
+
+        run_command(&[], None, false, ColorMode::Never, args)
+            .await
+            .expect("resume-latest dry run should succeed");
+    }
+
     #[tokio::test]
     async fn test_run_command_dry_run_inline_prompt_skips_execution() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -3932,20 +5216,119 @@ hats:
             json: false,
             ts: None,
             file: PathBuf::from(".ralph/events.jsonl"),
+            strict: false,
         }));
         assert!(!is_diagnostics_eligible_command(command.as_ref()));
     }
 
+    #[test]
+    fn test_event_in_time_window_bounds_are_inclusive() {
+        let record = |ts: &str| -> EventRecord {
+            serde_json::from_value(serde_json::json!({"ts": ts, "topic": "build.done"})).unwrap()
+        };
+        let at = |ts: &str| {
+            chrono::DateTime::parse_from_rfc3339(ts)
+                .unwrap()
+                .with_timezone(&chrono::Utc)
+        };
+        let since = Some(at("2026-03-09T10:00:00Z"));
+        let until = Some(at("2026-03-09T11:00:00Z"));
+
+        assert!(event_in_time_window(
+            &record("2026-03-09T10:00:00Z"),
+            since,
+            until
+        ));
+        assert!(event_in_time_window(
+            &record("2026-03-09T12:30:00+02:00"),
+            since,
+            until
+        ));
+        assert!(!event_in_time_window(
+            &record("2026-03-09T11:00:01Z"),
+            since,
+            until
+        ));
+        assert!(!event_in_time_window(
+            &record("2026-03-09T09:59:59Z"),
+            since,
+            None
+        ));
+        assert!(!event_in_time_window(&record("garbage"), None, until));
+        assert!(event_in_time_window(&record("garbage"), None, None));
+    }
+
     #[test]
     fn test_diagnostics_not_eligible_for_events_command() {
         let command = Some(Commands::Events(EventsArgs {
             last: None,
             topic: None,
             iteration: None,
+            since: None,
+            until: None,
             format: OutputFormat::Table,
             file: None,
             clear: false,
+            follow: false,
+            system: false,
+            stats: false,
         }));
         assert!(!is_diagnostics_eligible_command(command.as_ref()));
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_wait_for_parallel_slot_counts_live_worktree_loops() {
+        let workspace = TempDir::new().unwrap();
+        let worktree = workspace.path().join(".worktrees/busy-otter");
+        std::fs::create_dir_all(&worktree).unwrap();
+        LoopRegistry::new(workspace.path())
+            .register(LoopEntry::with_id(
+                "busy-otter",
+                "other task",
+                Some(worktree.to_string_lossy().to_string()),
+                worktree.to_string_lossy().to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(running_loop_count(workspace.path()), 2);
+        let free = wait_for_parallel_slot(workspace.path(), "task", 3, false, None).await;
+        assert!(free.unwrap().is_none());
+        let unlimited = wait_for_parallel_slot(workspace.path(), "task", 0, false, None).await;
+        assert!(unlimited.unwrap().is_none());
+
+        let err = wait_for_parallel_slot(workspace.path(), "task", 2, false, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("2 of 2 loops already running"));
+
+        // A stale heartbeat frees the slot even though the PID is still running
+        let registry_path = workspace.path().join(LoopRegistry::REGISTRY_FILE);
+        let mut data: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&registry_path).unwrap()).unwrap();
+        data["loops"][0]["last_seen"] =
+            serde_json::to_value(chrono::Utc::now() - chrono::Duration::minutes(2)).unwrap();
+        std::fs::write(&registry_path, data.to_string()).unwrap();
+        assert_eq!(running_loop_count(workspace.path()), 1);
+        LoopRegistry::new(workspace.path())
+            .heartbeat("busy-otter")
+            .unwrap();
+        assert_eq!(running_loop_count(workspace.path()), 2);
+
+        // A worktree that was removed no longer holds a slot
+        std::fs::remove_dir_all(&worktree).unwrap();
+        assert_eq!(running_loop_count(workspace.path()), 1);
+    }
+
+    #[test]
+    fn test_wait_timeout_requires_a_waiting_flag() {
+        assert!(Cli::try_parse_from(["ralph", "run", "--wait-timeout", "5"]).is_err());
+        for flag in ["--exclusive", "--wait-for-slot"] {
+            let cli = Cli::try_parse_from(["ralph", "run", flag, "--wait-timeout", "5"]).unwrap();
+            let Some(Commands::Run(args)) = cli.command else {
+                panic!("expected run command");
+            };
+            assert_eq!(args.wait_timeout, Some(5));
+        }
+    }
 }
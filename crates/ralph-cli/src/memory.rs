@@ -8,12 +8,14 @@
 //! - `search`: Find memories by query
 //! - `prime`: Output memories for context injection
 //! - `init`: Initialize memories file
+//! - `dedup`: Collapse near-duplicate memories
+//! - `gc`: Flag or archive memories referencing deleted files
 
 use crate::resolve_workspace_root;
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use ralph_core::{MarkdownMemoryStore, Memory, MemoryType, truncate_with_ellipsis};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// ANSI color codes for terminal output.
 mod colors {
@@ -103,6 +105,12 @@ pub enum MemoryCommands {
 
     /// Initialize memories file
     Init(InitArgs),
+
+    /// Collapse near-duplicate memories
+    Dedup(DedupArgs),
+
+    /// Flag memories referencing deleted files (archive them with --prune)
+    Gc(GcArgs),
 }
 
 /// Arguments for the `memory add` command.
@@ -119,6 +127,10 @@ pub struct AddArgs {
     #[arg(long)]
     pub tags: Option<String>,
 
+    /// Tag to attach (repeatable)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tag: Vec<String>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -131,6 +143,10 @@ pub struct ListArgs {
     #[arg(short = 't', long)]
     pub r#type: Option<MemoryType>,
 
+    /// Filter by tag (repeatable, OR logic)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tag: Vec<String>,
+
     /// Show only last N memories
     #[arg(long)]
     pub last: Option<usize>,
@@ -172,6 +188,10 @@ pub struct SearchArgs {
     #[arg(long)]
     pub tags: Option<String>,
 
+    /// Filter by tag (repeatable, OR logic with --tags)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tag: Vec<String>,
+
     /// Show all results (no limit)
     #[arg(long)]
     pub all: bool,
@@ -213,6 +233,30 @@ pub struct InitArgs {
     pub force: bool,
 }
 
+/// Arguments for the `memory dedup` command.
+#[derive(Parser, Debug)]
+pub struct DedupArgs {
+    /// Report what would be merged without writing
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+/// Arguments for the `memory gc` command.
+#[derive(Parser, Debug)]
+pub struct GcArgs {
+    /// Move stale memories to memories-archive.md instead of only reporting them
+    #[arg(long)]
+    pub prune: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
 /// Execute a memory command.
 pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
     let root = resolve_workspace_root(args.root.as_ref());
@@ -226,15 +270,16 @@ pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
         MemoryCommands::Search(search_args) => search_command(&store, search_args, use_colors),
         MemoryCommands::Prime(prime_args) => prime_command(&store, prime_args),
         MemoryCommands::Init(init_args) => init_command(&store, init_args, use_colors),
+        MemoryCommands::Dedup(dedup_args) => dedup_command(&store, dedup_args, use_colors),
+        MemoryCommands::Gc(gc_args) => gc_command(&store, &root, gc_args, use_colors),
     }
 }
 
 fn add_command(store: &MarkdownMemoryStore, args: AddArgs, use_colors: bool) -> Result<()> {
-    // Parse tags
-    let tags: Vec<String> = args
-        .tags
-        .map(|t| t.split(',').map(|s| s.trim().to_string()).collect())
-        .unwrap_or_default();
+    let tags = collect_tags(args.tags.as_deref(), &args.tag);
+    if let Some(bad) = tags.iter().find(|t| t.contains('|') || t.contains("-->")) {
+        anyhow::bail!("Invalid tag {bad:?}: tags cannot contain '|' or '-->'");
+    }
 
     // Create and store the memory
     let memory = Memory::new(args.r#type, args.content, tags);
@@ -273,19 +318,7 @@ fn add_command(store: &MarkdownMemoryStore, args: AddArgs, use_colors: bool) ->
 }
 
 fn list_command(store: &MarkdownMemoryStore, args: ListArgs, use_colors: bool) -> Result<()> {
-    let mut memories = store.load().context("Failed to load memories")?;
-
-    // Filter by type if specified
-    if let Some(memory_type) = args.r#type {
-        memories.retain(|m| m.memory_type == memory_type);
-    }
-
-    // Apply last N filter
-    if let Some(n) = args.last
-        && memories.len() > n
-    {
-        memories = memories.into_iter().rev().take(n).rev().collect();
-    }
+    let memories = filter_for_list(store.load().context("Failed to load memories")?, &args);
 
     if memories.is_empty() {
         if use_colors {
@@ -312,6 +345,44 @@ fn list_command(store: &MarkdownMemoryStore, args: ListArgs, use_colors: bool) -
     Ok(())
 }
 
+/// Applies the `memory list` type, tag and `--last` filters.
+fn filter_for_list(mut memories: Vec<Memory>, args: &ListArgs) -> Vec<Memory> {
+    // Filter by type if specified
+    if let Some(memory_type) = args.r#type {
+        memories.retain(|m| m.memory_type == memory_type);
+    }
+
+    // Filter by tags if specified
+    let tags = collect_tags(None, &args.tag);
+    if !tags.is_empty() {
+        memories.retain(|m| m.has_any_tag(&tags));
+    }
+
+    // Apply last N filter
+    if let Some(n) = args.last
+        && memories.len() > n
+    {
+        memories = memories.into_iter().rev().take(n).rev().collect();
+    }
+    memories
+}
+
+/// Merges a comma-separated `--tags` value with repeated `--tag` flags.
+///
+/// Blank entries are dropped and duplicates (case-insensitive) keep their
+/// first spelling.
+fn collect_tags(tags: Option<&str>, tag: &[String]) -> Vec<String> {
+    let mut collected: Vec<String> = Vec::new();
+    for raw in tags.into_iter().chain(tag.iter().map(String::as_str)) {
+        for t in raw.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if !collected.iter().any(|c| c.eq_ignore_ascii_case(t)) {
+                collected.push(t.to_string());
+            }
+        }
+    }
+    collected
+}
+
 fn show_command(store: &MarkdownMemoryStore, args: ShowArgs, use_colors: bool) -> Result<()> {
     let memory = store
         .get(&args.id)
@@ -345,23 +416,7 @@ fn delete_command(store: &MarkdownMemoryStore, args: DeleteArgs, use_colors: boo
 fn search_command(store: &MarkdownMemoryStore, args: SearchArgs, use_colors: bool) -> Result<()> {
     let all_memories = store.load().context("Failed to load memories")?;
     let total_count = all_memories.len();
-    let mut memories = all_memories;
-
-    // Filter by query if provided
-    if let Some(ref query) = args.query {
-        memories.retain(|m| m.matches_query(query));
-    }
-
-    // Filter by type if specified
-    if let Some(memory_type) = args.r#type {
-        memories.retain(|m| m.memory_type == memory_type);
-    }
-
-    // Filter by tags if specified
-    if let Some(ref tags_str) = args.tags {
-        let tags: Vec<String> = tags_str.split(',').map(|s| s.trim().to_string()).collect();
-        memories.retain(|m| m.has_any_tag(&tags));
-    }
+    let mut memories = filter_for_search(all_memories, &args);
 
     let match_count = memories.len();
     let truncated = !args.all && match_count > 10;
@@ -437,6 +492,26 @@ fn search_command(store: &MarkdownMemoryStore, args: SearchArgs, use_colors: boo
     Ok(())
 }
 
+/// Applies the `memory search` query, type and tag filters.
+fn filter_for_search(mut memories: Vec<Memory>, args: &SearchArgs) -> Vec<Memory> {
+    // Filter by query if provided
+    if let Some(ref query) = args.query {
+        memories.retain(|m| m.matches_query(query));
+    }
+
+    // Filter by type if specified
+    if let Some(memory_type) = args.r#type {
+        memories.retain(|m| m.memory_type == memory_type);
+    }
+
+    // Filter by tags if specified
+    let tags = collect_tags(args.tags.as_deref(), &args.tag);
+    if !tags.is_empty() {
+        memories.retain(|m| m.has_any_tag(&tags));
+    }
+    memories
+}
+
 fn prime_command(store: &MarkdownMemoryStore, args: PrimeArgs) -> Result<()> {
     let mut memories = store.load().context("Failed to load memories")?;
 
@@ -498,6 +573,169 @@ fn prime_command(store: &MarkdownMemoryStore, args: PrimeArgs) -> Result<()> {
     Ok(())
 }
 
+fn dedup_command(store: &MarkdownMemoryStore, args: DedupArgs, use_colors: bool) -> Result<()> {
+    let groups = store.find_duplicates().context("Failed to load memories")?;
+    let removed = if args.dry_run {
+        groups.iter().map(|g| g.removed.len()).sum()
+    } else {
+        store.dedup().context("Failed to deduplicate memories")?
+    };
+
+    match args.format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "dry_run": args.dry_run,
+                "removed": removed,
+                "groups": groups
+                    .iter()
+                    .map(|g| serde_json::json!({
+                        "kept": g.kept.id,
+                        "removed": g.removed.iter().map(|m| &m.id).collect::<Vec<_>>(),
+                    }))
+                    .collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Quiet => {
+            for memory in groups.iter().flat_map(|g| &g.removed) {
+                println!("{}", memory.id);
+            }
+        }
+        OutputFormat::Table | OutputFormat::Markdown => {
+            if groups.is_empty() {
+                println!("No duplicate memories found.");
+                return Ok(());
+            }
+
+            for group in &groups {
+                let removed_ids: Vec<_> = group.removed.iter().map(|m| m.id.as_str()).collect();
+                if use_colors {
+                    println!(
+                        "{}{}{} {}← {}{}",
+                        colors::BOLD,
+                        group.kept.id,
+                        colors::RESET,
+                        colors::DIM,
+                        removed_ids.join(", "),
+                        colors::RESET
+                    );
+                } else {
+                    println!("{} <- {}", group.kept.id, removed_ids.join(", "));
+                }
+                println!(
+                    "  {}",
+                    truncate_with_ellipsis(&group.kept.content.replace('\n', " "), 80)
+                );
+            }
+
+            let verb = if args.dry_run {
+                "Would remove"
+            } else {
+                "Removed"
+            };
+            if use_colors {
+                println!(
+                    "\n{}✓{} {} {} duplicate memories",
+                    colors::GREEN,
+                    colors::RESET,
+                    verb,
+                    removed
+                );
+            } else {
+                println!("\n{} {} duplicate memories", verb, removed);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn gc_command(
+    store: &MarkdownMemoryStore,
+    root: &Path,
+    args: GcArgs,
+    use_colors: bool,
+) -> Result<()> {
+    let stale = if args.prune {
+        store
+            .archive_stale(root)
+            .context("Failed to archive stale memories")?
+    } else {
+        store.find_stale(root).context("Failed to load memories")?
+    };
+
+    match args.format {
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "pruned": args.prune,
+                "stale": stale
+                    .iter()
+                    .map(|s| serde_json::json!({
+                        "id": s.memory.id,
+                        "missing_paths": s.missing_paths,
+                    }))
+                    .collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Quiet => {
+            for entry in &stale {
+                println!("{}", entry.memory.id);
+            }
+        }
+        OutputFormat::Table | OutputFormat::Markdown => {
+            if stale.is_empty() {
+                println!("No stale memories found.");
+                return Ok(());
+            }
+
+            for entry in &stale {
+                if use_colors {
+                    println!(
+                        "{}{}{} {}missing: {}{}",
+                        colors::BOLD,
+                        entry.memory.id,
+                        colors::RESET,
+                        colors::DIM,
+                        entry.missing_paths.join(", "),
+                        colors::RESET
+                    );
+                } else {
+                    println!(
+                        "{} missing: {}",
+                        entry.memory.id,
+                        entry.missing_paths.join(", ")
+                    );
+                }
+                println!(
+                    "  {}",
+                    truncate_with_ellipsis(&entry.memory.content.replace('\n', " "), 80)
+                );
+            }
+
+            let summary = if args.prune {
+                format!(
+                    "Archived {} stale memories to {}",
+                    stale.len(),
+                    store.archive_path().display()
+                )
+            } else {
+                format!(
+                    "Found {} stale memories (run with --prune to archive them)",
+                    stale.len()
+                )
+            };
+            if use_colors {
+                println!("\n{}✓{} {}", colors::GREEN, colors::RESET, summary);
+            } else {
+                println!("\n{}", summary);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn init_command(store: &MarkdownMemoryStore, args: InitArgs, use_colors: bool) -> Result<()> {
     store.init(args.force).map_err(|e| {
         if e.kind() == std::io::ErrorKind::AlreadyExists {
@@ -844,6 +1082,70 @@ mod tests {
         assert_eq!(format_relative_date_with_today(&date_str, today), date_str);
     }
 
+    #[test]
+    fn collect_tags_merges_flags_and_drops_blanks() {
+        let tags = collect_tags(
+            Some("auth, ,perf"),
+            &["Perf".to_string(), "db".to_string(), " ".to_string()],
+        );
+        assert_eq!(tags, vec!["auth", "perf", "db"]);
+        assert!(collect_tags(None, &[]).is_empty());
+    }
+
+    #[test]
+    fn add_and_search_with_repeated_tag_flags() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let store = MarkdownMemoryStore::new(temp.path().join("memories.md"));
+
+        let add = AddArgs::try_parse_from([
+            "add",
+            "Use bcrypt cost 12",
+            "--tag",
+            "auth",
+            "--tag",
+            "perf",
+        ])
+        .unwrap();
+        add_command(&store, add, false).unwrap();
+        add_command(
+            &store,
+            AddArgs::try_parse_from(["add", "Untagged note"]).unwrap(),
+            false,
+        )
+        .unwrap();
+
+        let memories = store.load().unwrap();
+        let tagged = memories
+            .iter()
+            .find(|m| m.content == "Use bcrypt cost 12")
+            .expect("tagged memory");
+        assert_eq!(tagged.tags, vec!["auth", "perf"]);
+
+        let search =
+            SearchArgs::try_parse_from(["search", "--tag", "perf", "--tag", "db"]).unwrap();
+        let matching = filter_for_search(memories.clone(), &search);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].content, "Use bcrypt cost 12");
+
+        let list = ListArgs::try_parse_from(["list", "--tag", "auth"]).unwrap();
+        let matching = filter_for_list(memories.clone(), &list);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].content, "Use bcrypt cost 12");
+
+        let list = ListArgs::try_parse_from(["list", "--tag", "db"]).unwrap();
+        assert!(filter_for_list(memories, &list).is_empty());
+    }
+
+    #[test]
+    fn add_rejects_tags_that_break_the_markdown_format() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let store = MarkdownMemoryStore::new(temp.path().join("memories.md"));
+
+        let add = AddArgs::try_parse_from(["add", "note", "--tag", "a|b"]).unwrap();
+        assert!(add_command(&store, add, false).is_err());
+        assert!(store.load().unwrap().is_empty());
+    }
+
     #[test]
     fn truncate_to_budget_prefers_complete_memory_blocks() {
         let content = "### mem-1\n> hi\n<!-- tags: a | created: 2026-01-31 -->\n\n\
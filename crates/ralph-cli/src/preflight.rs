@@ -10,6 +10,7 @@ use std::io::ErrorKind;
 use std::path::Path;
 use tracing::{info, warn};
 
+use crate::remote_fetch::{FetchRetryPolicy, fetch_text};
 use crate::{ConfigSource, HatsSource, config_resolution, presets};
 
 #[derive(Parser, Debug)]
@@ -321,22 +322,7 @@ async fn load_core_value(
             }
             ConfigSource::Remote(url) => {
                 info!("Fetching core config from {}", url);
-                let response = reqwest::get(url)
-                    .await
-                    .with_context(|| format!("Failed to fetch core config from {}", url))?;
-
-                if !response.status().is_success() {
-                    anyhow::bail!(
-                        "Failed to fetch core config from {}: HTTP {}",
-                        url,
-                        response.status()
-                    );
-                }
-
-                let content = response
-                    .text()
-                    .await
-                    .with_context(|| format!("Failed to read core config content from {}", url))?;
+                let content = fetch_text(url, "core config", FetchRetryPolicy::from_env()).await?;
 
                 let value = config_resolution::parse_yaml_value(&content, url)?;
                 reject_hat_imports_in_config_value(&value, url, UnsupportedImportSource::Remote)?;
@@ -444,22 +430,7 @@ async fn load_hats_value(source: &HatsSource) -> Result<Value> {
         }
         HatsSource::Remote(url) => {
             info!("Fetching hats config from {}", url);
-            let response = reqwest::get(url)
-                .await
-                .with_context(|| format!("Failed to fetch hats config from {}", url))?;
-
-            if !response.status().is_success() {
-                anyhow::bail!(
-                    "Failed to fetch hats config from {}: HTTP {}",
-                    url,
-                    response.status()
-                );
-            }
-
-            let content = response
-                .text()
-                .await
-                .with_context(|| format!("Failed to read hats config content from {}", url))?;
+            let content = fetch_text(url, "hats config", FetchRetryPolicy::from_env()).await?;
 
             let value = config_resolution::parse_yaml_value(&content, url)?;
             let value = normalize_hats_source_value(value, url)?;
@@ -467,24 +438,45 @@ async fn load_hats_value(source: &HatsSource) -> Result<Value> {
             Ok(value)
         }
         HatsSource::Builtin(name) => {
-            let preset = presets::get_preset(name).ok_or_else(|| {
-                let available = presets::preset_names().join(", ");
-                anyhow::anyhow!(
-                    "Unknown hat collection '{}'. Available builtins: {}",
-                    name,
-                    available
-                )
-            })?;
-
-            let preset_value =
-                config_resolution::parse_yaml_value(preset.content, &format!("builtin:{}", name))?;
-            let value = extract_hat_overlay_from_preset(preset_value)?;
-            reject_hat_imports_in_hats_source_value(
-                &value,
-                &format!("builtin:{}", name),
-                UnsupportedImportSource::Embedded,
-            )?;
-            Ok(value)
+            let preset = presets::resolve_preset(name)
+                .with_context(|| format!("Failed to read user preset '{}'", name))?
+                .ok_or_else(|| {
+                    let available = presets::preset_names().join(", ");
+                    anyhow::anyhow!(
+                        "Unknown hat collection '{}'. Available builtins: {}",
+                        name,
+                        available
+                    )
+                })?;
+
+            match &preset.origin {
+                presets::PresetOrigin::User(path) => {
+                    info!("Using user preset {} for builtin:{}", path.display(), name);
+                    let label = path.display().to_string();
+                    let preset_value =
+                        config_resolution::parse_yaml_value(&preset.content, &label)?;
+                    let mut value = extract_hat_overlay_from_preset(preset_value)?;
+                    resolve_hat_imports_in_hats_source_value(
+                        &mut value,
+                        source_base_dir(path),
+                        &label,
+                    )?;
+                    Ok(value)
+                }
+                presets::PresetOrigin::Embedded => {
+                    let preset_value = config_resolution::parse_yaml_value(
+                        &preset.content,
+                        &format!("builtin:{}", name),
+                    )?;
+                    let value = extract_hat_overlay_from_preset(preset_value)?;
+                    reject_hat_imports_in_hats_source_value(
+                        &value,
+                        &format!("builtin:{}", name),
+                        UnsupportedImportSource::Embedded,
+                    )?;
+                    Ok(value)
+                }
+            }
         }
         HatsSource::PresetDir(path) => {
             if !path.exists() {
@@ -603,9 +595,10 @@ fn resolve_hat_imports_in_hats_source_value(
 fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str) -> Result<()> {
     for (hat_key, hat_value) in hats.iter_mut() {
         let hat_id = hat_key_label(hat_key);
-        let Some(local_hat) = hat_value.as_mapping() else {
+        let Some(local_hat) = hat_value.as_mapping_mut() else {
             continue;
         };
+        anchor_instructions_file(local_hat, base_dir);
         let Some(import_value) = mapping_get(local_hat, "import") else {
             continue;
         };
@@ -667,7 +660,9 @@ fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str)
         }
 
         let local_overrides = local_hat.clone();
-        let resolved_hat = merge_imported_hat(imported_hat.clone(), &local_overrides);
+        let mut imported_hat = imported_hat.clone();
+        anchor_instructions_file(&mut imported_hat, source_base_dir(&resolved_path));
+        let resolved_hat = merge_imported_hat(imported_hat, &local_overrides);
         serde_yaml::from_value::<HatConfig>(Value::Mapping(resolved_hat.clone())).map_err(
             |err| {
                 hat_import_error(
@@ -684,6 +679,20 @@ fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str)
     Ok(())
 }
 
+/// Resolves a hat's relative `instructions_file` against the directory of the
+/// file that declared it. Hats from remote or embedded sources are left as-is
+/// and resolve against the workspace root.
+fn anchor_instructions_file(hat: &mut Mapping, base_dir: &Path) {
+    let Some(file) = mapping_get(hat, "instructions_file").and_then(Value::as_str) else {
+        return;
+    };
+    if Path::new(file).is_absolute() {
+        return;
+    }
+    let anchored = base_dir.join(file).to_string_lossy().into_owned();
+    mapping_insert(hat, "instructions_file", Value::String(anchored));
+}
+
 fn merge_imported_hat(mut imported: Mapping, local_overrides: &Mapping) -> Mapping {
     for (key, value) in local_overrides {
         if key.as_str() == Some("import") {
@@ -1198,6 +1207,55 @@ hats:
         );
     }
 
+    #[tokio::test]
+    async fn load_config_for_preflight_resolves_instructions_file_relative_to_each_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let hats_dir = temp_dir.path().join("hats");
+        std::fs::create_dir_all(hats_dir.join("shared")).unwrap();
+        std::fs::write(hats_dir.join("rubric.md"), "Local rubric").unwrap();
+        std::fs::write(hats_dir.join("shared/rubric.md"), "Imported rubric").unwrap();
+        std::fs::write(
+            hats_dir.join("shared/reviewer.yml"),
+            r"
+name: Reviewer
+triggers: [review.start]
+instructions_file: rubric.md
+",
+        )
+        .unwrap();
+
+        let hats_path = hats_dir.join("workflow.yml");
+        std::fs::write(
+            &hats_path,
+            r"
+hats:
+  builder:
+    name: Builder
+    triggers: [build.start]
+    instructions_file: rubric.md
+  reviewer:
+    import: shared/reviewer.yml
+",
+        )
+        .unwrap();
+
+        let config = load_config_for_preflight(
+            &[ConfigSource::File(temp_dir.path().join("missing.yml"))],
+            Some(&HatsSource::File(hats_path)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            config.resolve_hat_instructions("builder").unwrap(),
+            "Local rubric"
+        );
+        assert_eq!(
+            config.resolve_hat_instructions("reviewer").unwrap(),
+            "Imported rubric"
+        );
+    }
+
     #[test]
     fn resolve_hat_imports_rejects_missing_file() {
         let temp_dir = tempfile::tempdir().unwrap();
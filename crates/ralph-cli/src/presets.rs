@@ -7,6 +7,12 @@
 //! Canonical presets live in the shared `presets/` directory at the repo root.
 //! The sync script (`scripts/sync-embedded-files.sh`) mirrors them into
 //! `crates/ralph-cli/presets/` for `include_str!` to work with crates.io publishing.
+//!
+//! `builtin:<name>` lookups check user preset directories for `<name>.yml`
+//! (or `<name>.yaml`) before the embedded table, so teams can share presets
+//! without rebuilding the binary. See [`user_preset_dirs`] for the order.
+
+use std::path::{Path, PathBuf};
 
 /// An embedded preset with its name, description, and full content.
 #[derive(Debug, Clone)]
@@ -94,6 +100,111 @@ pub fn preset_names() -> Vec<&'static str> {
         .collect()
 }
 
+/// Where a resolved preset came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresetOrigin {
+    /// A `<name>.yml` file in a user preset directory.
+    User(PathBuf),
+    /// Compiled into the binary.
+    Embedded,
+}
+
+/// A preset resolved from a user directory or the embedded table.
+#[derive(Debug, Clone)]
+pub struct ResolvedPreset {
+    pub content: String,
+    pub origin: PresetOrigin,
+}
+
+/// User preset directories searched before the embedded presets.
+///
+/// Order, first hit wins:
+/// 1. `$XDG_CONFIG_HOME/ralph/presets/`
+/// 2. `$HOME/.config/ralph/presets/`
+/// 3. `$RALPH_PRESETS_DIR/`
+pub fn user_preset_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+        dirs.push(PathBuf::from(xdg).join("ralph/presets"));
+    }
+    if let Ok(home) = std::env::var("HOME") {
+        dirs.push(PathBuf::from(home).join(".config/ralph/presets"));
+    }
+    if let Ok(explicit) = std::env::var("RALPH_PRESETS_DIR") {
+        dirs.push(PathBuf::from(explicit));
+    }
+    dirs
+}
+
+/// Resolves a preset by name: user directories first, then embedded presets.
+///
+/// Returns `Ok(None)` if no preset has that name.
+///
+/// # Errors
+/// Returns an error if a matching user preset file exists but can't be read.
+pub fn resolve_preset(name: &str) -> std::io::Result<Option<ResolvedPreset>> {
+    resolve_preset_in(name, &user_preset_dirs())
+}
+
+fn resolve_preset_in(name: &str, dirs: &[PathBuf]) -> std::io::Result<Option<ResolvedPreset>> {
+    if let Some(path) = find_user_preset(name, dirs) {
+        let content = std::fs::read_to_string(&path)?;
+        return Ok(Some(ResolvedPreset {
+            content,
+            origin: PresetOrigin::User(path),
+        }));
+    }
+    Ok(get_preset(name).map(|preset| ResolvedPreset {
+        content: preset.content.to_string(),
+        origin: PresetOrigin::Embedded,
+    }))
+}
+
+/// Finds `<name>.yml` / `<name>.yaml` in the first directory that has one.
+fn find_user_preset(name: &str, dirs: &[PathBuf]) -> Option<PathBuf> {
+    // Only plain names; `builtin:../x` must not escape the preset directory.
+    if name.is_empty() || Path::new(name).file_name().and_then(|n| n.to_str()) != Some(name) {
+        return None;
+    }
+    dirs.iter()
+        .flat_map(|dir| ["yml", "yaml"].map(|ext| dir.join(format!("{name}.{ext}"))))
+        .find(|path| path.is_file())
+}
+
+/// Returns the names and paths of all user presets, first-wins per name.
+pub fn user_presets() -> Vec<(String, PathBuf)> {
+    user_presets_in(&user_preset_dirs())
+}
+
+fn user_presets_in(dirs: &[PathBuf]) -> Vec<(String, PathBuf)> {
+    let mut presets: Vec<(String, PathBuf)> = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        let mut found: Vec<(String, PathBuf)> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && matches!(
+                        path.extension().and_then(|e| e.to_str()),
+                        Some("yml" | "yaml")
+                    )
+            })
+            .filter_map(|path| Some((path.file_stem()?.to_str()?.to_string(), path)))
+            .collect();
+        found.sort();
+        for (name, path) in found {
+            if !presets.iter().any(|(existing, _)| *existing == name) {
+                presets.push((name, path));
+            }
+        }
+    }
+    presets.sort();
+    presets
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +275,39 @@ mod tests {
         assert!(preset.content.contains("git worktree remove"));
     }
 
+    #[test]
+    fn test_resolve_preset_prefers_user_dir_over_embedded() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        std::fs::write(first.path().join("code-assist.yml"), "# team override\n").unwrap();
+        std::fs::write(second.path().join("team-tdd.yaml"), "# team tdd\n").unwrap();
+        std::fs::write(second.path().join("code-assist.yml"), "# shadowed\n").unwrap();
+        let dirs = vec![first.path().to_path_buf(), second.path().to_path_buf()];
+
+        let overridden = resolve_preset_in("code-assist", &dirs).unwrap().unwrap();
+        assert_eq!(overridden.content, "# team override\n");
+        assert_eq!(
+            overridden.origin,
+            PresetOrigin::User(first.path().join("code-assist.yml"))
+        );
+
+        let user_only = resolve_preset_in("team-tdd", &dirs).unwrap().unwrap();
+        assert_eq!(user_only.content, "# team tdd\n");
+
+        let embedded = resolve_preset_in("debug", &dirs).unwrap().unwrap();
+        assert_eq!(embedded.origin, PresetOrigin::Embedded);
+
+        assert!(resolve_preset_in("missing", &dirs).unwrap().is_none());
+        assert!(
+            resolve_preset_in("../code-assist", &dirs)
+                .unwrap()
+                .is_none()
+        );
+
+        let names: Vec<String> = user_presets_in(&dirs).into_iter().map(|(n, _)| n).collect();
+        assert_eq!(names, vec!["code-assist", "team-tdd"]);
+    }
+
     #[test]
     fn test_get_preset_invalid_name() {
         let preset = get_preset("nonexistent-preset");
@@ -0,0 +1,812 @@
+//! Retrying HTTP fetches for remote config sources.
+//!
+//! Remote core config (`-c https://...`) and remote hats (`-H https://...`)
+//! are fetched through [`fetch_text`], which retries connection failures,
+//! timeouts and 5xx responses with exponential backoff. 4xx responses fail
+//! immediately since retrying will not change them.
+//!
+//! The policy is read from the environment because the config being fetched
+//! cannot configure its own fetch:
+//! - `RALPH_FETCH_RETRIES`: total attempts (default 3)
+//! - `RALPH_FETCH_BACKOFF_MS`: delay before the second attempt, doubled after
+//!   each further failure (default 500)
+//!
+//! Successful fetches are cached per URL under [`CACHE_DIR`] together with
+//! their `ETag` / `Last-Modified` validators. Later fetches send a conditional
+//! request and reuse the cached body on `304 Not Modified`; if the server
+//! can't be reached at all, the cached copy is used with a warning. Within
+//! `RALPH_REMOTE_CACHE_TTL_SECS` (default 0) of the last fetch the cache is
+//! used without a request. `--no-cache` bypasses the cache entirely.
+//!
+//! Remote sources may be untrusted, so each attempt is bounded by
+//! [`FetchLimits`]: a connect and overall timeout (`RALPH_FETCH_TIMEOUT_SECS`,
+//! default 10), a body size cap (`RALPH_FETCH_MAX_BYTES`, default 1 MiB), and
+//! a content type that must be `text/*` or YAML when the server sends one.
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+const DEFAULT_ATTEMPTS: u32 = 3;
+const DEFAULT_BACKOFF_MS: u64 = 500;
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_MAX_BYTES: usize = 1024 * 1024;
+
+/// Cache directory for remote config bodies, relative to the workspace.
+pub(crate) const CACHE_DIR: &str = ".ralph/cache/remote-configs";
+
+/// Set by `--no-cache`; checked on every fetch.
+static CACHE_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Disables the remote config cache for the rest of the process.
+pub(crate) fn disable_cache() {
+    CACHE_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether `--no-cache` was given.
+pub(crate) fn cache_disabled() -> bool {
+    CACHE_DISABLED.load(Ordering::Relaxed)
+}
+
+/// How many times to try a remote fetch and how long to wait between tries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FetchRetryPolicy {
+    pub attempts: u32,
+    pub initial_backoff: Duration,
+}
+
+impl Default for FetchRetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: DEFAULT_ATTEMPTS,
+            initial_backoff: Duration::from_millis(DEFAULT_BACKOFF_MS),
+        }
+    }
+}
+
+impl FetchRetryPolicy {
+    /// Reads the policy from `RALPH_FETCH_RETRIES` / `RALPH_FETCH_BACKOFF_MS`.
+    pub fn from_env() -> Self {
+        Self::from_values(
+            std::env::var("RALPH_FETCH_RETRIES").ok().as_deref(),
+            std::env::var("RALPH_FETCH_BACKOFF_MS").ok().as_deref(),
+        )
+    }
+
+    fn from_values(attempts: Option<&str>, backoff_ms: Option<&str>) -> Self {
+        let default = Self::default();
+        Self {
+            attempts: attempts
+                .and_then(|raw| raw.trim().parse::<u32>().ok())
+                .filter(|attempts| *attempts > 0)
+                .unwrap_or(default.attempts),
+            initial_backoff: backoff_ms
+                .and_then(|raw| raw.trim().parse::<u64>().ok())
+                .map_or(default.initial_backoff, Duration::from_millis),
+        }
+    }
+
+    /// Delay before retrying after `failed_attempts` failures.
+    fn backoff(&self, failed_attempts: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(failed_attempts.saturating_sub(1)))
+    }
+}
+
+/// Per-attempt guardrails for remote fetches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FetchLimits {
+    /// Applies to connecting and, separately, to the whole request.
+    pub timeout: Duration,
+    /// Largest response body accepted, in bytes.
+    pub max_body_bytes: usize,
+}
+
+impl Default for FetchLimits {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            max_body_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+impl FetchLimits {
+    /// Reads the limits from `RALPH_FETCH_TIMEOUT_SECS` / `RALPH_FETCH_MAX_BYTES`.
+    pub fn from_env() -> Self {
+        Self::from_values(
+            std::env::var("RALPH_FETCH_TIMEOUT_SECS").ok().as_deref(),
+            std::env::var("RALPH_FETCH_MAX_BYTES").ok().as_deref(),
+        )
+    }
+
+    fn from_values(timeout_secs: Option<&str>, max_bytes: Option<&str>) -> Self {
+        let default = Self::default();
+        Self {
+            timeout: timeout_secs
+                .and_then(|raw| raw.trim().parse::<u64>().ok())
+                .filter(|secs| *secs > 0)
+                .map_or(default.timeout, Duration::from_secs),
+            max_body_bytes: max_bytes
+                .and_then(|raw| raw.trim().parse::<usize>().ok())
+                .filter(|bytes| *bytes > 0)
+                .unwrap_or(default.max_body_bytes),
+        }
+    }
+}
+
+/// Whether a `Content-Type` header value is acceptable for a config body.
+fn is_config_content_type(content_type: &str) -> bool {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    mime.starts_with("text/")
+        || matches!(
+            mime.as_str(),
+            "application/yaml" | "application/x-yaml" | "application/yml"
+        )
+}
+
+/// A cached remote body with the validators needed to revalidate it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedRemote {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched_at: DateTime<Utc>,
+    body: String,
+}
+
+/// On-disk cache of remote config bodies, one JSON file per URL.
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl RemoteCache {
+    /// Cache under `dir`; entries younger than `ttl` are used without a request.
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// The workspace cache with `RALPH_REMOTE_CACHE_TTL_SECS`, or `None` after
+    /// `--no-cache`.
+    pub fn from_env() -> Option<Self> {
+        if cache_disabled() {
+            return None;
+        }
+        let ttl_secs = std::env::var("RALPH_REMOTE_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        Some(Self::new(CACHE_DIR, Duration::from_secs(ttl_secs)))
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}.json", ralph_core::content_hash(url)))
+    }
+
+    fn load(&self, url: &str) -> Option<CachedRemote> {
+        let content = std::fs::read_to_string(self.entry_path(url)).ok()?;
+        serde_json::from_str::<CachedRemote>(&content)
+            .ok()
+            .filter(|entry| entry.url == url)
+    }
+
+    fn store(&self, entry: &CachedRemote) {
+        let path = self.entry_path(&entry.url);
+        let result = std::fs::create_dir_all(&self.dir).and_then(|()| {
+            let json = serde_json::to_string_pretty(entry).map_err(std::io::Error::other)?;
+            std::fs::write(&path, json)
+        });
+        if let Err(error) = result {
+            warn!(
+                "Failed to cache {} at {}: {}",
+                entry.url,
+                path.display(),
+                error
+            );
+        }
+    }
+
+    fn is_fresh(&self, entry: &CachedRemote) -> bool {
+        (Utc::now() - entry.fetched_at)
+            .to_std()
+            .is_ok_and(|age| age < self.ttl)
+    }
+}
+
+/// Outcome of a single fetch attempt.
+enum AttemptError {
+    /// Worth retrying (connection error, timeout, 5xx).
+    Transient(anyhow::Error),
+    /// Retrying will not help (4xx, invalid URL).
+    Permanent(anyhow::Error),
+}
+
+/// A successful response.
+enum Fetched {
+    Body {
+        body: String,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+    /// `304 Not Modified` for a conditional request.
+    NotModified,
+}
+
+/// Fetches `url` as text, retrying transient failures per `policy`.
+///
+/// `what` names the resource in errors (e.g. "core config"). The final error
+/// says how many attempts were made. Goes through the workspace cache unless
+/// `--no-cache` was given.
+pub(crate) async fn fetch_text(url: &str, what: &str, policy: FetchRetryPolicy) -> Result<String> {
+    fetch_text_cached(
+        url,
+        what,
+        policy,
+        FetchLimits::from_env(),
+        RemoteCache::from_env().as_ref(),
+    )
+    .await
+}
+
+async fn fetch_text_cached(
+    url: &str,
+    what: &str,
+    policy: FetchRetryPolicy,
+    limits: FetchLimits,
+    cache: Option<&RemoteCache>,
+) -> Result<String> {
+    let cached = cache.and_then(|cache| cache.load(url));
+    if let (Some(cache), Some(entry)) = (cache, &cached)
+        && cache.is_fresh(entry)
+    {
+        debug!("Using cached {} for {} (within TTL)", what, url);
+        return Ok(entry.body.clone());
+    }
+
+    match fetch_with_retries(url, what, policy, limits, cached.as_ref()).await {
+        Ok(Fetched::Body {
+            body,
+            etag,
+            last_modified,
+        }) => {
+            if let Some(cache) = cache {
+                cache.store(&CachedRemote {
+                    url: url.to_string(),
+                    etag,
+                    last_modified,
+                    fetched_at: Utc::now(),
+                    body: body.clone(),
+                });
+            }
+            Ok(body)
+        }
+        Ok(Fetched::NotModified) => {
+            let Some(mut entry) = cached else {
+                return Err(anyhow!(
+                    "Failed to fetch {} from {}: unexpected 304 Not Modified",
+                    what,
+                    url
+                ));
+            };
+            debug!("{} at {} not modified; using cached copy", what, url);
+            entry.fetched_at = Utc::now();
+            if let Some(cache) = cache {
+                cache.store(&entry);
+            }
+            Ok(entry.body)
+        }
+        // Unreachable server: a stale copy beats failing the run.
+        Err(AttemptError::Transient(error)) => match cached {
+            Some(entry) => {
+                warn!(
+                    "{:#}; using cached copy from {}",
+                    error,
+                    entry.fetched_at.to_rfc3339()
+                );
+                Ok(entry.body)
+            }
+            None => Err(error),
+        },
+        Err(AttemptError::Permanent(error)) => Err(error),
+    }
+}
+
+async fn fetch_with_retries(
+    url: &str,
+    what: &str,
+    policy: FetchRetryPolicy,
+    limits: FetchLimits,
+    cached: Option<&CachedRemote>,
+) -> Result<Fetched, AttemptError> {
+    let attempts = policy.attempts.max(1);
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let error = match fetch_once(url, limits, cached).await {
+            Ok(fetched) => return Ok(fetched),
+            Err(AttemptError::Transient(error)) if attempt < attempts => {
+                let delay = policy.backoff(attempt);
+                warn!(
+                    "Fetching {} from {} failed (attempt {}/{}): {}; retrying in {:?}",
+                    what, url, attempt, attempts, error, delay
+                );
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+            Err(error) => error,
+        };
+        let noun = if attempt == 1 { "attempt" } else { "attempts" };
+        let describe = |error: anyhow::Error| {
+            anyhow!(
+                "Failed to fetch {} from {} after {} {}: {:#}",
+                what,
+                url,
+                attempt,
+                noun,
+                error
+            )
+        };
+        return Err(match error {
+            AttemptError::Transient(error) => AttemptError::Transient(describe(error)),
+            AttemptError::Permanent(error) => AttemptError::Permanent(describe(error)),
+        });
+    }
+}
+
+async fn fetch_once(
+    url: &str,
+    limits: FetchLimits,
+    cached: Option<&CachedRemote>,
+) -> Result<Fetched, AttemptError> {
+    use reqwest::header::{CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+    let client = reqwest::Client::builder()
+        .connect_timeout(limits.timeout)
+        .timeout(limits.timeout)
+        .build()
+        .map_err(|error| AttemptError::Permanent(error.into()))?;
+    let mut request = client.get(url);
+    if let Some(entry) = cached {
+        if let Some(etag) = &entry.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = request.send().await.map_err(|error| {
+        if error.is_connect() || error.is_timeout() || error.is_request() {
+            AttemptError::Transient(error.into())
+        } else {
+            AttemptError::Permanent(error.into())
+        }
+    })?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_MODIFIED && cached.is_some() {
+        return Ok(Fetched::NotModified);
+    }
+    if status.is_server_error() {
+        return Err(AttemptError::Transient(anyhow!("HTTP {}", status)));
+    }
+    if !status.is_success() {
+        return Err(AttemptError::Permanent(anyhow!("HTTP {}", status)));
+    }
+
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    if let Some(content_type) = header(CONTENT_TYPE)
+        && !is_config_content_type(&content_type)
+    {
+        return Err(AttemptError::Permanent(anyhow!(
+            "unexpected content type '{}' (expected text/* or application/yaml)",
+            content_type
+        )));
+    }
+    let too_large = || {
+        AttemptError::Permanent(anyhow!(
+            "response body exceeds {} bytes (raise RALPH_FETCH_MAX_BYTES to allow it)",
+            limits.max_body_bytes
+        ))
+    };
+    if response
+        .content_length()
+        .is_some_and(|len| len > limits.max_body_bytes as u64)
+    {
+        return Err(too_large());
+    }
+    let etag = header(ETAG);
+    let last_modified = header(LAST_MODIFIED);
+
+    let mut response = response;
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|error| AttemptError::Transient(anyhow!("failed to read body: {}", error)))?
+    {
+        if bytes.len() + chunk.len() > limits.max_body_bytes {
+            return Err(too_large());
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    let body = String::from_utf8(bytes)
+        .map_err(|_| AttemptError::Permanent(anyhow!("response body is not valid UTF-8")))?;
+    Ok(Fetched::Body {
+        body,
+        etag,
+        last_modified,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// Serves `responses` in order (status, body), one per connection, and
+    /// counts the requests received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+        let (url, hits, _) = serve_with_headers(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, "", body))
+                .collect(),
+        );
+        (url, hits)
+    }
+
+    /// Like [`serve`], with extra response header lines per response; also
+    /// records each request's header lines.
+    fn serve_with_headers(
+        responses: Vec<(u16, &'static str, &'static str)>,
+    ) -> (String, Arc<AtomicUsize>, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/ralph.yml", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let counter = Arc::clone(&hits);
+        let seen = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for (status, headers, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 0) {
+                    if line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line.to_ascii_lowercase());
+                    line.clear();
+                }
+                seen.lock().unwrap().push(request);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        (url, hits, requests)
+    }
+
+    fn fast_policy(attempts: u32) -> FetchRetryPolicy {
+        FetchRetryPolicy {
+            attempts,
+            initial_backoff: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn recovers_after_two_server_errors() {
+        let (url, hits) = serve(vec![
+            (503, "unavailable"),
+            (502, "bad gateway"),
+            (200, "cli:\n  backend: claude\n"),
+        ]);
+
+        let content = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(3),
+            FetchLimits::default(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(content, "cli:\n  backend: claude\n");
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_configured_attempts() {
+        let (url, hits) = serve(vec![(500, "boom"), (500, "boom"), (200, "late")]);
+
+        let error = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(2),
+            FetchLimits::default(),
+            None,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("after 2 attempts"), "{error}");
+        assert!(error.contains("HTTP 500"), "{error}");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let (url, hits) = serve(vec![(404, "missing"), (200, "unreachable")]);
+
+        let error = fetch_text_cached(
+            &url,
+            "hats config",
+            fast_policy(3),
+            FetchLimits::default(),
+            None,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+        assert!(error.contains("after 1 attempt:"), "{error}");
+        assert!(error.contains("HTTP 404"), "{error}");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn revalidates_cached_copy_with_etag() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = RemoteCache::new(temp.path(), Duration::ZERO);
+        let (url, hits, requests) = serve_with_headers(vec![
+            (200, "ETag: \"v1\"\r\n", "cli:\n  backend: claude\n"),
+            (304, "", ""),
+        ]);
+
+        let first = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(1),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+        let second = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(1),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(first, "cli:\n  backend: claude\n");
+        assert_eq!(second, first);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        let requests = requests.lock().unwrap();
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(
+            requests[1].contains("if-none-match: \"v1\""),
+            "{}",
+            requests[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_cache_when_unreachable() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = RemoteCache::new(temp.path(), Duration::ZERO);
+        let (url, _, _) = serve_with_headers(vec![(200, "", "cached body")]);
+        fetch_text_cached(
+            &url,
+            "hats config",
+            fast_policy(1),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+
+        // The server thread has exited, so the port now refuses connections.
+        let content = fetch_text_cached(
+            &url,
+            "hats config",
+            fast_policy(2),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(content, "cached body");
+        assert!(
+            fetch_text_cached(
+                &url,
+                "hats config",
+                fast_policy(1),
+                FetchLimits::default(),
+                None
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn fresh_cache_entry_skips_request() {
+        let temp = tempfile::tempdir().unwrap();
+        let cache = RemoteCache::new(temp.path(), Duration::from_hours(1));
+        let (url, hits, _) = serve_with_headers(vec![(200, "", "v1"), (200, "", "v2")]);
+
+        fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(1),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+        let content = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(1),
+            FetchLimits::default(),
+            Some(&cache),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(content, "v1");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_body_without_retrying() {
+        let (url, hits) = serve(vec![(200, "cli:\n  backend: claude\n"), (200, "x")]);
+        let limits = FetchLimits {
+            max_body_bytes: 8,
+            ..FetchLimits::default()
+        };
+
+        let error = fetch_text_cached(&url, "core config", fast_policy(2), limits, None)
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("exceeds 8 bytes"), "{error:#}");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_text_content_type() {
+        let (url, _, _) = serve_with_headers(vec![(
+            200,
+            "Content-Type: application/octet-stream\r\n",
+            "cli: {}",
+        )]);
+
+        let error = fetch_text_cached(
+            &url,
+            "hats config",
+            fast_policy(1),
+            FetchLimits::default(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("unexpected content type 'application/octet-stream'"),
+            "{error:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_yaml_content_type() {
+        let (url, _, _) = serve_with_headers(vec![(
+            200,
+            "Content-Type: application/yaml; charset=utf-8\r\n",
+            "cli: {}",
+        )]);
+
+        let content = fetch_text_cached(
+            &url,
+            "core config",
+            fast_policy(1),
+            FetchLimits::default(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(content, "cli: {}");
+    }
+
+    #[tokio::test]
+    async fn times_out_when_server_never_responds() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/ralph.yml", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let _held: Vec<_> = listener.incoming().take(2).collect();
+            std::thread::sleep(Duration::from_secs(5));
+        });
+        let limits = FetchLimits {
+            timeout: Duration::from_millis(200),
+            ..FetchLimits::default()
+        };
+
+        let started = std::time::Instant::now();
+        let error = fetch_text_cached(&url, "core config", fast_policy(2), limits, None)
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("after 2 attempts"), "{error:#}");
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn limits_from_env_values() {
+        assert_eq!(FetchLimits::from_values(None, None), FetchLimits::default());
+        let limits = FetchLimits::from_values(Some("3"), Some("2048"));
+        assert_eq!(limits.timeout, Duration::from_secs(3));
+        assert_eq!(limits.max_body_bytes, 2048);
+        assert_eq!(
+            FetchLimits::from_values(Some("0"), Some("lots")),
+            FetchLimits::default()
+        );
+    }
+
+    #[test]
+    fn policy_from_env_values() {
+        assert_eq!(
+            FetchRetryPolicy::from_values(None, None),
+            FetchRetryPolicy::default()
+        );
+        let policy = FetchRetryPolicy::from_values(Some("5"), Some("100"));
+        assert_eq!(policy.attempts, 5);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        // Zero or garbage attempts fall back to the default
+        assert_eq!(
+            FetchRetryPolicy::from_values(Some("0"), Some("x")),
+            FetchRetryPolicy::default()
+        );
+    }
+}
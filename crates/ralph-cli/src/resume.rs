@@ -0,0 +1,365 @@
+//! Discovery of interrupted loops for `ralph run --resume-latest`.
+//!
+//! Scans the primary workspace and every `ralph/*` worktree for a
+//! `.ralph/history.jsonl` whose latest run never reached its completion
+//! promise, skipping loops that are still alive in the `LoopRegistry`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Duration, Utc};
+use ralph_core::worktree::Worktree;
+use ralph_core::{HistoryEventType, LoopContext, LoopHistory, LoopRegistry};
+
+/// Candidates whose last activity falls within this window of the newest one
+/// are considered equally recent, and the user must pick one explicitly.
+const AMBIGUITY_WINDOW_MINUTES: i64 = 15;
+
+/// Identifier used for the primary loop when no `current-loop-id` marker exists.
+const PRIMARY_LOOP_LABEL: &str = "primary";
+
+/// An interrupted loop that `--resume-latest` can pick up.
+#[derive(Debug, Clone)]
+pub struct ResumeCandidate {
+    /// Loop ID (worktree name, or the primary loop's `current-loop-id`).
+    pub id: String,
+    /// Workspace the loop ran in.
+    pub workspace: PathBuf,
+    /// Whether this is the primary loop (not a worktree).
+    pub is_primary: bool,
+    /// Prompt that started the latest run.
+    pub prompt: String,
+    /// Last iteration that completed, if any.
+    pub last_iteration: Option<u32>,
+    /// Timestamp of the most recent history event.
+    pub last_activity: DateTime<Utc>,
+}
+
+impl ResumeCandidate {
+    /// Loop context for resuming this candidate.
+    pub fn context(&self, repo_root: &Path) -> LoopContext {
+        if self.is_primary {
+            LoopContext::primary(self.workspace.clone())
+        } else {
+            LoopContext::worktree(
+                self.id.clone(),
+                self.workspace.clone(),
+                repo_root.to_path_buf(),
+            )
+        }
+    }
+}
+
+/// Finds interrupted loops under `repo_root`, newest first.
+pub fn find_resume_candidates(
+    repo_root: &Path,
+    worktrees: &[Worktree],
+) -> Result<Vec<ResumeCandidate>> {
+    let live = LoopRegistry::new(repo_root)
+        .list()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(ralph_core::LoopEntry::is_alive)
+        .collect::<Vec<_>>();
+
+    let mut candidates = Vec::new();
+
+    let primary = LoopContext::primary(repo_root.to_path_buf());
+    let primary_alive = live.iter().any(|e| e.worktree_path.is_none());
+    if !primary_alive {
+        let marker = primary.ralph_dir().join("current-loop-id");
+        let id = std::fs::read_to_string(marker)
+            .map(|s| s.trim().to_string())
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| PRIMARY_LOOP_LABEL.to_string());
+        if let Some(candidate) = inspect_history(&primary, id, true)? {
+            candidates.push(candidate);
+        }
+    }
+
+    for worktree in worktrees.iter().filter(|wt| !wt.is_main) {
+        let Some(id) = worktree.branch.strip_prefix("ralph/") else {
+            continue;
+        };
+        if live.iter().any(|e| e.id == id) {
+            continue;
+        }
+        let context = LoopContext::worktree(id, worktree.path.clone(), repo_root.to_path_buf());
+        if let Some(candidate) = inspect_history(&context, id.to_string(), false)? {
+            candidates.push(candidate);
+        }
+    }
+
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.last_activity));
+    Ok(candidates)
+}
+
+/// Picks the candidate to resume.
+///
+/// With `selection`, matches a candidate ID exactly or by suffix. Without
+/// it, returns the newest candidate unless several are equally recent, in
+/// which case the error lists them so the user can choose with `--loop-id`.
+pub fn select_candidate(
+    candidates: Vec<ResumeCandidate>,
+    selection: Option<&str>,
+) -> Result<ResumeCandidate> {
+    if candidates.is_empty() {
+        bail!("No interrupted loops found. Start a fresh run with `ralph run`.");
+    }
+
+    if let Some(selection) = selection {
+        return candidates
+            .into_iter()
+            .find(|c| c.id == selection || c.id.ends_with(selection))
+            .ok_or_else(|| anyhow::anyhow!("No interrupted loop matches '{selection}'."));
+    }
+
+    let newest = candidates[0].last_activity;
+    let cutoff = newest - Duration::minutes(AMBIGUITY_WINDOW_MINUTES);
+    let recent: Vec<_> = candidates
+        .iter()
+        .filter(|c| c.last_activity >= cutoff)
+        .collect();
+
+    if recent.len() > 1 {
+        let listing = recent
+            .iter()
+            .map(|c| {
+                format!(
+                    "  {}  {}  {}",
+                    c.id,
+                    c.last_activity.format("%Y-%m-%d %H:%M:%S UTC"),
+                    ralph_core::truncate_with_ellipsis(&c.prompt.replace('\n', " "), 50)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        bail!(
+            "Several loops were interrupted recently:\n{listing}\n\
+             Re-run with `--resume-latest --loop-id <ID>` to choose one."
+        );
+    }
+
+    Ok(candidates.into_iter().next().expect("checked non-empty"))
+}
+
+/// Builds a candidate from a loop's history if its latest run is resumable.
+fn inspect_history(
+    context: &LoopContext,
+    id: String,
+    is_primary: bool,
+) -> Result<Option<ResumeCandidate>> {
+    let history = LoopHistory::from_context(context);
+    if !history.path().exists() {
+        return Ok(None);
+    }
+
+    let events = history.read_all()?;
+    // History is append-only across runs; only the latest run matters.
+    let Some(start) = events
+        .iter()
+        .rposition(|e| matches!(e.event_type, HistoryEventType::LoopStarted { .. }))
+    else {
+        return Ok(None);
+    };
+    let run = &events[start..];
+
+    let mut prompt = String::new();
+    let mut last_iteration = None;
+    for event in run {
+        match &event.event_type {
+            HistoryEventType::LoopStarted { prompt: p } => prompt.clone_from(p),
+            HistoryEventType::IterationCompleted { iteration, .. } => {
+                last_iteration = Some(*iteration);
+            }
+            // Finished on its completion promise, merged, or thrown away.
+            HistoryEventType::LoopCompleted { reason } if reason == "completion_promise" => {
+                return Ok(None);
+            }
+            HistoryEventType::MergeCompleted { .. } | HistoryEventType::LoopDiscarded { .. } => {
+                return Ok(None);
+            }
+            _ => {}
+        }
+    }
+
+    let last_activity = run.last().map_or_else(Utc::now, |e| e.timestamp);
+    Ok(Some(ResumeCandidate {
+        id,
+        workspace: context.workspace().to_path_buf(),
+        is_primary,
+        prompt,
+        last_iteration,
+        last_activity,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ralph_core::HistoryEvent;
+    use tempfile::TempDir;
+
+    fn seed(history: &LoopHistory, events: Vec<(HistoryEventType, DateTime<Utc>)>) {
+        std::fs::create_dir_all(history.path().parent().unwrap()).unwrap();
+        for (event_type, timestamp) in events {
+            let mut event = HistoryEvent::new(event_type);
+            event.timestamp = timestamp;
+            history.append(event).unwrap();
+        }
+    }
+
+    fn started(prompt: &str) -> HistoryEventType {
+        HistoryEventType::LoopStarted {
+            prompt: prompt.to_string(),
+        }
+    }
+
+    fn worktree(repo: &Path, id: &str) -> Worktree {
+        Worktree {
+            path: repo.join(".worktrees").join(id),
+            branch: format!("ralph/{id}"),
+            is_main: false,
+            head: None,
+        }
+    }
+
+    #[test]
+    fn picks_most_recent_interrupted_loop() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        let now = Utc::now();
+
+        // Primary loop finished on its completion promise: not resumable
+        seed(
+            &LoopHistory::from_context(&LoopContext::primary(repo.to_path_buf())),
+            vec![
+                (started("done work"), now - Duration::hours(3)),
+                (
+                    HistoryEventType::LoopCompleted {
+                        reason: "completion_promise".to_string(),
+                    },
+                    now - Duration::hours(2),
+                ),
+            ],
+        );
+
+        // Older interrupted worktree loop
+        let old = worktree(repo, "old-otter");
+        seed(
+            &LoopHistory::new(old.path.join(".ralph/history.jsonl")),
+            vec![
+                (started("old task"), now - Duration::hours(5)),
+                (
+                    HistoryEventType::LoopTerminated {
+                        signal: "SIGTERM".to_string(),
+                    },
+                    now - Duration::hours(4),
+                ),
+            ],
+        );
+
+        // Newest interrupted worktree loop (crashed mid-iteration)
+        let fresh = worktree(repo, "brave-falcon");
+        seed(
+            &LoopHistory::new(fresh.path.join(".ralph/history.jsonl")),
+            vec![
+                (started("fresh task"), now - Duration::minutes(30)),
+                (
+                    HistoryEventType::IterationCompleted {
+                        iteration: 2,
+                        success: true,
+                    },
+                    now - Duration::minutes(20),
+                ),
+            ],
+        );
+
+        let candidates = find_resume_candidates(repo, &[old, fresh]).unwrap();
+        assert_eq!(candidates.len(), 2);
+
+        let selected = select_candidate(candidates, None).unwrap();
+        assert_eq!(selected.id, "brave-falcon");
+        assert!(!selected.is_primary);
+        assert_eq!(selected.prompt, "fresh task");
+        assert_eq!(selected.last_iteration, Some(2));
+        assert_eq!(
+            selected.context(repo).scratchpad_path(),
+            repo.join(".worktrees/brave-falcon/.ralph/agent/scratchpad.md")
+        );
+    }
+
+    #[test]
+    fn safeguard_stop_is_resumable_but_only_latest_run_counts() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        let ralph_dir = repo.join(".ralph");
+        let now = Utc::now();
+
+        seed(
+            &LoopHistory::new(ralph_dir.join("history.jsonl")),
+            vec![
+                (started("first"), now - Duration::hours(2)),
+                (
+                    HistoryEventType::LoopCompleted {
+                        reason: "completion_promise".to_string(),
+                    },
+                    now - Duration::hours(2),
+                ),
+                (started("second"), now - Duration::hours(1)),
+                (
+                    HistoryEventType::LoopCompleted {
+                        reason: "max_iterations".to_string(),
+                    },
+                    now - Duration::minutes(50),
+                ),
+            ],
+        );
+        std::fs::write(
+            ralph_dir.join("current-loop-id"),
+            "primary-20260101-120000\n",
+        )
+        .unwrap();
+
+        let candidates = find_resume_candidates(repo, &[]).unwrap();
+        let selected = select_candidate(candidates, None).unwrap();
+        assert!(selected.is_primary);
+        assert_eq!(selected.id, "primary-20260101-120000");
+        assert_eq!(selected.prompt, "second");
+    }
+
+    #[test]
+    fn several_recent_loops_require_selection() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        let now = Utc::now();
+
+        let a = worktree(repo, "calm-heron");
+        let b = worktree(repo, "quick-lynx");
+        for (wt, age) in [(&a, 5), (&b, 2)] {
+            seed(
+                &LoopHistory::new(wt.path.join(".ralph/history.jsonl")),
+                vec![(started("task"), now - Duration::minutes(age))],
+            );
+        }
+
+        let candidates = find_resume_candidates(repo, &[a, b]).unwrap();
+        let err = select_candidate(candidates.clone(), None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("calm-heron"), "{err}");
+        assert!(err.contains("quick-lynx"), "{err}");
+        assert!(err.contains("--loop-id"), "{err}");
+
+        let selected = select_candidate(candidates, Some("calm-heron")).unwrap();
+        assert_eq!(selected.id, "calm-heron");
+    }
+
+    #[test]
+    fn no_candidates_is_an_error() {
+        let temp = TempDir::new().unwrap();
+        let candidates = find_resume_candidates(temp.path(), &[]).unwrap();
+        assert!(select_candidate(candidates, None).is_err());
+    }
+}
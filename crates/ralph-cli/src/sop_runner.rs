@@ -12,6 +12,7 @@ use ralph_core::RalphConfig;
 use crate::backend_support;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Bundled SOP content - embedded at compile time for self-contained binary.
@@ -74,6 +75,8 @@ pub struct SopRunConfig {
     pub custom_args: Option<Vec<String>>,
     /// Enable Claude Code's experimental Agent Teams feature.
     pub agent_teams: bool,
+    /// Kill the session once it has run this long (`None` waits indefinitely).
+    pub timeout: Option<Duration>,
 }
 
 /// Errors that can occur when running an SOP.
@@ -87,6 +90,9 @@ pub enum SopRunError {
 
     #[error("Failed to spawn backend: {0}")]
     SpawnError(#[from] std::io::Error),
+
+    #[error("SOP session exceeded the {}s timeout and was killed", .0.as_secs())]
+    TimedOut(Duration),
 }
 
 impl From<CustomBackendError> for SopRunError {
@@ -197,7 +203,7 @@ pub fn run_sop(config: SopRunConfig) -> Result<(), SopRunError> {
     };
 
     // 4. Spawn the interactive session
-    spawn_interactive(&cli_backend, &prompt)?;
+    spawn_interactive(&cli_backend, &prompt, config.timeout)?;
 
     Ok(())
 }
@@ -283,7 +289,12 @@ fn build_prompt(sop: Sop, user_input: Option<&str>, addendums: &[(&str, &str)])
 /// Spawns an interactive backend session.
 ///
 /// The session inherits stdin/stdout/stderr for full interactive capability.
-fn spawn_interactive(backend: &CliBackend, prompt: &str) -> Result<(), SopRunError> {
+/// With a `timeout`, the backend is killed once the deadline passes.
+fn spawn_interactive(
+    backend: &CliBackend,
+    prompt: &str,
+    timeout: Option<Duration>,
+) -> Result<(), SopRunError> {
     let (command, args, _stdin_input, _temp_file) = backend.build_command(prompt, true);
 
     let mut cmd = Command::new(&command);
@@ -298,11 +309,27 @@ fn spawn_interactive(backend: &CliBackend, prompt: &str) -> Result<(), SopRunErr
     let mut child = cmd.spawn()?;
 
     // Wait for the interactive session to complete
-    child.wait()?;
+    let Some(timeout) = timeout else {
+        child.wait()?;
+        return Ok(());
+    };
+
+    let deadline = Instant::now() + timeout;
+    while child.try_wait()?.is_none() {
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            child.wait()?;
+            return Err(SopRunError::TimedOut(timeout));
+        }
+        std::thread::sleep(SESSION_POLL_INTERVAL);
+    }
 
     Ok(())
 }
 
+/// How often a timed session checks whether the backend has exited.
+const SESSION_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,6 +468,7 @@ mod tests {
             config_path: None,
             custom_args: None,
             agent_teams: false,
+            timeout: None,
         };
 
         let err = run_sop(config).expect_err("expected error");
@@ -469,11 +497,39 @@ mod tests {
                 "exit 0".to_string(),
             ]),
             agent_teams: false,
+            timeout: None,
         };
 
         run_sop(config).expect("run sop");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_run_sop_kills_session_after_timeout() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let config = SopRunConfig {
+            sop: Sop::Pdd,
+            user_input: None,
+            backend_override: Some("custom".to_string()),
+            config: None,
+            config_path: None,
+            custom_args: Some(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "exec sleep 30".to_string(),
+            ]),
+            agent_teams: false,
+            timeout: Some(Duration::from_millis(200)),
+        };
+
+        let started = Instant::now();
+        let err = run_sop(config).expect_err("expected timeout");
+        assert!(matches!(err, SopRunError::TimedOut(_)));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Tests for build_prompt addendums
     // ─────────────────────────────────────────────────────────────────────────
@@ -9,13 +9,17 @@
 //! - `close`: Mark a task as complete
 //! - `reopen`: Reopen a closed/failed task
 //! - `show`: Show a single task by ID
+//! - `graph`: Print the `blocked_by` dependency graph
 
-use crate::{display::colors, resolve_path_from_workspace, resolve_workspace_root};
+use crate::{
+    default_config_path, display::colors, hats::DiagramFormat, resolve_path_from_workspace,
+    resolve_workspace_root,
+};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand, ValueEnum};
-use ralph_core::{Task, TaskStatus, TaskStore};
-use std::path::PathBuf;
+use ralph_core::{FormattingConfig, RalphConfig, Task, TaskStatus, TaskStore};
+use std::path::{Path, PathBuf};
 
 /// Output format for task commands.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
@@ -68,6 +72,9 @@ pub enum TaskCommands {
 
     /// Show a single task by ID
     Show(ShowArgs),
+
+    /// Print the task dependency graph as Mermaid or DOT
+    Graph(GraphArgs),
 }
 
 /// Arguments for the `task add` command.
@@ -88,6 +95,10 @@ pub struct AddArgs {
     #[arg(long)]
     pub blocked_by: Option<String>,
 
+    /// Tag to attach (repeatable: --tag backend --tag urgent)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -115,6 +126,10 @@ pub struct EnsureArgs {
     #[arg(long)]
     pub blocked_by: Option<String>,
 
+    /// Tag to attach (repeatable: --tag backend --tag urgent)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -139,6 +154,10 @@ pub struct ListArgs {
     #[arg(long, short = 'a')]
     pub all: bool,
 
+    /// Show only tasks with this tag
+    #[arg(long, value_name = "TAG")]
+    pub tag: Option<String>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -151,6 +170,10 @@ pub struct ReadyArgs {
     #[arg(long, short = 'a')]
     pub all: bool,
 
+    /// Show only tasks with this tag
+    #[arg(long, value_name = "TAG")]
+    pub tag: Option<String>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -195,11 +218,43 @@ pub struct ShowArgs {
     pub format: OutputFormat,
 }
 
+/// Arguments for the `task graph` command.
+#[derive(Parser, Debug)]
+pub struct GraphArgs {
+    /// Graph format
+    #[arg(long, default_value = "mermaid")]
+    pub format: DiagramFormat,
+
+    /// Include closed and failed tasks (hidden by default)
+    #[arg(long, short = 'a')]
+    pub all: bool,
+
+    /// Write to this file instead of stdout (e.g. tasks.mmd)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
 /// Gets the tasks file path.
 fn get_tasks_path(root: Option<&PathBuf>) -> PathBuf {
     resolve_path_from_workspace(".ralph/agent/tasks.jsonl", root)
 }
 
+/// Formatting policy (`core.formatting`) from the workspace config, so task
+/// files written here match the ones the loop writes. Falls back to the
+/// defaults when there is no readable config.
+fn workspace_formatting(root: Option<&PathBuf>) -> FormattingConfig {
+    let config_path = resolve_workspace_root(root).join(default_config_path());
+    RalphConfig::from_file(&config_path)
+        .map(|config| config.core.formatting)
+        .unwrap_or_default()
+}
+
+fn load_task_store_for_write(path: &Path, root: Option<&PathBuf>) -> Result<TaskStore> {
+    Ok(TaskStore::load(path)
+        .context("Failed to load tasks")?
+        .with_formatting(workspace_formatting(root)))
+}
+
 fn read_current_loop_id(root: Option<&PathBuf>) -> Option<String> {
     let loop_id_marker = resolve_workspace_root(root).join(".ralph/current-loop-id");
 
@@ -213,6 +268,7 @@ fn add_common_task_fields(
     root: Option<&PathBuf>,
     description: Option<String>,
     blocked_by: Option<String>,
+    tags: Vec<String>,
 ) -> Task {
     if let Some(loop_id) = read_current_loop_id(root) {
         task = task.with_loop_id(Some(loop_id));
@@ -232,6 +288,12 @@ fn add_common_task_fields(
         }
     }
 
+    for tag in tags.into_iter().map(|t| t.trim().to_string()) {
+        if !tag.is_empty() {
+            task = task.with_tag(tag);
+        }
+    }
+
     task
 }
 
@@ -264,6 +326,10 @@ fn filter_tasks_for_list(store: &TaskStore, args: &ListArgs) -> Vec<Task> {
             .collect()
     };
 
+    if let Some(tag) = args.tag.as_deref() {
+        tasks.retain(|t| t.has_tag(tag));
+    }
+
     if let Some(days) = args.days {
         let cutoff = Utc::now() - chrono::Duration::days(days);
         tasks.retain(|t| {
@@ -321,6 +387,10 @@ fn filter_tasks_for_ready(
 ) -> Vec<Task> {
     let mut ready: Vec<Task> = store.ready().into_iter().cloned().collect();
 
+    if let Some(tag) = args.tag.as_deref() {
+        ready.retain(|t| t.has_tag(tag));
+    }
+
     if !args.all {
         let loop_id_marker = Some(resolve_workspace_root(root).join(".ralph/current-loop-id"));
         if let Some(marker_path) = loop_id_marker
@@ -350,22 +420,24 @@ pub fn execute(args: TaskArgs, use_colors: bool) -> Result<()> {
         TaskCommands::Fail(fail_args) => execute_fail(fail_args, root.as_ref(), use_colors),
         TaskCommands::Reopen(reopen_args) => execute_reopen(reopen_args, root.as_ref(), use_colors),
         TaskCommands::Show(show_args) => execute_show(show_args, root.as_ref(), use_colors),
+        TaskCommands::Graph(graph_args) => execute_graph(graph_args, root.as_ref()),
     }
 }
 
 fn execute_add(args: AddArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task = add_common_task_fields(
         Task::new(args.title, args.priority),
         root,
         args.description,
         args.blocked_by,
+        args.tags,
     );
 
     let task_id = task.id.clone();
-    store.add(task.clone());
+    store.try_add(task.clone())?;
     store.save().context("Failed to save tasks")?;
 
     match args.format {
@@ -383,6 +455,9 @@ fn execute_add(args: AddArgs, root: Option<&PathBuf>, use_colors: bool) -> Resul
             if !task.blocked_by.is_empty() {
                 println!("  Blocked by: {}", task.blocked_by.join(", "));
             }
+            if !task.tags.is_empty() {
+                println!("  Tags: {}", task.tags.join(", "));
+            }
         }
         OutputFormat::Json => {
             println!("{}", serde_json::to_string(&task)?);
@@ -397,20 +472,21 @@ fn execute_add(args: AddArgs, root: Option<&PathBuf>, use_colors: bool) -> Resul
 
 fn execute_ensure(args: EnsureArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task = add_common_task_fields(
         Task::new(args.title, args.priority).with_key(Some(args.key.clone())),
         root,
         args.description,
         args.blocked_by,
+        args.tags,
     );
     let key = task.key.clone().expect("ensure key should be set");
     let existed = store.get_by_key(&key).is_some();
 
     let ensured = store
-        .with_exclusive_lock(|s| s.ensure(task).clone())
-        .context("Failed to ensure task")?;
+        .with_exclusive_lock(|s| s.try_ensure(task).cloned())
+        .context("Failed to ensure task")??;
 
     match args.format {
         OutputFormat::Table => {
@@ -432,6 +508,9 @@ fn execute_ensure(args: EnsureArgs, root: Option<&PathBuf>, use_colors: bool) ->
             if !ensured.blocked_by.is_empty() {
                 println!("  Blocked by: {}", ensured.blocked_by.join(", "));
             }
+            if !ensured.tags.is_empty() {
+                println!("  Tags: {}", ensured.tags.join(", "));
+            }
         }
         OutputFormat::Json => {
             println!("{}", serde_json::to_string(&ensured)?);
@@ -542,6 +621,15 @@ fn execute_ready(args: ReadyArgs, root: Option<&PathBuf>, use_colors: bool) -> R
 
     let ready = filter_tasks_for_ready(&store, &args, root);
 
+    if let Some(cycle) = store.find_cycle() {
+        let warning = format!("Warning: {cycle}; tasks on it can never become ready");
+        if use_colors {
+            eprintln!("{}{}{}", colors::YELLOW, warning, colors::RESET);
+        } else {
+            eprintln!("{}", warning);
+        }
+    }
+
     match args.format {
         OutputFormat::Table => {
             if ready.is_empty() {
@@ -618,7 +706,7 @@ fn execute_ready(args: ReadyArgs, root: Option<&PathBuf>, use_colors: bool) -> R
 
 fn execute_start(args: StartArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task_id = args.id;
     let started = store
@@ -643,7 +731,7 @@ fn execute_start(args: StartArgs, root: Option<&PathBuf>, use_colors: bool) -> R
 
 fn execute_close(args: CloseArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task_id = args.id;
     let title = store
@@ -671,7 +759,7 @@ fn execute_close(args: CloseArgs, root: Option<&PathBuf>, use_colors: bool) -> R
 
 fn execute_fail(args: FailArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task_id = args.id;
     let title = store
@@ -750,6 +838,9 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                 if !task.blocked_by.is_empty() {
                     println!("Blocked by:  {}", task.blocked_by.join(", "));
                 }
+                if !task.tags.is_empty() {
+                    println!("Tags:        {}", task.tags.join(", "));
+                }
                 println!("Created:     {}", task.created);
                 if let Some(started) = &task.started {
                     println!("Started:     {}", started);
@@ -771,6 +862,9 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                 if !task.blocked_by.is_empty() {
                     println!("Blocked by:  {}", task.blocked_by.join(", "));
                 }
+                if !task.tags.is_empty() {
+                    println!("Tags:        {}", task.tags.join(", "));
+                }
                 println!("Created:     {}", task.created);
                 if let Some(started) = &task.started {
                     println!("Started:     {}", started);
@@ -791,9 +885,115 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
     Ok(())
 }
 
+fn execute_graph(args: GraphArgs, root: Option<&PathBuf>) -> Result<()> {
+    let path = get_tasks_path(root);
+    let store = TaskStore::load(&path).context("Failed to load tasks")?;
+
+    let tasks: Vec<&Task> = store
+        .all()
+        .iter()
+        .filter(|t| args.all || !t.status.is_terminal())
+        .collect();
+    let cycle = store.find_cycle().map(|c| c.0).unwrap_or_default();
+    let graph = render_task_graph(&tasks, &cycle, args.format);
+
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, graph)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            eprintln!("Wrote task graph to {}", path.display());
+        }
+        None => print!("{}", graph),
+    }
+    Ok(())
+}
+
+/// Renders tasks as a graph with an edge from each blocker to the task it
+/// blocks. Tasks on `cycle` are highlighted; blockers outside `tasks` are
+/// left out.
+fn render_task_graph(tasks: &[&Task], cycle: &[String], format: DiagramFormat) -> String {
+    let shown = |id: &str| tasks.iter().any(|t| t.id == id);
+    let label = |task: &Task| format!("{} ({})", task.title, status_label(task.status));
+
+    match format {
+        DiagramFormat::Mermaid => {
+            // Sanitized Mermaid node IDs (task IDs contain dashes)
+            let node_id = |id: &str| -> String {
+                id.chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                    .collect()
+            };
+
+            let mut diagram = String::from("flowchart TD\n");
+            for task in tasks {
+                diagram.push_str(&format!(
+                    "    {}[\"{}<br/>{}\"]\n",
+                    node_id(&task.id),
+                    task.id,
+                    label(task).replace('"', "#quot;")
+                ));
+            }
+            for task in tasks {
+                for blocker in task.blocked_by.iter().filter(|b| shown(b)) {
+                    diagram.push_str(&format!(
+                        "    {} --> {}\n",
+                        node_id(blocker),
+                        node_id(&task.id)
+                    ));
+                }
+            }
+            let on_cycle: Vec<String> = tasks
+                .iter()
+                .filter(|t| cycle.contains(&t.id))
+                .map(|t| node_id(&t.id))
+                .collect();
+            if !on_cycle.is_empty() {
+                diagram.push_str("    classDef cycle stroke:#d33,stroke-width:2px\n");
+                diagram.push_str(&format!("    class {} cycle\n", on_cycle.join(",")));
+            }
+            diagram
+        }
+        DiagramFormat::Dot => {
+            let quote =
+                |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+
+            let mut diagram = String::from("digraph tasks {\n    rankdir=TB;\n");
+            for task in tasks {
+                let style = if cycle.contains(&task.id) {
+                    ", color=red"
+                } else {
+                    ""
+                };
+                diagram.push_str(&format!(
+                    "    {} [label={}{}];\n",
+                    quote(&task.id),
+                    quote(&format!("{}\\n{}", task.id, label(task))),
+                    style
+                ));
+            }
+            for task in tasks {
+                for blocker in task.blocked_by.iter().filter(|b| shown(b)) {
+                    diagram.push_str(&format!("    {} -> {};\n", quote(blocker), quote(&task.id)));
+                }
+            }
+            diagram.push_str("}\n");
+            diagram
+        }
+    }
+}
+
+fn status_label(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Open => "open",
+        TaskStatus::InProgress => "in_progress",
+        TaskStatus::Closed => "closed",
+        TaskStatus::Failed => "failed",
+    }
+}
+
 fn execute_reopen(args: ReopenArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
-    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let mut store = load_task_store_for_write(&path, root)?;
 
     let task_id = args.id;
     let reopened = store
@@ -833,6 +1033,33 @@ mod tests {
         TaskStore::load(&path).expect("reload task store")
     }
 
+    #[test]
+    fn test_add_follows_workspace_formatting_policy() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let root = temp_dir.path().to_path_buf();
+        std::fs::write(
+            root.join("ralph.yml"),
+            "core:\n  formatting:\n    trailing_newline: false\n",
+        )
+        .expect("write config");
+
+        for title in ["First", "Second"] {
+            let args = AddArgs {
+                title: title.to_string(),
+                priority: 3,
+                description: None,
+                blocked_by: None,
+                tags: Vec::new(),
+                format: OutputFormat::Quiet,
+            };
+            execute_add(args, Some(&root), false).expect("add task");
+        }
+
+        let content = std::fs::read_to_string(get_tasks_path(Some(&root))).expect("read tasks");
+        assert_eq!(content.lines().count(), 2);
+        assert!(!content.ends_with('\n'), "{content:?}");
+    }
+
     #[test]
     fn test_list_status_filter_accepts_in_progress() {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -848,6 +1075,7 @@ mod tests {
             days: None,
             limit: None,
             all: true,
+            tag: None,
             format: OutputFormat::Quiet,
         };
 
@@ -856,6 +1084,77 @@ mod tests {
         assert_eq!(filtered[0].status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_add_tags_filter_list_and_ready() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let root = temp_dir.path().to_path_buf();
+
+        for (title, tags) in [
+            ("API", vec!["backend", "urgent"]),
+            ("Button", vec!["frontend"]),
+        ] {
+            let args = AddArgs {
+                title: title.to_string(),
+                priority: 3,
+                description: None,
+                blocked_by: None,
+                tags: tags.into_iter().map(String::from).collect(),
+                format: OutputFormat::Quiet,
+            };
+            execute_add(args, Some(&root), false).expect("add task");
+        }
+        let store = TaskStore::load(&get_tasks_path(Some(&root))).expect("load tasks");
+
+        let list_args = ListArgs {
+            status: None,
+            days: None,
+            limit: None,
+            all: false,
+            tag: Some("urgent".to_string()),
+            format: OutputFormat::Quiet,
+        };
+        let listed = filter_tasks_for_list(&store, &list_args);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].title, "API");
+
+        let ready_args = ReadyArgs {
+            all: true,
+            tag: Some("frontend".to_string()),
+            format: OutputFormat::Quiet,
+        };
+        let ready = filter_tasks_for_ready(&store, &ready_args, Some(&root));
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].title, "Button");
+    }
+
+    #[test]
+    fn test_render_task_graph_highlights_cycle() {
+        let mut a = Task::new("Setup \"infra\"".to_string(), 1);
+        a.id = "task-a".to_string();
+        a.blocked_by.push("task-b".to_string());
+        let mut b = Task::new("Deploy".to_string(), 1);
+        b.id = "task-b".to_string();
+        b.blocked_by = vec!["task-a".to_string(), "task-gone".to_string()];
+        let cycle = vec![
+            "task-a".to_string(),
+            "task-b".to_string(),
+            "task-a".to_string(),
+        ];
+
+        let mermaid = render_task_graph(&[&a, &b], &cycle, DiagramFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("task_a[\"task-a<br/>Setup #quot;infra#quot; (open)\"]"));
+        assert!(mermaid.contains("    task_a --> task_b\n"));
+        assert!(mermaid.contains("    task_b --> task_a\n"));
+        assert!(!mermaid.contains("task_gone"), "{mermaid}");
+        assert!(mermaid.contains("class task_a,task_b cycle"));
+
+        let dot = render_task_graph(&[&a, &b], &[], DiagramFormat::Dot);
+        assert!(dot.starts_with("digraph tasks {"));
+        assert!(dot.contains("\"task-a\" -> \"task-b\";"));
+        assert!(!dot.contains("color=red"));
+    }
+
     #[test]
     fn test_ready_filters_by_loop_id_marker() {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -874,6 +1173,7 @@ mod tests {
 
         let args = ReadyArgs {
             all: false,
+            tag: None,
             format: OutputFormat::Quiet,
         };
 
@@ -0,0 +1,83 @@
+//! Integration tests for `ralph config migrate`.
+
+use anyhow::Result;
+use ralph_core::RalphConfig;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const V1_CONFIG: &str = "\
+# Project loop settings
+agent: claude
+max_iterations: 40
+completion_promise: ALL_DONE
+
+cli:
+  prompt_mode: arg
+";
+
+fn ralph_config_migrate(dir: &Path, extra: &[&str]) -> Result<Output> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["config", "migrate", "--color", "never", "--config"])
+        .arg(dir.join("ralph.yml"))
+        .args(extra)
+        .current_dir(dir)
+        .env("HOME", dir)
+        .output()?)
+}
+
+#[test]
+fn test_config_migrate_previews_diff_then_writes_v2() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    let config_path = temp_path.join("ralph.yml");
+    fs::write(&config_path, V1_CONFIG)?;
+
+    // Without --write: diff only, file untouched
+    let output = ralph_config_migrate(temp_path, &[])?;
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("-agent: claude"), "{stdout}");
+    assert!(stdout.contains("+  backend: claude"), "{stdout}");
+    assert!(stdout.contains("--write"), "{stdout}");
+    assert_eq!(fs::read_to_string(&config_path)?, V1_CONFIG);
+
+    // With --write: file rewritten and changes reported
+    let output = ralph_config_migrate(temp_path, &["--write"])?;
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("moved `agent` to `cli.backend`"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("moved `max_iterations` to `event_loop.max_iterations`"),
+        "{stdout}"
+    );
+
+    let migrated = fs::read_to_string(&config_path)?;
+    assert!(
+        migrated.starts_with("# Project loop settings\n"),
+        "{migrated}"
+    );
+
+    let mut expected = RalphConfig::parse_yaml(V1_CONFIG)?;
+    expected.normalize();
+    let config = RalphConfig::parse_yaml(&migrated)?;
+    assert!(config.agent.is_none());
+    assert!(config.max_iterations.is_none());
+    assert!(config.completion_promise.is_none());
+    assert_eq!(config.cli.backend, expected.cli.backend);
+    assert_eq!(config.cli.prompt_mode, "arg");
+    assert_eq!(config.event_loop.max_iterations, 40);
+    assert_eq!(config.event_loop.completion_promise, "ALL_DONE");
+
+    // Already-migrated file is a no-op
+    let output = ralph_config_migrate(temp_path, &["--write"])?;
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("nothing to migrate"));
+    assert_eq!(fs::read_to_string(&config_path)?, migrated);
+
+    Ok(())
+}
@@ -777,3 +777,145 @@ fn test_ralph_directory_created() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_quiet_events_routes_system_events_to_separate_file() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    create_test_config(temp_path)?;
+
+    let _output = Command::new(ralph_bin())
+        .arg("run")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .arg("--quiet-events")
+        .current_dir(temp_path)
+        .output()?;
+
+    let marker_content = fs::read_to_string(temp_path.join(".ralph/current-events"))?;
+    let events_rel = marker_content.trim();
+    let events_path = temp_path.join(events_rel);
+    let system_path = events_path.with_file_name(format!(
+        "system-{}",
+        events_path.file_name().unwrap().to_string_lossy()
+    ));
+
+    let main = fs::read_to_string(&events_path)?;
+    assert!(main.contains("\"task.start\""), "{main}");
+    assert!(main.contains("\"loop.terminate\""), "{main}");
+    assert!(!main.contains("\"iteration.summary\""), "{main}");
+
+    let system = fs::read_to_string(&system_path)?;
+    assert!(system.contains("\"iteration.summary\""), "{system}");
+
+    let events = |extra: &[&str]| -> Result<String> {
+        let output = Command::new(ralph_bin())
+            .args(["events", "--topic", "iteration.summary", "--format", "json"])
+            .args(extra)
+            .current_dir(temp_path)
+            .output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    };
+    assert!(events(&[])?.contains("No matching events"));
+    assert!(events(&["--system"])?.contains("\"iteration.summary\""));
+
+    Ok(())
+}
+
+// =============================================================================
+// Events Follow Tests
+// =============================================================================
+
+fn event_line(topic: &str, payload: &str) -> String {
+    format!(
+        r#"{{"ts":"2024-01-01T00:00:00Z","iteration":1,"hat":"ralph","topic":"{topic}","payload":"{payload}"}}"#
+    ) + "\n"
+}
+
+fn append(path: &std::path::Path, line: &str) -> Result<()> {
+    use std::io::Write;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+#[test]
+fn test_events_follow_streams_filtered_events_across_rotation() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    use std::sync::mpsc;
+
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    let ralph_dir = temp_path.join(".ralph");
+    fs::create_dir_all(&ralph_dir)?;
+
+    // Marker points at a file that doesn't exist yet
+    fs::write(
+        ralph_dir.join("current-events"),
+        ".ralph/events-first.jsonl",
+    )?;
+
+    let mut child = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow",
+            "--format",
+            "json",
+            "--topic",
+            "build.done",
+        ])
+        .current_dir(temp_path)
+        .env("RALPH_WORKSPACE_ROOT", temp_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    thread::sleep(Duration::from_millis(500));
+    append(
+        &ralph_dir.join("events-first.jsonl"),
+        &event_line("build.blocked", "ignored"),
+    )?;
+    append(
+        &ralph_dir.join("events-first.jsonl"),
+        &event_line("build.done", "first"),
+    )?;
+
+    let first = rx.recv_timeout(Duration::from_secs(5));
+
+    // Rotate to a new events file, as a fresh run would
+    fs::write(
+        ralph_dir.join("current-events"),
+        ".ralph/events-second.jsonl",
+    )?;
+    append(
+        &ralph_dir.join("events-second.jsonl"),
+        &event_line("build.done", "second"),
+    )?;
+
+    let second = rx.recv_timeout(Duration::from_secs(5));
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    let first: serde_json::Value = serde_json::from_str(&first?)?;
+    assert_eq!(first["payload"], "first");
+    let second: serde_json::Value = serde_json::from_str(&second?)?;
+    assert_eq!(second["payload"], "second");
+
+    Ok(())
+}
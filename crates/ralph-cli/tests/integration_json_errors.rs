@@ -0,0 +1,136 @@
+//! Integration tests for `--json-errors` / `RALPH_JSON_ERRORS=1`.
+//!
+//! Covers:
+//! - A config-load failure prints a `config` category JSON object on stderr
+//! - A held loop lock (parallel loops disabled) prints a `lock` category object
+//! - Without the flag, errors keep the human-readable anyhow format
+
+use anyhow::Result;
+use ralph_core::LoopLock;
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn parse_stderr_json(output: &Output) -> serde_json::Value {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr
+        .lines()
+        .rev()
+        .find(|line| line.trim_start().starts_with('{'))
+        .unwrap_or_else(|| panic!("no JSON object on stderr:\n{stderr}"));
+    serde_json::from_str(line).expect("stderr JSON should parse")
+}
+
+#[test]
+fn test_config_load_failure_emits_json_error() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::write(temp_path.join("ralph.yml"), "event_loop: [unclosed\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["clean", "--json-errors", "--config"])
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .env("HOME", temp_path)
+        .output()?;
+
+    assert!(!output.status.success());
+    let json = parse_stderr_json(&output);
+    assert_eq!(json["category"], "config");
+    assert_eq!(json["code"], "config_parse");
+    assert!(
+        json["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("Failed to parse YAML"))
+    );
+    assert!(json["context"].as_array().is_some_and(|c| !c.is_empty()));
+
+    Ok(())
+}
+
+#[test]
+fn test_json_errors_env_var_enables_json_output() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::write(temp_path.join("ralph.yml"), "event_loop: [unclosed\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["clean", "--config"])
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .env("HOME", temp_path)
+        .env("RALPH_JSON_ERRORS", "1")
+        .output()?;
+
+    assert!(!output.status.success());
+    assert_eq!(parse_stderr_json(&output)["category"], "config");
+
+    Ok(())
+}
+
+#[test]
+fn test_without_flag_errors_stay_human_readable() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::write(temp_path.join("ralph.yml"), "event_loop: [unclosed\n")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["clean", "--config"])
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .env("HOME", temp_path)
+        .env_remove("RALPH_JSON_ERRORS")
+        .output()?;
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Error: Failed to parse YAML"), "{stderr}");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_lock_error_emits_json_error() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::write(
+        temp_path.join("ralph.yml"),
+        r"
+cli:
+  backend: claude
+features:
+  parallel: false
+",
+    )?;
+
+    // Hold the primary loop lock so the spawned run sees it as taken.
+    let _guard = LoopLock::try_acquire(temp_path, "held by test")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args([
+            "run",
+            "--json-errors",
+            "--no-tui",
+            "--skip-preflight",
+            "-p",
+            "hello",
+            "--config",
+        ])
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .env("HOME", temp_path)
+        .output()?;
+
+    assert!(!output.status.success());
+    let json = parse_stderr_json(&output);
+    assert_eq!(json["category"], "lock");
+    assert_eq!(json["code"], "lock_held");
+    assert!(
+        json["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("Another loop is already running"))
+    );
+
+    Ok(())
+}
@@ -708,3 +708,99 @@ fn test_memory_list_color_never() -> Result<()> {
 
     Ok(())
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Dedup Command Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_memory_dedup_dry_run_then_apply() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    ralph_memory_ok(temp_path, &["init"]);
+    ralph_memory_ok(
+        temp_path,
+        &["add", "Use anyhow for errors", "-t", "pattern"],
+    );
+    ralph_memory_ok(
+        temp_path,
+        &["add", "use  ANYHOW for errors", "-t", "decision"],
+    );
+    ralph_memory_ok(
+        temp_path,
+        &["add", "Run clippy before pushing", "-t", "fix"],
+    );
+
+    // Dry run reports the merge but leaves the file alone
+    let memories_path = temp_path.join(".ralph/agent/memories.md");
+    let before = fs::read_to_string(&memories_path)?;
+    let stdout = ralph_memory_ok(temp_path, &["dedup", "--dry-run", "--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["removed"], 1);
+    assert_eq!(json["groups"].as_array().map(Vec::len), Some(1));
+    assert_eq!(fs::read_to_string(&memories_path)?, before);
+
+    let stdout = ralph_memory_ok(temp_path, &["dedup"]);
+    assert!(stdout.contains("Removed 1 duplicate"), "{stdout}");
+
+    let list = ralph_memory_ok(temp_path, &["list", "--format", "json"]);
+    let memories: Vec<serde_json::Value> = serde_json::from_str(&list)?;
+    assert_eq!(memories.len(), 2);
+
+    let stdout = ralph_memory_ok(temp_path, &["dedup"]);
+    assert!(stdout.contains("No duplicate memories found"), "{stdout}");
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_gc_flags_then_prunes_stale_memories() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::create_dir_all(temp_path.join("src"))?;
+    fs::write(temp_path.join("src/main.rs"), "fn main() {}\n")?;
+
+    ralph_memory_ok(temp_path, &["init"]);
+    ralph_memory_ok(
+        temp_path,
+        &["add", "Entry point is src/main.rs", "-t", "context"],
+    );
+    ralph_memory_ok(
+        temp_path,
+        &[
+            "add",
+            "Config parsing lives in `src/old_config.rs`",
+            "-t",
+            "pattern",
+        ],
+    );
+
+    // Report only: the file is untouched
+    let memories_path = temp_path.join(".ralph/agent/memories.md");
+    let before = fs::read_to_string(&memories_path)?;
+    let stdout = ralph_memory_ok(temp_path, &["gc", "--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(json["pruned"], false);
+    let stale = json["stale"].as_array().expect("stale array");
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0]["missing_paths"][0], "src/old_config.rs");
+    assert_eq!(fs::read_to_string(&memories_path)?, before);
+
+    let stdout = ralph_memory_ok(temp_path, &["gc", "--prune"]);
+    assert!(stdout.contains("Archived 1 stale"), "{stdout}");
+
+    let list = ralph_memory_ok(temp_path, &["list", "--format", "json"]);
+    let memories: Vec<serde_json::Value> = serde_json::from_str(&list)?;
+    assert_eq!(memories.len(), 1);
+    assert!(
+        fs::read_to_string(temp_path.join(".ralph/agent/memories-archive.md"))?
+            .contains("src/old_config.rs")
+    );
+
+    let stdout = ralph_memory_ok(temp_path, &["gc"]);
+    assert!(stdout.contains("No stale memories found"), "{stdout}");
+
+    Ok(())
+}
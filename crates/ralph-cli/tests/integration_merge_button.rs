@@ -473,6 +473,63 @@ fn test_merge_steering_provides_helpful_options() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_squash_needs_steering_when_branch_has_multiple_authors() -> Result<()> {
+    let temp_dir = setup_workspace()?;
+    let temp_path = temp_dir.path();
+
+    // Given: A single-author branch that squashes cleanly
+    let worktree_path = create_worktree_with_commits(temp_path, "test-loop-011", 2)?;
+    let decision = ralph_core::merge_needs_steering_with_strategy(
+        temp_path,
+        "test-loop-011",
+        ralph_core::MergeStrategy::Squash,
+    )?;
+    assert!(!decision.needs_input, "single author should squash cleanly");
+
+    // When: Another author commits to the branch
+    fs::write(worktree_path.join("extra.txt"), "extra")?;
+    Command::new("git")
+        .args(["add", "extra.txt"])
+        .current_dir(&worktree_path)
+        .output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Add extra"])
+        .env("GIT_AUTHOR_EMAIL", "other@example.com")
+        .current_dir(&worktree_path)
+        .output()?;
+
+    // Then: Squashing needs steering, but a regular merge does not
+    let decision = ralph_core::merge_needs_steering_with_strategy(
+        temp_path,
+        "test-loop-011",
+        ralph_core::MergeStrategy::Squash,
+    )?;
+    assert!(decision.needs_input);
+    assert!(decision.reason.contains("2 authors"), "{}", decision.reason);
+    assert!(
+        decision
+            .options
+            .iter()
+            .any(|o| o.label == "Merge instead (keep history)")
+    );
+
+    let decision = ralph_core::merge_needs_steering_with_strategy(
+        temp_path,
+        "test-loop-011",
+        ralph_core::MergeStrategy::Merge,
+    )?;
+    assert!(!decision.needs_input);
+
+    // And: The squash message comes from the branch's latest commit
+    assert_eq!(
+        ralph_core::squash_commit_message(temp_path, "test-loop-011"),
+        "merge(ralph): Add extra (loop test-loop-011)"
+    );
+
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // 4. ralph loops list UX Improvements
 // ─────────────────────────────────────────────────────────────────────────────
@@ -364,3 +364,57 @@ This scratchpad contains UNIQUE_CONTENT_MARKER for testing.
 
     Ok(())
 }
+
+#[test]
+fn test_continue_with_missing_prompt_file_ends_with_config_error() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let config_content = r#"
+event_loop:
+  prompt_file: "PROMPT.md"
+  completion_promise: "LOOP_COMPLETE"
+  max_iterations: 3
+  max_runtime_seconds: 30
+
+cli:
+  backend: "custom"
+  command: "true"
+
+core:
+  scratchpad: ".ralph/agent/scratchpad.md"
+"#;
+    fs::write(temp_path.join("ralph.yml"), config_content)?;
+
+    // The previous run left a scratchpad, but PROMPT.md has since been deleted
+    let agent_dir = temp_path.join(".ralph/agent");
+    fs::create_dir_all(&agent_dir)?;
+    fs::write(agent_dir.join("scratchpad.md"), "- [ ] Unfinished\n")?;
+    let ralph_dir = temp_path.join(".ralph");
+    let events_path = ".ralph/events-config-error.jsonl";
+    fs::write(ralph_dir.join("current-events"), events_path)?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("run")
+        .arg("--continue")
+        .arg("--no-tui")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .output()?;
+
+    assert_eq!(output.status.code(), Some(1));
+
+    let events = fs::read_to_string(temp_path.join(events_path))?;
+    let terminate = events
+        .lines()
+        .find(|line| line.contains("loop.terminate"))
+        .expect("loop.terminate event");
+    assert!(terminate.contains("config_error"), "{terminate}");
+    assert!(
+        terminate.contains("Prompt file 'PROMPT.md' not found"),
+        "{terminate}"
+    );
+
+    Ok(())
+}
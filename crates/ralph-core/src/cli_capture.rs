@@ -3,11 +3,36 @@
 //! `CliCapture` wraps a `Write` implementation to capture all bytes written
 //! to stdout/stderr while forwarding them to the underlying writer. This
 //! enables transparent recording without changing calling code.
+//!
+//! A [`CaptureMode`] selects what is recorded: raw bytes for faithful
+//! terminal replay, or ANSI-stripped lines for compact, diff-friendly text.
 
+use crate::session_player::{ReplayMode, strip_ansi};
 use ralph_proto::{FrameCapture, TerminalWrite, UxEvent};
 use std::io::{self, Write};
 use std::time::Instant;
 
+/// What a [`CliCapture`] records for each write.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaptureMode {
+    /// Every write as raw bytes, ANSI sequences included.
+    #[default]
+    Raw,
+    /// Complete lines with ANSI sequences stripped and `\r\n` normalized
+    /// to `\n`, one event per line.
+    Lines,
+}
+
+impl From<ReplayMode> for CaptureMode {
+    /// Terminal replay needs the raw bytes; text replay only needs lines.
+    fn from(mode: ReplayMode) -> Self {
+        match mode {
+            ReplayMode::Terminal => Self::Raw,
+            ReplayMode::Text => Self::Lines,
+        }
+    }
+}
+
 /// A writer that captures all output while forwarding to an inner writer.
 ///
 /// This wrapper implements `std::io::Write` and records every write operation
@@ -46,6 +71,12 @@ pub struct CliCapture<W> {
 
     /// Whether this captures stdout (true) or stderr (false).
     is_stdout: bool,
+
+    /// What is recorded for each write.
+    mode: CaptureMode,
+
+    /// Bytes of an unfinished line, in [`CaptureMode::Lines`].
+    partial_line: Vec<u8>,
 }
 
 impl<W> CliCapture<W> {
@@ -56,12 +87,7 @@ impl<W> CliCapture<W> {
     /// * `inner` - The writer to forward output to
     /// * `is_stdout` - `true` if capturing stdout, `false` for stderr
     pub fn new(inner: W, is_stdout: bool) -> Self {
-        Self {
-            inner,
-            captures: Vec::new(),
-            start_time: Instant::now(),
-            is_stdout,
-        }
+        Self::with_start_time(inner, is_stdout, Instant::now())
     }
 
     /// Creates a capture wrapper with a custom start time.
@@ -74,7 +100,38 @@ impl<W> CliCapture<W> {
             captures: Vec::new(),
             start_time,
             is_stdout,
+            mode: CaptureMode::default(),
+            partial_line: Vec::new(),
+        }
+    }
+
+    /// Sets what is recorded for each write (default: [`CaptureMode::Raw`]).
+    pub fn with_mode(mut self, mode: CaptureMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the capture mode.
+    pub fn mode(&self) -> CaptureMode {
+        self.mode
+    }
+
+    /// Records one line (or unfinished line) as ANSI-stripped text.
+    fn push_line(&mut self, line: &[u8]) {
+        let (body, newline) = match line.strip_suffix(b"\n") {
+            Some(body) => (body.strip_suffix(b"\r").unwrap_or(body), true),
+            None => (line, false),
+        };
+        let mut text = strip_ansi(body);
+        if newline {
+            text.push(b'\n');
         }
+        self.captures
+            .push(UxEvent::TerminalWrite(TerminalWrite::new(
+                &text,
+                self.is_stdout,
+                self.offset_ms(),
+            )));
     }
 
     /// Returns the current offset in milliseconds since capture started.
@@ -106,13 +163,25 @@ impl<W: Write> Write for CliCapture<W> {
         let n = self.inner.write(buf)?;
 
         // Only capture the bytes that were actually written
-        if n > 0 {
-            self.captures
-                .push(UxEvent::TerminalWrite(TerminalWrite::new(
-                    &buf[..n],
-                    self.is_stdout,
-                    self.offset_ms(),
-                )));
+        if n == 0 {
+            return Ok(n);
+        }
+        match self.mode {
+            CaptureMode::Raw => {
+                self.captures
+                    .push(UxEvent::TerminalWrite(TerminalWrite::new(
+                        &buf[..n],
+                        self.is_stdout,
+                        self.offset_ms(),
+                    )));
+            }
+            CaptureMode::Lines => {
+                self.partial_line.extend_from_slice(&buf[..n]);
+                while let Some(end) = self.partial_line.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = self.partial_line.drain(..=end).collect();
+                    self.push_line(&line);
+                }
+            }
         }
 
         Ok(n)
@@ -124,12 +193,20 @@ impl<W: Write> Write for CliCapture<W> {
 }
 
 impl<W: Send + Sync> FrameCapture for CliCapture<W> {
+    /// Returns the captured events in this capture's [`CaptureMode`].
+    ///
+    /// In [`CaptureMode::Lines`], an unfinished trailing line is returned
+    /// too, so nothing written so far is held back.
     fn take_captures(&mut self) -> Vec<UxEvent> {
+        if !self.partial_line.is_empty() {
+            let line = std::mem::take(&mut self.partial_line);
+            self.push_line(&line);
+        }
         std::mem::take(&mut self.captures)
     }
 
     fn has_captures(&self) -> bool {
-        !self.captures.is_empty()
+        !self.captures.is_empty() || !self.partial_line.is_empty()
     }
 }
 
@@ -148,10 +225,15 @@ pub struct CliCapturePair<Stdout, Stderr> {
 impl<Stdout, Stderr> CliCapturePair<Stdout, Stderr> {
     /// Creates a new capture pair with a shared start time.
     pub fn new(stdout: Stdout, stderr: Stderr) -> Self {
+        Self::with_mode(stdout, stderr, CaptureMode::default())
+    }
+
+    /// Creates a capture pair with a shared start time, recording in `mode`.
+    pub fn with_mode(stdout: Stdout, stderr: Stderr, mode: CaptureMode) -> Self {
         let start_time = Instant::now();
         Self {
-            stdout: CliCapture::with_start_time(stdout, true, start_time),
-            stderr: CliCapture::with_start_time(stderr, false, start_time),
+            stdout: CliCapture::with_start_time(stdout, true, start_time).with_mode(mode),
+            stderr: CliCapture::with_start_time(stderr, false, start_time).with_mode(mode),
         }
     }
 }
@@ -280,4 +362,48 @@ mod tests {
             assert_eq!(decoded, ansi_text);
         }
     }
+
+    fn decoded(events: &[UxEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                UxEvent::TerminalWrite(tw) => {
+                    String::from_utf8(tw.decode_bytes().unwrap()).unwrap()
+                }
+                _ => panic!("Expected TerminalWrite event"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lines_mode_strips_ansi_and_buffers_partial_lines() {
+        let mut output = Vec::new();
+        let mut capture = CliCapture::new(&mut output, true).with_mode(CaptureMode::Lines);
+
+        capture.write_all(b"\x1b[32mGre").unwrap();
+        capture.write_all(b"en\x1b[0m\r\nsecond\nthi").unwrap();
+        assert!(capture.has_captures());
+
+        let events = capture.take_captures();
+        assert_eq!(decoded(&events), vec!["Green\n", "second\n", "thi"]);
+        assert!(!capture.has_captures());
+
+        // The terminal still received the raw bytes
+        drop(capture);
+        assert!(output.starts_with(b"\x1b[32mGre"));
+    }
+
+    #[test]
+    fn test_capture_pair_with_mode_follows_replay_mode() {
+        assert_eq!(CaptureMode::from(ReplayMode::Terminal), CaptureMode::Raw);
+        assert_eq!(CaptureMode::from(ReplayMode::Text), CaptureMode::Lines);
+
+        let mut pair = CliCapturePair::with_mode(Vec::new(), Vec::new(), ReplayMode::Text.into());
+        writeln!(pair.stdout, "\x1b[1mout\x1b[0m").unwrap();
+        writeln!(pair.stderr, "err").unwrap();
+
+        let mut lines = decoded(&pair.take_all_captures());
+        lines.sort();
+        assert_eq!(lines, vec!["err\n", "out\n"]);
+    }
 }
@@ -3,11 +3,13 @@
 //! This module supports both v1.x flat configuration format and v2.0 nested format.
 //! Users can switch from Python v1.x to Rust v2.0 with zero config changes.
 
+use crate::memory::{Memory, MemoryType};
+use crate::task_definition::Verification;
 use ralph_proto::Topic;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Scratchpad configuration with enabled flag and path.
 ///
@@ -272,6 +274,10 @@ pub struct RalphConfig {
     #[serde(default)]
     pub features: FeaturesConfig,
 
+    /// How completed worktree loops are merged into main.
+    #[serde(default)]
+    pub merge: MergeConfig,
+
     /// RObot (Ralph-Orchestrator bot) configuration for Telegram-based interaction.
     #[serde(default, rename = "RObot")]
     pub robot: RobotConfig,
@@ -320,6 +326,7 @@ impl Default for RalphConfig {
             skills: SkillsConfig::default(),
             // Features
             features: FeaturesConfig::default(),
+            merge: MergeConfig::default(),
             // RObot (Ralph-Orchestrator bot)
             robot: RobotConfig::default(),
         }
@@ -364,6 +371,58 @@ pub struct AdapterSettings {
     /// Tool permissions (DROPPED: CLI tool manages its own permissions).
     #[serde(default)]
     pub tool_permissions: Option<Vec<String>>,
+
+    /// Markers preceding the per-iteration cost in this backend's output
+    /// (e.g. `"Total cost:"`). Used when the adapter does not report cost
+    /// natively. `None` uses the built-in markers (Claude's stream-json
+    /// `"total_cost_usd":` only); an empty list disables it.
+    #[serde(default)]
+    pub cost_markers: Option<Vec<String>>,
+
+    /// Environment variables set on this backend's process (e.g. API keys,
+    /// base URLs). Values may reference the parent environment as `${VAR}`.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+impl AdapterSettings {
+    /// Returns `env` with `${VAR}` references expanded from the parent
+    /// environment, sorted by name. Unset variables expand to an empty string.
+    pub fn resolved_env(&self) -> Vec<(String, String)> {
+        self.resolved_env_with(|name| std::env::var(name).ok())
+    }
+
+    fn resolved_env_with(&self, lookup: impl Fn(&str) -> Option<String>) -> Vec<(String, String)> {
+        let mut env: Vec<(String, String)> = self
+            .env
+            .iter()
+            .map(|(key, value)| (key.clone(), expand_env_refs(value, &lookup)))
+            .collect();
+        env.sort();
+        env
+    }
+}
+
+/// Expands `${VAR}` references in `value` using `lookup`.
+///
+/// An unterminated `${` is kept literally.
+fn expand_env_refs(value: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 2..start + 2 + len];
+        out.push_str(&rest[..start]);
+        match lookup(name) {
+            Some(resolved) => out.push_str(&resolved),
+            None => warn!(variable = %name, "Adapter env references an unset variable"),
+        }
+        rest = &rest[start + 3 + len..];
+    }
+    out.push_str(rest);
+    out
 }
 
 fn default_timeout() -> u64 {
@@ -376,6 +435,8 @@ impl Default for AdapterSettings {
             timeout: default_timeout(),
             enabled: true,
             tool_permissions: None,
+            cost_markers: None,
+            env: HashMap::new(),
         }
     }
 }
@@ -500,6 +561,51 @@ impl RalphConfig {
         }
     }
 
+    /// Resolves a hat's full instructions: the `extends` parent's resolved
+    /// instructions, then the contents of `instructions_file`, then the hat's
+    /// own `instructions`, separated by blank lines.
+    pub fn resolve_hat_instructions(&self, hat_id: &str) -> Result<String, ConfigError> {
+        let error = |message: String| ConfigError::HatInstructions {
+            hat: hat_id.to_string(),
+            message,
+        };
+
+        // Walk up the `extends` chain, child first
+        let mut chain: Vec<(&str, &HatConfig)> = Vec::new();
+        let mut current = hat_id;
+        loop {
+            if chain.iter().any(|(id, _)| *id == current) {
+                let mut cycle: Vec<&str> = chain.iter().map(|(id, _)| *id).collect();
+                cycle.push(current);
+                return Err(error(format!("'extends' cycle: {}", cycle.join(" -> "))));
+            }
+            let Some(hat) = self.hats.get(current) else {
+                return Err(error(format!("'extends' names unknown hat '{current}'")));
+            };
+            chain.push((current, hat));
+            match hat.extends.as_deref() {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        let mut instructions = String::new();
+        for (id, hat) in chain.into_iter().rev() {
+            if let Some(file) = &hat.instructions_file {
+                let path = self.core.resolve_path(file);
+                let fragment = std::fs::read_to_string(&path).map_err(|e| {
+                    error(format!(
+                        "cannot read instructions_file '{}' of hat '{id}': {e}",
+                        path.display()
+                    ))
+                })?;
+                append_instructions(&mut instructions, &fragment);
+            }
+            append_instructions(&mut instructions, &hat.instructions);
+        }
+        Ok(instructions)
+    }
+
     /// Validates the configuration and returns warnings.
     ///
     /// This method checks for:
@@ -531,6 +637,16 @@ impl RalphConfig {
         if self.event_loop.completion_promise.trim().is_empty() {
             return Err(ConfigError::InvalidCompletionPromise);
         }
+        for (list, guardrails) in [
+            ("core.guardrails", &self.core.guardrails),
+            ("core.extra_guardrails", &self.core.extra_guardrails),
+        ] {
+            if let Some(index) = guardrails.iter().position(|g| g.trim().is_empty()) {
+                return Err(ConfigError::EmptyGuardrail {
+                    field: format!("{list}[{index}]"),
+                });
+            }
+        }
 
         // Check custom backend has a command
         if self.cli.backend == "custom" && self.cli.command.as_ref().is_none_or(String::is_empty) {
@@ -576,6 +692,48 @@ impl RalphConfig {
             });
         }
 
+        for memory_type in &self.memories.filter.types {
+            if memory_type.parse::<MemoryType>().is_err() {
+                warnings.push(ConfigWarning::InvalidValue {
+                    field: "memories.filter.types".to_string(),
+                    message: format!(
+                        "Unknown memory type '{memory_type}' is ignored. \
+                         Valid types: pattern, decision, fix, context"
+                    ),
+                });
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.features.drift_detection.threshold) {
+            warnings.push(ConfigWarning::InvalidValue {
+                field: "features.drift_detection.threshold".to_string(),
+                message: "Value must be between 0 and 1".to_string(),
+            });
+        }
+
+        // Zero thresholds silently disable the thrashing safeguards
+        for (field, value) in [
+            (
+                "event_loop.max_task_blocks",
+                self.event_loop.max_task_blocks,
+            ),
+            (
+                "event_loop.max_malformed_events",
+                self.event_loop.max_malformed_events,
+            ),
+            (
+                "event_loop.max_abandoned_redispatches",
+                self.event_loop.max_abandoned_redispatches,
+            ),
+        ] {
+            if value == 0 {
+                warnings.push(ConfigWarning::InvalidValue {
+                    field: field.to_string(),
+                    message: "Value 0 disables this safeguard entirely".to_string(),
+                });
+            }
+        }
+
         // Check adapter tool_permissions (dropped field)
         if self.adapters.claude.tool_permissions.is_some()
             || self.adapters.gemini.tool_permissions.is_some()
@@ -607,6 +765,10 @@ impl RalphConfig {
             }
         }
 
+        for hat_id in self.hats.keys() {
+            self.resolve_hat_instructions(hat_id)?;
+        }
+
         // Check wave config validity
         for (hat_id, hat_config) in &self.hats {
             if hat_config.concurrency == 0 {
@@ -829,13 +991,28 @@ impl RalphConfig {
     /// Gets the adapter settings for a specific backend.
     #[allow(clippy::match_same_arms)] // Explicit match arms for each backend improves readability
     pub fn adapter_settings(&self, backend: &str) -> &AdapterSettings {
+        self.named_adapter_settings(backend)
+            .unwrap_or(&self.adapters.claude) // Default fallback
+    }
+
+    /// Returns the resolved `env` for a backend's process.
+    ///
+    /// Unlike [`Self::adapter_settings`], backends without their own
+    /// `adapters` entry get no variables rather than Claude's.
+    pub fn adapter_env(&self, backend: &str) -> Vec<(String, String)> {
+        self.named_adapter_settings(backend)
+            .map(AdapterSettings::resolved_env)
+            .unwrap_or_default()
+    }
+
+    fn named_adapter_settings(&self, backend: &str) -> Option<&AdapterSettings> {
         match backend {
-            "claude" => &self.adapters.claude,
-            "gemini" => &self.adapters.gemini,
-            "kiro" => &self.adapters.kiro,
-            "codex" => &self.adapters.codex,
-            "amp" => &self.adapters.amp,
-            _ => &self.adapters.claude, // Default fallback
+            "claude" => Some(&self.adapters.claude),
+            "gemini" => Some(&self.adapters.gemini),
+            "kiro" => Some(&self.adapters.kiro),
+            "codex" => Some(&self.adapters.codex),
+            "amp" => Some(&self.adapters.amp),
+            _ => None,
         }
     }
 }
@@ -895,6 +1072,21 @@ pub struct EventLoopConfig {
     #[serde(default = "default_max_failures")]
     pub max_consecutive_failures: u32,
 
+    /// Abandon a task after this many `build.blocked` events for it.
+    /// Set to 0 to disable task abandonment (not recommended).
+    #[serde(default = "default_thrashing_threshold")]
+    pub max_task_blocks: u32,
+
+    /// Stop after this many consecutive malformed JSONL event lines.
+    /// Set to 0 to disable the validation-failure safeguard (not recommended).
+    #[serde(default = "default_thrashing_threshold")]
+    pub max_malformed_events: u32,
+
+    /// Stop after the planner re-dispatches abandoned tasks this many times.
+    /// Set to 0 to disable the loop-thrashing safeguard (not recommended).
+    #[serde(default = "default_thrashing_threshold")]
+    pub max_abandoned_redispatches: u32,
+
     /// Delay in seconds before starting the next iteration.
     /// Skipped when the next iteration is triggered by a human event.
     #[serde(default)]
@@ -935,6 +1127,14 @@ pub struct EventLoopConfig {
     #[serde(default)]
     pub required_events: Vec<String>,
 
+    /// When true and memories are disabled (scratchpad mode), a completion
+    /// event is rejected while the scratchpad still has pending `- [ ]` tasks,
+    /// and a `task.resume` listing them is injected. Defaults to false: pending
+    /// scratchpad tasks only log a warning. With memories enabled, open runtime
+    /// tasks always block completion regardless of this flag.
+    #[serde(default)]
+    pub completion_requires_tasks_done: bool,
+
     /// Event topic that triggers graceful early termination WITHOUT chain validation.
     /// Use this for human rejection, timeout escalation, or other abort paths.
     /// Defaults to "" (disabled). Set to "loop.cancel" to enable.
@@ -953,6 +1153,72 @@ pub struct EventLoopConfig {
     /// (e.g. Sonnet 4.x `[1m]` users: `1_000_000`).
     #[serde(default)]
     pub context_window_tokens: Option<u64>,
+
+    /// Independent check run in the workspace when a `build.done` arrives.
+    ///
+    /// When set, the command's result decides whether `build.done` is
+    /// accepted, regardless of the backpressure evidence in the payload. A
+    /// failing command synthesizes `build.blocked` carrying its output.
+    #[serde(default)]
+    pub build_verification: Option<Verification>,
+
+    /// Where system events (`event.*`, `iteration.summary`, `<hat>.exhausted`)
+    /// are logged. `loop.terminate` always stays in the events file because
+    /// `--continue` uses it as the run boundary.
+    #[serde(default)]
+    pub system_events: SystemEventsMode,
+
+    /// Maximum characters of each event payload included in the prompt
+    /// context. Longer payloads are truncated with an ellipsis; the events
+    /// file keeps the full payload. Top-level prompts (`task.start`,
+    /// `task.resume`) are never truncated. `None` means no limit.
+    #[serde(default)]
+    pub max_event_payload_in_prompt: Option<usize>,
+
+    /// Maximum characters of the whole assembled prompt. Over budget, the
+    /// context files list is dropped first, then pending events, oldest
+    /// first. The objective, the newest pending event and the active hat's
+    /// instructions are always kept. `None` means no limit.
+    #[serde(default)]
+    pub prompt_budget_chars: Option<usize>,
+
+    /// Maximum characters of an event payload. `ralph emit` truncates longer
+    /// string payloads (and rejects longer JSON ones), and payloads shown in
+    /// prompt context are capped defensively. Events are routing signals;
+    /// details belong in the scratchpad.
+    #[serde(default = "default_max_event_payload_chars")]
+    pub max_event_payload_chars: usize,
+
+    /// What `ralph emit` does with a topic no hat triggers on or publishes
+    /// (and that isn't a system topic). `ralph emit --strict` forces `error`.
+    #[serde(default)]
+    pub emit_topic_check: TopicCheckMode,
+}
+
+/// How `ralph emit` treats topics the config doesn't know about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TopicCheckMode {
+    /// Emit without checking.
+    #[default]
+    Off,
+    /// Emit, but print a warning with the closest known topic.
+    Warn,
+    /// Refuse to emit.
+    Error,
+}
+
+/// Routing for system events in the events log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SystemEventsMode {
+    /// Logged in the events file alongside agent events.
+    #[default]
+    Inline,
+    /// Logged to a sibling `system-events*.jsonl` file.
+    Separate,
+    /// Not logged at all.
+    Suppress,
 }
 
 /// Resolves the context-window ceiling in tokens for this run.
@@ -995,10 +1261,18 @@ fn default_max_runtime() -> u64 {
     14400 // 4 hours
 }
 
+fn default_max_event_payload_chars() -> usize {
+    16_384
+}
+
 fn default_max_failures() -> u32 {
     5
 }
 
+fn default_thrashing_threshold() -> u32 {
+    3
+}
+
 impl Default for EventLoopConfig {
     fn default() -> Self {
         Self {
@@ -1009,15 +1283,25 @@ impl Default for EventLoopConfig {
             max_runtime_seconds: default_max_runtime(),
             max_cost_usd: None,
             max_consecutive_failures: default_max_failures(),
+            max_task_blocks: default_thrashing_threshold(),
+            max_malformed_events: default_thrashing_threshold(),
+            max_abandoned_redispatches: default_thrashing_threshold(),
             cooldown_delay_seconds: 0,
             starting_hat: None,
             starting_event: None,
             mutation_score_warn_threshold: None,
             persistent: false,
             required_events: Vec::new(),
+            completion_requires_tasks_done: false,
             cancellation_promise: String::new(),
             enforce_hat_scope: false,
             context_window_tokens: None,
+            build_verification: None,
+            system_events: SystemEventsMode::Inline,
+            max_event_payload_in_prompt: None,
+            prompt_budget_chars: None,
+            max_event_payload_chars: default_max_event_payload_chars(),
+            emit_topic_check: TopicCheckMode::Off,
         }
     }
 }
@@ -1038,10 +1322,15 @@ pub struct CoreConfig {
 
     /// Guardrails injected into every prompt (core behaviors).
     ///
-    /// Per spec: These are always present regardless of hat.
+    /// Per spec: These are always present regardless of hat. Setting this
+    /// list replaces the defaults; use `extra_guardrails` to add to them.
     #[serde(default = "default_guardrails")]
     pub guardrails: Vec<String>,
 
+    /// Guardrails appended after `guardrails` (e.g. "Never touch migrations").
+    #[serde(default)]
+    pub extra_guardrails: Vec<String>,
+
     /// Root directory for workspace-relative paths (.ralph/, specs, etc.).
     ///
     /// All relative paths (scratchpad, specs_dir, memories) are resolved relative
@@ -1050,6 +1339,106 @@ pub struct CoreConfig {
     /// This is especially important for E2E tests that run in isolated workspaces.
     #[serde(skip)]
     pub workspace_root: std::path::PathBuf,
+
+    /// Formatting policy for Ralph-owned generated files (tasks, summary).
+    #[serde(default)]
+    pub formatting: FormattingConfig,
+}
+
+/// Formatting policy applied when Ralph writes its own files
+/// (`tasks.jsonl`, `summary.md`, and the handoff files). The scratchpad is the
+/// agent's file; Ralph only appends guidance to it and leaves its layout alone.
+///
+/// Keeps those files diff-friendly and identical across runs and parallel
+/// loops. Defaults reproduce the historical output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormattingConfig {
+    /// End non-empty generated files with a newline.
+    #[serde(default = "default_true")]
+    pub trailing_newline: bool,
+
+    /// Bullet used for markdown list items in the loop summary and handoffs.
+    #[serde(default)]
+    pub list_marker: ListMarker,
+
+    /// Order of the loop summary sections. Sections not listed are omitted.
+    #[serde(default = "default_summary_sections")]
+    pub summary_sections: Vec<SummarySection>,
+}
+
+impl Default for FormattingConfig {
+    fn default() -> Self {
+        Self {
+            trailing_newline: true,
+            list_marker: ListMarker::default(),
+            summary_sections: default_summary_sections(),
+        }
+    }
+}
+
+impl FormattingConfig {
+    /// Applies the trailing-newline policy to generated file content.
+    pub fn finish(&self, mut content: String) -> String {
+        if self.trailing_newline {
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+        } else {
+            content.truncate(content.trim_end_matches('\n').len());
+        }
+        content
+    }
+}
+
+/// Markdown list bullet style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListMarker {
+    #[default]
+    #[serde(rename = "-")]
+    Dash,
+    #[serde(rename = "*")]
+    Asterisk,
+}
+
+impl ListMarker {
+    /// Returns the marker character as written in markdown.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ListMarker::Dash => "-",
+            ListMarker::Asterisk => "*",
+        }
+    }
+}
+
+/// A section of the generated loop summary (`summary.md`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummarySection {
+    Tasks,
+    Events,
+    FinalCommit,
+    Landing,
+}
+
+fn default_summary_sections() -> Vec<SummarySection> {
+    vec![
+        SummarySection::Tasks,
+        SummarySection::Events,
+        SummarySection::FinalCommit,
+        SummarySection::Landing,
+    ]
+}
+
+/// Appends an instruction block, separated from the previous one by a blank line.
+fn append_instructions(instructions: &mut String, block: &str) {
+    if block.trim().is_empty() {
+        return;
+    }
+    if !instructions.is_empty() {
+        instructions.truncate(instructions.trim_end().len());
+        instructions.push_str("\n\n");
+    }
+    instructions.push_str(block);
 }
 
 fn default_specs_dir() -> String {
@@ -1073,16 +1462,23 @@ impl Default for CoreConfig {
             scratchpad: ScratchpadConfig::default(),
             specs_dir: default_specs_dir(),
             guardrails: default_guardrails(),
+            extra_guardrails: Vec::new(),
             workspace_root: std::env::var("RALPH_WORKSPACE_ROOT")
                 .map(std::path::PathBuf::from)
                 .unwrap_or_else(|_| {
                     std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
                 }),
+            formatting: FormattingConfig::default(),
         }
     }
 }
 
 impl CoreConfig {
+    /// Returns the guardrails in prompt order: `guardrails`, then `extra_guardrails`.
+    pub fn all_guardrails(&self) -> impl Iterator<Item = &String> {
+        self.guardrails.iter().chain(&self.extra_guardrails)
+    }
+
     /// Sets the workspace root for resolving relative paths.
     ///
     /// This is used by E2E tests to point to their isolated test workspace.
@@ -1141,6 +1537,17 @@ pub struct CliConfig {
     /// If None, defaults to "-p" for arg mode.
     #[serde(default)]
     pub prompt_flag: Option<String>,
+
+    /// Retries for a backend invocation that fails transiently (spawn/IO
+    /// errors, rate limits, dropped connections) before the iteration counts
+    /// as failed. Off (0) by default: a retry re-runs the whole iteration,
+    /// side effects included.
+    #[serde(default)]
+    pub max_retries: u32,
+
+    /// Delay before the first retry in milliseconds, doubled for each further retry.
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
 }
 
 fn default_backend() -> String {
@@ -1159,6 +1566,10 @@ fn default_idle_timeout() -> u32 {
     30 // 30 seconds per spec
 }
 
+fn default_retry_backoff_ms() -> u64 {
+    1000
+}
+
 impl Default for CliConfig {
     fn default() -> Self {
         Self {
@@ -1169,6 +1580,8 @@ impl Default for CliConfig {
             idle_timeout_secs: default_idle_timeout(),
             args: Vec::new(),
             prompt_flag: None,
+            max_retries: 0,
+            retry_backoff_ms: default_retry_backoff_ms(),
         }
     }
 }
@@ -1206,6 +1619,18 @@ impl std::fmt::Display for InjectMode {
     }
 }
 
+/// How memories are ordered before the injection budget is applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryRanking {
+    /// Keep file order; the budget truncates whatever comes last.
+    #[default]
+    Off,
+    /// Rank by term overlap with the objective and pending events, so the
+    /// budget is spent on the most relevant memories.
+    Relevance,
+}
+
 /// Memories configuration.
 ///
 /// Controls the persistent learning system that allows Ralph to accumulate
@@ -1220,6 +1645,7 @@ impl std::fmt::Display for InjectMode {
 ///   enabled: true
 ///   inject: auto
 ///   budget: 2000
+///   ranking: relevance
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoriesConfig {
@@ -1242,6 +1668,17 @@ pub struct MemoriesConfig {
     /// Filter configuration for memory injection.
     #[serde(default)]
     pub filter: MemoriesFilter,
+
+    /// How memories are ordered before the budget is applied.
+    #[serde(default)]
+    pub ranking: MemoryRanking,
+
+    /// Whether to inject the `ralph-tools-memories` skill (default: true).
+    ///
+    /// Disabling it saves prompt budget but assumes the agent already knows
+    /// the `ralph tools memory` commands. Memory data is injected either way.
+    #[serde(default = "default_true")]
+    pub inject_skill: bool,
 }
 
 impl Default for MemoriesConfig {
@@ -1251,16 +1688,23 @@ impl Default for MemoriesConfig {
             inject: InjectMode::Auto,
             budget: 0,
             filter: MemoriesFilter::default(),
+            ranking: MemoryRanking::Off,
+            inject_skill: true,
         }
     }
 }
 
 /// Filter configuration for memory injection.
 ///
-/// Controls which memories are included when priming context.
+/// Controls which memories are included when auto-injecting context.
+/// All three fields are honored; within `types` and `tags` any match
+/// passes, and the fields combine with AND.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MemoriesFilter {
     /// Filter by memory types (empty = all types).
+    ///
+    /// Accepts `pattern`, `decision`, `fix`, `context`. Unknown names are
+    /// ignored (and reported by `validate()`).
     #[serde(default)]
     pub types: Vec<String>,
 
@@ -1273,6 +1717,32 @@ pub struct MemoriesFilter {
     pub recent: u32,
 }
 
+impl MemoriesFilter {
+    /// Returns true if `memory` passes every configured filter field.
+    ///
+    /// `today` anchors the `recent` window; memories with an unparseable
+    /// creation date are kept.
+    pub fn matches(&self, memory: &Memory, today: chrono::NaiveDate) -> bool {
+        let types: Vec<MemoryType> = self.types.iter().filter_map(|t| t.parse().ok()).collect();
+        if !types.is_empty() && !types.contains(&memory.memory_type) {
+            return false;
+        }
+
+        if !self.tags.is_empty() && !memory.has_any_tag(&self.tags) {
+            return false;
+        }
+
+        if self.recent > 0
+            && let Ok(created) = chrono::NaiveDate::parse_from_str(&memory.created, "%Y-%m-%d")
+            && (today - created).num_days() > i64::from(self.recent)
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
 /// Tasks configuration.
 ///
 /// Controls the runtime task tracking system that allows Ralph to manage
@@ -1658,6 +2128,44 @@ pub struct PreflightConfig {
     pub skip: Vec<String>,
 }
 
+/// Objective-drift detection configuration.
+///
+/// When enabled, each iteration's event activity is compared against the
+/// objective's keywords. If overlap stays below `threshold` for `window`
+/// consecutive iterations, an `objective.drift` event is published.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftDetectionConfig {
+    /// Whether drift detection runs (opt-in).
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minimum keyword overlap ratio (0.0-1.0) for activity to count as on-topic.
+    #[serde(default = "default_drift_threshold")]
+    pub threshold: f64,
+
+    /// Consecutive off-topic iterations before `objective.drift` fires.
+    #[serde(default = "default_drift_window")]
+    pub window: u32,
+}
+
+fn default_drift_threshold() -> f64 {
+    0.1
+}
+
+fn default_drift_window() -> u32 {
+    3
+}
+
+impl Default for DriftDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: default_drift_threshold(),
+            window: default_drift_window(),
+        }
+    }
+}
+
 /// Feature flags for optional Ralph capabilities.
 ///
 /// Example configuration:
@@ -1672,6 +2180,14 @@ pub struct PreflightConfig {
 ///   loop_naming:
 ///     format: human-readable  # or "timestamp" for legacy format
 ///     max_length: 50
+///     theme: default  # animals, colors, nato or celestial
+///   worktree:
+///     sync_extra: [".env"]  # Gitignored paths (globs) to copy into new worktrees
+///     base_ref: main        # Start worktrees from this ref instead of HEAD
+///   drift_detection:
+///     enabled: false      # Opt-in: warn when activity strays from the objective
+///     threshold: 0.1      # Minimum keyword overlap to count as on-topic
+///     window: 3           # Off-topic iterations before objective.drift fires
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeaturesConfig {
@@ -1682,6 +2198,14 @@ pub struct FeaturesConfig {
     #[serde(default = "default_true")]
     pub parallel: bool,
 
+    /// Maximum number of loops running at once in this workspace, counting
+    /// the primary loop and live worktree loops in the loop registry.
+    ///
+    /// When reached, `ralph run` refuses to spawn another worktree loop
+    /// (or waits for a slot with `--wait-for-slot`). 0 disables the limit.
+    #[serde(default = "default_max_parallel_loops")]
+    pub max_parallel_loops: usize,
+
     /// Whether to automatically merge worktree branches on completion.
     ///
     /// When false (default), completed worktree loops queue for manual merge.
@@ -1698,22 +2222,94 @@ pub struct FeaturesConfig {
     #[serde(default)]
     pub loop_naming: crate::loop_name::LoopNamingConfig,
 
+    /// Worktree configuration for parallel loops.
+    #[serde(default)]
+    pub worktree: crate::worktree::WorktreeConfig,
+
     /// Preflight check configuration.
     #[serde(default)]
     pub preflight: PreflightConfig,
+
+    /// Objective-drift detection configuration.
+    #[serde(default)]
+    pub drift_detection: DriftDetectionConfig,
 }
 
 impl Default for FeaturesConfig {
     fn default() -> Self {
         Self {
-            parallel: true,    // Parallel loops enabled by default
+            parallel: true, // Parallel loops enabled by default
+            max_parallel_loops: default_max_parallel_loops(),
             auto_merge: false, // Auto-merge disabled by default for safety
             loop_naming: crate::loop_name::LoopNamingConfig::default(),
+            worktree: crate::worktree::WorktreeConfig::default(),
             preflight: PreflightConfig::default(),
+            drift_detection: DriftDetectionConfig::default(),
+        }
+    }
+}
+
+fn default_max_parallel_loops() -> usize {
+    4
+}
+
+/// Merge configuration for completed worktree loops.
+///
+/// Example configuration:
+/// ```yaml
+/// merge:
+///   strategy: squash  # merge (default), rebase or squash
+///   pre_merge_command: "cargo test"  # must pass in the worktree before merging
+///   pre_merge_timeout_secs: 1800     # kill the command and skip the merge after this
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeConfig {
+    /// How the merge loop integrates a worktree branch into main.
+    ///
+    /// `merge` creates a merge commit, `rebase` replays the branch onto main
+    /// and fast-forwards, and `squash` collapses the branch into a single
+    /// commit. The strategy is recorded when the loop is queued.
+    #[serde(default)]
+    pub strategy: crate::merge_queue::MergeStrategy,
+
+    /// Command run in the loop's worktree before it is auto-merged.
+    ///
+    /// Runs with `bash -c`; a non-zero exit skips the merge and marks the
+    /// loop `verification-failed` with the command's output recorded in the
+    /// merge queue. Unset (default) merges without verification.
+    #[serde(default)]
+    pub pre_merge_command: Option<String>,
+
+    /// Seconds `pre_merge_command` may run before it is killed and the loop
+    /// is marked `verification-failed` (default: 1800).
+    #[serde(default = "default_pre_merge_timeout_secs")]
+    pub pre_merge_timeout_secs: u64,
+}
+
+fn default_pre_merge_timeout_secs() -> u64 {
+    1800
+}
+
+impl Default for MergeConfig {
+    fn default() -> Self {
+        Self {
+            strategy: crate::merge_queue::MergeStrategy::default(),
+            pre_merge_command: None,
+            pre_merge_timeout_secs: default_pre_merge_timeout_secs(),
         }
     }
 }
 
+impl MergeConfig {
+    /// Returns the verification to run before merging, if one is configured.
+    pub fn pre_merge_verification(&self) -> Option<Verification> {
+        self.pre_merge_command.as_ref().map(|command| Verification {
+            timeout_seconds: Some(self.pre_merge_timeout_secs),
+            ..Verification::new(command)
+        })
+    }
+}
+
 fn default_prefix_key() -> String {
     "ctrl-a".to_string()
 }
@@ -1882,16 +2478,42 @@ pub struct HatConfig {
     #[serde(default)]
     pub extra_instructions: Vec<String>,
 
+    /// File whose contents go before `instructions`, for a fragment shared by
+    /// several hats (e.g. a common review rubric). Relative paths resolve
+    /// against the directory of the config file that declares the hat, or
+    /// `core.workspace_root` for remote and built-in sources.
+    #[serde(default)]
+    pub instructions_file: Option<String>,
+
+    /// Id of another hat whose resolved instructions are prepended to this
+    /// hat's. Chains are followed; cycles are a config error.
+    #[serde(default)]
+    pub extends: Option<String>,
+
     /// Backend to use for this hat (inherits from cli.backend if not specified).
     #[serde(default)]
     pub backend: Option<HatBackend>,
 
     /// Custom args to append to the backend CLI when this hat is active.
     ///
-    /// Accepts both `backend_args:` and shorthand `args:`.
-    #[serde(default, alias = "args")]
+    /// Accepts `backend_args:`, shorthand `args:`, and `extra_args:`.
+    #[serde(default, alias = "args", alias = "extra_args")]
     pub backend_args: Option<Vec<String>>,
 
+    /// Sampling temperature requested for this hat.
+    ///
+    /// Exported to the backend as `RALPH_TEMPERATURE`; no built-in agent CLI
+    /// exposes a temperature flag, so only custom backends honor it.
+    #[serde(default)]
+    pub temperature: Option<f32>,
+
+    /// Output token limit requested for this hat.
+    ///
+    /// Exported as `RALPH_MAX_TOKENS`, and as `CLAUDE_CODE_MAX_OUTPUT_TOKENS`
+    /// for the Claude backend.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+
     /// Default event to publish if hat forgets to write an event.
     #[serde(default)]
     pub default_publishes: Option<String>,
@@ -1922,6 +2544,14 @@ pub struct HatConfig {
     #[serde(default)]
     pub timeout: Option<u32>,
 
+    /// Maximum bytes of this hat's output to ingest per iteration.
+    ///
+    /// Oversized output is elided in the middle (keeping the head and tail,
+    /// so a trailing completion promise survives) before it is processed,
+    /// logged, or checked for completion. Unlimited when not set.
+    #[serde(default)]
+    pub max_output_bytes: Option<u64>,
+
     /// Maximum concurrent wave instances for this hat.
     ///
     /// When > 1, the loop runner spawns multiple backend instances in parallel
@@ -2150,6 +2780,11 @@ pub enum ConfigError {
     #[error("Invalid completion_promise: must be non-empty and non-whitespace")]
     InvalidCompletionPromise,
 
+    #[error(
+        "Empty guardrail at '{field}': guardrails must be non-empty text.\nFix: remove the entry or write the rule out."
+    )]
+    EmptyGuardrail { field: String },
+
     #[error(
         "Custom backend requires a command.\nFix: set 'cli.command' in your config (or run `ralph init --backend custom`).\nSee: docs/reference/troubleshooting.md#custom-backend-command"
     )]
@@ -2190,6 +2825,11 @@ pub enum ConfigError {
     )]
     DeprecatedProjectKey,
 
+    #[error(
+        "Hat '{hat}' instructions could not be resolved: {message}\nFix: check the hat's 'extends' and 'instructions_file' fields."
+    )]
+    HatInstructions { hat: String, message: String },
+
     #[error(
         "Hat '{hat}' has invalid concurrency: {value}. Must be >= 1.\nFix: set 'concurrency' to 1 or higher."
     )]
@@ -2205,6 +2845,53 @@ pub enum ConfigError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_memories_filter_matches_types_tags_and_recent() {
+        use crate::memory::{Memory, MemoryType};
+
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let memory = Memory {
+            id: "mem-1-aaaa".to_string(),
+            memory_type: MemoryType::Fix,
+            content: "content".to_string(),
+            tags: vec!["ci".to_string()],
+            created: "2025-03-01".to_string(),
+        };
+
+        assert!(MemoriesFilter::default().matches(&memory, today));
+
+        let by_type = MemoriesFilter {
+            types: vec!["pattern".to_string()],
+            ..Default::default()
+        };
+        assert!(!by_type.matches(&memory, today));
+
+        let by_tag = MemoriesFilter {
+            tags: vec!["CI".to_string(), "docs".to_string()],
+            ..Default::default()
+        };
+        assert!(by_tag.matches(&memory, today));
+
+        let recent = MemoriesFilter {
+            recent: 7,
+            ..Default::default()
+        };
+        assert!(!recent.matches(&memory, today));
+    }
+
+    #[test]
+    fn test_unknown_memory_filter_type_warns() {
+        let mut config = RalphConfig::default();
+        config.memories.filter.types = vec!["pattern".to_string(), "architecture".to_string()];
+
+        let warnings = config.validate().unwrap();
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            ConfigWarning::InvalidValue { field, message }
+                if field == "memories.filter.types" && message.contains("architecture")
+        )));
+    }
+
     #[test]
     fn test_default_config() {
         let config = RalphConfig::default();
@@ -2419,6 +3106,31 @@ adapters:
             .any(|w| matches!(w, ConfigWarning::DroppedField { field, .. } if field == "adapters.*.tool_permissions")));
     }
 
+    #[test]
+    fn test_thrashing_thresholds_default_and_override() {
+        let config = RalphConfig::default();
+        assert_eq!(config.event_loop.max_task_blocks, 3);
+        assert_eq!(config.event_loop.max_malformed_events, 3);
+        assert_eq!(config.event_loop.max_abandoned_redispatches, 3);
+
+        let yaml = r"
+event_loop:
+  max_task_blocks: 5
+  max_malformed_events: 2
+  max_abandoned_redispatches: 0
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.event_loop.max_task_blocks, 5);
+        assert_eq!(config.event_loop.max_malformed_events, 2);
+
+        let warnings = config.validate().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            ConfigWarning::InvalidValue { field, .. } if field == "event_loop.max_abandoned_redispatches"
+        )));
+    }
+
     #[test]
     fn test_suppress_warnings() {
         let yaml = r"
@@ -2455,6 +3167,34 @@ adapters:
         assert!(!gemini.enabled);
     }
 
+    #[test]
+    fn test_adapter_env_expands_parent_variables() {
+        let yaml = r#"
+adapters:
+  codex:
+    env:
+      OPENAI_API_KEY: "${TEST_KEY}"
+      OPENAI_BASE_URL: "https://${TEST_HOST}/v1"
+      LITERAL: "${unterminated"
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let env = config
+            .adapters
+            .codex
+            .resolved_env_with(|name| (name == "TEST_KEY").then(|| "sk-test".to_string()));
+
+        assert_eq!(
+            env,
+            vec![
+                ("LITERAL".to_string(), "${unterminated".to_string()),
+                ("OPENAI_API_KEY".to_string(), "sk-test".to_string()),
+                ("OPENAI_BASE_URL".to_string(), "https:///v1".to_string()),
+            ]
+        );
+        assert!(config.adapter_env("claude").is_empty());
+        assert!(config.adapter_env("opencode").is_empty());
+    }
+
     #[test]
     fn test_unknown_fields_ignored() {
         // Unknown fields should be silently ignored (forward compatibility)
@@ -2506,6 +3246,27 @@ hats:
         );
     }
 
+    #[test]
+    fn test_hat_sampling_and_extra_args() {
+        let yaml = r#"
+hats:
+  reviewer:
+    name: "Reviewer"
+    triggers: ["review.request"]
+    temperature: 0.2
+    max_tokens: 4096
+    extra_args: ["--model", "opus"]
+"#;
+        let config = RalphConfig::parse_yaml(yaml).unwrap();
+        let hat = config.hats.get("reviewer").unwrap();
+        assert_eq!(hat.temperature, Some(0.2));
+        assert_eq!(hat.max_tokens, Some(4096));
+        assert_eq!(
+            hat.backend_args.as_ref().unwrap(),
+            &vec!["--model".to_string(), "opus".to_string()]
+        );
+    }
+
     #[test]
     fn test_project_key_rejected() {
         let yaml = r#"
@@ -2639,6 +3400,26 @@ hats:
         );
     }
 
+    #[test]
+    fn test_unresolvable_hat_instructions_rejected() {
+        let yaml = r#"
+hats:
+  my_hat:
+    name: "My Hat"
+    description: "Extends a hat that does not exist"
+    triggers: ["build.task"]
+    extends: "missing_hat"
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::HatInstructions { hat, message }
+                if hat == "my_hat" && message.contains("unknown hat 'missing_hat'")),
+            "Expected HatInstructions error, got: {:?}",
+            err
+        );
+    }
+
     #[test]
     fn test_empty_description_rejected() {
         // Empty description should also be rejected
@@ -2709,6 +3490,37 @@ core:
         assert_eq!(config.core.guardrails[1], "Custom rule two");
     }
 
+    #[test]
+    fn test_core_config_extra_guardrails_append_to_defaults() {
+        let yaml = r#"
+core:
+  extra_guardrails:
+    - "Never touch migrations"
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let all: Vec<&String> = config.core.all_guardrails().collect();
+        assert_eq!(all.len(), 7);
+        assert!(all[0].contains("Fresh context"));
+        assert_eq!(all[6], "Never touch migrations");
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_guardrail() {
+        let mut config = RalphConfig::default();
+        config.core.extra_guardrails = vec!["Keep it".to_string(), "  ".to_string()];
+        let err = config.validate().unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::EmptyGuardrail { field } if field == "core.extra_guardrails[1]")
+        );
+
+        config.core.extra_guardrails.clear();
+        config.core.guardrails = vec![String::new()];
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::EmptyGuardrail { .. })
+        ));
+    }
+
     #[test]
     fn test_prompt_and_prompt_file_mutually_exclusive() {
         // Both prompt and prompt_file specified in config should error
@@ -0,0 +1,459 @@
+//! Rewrites v1 flat configuration files into the canonical v2 nested form.
+//!
+//! `RalphConfig::normalize()` maps v1 fields at load time, but the file on
+//! disk keeps its v1 shape. This module performs the same mapping on the YAML
+//! text so `ralph config migrate` can update the file itself.
+//!
+//! The rewrite is line-based to keep comments and layout intact: each v1
+//! entry (with any comment lines directly above it) moves under its v2
+//! section. When the file's shape defeats that (for example a flow-style
+//! `cli: { ... }` mapping), the migration falls back to re-serializing the
+//! YAML, which drops comments. Either way, the result is checked to load to
+//! the same configuration as the original.
+
+use serde_yaml::{Mapping, Value};
+
+use crate::config::{ConfigError, RalphConfig};
+
+/// V1 flat fields and their v2 `(section, key)` destinations, in the order
+/// `RalphConfig::normalize()` applies them.
+const V1_FIELDS: &[(&str, &str, &str)] = &[
+    ("agent", "cli", "backend"),
+    ("prompt_file", "event_loop", "prompt_file"),
+    ("completion_promise", "event_loop", "completion_promise"),
+    ("max_iterations", "event_loop", "max_iterations"),
+    ("max_runtime", "event_loop", "max_runtime_seconds"),
+    ("max_cost", "event_loop", "max_cost_usd"),
+];
+
+/// Result of migrating a configuration file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigMigration {
+    /// The migrated YAML text.
+    pub migrated: String,
+    /// Human-readable description of each change, in application order.
+    pub changes: Vec<String>,
+    /// Whether comments and layout were preserved (false after a fallback
+    /// re-serialization).
+    pub preserved_formatting: bool,
+}
+
+impl ConfigMigration {
+    /// Returns true when the file had no v1 fields to migrate.
+    pub fn is_noop(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Migrates v1 flat fields in `content` to their v2 nested locations.
+///
+/// V1 values win over existing nested values, matching `normalize()`.
+/// Content without v1 fields is returned unchanged.
+pub fn migrate_v1_config(content: &str) -> Result<ConfigMigration, ConfigError> {
+    let original = RalphConfig::parse_yaml(content)?;
+    let value: Value = serde_yaml::from_str(content)?;
+
+    let present: Vec<(&str, &str, &str)> = V1_FIELDS
+        .iter()
+        .copied()
+        .filter(|(field, _, _)| {
+            value
+                .get(*field)
+                .is_some_and(|v| !v.is_null() && !v.is_mapping() && !v.is_sequence())
+        })
+        .collect();
+
+    if present.is_empty() {
+        return Ok(ConfigMigration {
+            migrated: content.to_string(),
+            changes: Vec::new(),
+            preserved_formatting: true,
+        });
+    }
+
+    let changes = present
+        .iter()
+        .map(|(field, section, key)| {
+            let overridden = value.get(*section).and_then(|s| s.get(*key)).is_some();
+            if overridden {
+                format!("moved `{field}` to `{section}.{key}` (replacing the existing value)")
+            } else {
+                format!("moved `{field}` to `{section}.{key}`")
+            }
+        })
+        .collect();
+
+    let expected = canonical_json(original)?;
+    if let Some(migrated) = rewrite_lines(content, &present)
+        && RalphConfig::parse_yaml(&migrated)
+            .ok()
+            .and_then(|config| canonical_json(config).ok())
+            .is_some_and(|json| json == expected)
+    {
+        return Ok(ConfigMigration {
+            migrated,
+            changes,
+            preserved_formatting: true,
+        });
+    }
+
+    let migrated = rewrite_value(value, &present)?;
+    Ok(ConfigMigration {
+        migrated,
+        changes,
+        preserved_formatting: false,
+    })
+}
+
+/// Normalizes a config and strips v1 fields, for equivalence checks.
+fn canonical_json(mut config: RalphConfig) -> Result<serde_json::Value, ConfigError> {
+    config.normalize();
+    config.agent = None;
+    config.prompt_file = None;
+    config.completion_promise = None;
+    config.max_iterations = None;
+    config.max_runtime = None;
+    config.max_cost = None;
+    serde_json::to_value(&config).map_err(|e| ConfigError::Io(std::io::Error::other(e)))
+}
+
+/// A top-level YAML entry: its key (if any) and its lines, including comment
+/// lines directly above it.
+struct Entry {
+    key: Option<String>,
+    /// Number of leading comment lines in `lines`.
+    leading: usize,
+    lines: Vec<String>,
+}
+
+/// Splits a document into top-level entries. Blank lines, detached comments,
+/// and document markers become keyless entries.
+///
+/// Comment lines directly above a key travel with it, except at the start of
+/// the file where they usually describe the whole document.
+fn split_entries(content: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut pending_comments: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with([' ', '\t']) || line.trim().is_empty() {
+            entries.extend(pending_comments.drain(..).map(keyless));
+            match entries.last_mut() {
+                Some(last) if last.key.is_some() => last.lines.push(line.to_string()),
+                _ => entries.push(keyless(line.to_string())),
+            }
+            continue;
+        }
+
+        if line.starts_with('#') {
+            pending_comments.push(line.to_string());
+            continue;
+        }
+
+        match top_level_key(line) {
+            Some(key) => {
+                if entries.is_empty() {
+                    entries.extend(pending_comments.drain(..).map(keyless));
+                }
+                let leading = pending_comments.len();
+                let mut lines = std::mem::take(&mut pending_comments);
+                lines.push(line.to_string());
+                entries.push(Entry {
+                    key: Some(key),
+                    leading,
+                    lines,
+                });
+            }
+            None => {
+                entries.extend(pending_comments.drain(..).map(keyless));
+                entries.push(keyless(line.to_string()));
+            }
+        }
+    }
+    entries.extend(pending_comments.into_iter().map(keyless));
+
+    // Trailing blank lines belong outside the entry they follow.
+    let mut result = Vec::with_capacity(entries.len());
+    for mut entry in entries {
+        let mut trailing = Vec::new();
+        while entry.key.is_some()
+            && entry.lines.len() > entry.leading + 1
+            && entry.lines.last().is_some_and(|l| l.trim().is_empty())
+        {
+            trailing.extend(entry.lines.pop());
+        }
+        result.push(entry);
+        result.extend(trailing.into_iter().rev().map(keyless));
+    }
+    result
+}
+
+fn keyless(line: String) -> Entry {
+    Entry {
+        key: None,
+        leading: 0,
+        lines: vec![line],
+    }
+}
+
+/// Returns the key of an unindented `key: ...` line.
+fn top_level_key(line: &str) -> Option<String> {
+    let (key, _) = line.split_once(':')?;
+    let key = key.trim_end();
+    (!key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-'))
+    .then(|| key.to_string())
+}
+
+/// Returns the text after `key:` on a mapping line, without any comment.
+fn inline_value(line: &str) -> &str {
+    let rest = line.split_once(':').map_or("", |(_, rest)| rest);
+    rest.split(" #").next().unwrap_or("").trim()
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Rewrites v1 entries line by line. Returns `None` when the document's
+/// shape is not something the line-based rewrite handles.
+fn rewrite_lines(content: &str, present: &[(&str, &str, &str)]) -> Option<String> {
+    let mut entries = split_entries(content);
+
+    // Lines to insert into each section, keyed by section name.
+    let mut moved: Vec<(&str, &str, Vec<String>)> = Vec::new();
+    // Where a section without an existing entry should be created.
+    let mut new_section_at: Vec<(&str, usize)> = Vec::new();
+
+    for (field, section, key) in present {
+        let position = entries
+            .iter()
+            .position(|e| e.key.as_deref() == Some(*field))?;
+        let entry = &entries[position];
+        let mut lines = Vec::with_capacity(entry.lines.len());
+        for (index, line) in entry.lines.iter().enumerate() {
+            if index == entry.leading {
+                let rest = line.split_once(':').map_or("", |(_, rest)| rest);
+                lines.push(format!("{key}:{rest}"));
+            } else {
+                lines.push(line.clone());
+            }
+        }
+        moved.push((section, key, lines));
+
+        let section_exists = entries.iter().any(|e| e.key.as_deref() == Some(*section));
+        if !section_exists && !new_section_at.iter().any(|(s, _)| s == section) {
+            new_section_at.push((section, position));
+        }
+    }
+
+    // Build the output, dropping v1 entries and creating missing sections.
+    let mut output: Vec<Entry> = Vec::with_capacity(entries.len());
+    for (position, entry) in entries.drain(..).enumerate() {
+        if let Some((section, _)) = new_section_at.iter().find(|(_, at)| *at == position) {
+            output.push(Entry {
+                key: Some((*section).to_string()),
+                leading: 0,
+                lines: vec![format!("{section}:")],
+            });
+        }
+        let is_v1 = entry
+            .key
+            .as_deref()
+            .is_some_and(|k| present.iter().any(|(field, _, _)| *field == k));
+        if !is_v1 {
+            output.push(entry);
+        }
+    }
+
+    for (section, key, lines) in moved {
+        let entry = output
+            .iter_mut()
+            .find(|e| e.key.as_deref() == Some(section))?;
+        insert_into_section(entry, key, lines)?;
+    }
+
+    // Removed entries can leave stacked blank lines behind; collapse them.
+    let mut lines: Vec<String> = Vec::new();
+    for line in output.into_iter().flat_map(|e| e.lines) {
+        let blank = line.trim().is_empty();
+        if blank && lines.last().is_none_or(|l: &String| l.trim().is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    let mut text = lines.join("\n");
+    if content.ends_with('\n') {
+        text.push('\n');
+    }
+    Some(text)
+}
+
+/// Inserts (or replaces) `key` in a block-style section entry.
+fn insert_into_section(entry: &mut Entry, key: &str, lines: Vec<String>) -> Option<()> {
+    let header = entry.leading;
+    // Flow mappings and scalars are left to the fallback.
+    if !inline_value(&entry.lines[header]).is_empty() {
+        return None;
+    }
+
+    let child_indent = entry.lines[header + 1..]
+        .iter()
+        .find(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map_or(2, |l| indent_of(l));
+    let pad = " ".repeat(child_indent);
+    let indented: Vec<String> = lines
+        .into_iter()
+        .map(|l| {
+            if l.trim().is_empty() {
+                l
+            } else {
+                format!("{pad}{l}")
+            }
+        })
+        .collect();
+
+    let existing = entry.lines[header + 1..].iter().position(|l| {
+        indent_of(l) == child_indent
+            && l.trim_start()
+                .strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+    });
+
+    match existing {
+        Some(offset) => {
+            let start = header + 1 + offset;
+            let mut end = start + 1;
+            while end < entry.lines.len()
+                && (entry.lines[end].trim().is_empty()
+                    || indent_of(&entry.lines[end]) > child_indent)
+            {
+                end += 1;
+            }
+            while end > start + 1 && entry.lines[end - 1].trim().is_empty() {
+                end -= 1;
+            }
+            entry.lines.splice(start..end, indented);
+        }
+        None => entry.lines.extend(indented),
+    }
+    Some(())
+}
+
+/// Fallback: moves v1 keys in the parsed YAML and re-serializes it.
+fn rewrite_value(mut value: Value, present: &[(&str, &str, &str)]) -> Result<String, ConfigError> {
+    let Some(root) = value.as_mapping_mut() else {
+        return Ok(serde_yaml::to_string(&value)?);
+    };
+
+    for (field, section, key) in present {
+        let Some(v) = root.remove(*field) else {
+            continue;
+        };
+        let section_value = root
+            .entry(Value::String((*section).to_string()))
+            .or_insert_with(|| Value::Mapping(Mapping::new()));
+        if !section_value.is_mapping() {
+            *section_value = Value::Mapping(Mapping::new());
+        }
+        if let Some(section_map) = section_value.as_mapping_mut() {
+            section_map.insert(Value::String((*key).to_string()), v);
+        }
+    }
+
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_FIXTURE: &str = "\
+# Legacy ralph config
+agent: claude
+max_iterations: 50 # keep it short
+
+# Stop when this appears
+completion_promise: DONE
+max_runtime: 3600
+max_cost: 5.0
+
+cli:
+  prompt_mode: arg
+
+event_loop:
+  max_iterations: 10
+  cooldown_delay_seconds: 3
+";
+
+    #[test]
+    fn migrates_v1_fixture_to_equivalent_v2() {
+        let migration = migrate_v1_config(V1_FIXTURE).unwrap();
+        assert!(migration.preserved_formatting);
+        assert_eq!(migration.changes.len(), 5);
+        assert!(
+            migration.changes[2].contains("replacing the existing value"),
+            "{:?}",
+            migration.changes
+        );
+
+        assert_eq!(
+            migration.migrated,
+            "\
+# Legacy ralph config
+
+cli:
+  prompt_mode: arg
+  backend: claude
+
+event_loop:
+  max_iterations: 50 # keep it short
+  cooldown_delay_seconds: 3
+  # Stop when this appears
+  completion_promise: DONE
+  max_runtime_seconds: 3600
+  max_cost_usd: 5.0
+"
+        );
+
+        let mut before = RalphConfig::parse_yaml(V1_FIXTURE).unwrap();
+        before.normalize();
+        let after = RalphConfig::parse_yaml(&migration.migrated).unwrap();
+        assert!(after.agent.is_none());
+        assert_eq!(after.cli.backend, before.cli.backend);
+        assert_eq!(after.event_loop.max_iterations, 50);
+        assert_eq!(after.event_loop.completion_promise, "DONE");
+        assert_eq!(after.event_loop.max_runtime_seconds, 3600);
+        assert_eq!(after.event_loop.max_cost_usd, Some(5.0));
+    }
+
+    #[test]
+    fn creates_missing_sections_where_v1_fields_were() {
+        let migration = migrate_v1_config("agent: gemini\nverbose: true\n").unwrap();
+        assert_eq!(
+            migration.migrated,
+            "cli:\n  backend: gemini\nverbose: true\n"
+        );
+    }
+
+    #[test]
+    fn v2_config_is_unchanged() {
+        let content = "cli:\n  backend: claude\n# trailing comment\n";
+        let migration = migrate_v1_config(content).unwrap();
+        assert!(migration.is_noop());
+        assert_eq!(migration.migrated, content);
+    }
+
+    #[test]
+    fn flow_style_section_falls_back_to_reserialization() {
+        let migration = migrate_v1_config("agent: codex\ncli: { prompt_mode: stdin }\n").unwrap();
+        assert!(!migration.preserved_formatting);
+
+        let config = RalphConfig::parse_yaml(&migration.migrated).unwrap();
+        assert!(config.agent.is_none());
+        assert_eq!(config.cli.backend, "codex");
+        assert_eq!(config.cli.prompt_mode, "stdin");
+    }
+}
@@ -0,0 +1,127 @@
+//! Line coverage extraction from lcov and Cobertura reports.
+//!
+//! Used by benchmark verification (`Verification::coverage_report`). A report
+//! that is missing or cannot be parsed yields `None` rather than an error, so a
+//! broken coverage step never aborts a benchmark run.
+
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Matches the `line-rate` attribute of the root `<coverage>` element.
+static COBERTURA_LINE_RATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<coverage\b[^>]*\bline-rate="([0-9.eE+-]+)""#).unwrap());
+
+/// Reads the report at `path` and returns its line coverage as a percentage.
+///
+/// The format is detected from the content: Cobertura XML has a `<coverage>`
+/// root element, anything else is read as lcov tracefile data.
+pub(crate) fn read_coverage_report(path: &Path) -> Option<f64> {
+    let content = std::fs::read_to_string(path)
+        .inspect_err(|e| tracing::debug!("Cannot read coverage report {}: {e}", path.display()))
+        .ok()?;
+    let coverage = if content.contains("<coverage") {
+        parse_cobertura(&content)
+    } else {
+        parse_lcov(&content)
+    };
+    if coverage.is_none() {
+        tracing::debug!("No coverage found in report {}", path.display());
+    }
+    coverage
+}
+
+/// Line coverage of an lcov tracefile, summed over all source files.
+///
+/// Uses the `LF`/`LH` totals, falling back to counting `DA` records for
+/// tracefiles without them. `None` when no lines were instrumented.
+pub(crate) fn parse_lcov(content: &str) -> Option<f64> {
+    let (mut found, mut hit) = (0u64, 0u64);
+    let (mut da_found, mut da_hit) = (0u64, 0u64);
+    for line in content.lines().map(str::trim) {
+        if let Some(count) = line.strip_prefix("LF:") {
+            found += count.parse::<u64>().ok()?;
+        } else if let Some(count) = line.strip_prefix("LH:") {
+            hit += count.parse::<u64>().ok()?;
+        } else if let Some(record) = line.strip_prefix("DA:") {
+            // DA:<line>,<hits>[,<checksum>]
+            let hits = record.split(',').nth(1)?.parse::<u64>().ok()?;
+            da_found += 1;
+            da_hit += u64::from(hits > 0);
+        }
+    }
+    if found == 0 {
+        (found, hit) = (da_found, da_hit);
+    }
+    (found > 0 && hit <= found).then(|| hit as f64 / found as f64 * 100.0)
+}
+
+/// Line coverage of a Cobertura report, from the root `line-rate` (0.0-1.0).
+pub(crate) fn parse_cobertura(content: &str) -> Option<f64> {
+    let rate: f64 = COBERTURA_LINE_RATE.captures(content)?[1].parse().ok()?;
+    (0.0..=1.0).contains(&rate).then_some(rate * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LCOV: &str = "\
+TN:
+SF:src/lib.rs
+DA:1,4
+DA:2,0
+LF:4
+LH:3
+end_of_record
+SF:src/main.rs
+LF:4
+LH:2
+end_of_record
+";
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("coverage should parse");
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn lcov_sums_line_totals_across_files() {
+        assert_close(parse_lcov(LCOV), 62.5);
+    }
+
+    #[test]
+    fn lcov_without_totals_counts_da_records() {
+        assert_close(
+            parse_lcov("SF:a.rs\nDA:1,1\nDA:2,0\nDA:3,7\nend_of_record\n"),
+            200.0 / 3.0,
+        );
+    }
+
+    #[test]
+    fn lcov_without_instrumented_lines_or_with_garbage_is_none() {
+        assert_eq!(parse_lcov("TN:\nend_of_record\n"), None);
+        assert_eq!(parse_lcov("LF:ten\nLH:3\n"), None);
+        assert_eq!(parse_lcov("LF:2\nLH:3\n"), None);
+    }
+
+    #[test]
+    fn cobertura_reads_root_line_rate() {
+        let xml = r#"<?xml version="1.0" ?>
+<coverage line-rate="0.875" branch-rate="0.5" version="1.9">
+  <packages><package name="app" line-rate="0.1"/></packages>
+</coverage>"#;
+        assert_close(parse_cobertura(xml), 87.5);
+        assert_eq!(parse_cobertura("<coverage branch-rate=\"1\">"), None);
+        assert_eq!(parse_cobertura("<coverage line-rate=\"1.5\">"), None);
+    }
+
+    #[test]
+    fn missing_report_is_none() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(
+            read_coverage_report(&temp_dir.path().join("lcov.info")),
+            None
+        );
+    }
+}
@@ -10,6 +10,7 @@ mod log_rotation;
 mod orchestration;
 mod performance;
 mod stream_handler;
+mod summary;
 mod trace_layer;
 
 #[cfg(test)]
@@ -22,6 +23,7 @@ pub use log_rotation::{create_log_file, rotate_logs};
 pub use orchestration::{OrchestrationEvent, OrchestrationLogger};
 pub use performance::{PerformanceLogger, PerformanceMetric};
 pub use stream_handler::DiagnosticStreamHandler;
+pub use summary::{RunSummary, SUMMARY_FILE};
 pub use trace_layer::{DiagnosticTraceLayer, TraceEntry};
 
 use chrono::Local;
@@ -40,6 +42,7 @@ pub struct DiagnosticsCollector {
     performance_logger: Option<Arc<Mutex<performance::PerformanceLogger>>>,
     error_logger: Option<Arc<Mutex<errors::ErrorLogger>>>,
     hook_run_logger: Option<Arc<Mutex<hook_runs::HookRunLogger>>>,
+    summary: Mutex<RunSummary>,
 }
 
 impl DiagnosticsCollector {
@@ -87,6 +90,7 @@ impl DiagnosticsCollector {
             performance_logger,
             error_logger,
             hook_run_logger,
+            summary: Mutex::default(),
         })
     }
 
@@ -99,6 +103,7 @@ impl DiagnosticsCollector {
             performance_logger: None,
             error_logger: None,
             hook_run_logger: None,
+            summary: Mutex::default(),
         }
     }
 
@@ -136,10 +141,32 @@ impl DiagnosticsCollector {
         if let Some(logger) = &self.orchestration_logger
             && let Ok(mut logger) = logger.lock()
         {
+            if let Ok(mut summary) = self.summary.lock() {
+                summary.record(iteration, &event);
+            }
             let _ = logger.log(iteration, hat, event);
         }
     }
 
+    /// Writes `summary.json` to the session directory.
+    ///
+    /// Combines the counts gathered from logged orchestration events with the
+    /// final iteration count and termination reason. Does nothing if
+    /// diagnostics are disabled.
+    pub fn write_summary(&self, iterations: u32, termination_reason: &str) {
+        let Some(session_dir) = &self.session_dir else {
+            return;
+        };
+        let Ok(mut summary) = self.summary.lock() else {
+            return;
+        };
+        summary.iterations = summary.iterations.max(iterations);
+        summary.termination_reason = Some(termination_reason.to_string());
+        if let Err(e) = summary.write(session_dir) {
+            tracing::warn!("Failed to write diagnostics summary: {}", e);
+        }
+    }
+
     /// Logs a performance metric.
     ///
     /// Does nothing if diagnostics are disabled.
@@ -276,6 +303,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_write_summary_after_orchestration_events() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled(temp.path(), true).unwrap();
+
+        collector.log_orchestration(1, "loop", OrchestrationEvent::IterationStarted);
+        collector.log_orchestration(
+            1,
+            "loop",
+            OrchestrationEvent::HatSelected {
+                hat: "ralph".to_string(),
+                reason: "process_output".to_string(),
+            },
+        );
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::EventPublished {
+                topic: "build.task".to_string(),
+            },
+        );
+        collector.write_summary(1, "completed");
+
+        let summary = RunSummary::load(collector.session_dir().unwrap()).unwrap();
+        assert_eq!(summary.iterations, 1);
+        assert_eq!(summary.hat_activations["ralph"], 1);
+        assert_eq!(summary.topic_counts["build.task"], 1);
+        assert_eq!(summary.termination_reason.as_deref(), Some("completed"));
+    }
+
     #[test]
     fn test_error_logger_integration() {
         let temp = TempDir::new().unwrap();
@@ -25,9 +25,27 @@ pub enum OrchestrationEvent {
     BackpressureTriggered {
         reason: String,
     },
+    /// A backend invocation failed transiently and is being retried.
+    BackendRetry {
+        attempt: u32,
+        max_retries: u32,
+        delay_ms: u64,
+        reason: String,
+    },
+    /// A hat other than Ralph output the completion promise; it was ignored.
+    CompletionMisplaced {
+        hat: String,
+        topic: String,
+    },
     LoopTerminated {
         reason: String,
     },
+    /// Child processes were still alive after the process group was sent
+    /// SIGTERM. `killed` is true when some needed SIGKILL.
+    OrphanedProcesses {
+        pids: Vec<u32>,
+        killed: bool,
+    },
     TaskAbandoned {
         reason: String,
     },
@@ -112,9 +130,19 @@ mod tests {
             OrchestrationEvent::BackpressureTriggered {
                 reason: "tests failed".to_string(),
             },
+            OrchestrationEvent::BackendRetry {
+                attempt: 1,
+                max_retries: 2,
+                delay_ms: 1000,
+                reason: "rate limit".to_string(),
+            },
             OrchestrationEvent::LoopTerminated {
                 reason: "completion_promise".to_string(),
             },
+            OrchestrationEvent::OrphanedProcesses {
+                pids: vec![4242],
+                killed: true,
+            },
             OrchestrationEvent::TaskAbandoned {
                 reason: "max_iterations".to_string(),
             },
@@ -0,0 +1,132 @@
+//! Per-session run summary written as `summary.json` at loop end.
+//!
+//! Built incrementally from the orchestration events the collector logs, so
+//! the summary always agrees with `orchestration.jsonl`.
+
+use super::OrchestrationEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// File name of the summary inside a diagnostics session directory.
+pub const SUMMARY_FILE: &str = "summary.json";
+
+/// Scannable overview of one diagnostics session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Iterations the loop ran.
+    pub iterations: u32,
+    /// Number of iterations each hat was selected for.
+    pub hat_activations: BTreeMap<String, u32>,
+    /// Number of times each topic was published.
+    pub topic_counts: BTreeMap<String, u32>,
+    /// Times backpressure rejected an event.
+    pub backpressure_triggers: u32,
+    /// Reasons recorded for abandoned tasks.
+    pub abandoned_tasks: Vec<String>,
+    /// Why the loop stopped, once it has.
+    pub termination_reason: Option<String>,
+    /// Child processes that survived termination of the process group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orphaned_pids: Vec<u32>,
+}
+
+impl RunSummary {
+    /// Folds one orchestration event into the summary.
+    pub fn record(&mut self, iteration: u32, event: &OrchestrationEvent) {
+        match event {
+            OrchestrationEvent::IterationStarted => {
+                self.iterations = self.iterations.max(iteration);
+            }
+            OrchestrationEvent::HatSelected { hat, .. } => {
+                *self.hat_activations.entry(hat.clone()).or_default() += 1;
+            }
+            OrchestrationEvent::EventPublished { topic } => {
+                *self.topic_counts.entry(topic.clone()).or_default() += 1;
+            }
+            OrchestrationEvent::BackpressureTriggered { .. } => {
+                self.backpressure_triggers += 1;
+            }
+            OrchestrationEvent::TaskAbandoned { reason } => {
+                self.abandoned_tasks.push(reason.clone());
+            }
+            OrchestrationEvent::LoopTerminated { reason } => {
+                self.termination_reason = Some(reason.clone());
+            }
+            OrchestrationEvent::OrphanedProcesses { pids, .. } => {
+                self.orphaned_pids.extend(pids);
+            }
+            _ => {}
+        }
+    }
+
+    /// Reads `summary.json` from a session directory.
+    pub fn load(session_dir: &Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(session_dir.join(SUMMARY_FILE))?;
+        serde_json::from_str(&content).map_err(std::io::Error::other)
+    }
+
+    /// Writes the summary as pretty JSON to `summary.json` in `session_dir`.
+    pub fn write(&self, session_dir: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(session_dir.join(SUMMARY_FILE), json + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_counts_events_and_round_trips() {
+        let mut summary = RunSummary::default();
+        for iteration in 1..=2 {
+            summary.record(iteration, &OrchestrationEvent::IterationStarted);
+            summary.record(
+                iteration,
+                &OrchestrationEvent::HatSelected {
+                    hat: "builder".to_string(),
+                    reason: "process_output".to_string(),
+                },
+            );
+            summary.record(
+                iteration,
+                &OrchestrationEvent::EventPublished {
+                    topic: "build.done".to_string(),
+                },
+            );
+        }
+        summary.record(
+            2,
+            &OrchestrationEvent::BackpressureTriggered {
+                reason: "missing tests".to_string(),
+            },
+        );
+        summary.record(
+            2,
+            &OrchestrationEvent::TaskAbandoned {
+                reason: "blocked 3 times".to_string(),
+            },
+        );
+
+        assert_eq!(summary.iterations, 2);
+        assert_eq!(summary.hat_activations["builder"], 2);
+        assert_eq!(summary.topic_counts["build.done"], 2);
+        assert_eq!(summary.backpressure_triggers, 1);
+        assert_eq!(summary.abandoned_tasks, vec!["blocked 3 times"]);
+
+        summary.record(
+            2,
+            &OrchestrationEvent::OrphanedProcesses {
+                pids: vec![4242],
+                killed: false,
+            },
+        );
+        assert_eq!(summary.orphaned_pids, vec![4242]);
+
+        let temp = TempDir::new().unwrap();
+        summary.write(temp.path()).unwrap();
+        assert_eq!(RunSummary::load(temp.path()).unwrap(), summary);
+    }
+}
@@ -0,0 +1,192 @@
+//! Objective-drift detection.
+//!
+//! A cheap keyword heuristic that compares each iteration's event activity
+//! against the loop objective. When overlap stays below a threshold for
+//! several consecutive iterations, the detector reports drift so the loop can
+//! publish an `objective.drift` event nudging the agent to re-orient.
+//!
+//! Opt-in via `features.drift_detection.enabled`.
+
+use crate::config::DriftDetectionConfig;
+use std::collections::HashSet;
+
+/// Topic published when sustained drift is detected.
+pub const DRIFT_TOPIC: &str = "objective.drift";
+
+/// Words too common to signal topical overlap.
+const STOPWORDS: &[&str] = &[
+    "about", "after", "also", "been", "before", "being", "both", "does", "done", "each", "from",
+    "have", "into", "just", "make", "more", "must", "only", "other", "should", "some", "such",
+    "than", "that", "their", "them", "then", "there", "these", "they", "this", "those", "very",
+    "when", "where", "which", "while", "will", "with", "would", "your",
+];
+
+/// Drift observed over the configured window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftReport {
+    /// Consecutive iterations with overlap below the threshold.
+    pub iterations: u32,
+    /// Overlap ratio of the most recent iteration (0.0–1.0).
+    pub overlap: f64,
+}
+
+impl DriftReport {
+    /// Human-readable payload for the `objective.drift` event.
+    pub fn payload(&self) -> String {
+        format!(
+            "Recent activity has drifted from the objective for {} iterations \
+             (keyword overlap {:.0}%). Re-read the objective and refocus on it.",
+            self.iterations,
+            self.overlap * 100.0
+        )
+    }
+}
+
+/// Tracks keyword overlap between iteration activity and the objective.
+#[derive(Debug, Clone)]
+pub struct DriftDetector {
+    keywords: HashSet<String>,
+    threshold: f64,
+    window: u32,
+    consecutive_off_topic: u32,
+}
+
+impl DriftDetector {
+    /// Creates a detector for the given objective.
+    pub fn new(objective: &str, config: &DriftDetectionConfig) -> Self {
+        Self {
+            keywords: extract_keywords(objective),
+            threshold: config.threshold,
+            window: config.window.max(1),
+            consecutive_off_topic: 0,
+        }
+    }
+
+    /// Records one iteration's activity (event payloads, topics, file paths).
+    ///
+    /// Returns a report when overlap has stayed below the threshold for
+    /// `window` consecutive iterations; the counter then resets so the nudge
+    /// repeats at most once per window. Iterations without any keywords (or an
+    /// objective without keywords) carry no signal and are ignored.
+    pub fn observe_iteration<S: AsRef<str>>(&mut self, activity: &[S]) -> Option<DriftReport> {
+        if self.keywords.is_empty() {
+            return None;
+        }
+
+        let words: HashSet<String> = activity
+            .iter()
+            .flat_map(|text| extract_keywords(text.as_ref()))
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+
+        let matched = words.iter().filter(|w| self.keywords.contains(*w)).count();
+        let overlap = matched as f64 / words.len() as f64;
+
+        if overlap >= self.threshold {
+            self.consecutive_off_topic = 0;
+            return None;
+        }
+
+        self.consecutive_off_topic += 1;
+        if self.consecutive_off_topic < self.window {
+            return None;
+        }
+
+        let report = DriftReport {
+            iterations: self.consecutive_off_topic,
+            overlap,
+        };
+        self.consecutive_off_topic = 0;
+        Some(report)
+    }
+}
+
+/// Splits text into lowercase keywords, dropping short words and stopwords.
+///
+/// Path separators and punctuation split words, so `src/auth/login.rs`
+/// contributes `auth` and `login`.
+pub fn extract_keywords(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 4)
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector(window: u32) -> DriftDetector {
+        let config = DriftDetectionConfig {
+            enabled: true,
+            threshold: 0.2,
+            window,
+        };
+        DriftDetector::new(
+            "Add password reset flow to the login page with email verification",
+            &config,
+        )
+    }
+
+    #[test]
+    fn extract_keywords_drops_short_words_and_stopwords() {
+        let words = extract_keywords("Fix the login bug in src/auth/login.rs with tests");
+        assert!(words.contains("login"));
+        assert!(words.contains("auth"));
+        assert!(words.contains("tests"));
+        assert!(!words.contains("the"));
+        assert!(!words.contains("with"));
+        assert!(!words.contains("fix"));
+    }
+
+    #[test]
+    fn on_topic_activity_never_reports_drift() {
+        let mut detector = detector(2);
+        for _ in 0..5 {
+            assert_eq!(
+                detector.observe_iteration(&["Implemented password reset email for login page"]),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn sustained_off_topic_activity_reports_drift() {
+        let mut detector = detector(3);
+        let off_topic = ["Refactored kubernetes helm charts and grafana dashboards"];
+
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+        let report = detector.observe_iteration(&off_topic).expect("drift");
+        assert_eq!(report.iterations, 3);
+        assert!(report.overlap < 0.2);
+        assert!(report.payload().contains("3 iterations"));
+
+        // Counter resets after reporting
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+    }
+
+    #[test]
+    fn brief_off_topic_activity_is_tolerated() {
+        let mut detector = detector(3);
+        let off_topic = ["Refactored kubernetes helm charts"];
+        let on_topic = ["Added email verification to password reset"];
+
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+        assert_eq!(detector.observe_iteration(&on_topic), None);
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+        assert_eq!(detector.observe_iteration(&off_topic), None);
+    }
+
+    #[test]
+    fn empty_activity_is_ignored() {
+        let mut detector = detector(1);
+        let empty: [&str; 0] = [];
+        assert_eq!(detector.observe_iteration(&empty), None);
+        assert_eq!(detector.observe_iteration(&["ok"]), None);
+    }
+}
@@ -0,0 +1,212 @@
+//! Validated construction of events.
+//!
+//! `EventBuilder` is the construction path for events Ralph creates itself.
+//! Events on Ralph's own fixed topics (`task.resume`, `human.guidance`,
+//! `user.steer`, `build.blocked`, `event.malformed`, `loop.terminate`, ...)
+//! use the infallible [`EventBuilder::system`]. Events whose topic comes from
+//! outside — `ralph emit`, or topics derived from hat ids such as
+//! `<hat>.exhausted` and `<hat>.scope_violation` — go through
+//! [`EventBuilder::build`], which checks the topic once, here, and attaches
+//! source, target, and wave metadata fluently.
+//!
+//! Events re-published from agent output keep the topic the agent wrote, and
+//! the start event uses the configured `event_loop.starting_event`.
+//!
+//! Valid topics are concrete (no `*` wildcards, which belong to
+//! subscriptions), contain no whitespace or control characters, and have no
+//! empty dot-separated segments.
+
+use ralph_proto::{Event, HatId, Topic};
+
+/// Why an event could not be built.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EventBuildError {
+    /// The topic was empty.
+    #[error("event topic is empty")]
+    EmptyTopic,
+    /// The topic was malformed.
+    #[error("invalid event topic '{topic}': {reason}")]
+    InvalidTopic { topic: String, reason: &'static str },
+}
+
+/// Checks that `topic` is a valid concrete event topic.
+pub fn validate_topic(topic: &str) -> Result<(), EventBuildError> {
+    let invalid = |reason| {
+        Err(EventBuildError::InvalidTopic {
+            topic: topic.to_string(),
+            reason,
+        })
+    };
+
+    if topic.is_empty() {
+        return Err(EventBuildError::EmptyTopic);
+    }
+    if topic.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return invalid("topics must not contain whitespace");
+    }
+    if topic.contains('*') {
+        return invalid("wildcards are only valid in subscriptions");
+    }
+    if topic.split('.').any(str::is_empty) {
+        return invalid("topics must not have empty segments");
+    }
+    Ok(())
+}
+
+/// Fluent, validating builder for [`Event`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct EventBuilder {
+    topic: String,
+    payload: String,
+    source: Option<HatId>,
+    target: Option<HatId>,
+    wave_id: Option<String>,
+    wave_index: Option<u32>,
+    wave_total: Option<u32>,
+}
+
+impl EventBuilder {
+    /// Starts an event on `topic` with an empty payload.
+    pub fn new(topic: impl Into<String>) -> Self {
+        Self {
+            topic: topic.into(),
+            payload: String::new(),
+            source: None,
+            target: None,
+            wave_id: None,
+            wave_index: None,
+            wave_total: None,
+        }
+    }
+
+    /// Creates one of Ralph's own events on a fixed, known-valid topic.
+    ///
+    /// Unlike [`EventBuilder::build`] this cannot fail; the topic is a literal
+    /// in Ralph's source, checked in debug builds and by the tests.
+    pub fn system(topic: &'static str, payload: impl Into<String>) -> Event {
+        debug_assert!(
+            validate_topic(topic).is_ok(),
+            "invalid system topic '{topic}'"
+        );
+        Event::new(topic, payload)
+    }
+
+    /// Sets the payload.
+    pub fn payload(mut self, payload: impl Into<String>) -> Self {
+        self.payload = payload.into();
+        self
+    }
+
+    /// Sets the publishing hat.
+    pub fn source(mut self, source: impl Into<HatId>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the target hat for a direct handoff.
+    pub fn target(mut self, target: impl Into<HatId>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Tags the event as member `index` of wave `wave_id`.
+    pub fn wave(mut self, wave_id: impl Into<String>, index: u32) -> Self {
+        self.wave_id = Some(wave_id.into());
+        self.wave_index = Some(index);
+        self
+    }
+
+    /// Sets the total number of events in the wave.
+    pub fn wave_total(mut self, total: u32) -> Self {
+        self.wave_total = Some(total);
+        self
+    }
+
+    /// Validates the topic and builds the event.
+    pub fn build(self) -> Result<Event, EventBuildError> {
+        validate_topic(&self.topic)?;
+        let mut event = Event::new(Topic::new(self.topic), self.payload);
+        event.source = self.source;
+        event.target = self.target;
+        event.wave_id = self.wave_id;
+        event.wave_index = self.wave_index;
+        event.wave_total = self.wave_total;
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_events_use_their_fixed_topic() {
+        let event = EventBuilder::system("task.resume", "continue");
+        assert_eq!(event.topic.as_str(), "task.resume");
+        assert_eq!(event.payload, "continue");
+        assert!(event.source.is_none() && event.target.is_none());
+        for topic in crate::topic_check::SYSTEM_TOPICS {
+            assert_eq!(validate_topic(topic), Ok(()), "{topic}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_topics() {
+        assert_eq!(
+            EventBuilder::new("").build().unwrap_err(),
+            EventBuildError::EmptyTopic
+        );
+        for topic in [
+            "build done",
+            "build.*",
+            "build..done",
+            ".build",
+            "build.",
+            "a\tb",
+        ] {
+            let err = EventBuilder::new(topic).build().unwrap_err();
+            assert!(
+                matches!(err, EventBuildError::InvalidTopic { .. }),
+                "{topic}: {err}"
+            );
+        }
+        let message = EventBuilder::new("review.*")
+            .build()
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("review.*"), "{message}");
+    }
+
+    #[test]
+    fn attaches_metadata() {
+        let event = EventBuilder::new("review.file")
+            .payload("src/main.rs")
+            .source("dispatcher")
+            .target("reviewer")
+            .wave("w-1a2b3c4d", 1)
+            .wave_total(3)
+            .build()
+            .unwrap();
+
+        assert_eq!(event.topic.as_str(), "review.file");
+        assert_eq!(event.payload, "src/main.rs");
+        assert_eq!(event.source.as_ref().map(HatId::as_str), Some("dispatcher"));
+        assert_eq!(event.target.as_ref().map(HatId::as_str), Some("reviewer"));
+        assert_eq!(event.wave_id.as_deref(), Some("w-1a2b3c4d"));
+        assert_eq!(event.wave_index, Some(1));
+        assert_eq!(event.wave_total, Some(3));
+    }
+
+    #[test]
+    fn accepts_hat_derived_topics() {
+        for topic in [
+            "task.start",
+            "test_writer.exhausted",
+            "build.task.abandoned",
+            "LOOP_COMPLETE",
+        ] {
+            assert!(validate_topic(topic).is_ok(), "{topic}");
+        }
+    }
+}
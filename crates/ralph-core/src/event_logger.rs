@@ -2,13 +2,17 @@
 //!
 //! Logs all events to `.ralph/events.jsonl` as specified in the event-loop spec.
 //! The observer pattern allows hooking into the event bus without modifying routing.
+//!
+//! System events (see [`is_system_topic`]) can be routed to a sibling
+//! `system-events*.jsonl` file or dropped via `event_loop.system_events`.
 
+use crate::config::SystemEventsMode;
 use crate::loop_context::LoopContext;
 use crate::text::floor_char_boundary;
 use ralph_proto::{Event, HatId};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
@@ -138,6 +142,28 @@ impl EventRecord {
     }
 }
 
+/// Returns true for topics Ralph emits for diagnostics rather than workflow:
+/// `event.*` (malformed, orphaned), `iteration.summary`, `loop.iteration`, and
+/// `<hat>.exhausted`.
+///
+/// `loop.terminate` is deliberately excluded: `--continue` finds the run
+/// boundary by reading it from the events file.
+pub fn is_system_topic(topic: &str) -> bool {
+    topic.starts_with("event.")
+        || topic == "iteration.summary"
+        || topic == "loop.iteration"
+        || topic.ends_with(".exhausted")
+}
+
+/// Path of the system events file paired with `events_path`:
+/// `events-20260127-123456.jsonl` pairs with `system-events-20260127-123456.jsonl`.
+pub fn system_events_path(events_path: &Path) -> PathBuf {
+    let name = events_path
+        .file_name()
+        .map_or_else(|| "events.jsonl".into(), |n| n.to_string_lossy());
+    events_path.with_file_name(format!("system-{name}"))
+}
+
 /// Logger that writes events to a JSONL file.
 pub struct EventLogger {
     /// Path to the events file.
@@ -145,6 +171,12 @@ pub struct EventLogger {
 
     /// File handle for appending.
     file: Option<File>,
+
+    /// Where system events go.
+    system_events: SystemEventsMode,
+
+    /// File handle for the system events file (`SystemEventsMode::Separate`).
+    system_file: Option<File>,
 }
 
 impl EventLogger {
@@ -158,9 +190,18 @@ impl EventLogger {
         Self {
             path: path.into(),
             file: None,
+            system_events: SystemEventsMode::Inline,
+            system_file: None,
         }
     }
 
+    /// Sets where system events are logged.
+    #[must_use]
+    pub fn with_system_events(mut self, mode: SystemEventsMode) -> Self {
+        self.system_events = mode;
+        self
+    }
+
     /// Creates a logger with the default path.
     pub fn default_path() -> Self {
         Self::new(Self::DEFAULT_PATH)
@@ -184,19 +225,16 @@ impl EventLogger {
         Self::new(events_path)
     }
 
-    /// Ensures the parent directory exists and opens the file.
-    fn ensure_open(&mut self) -> std::io::Result<&mut File> {
-        if self.file.is_none() {
-            if let Some(parent) = self.path.parent() {
+    /// Ensures the parent directory exists and opens `path` for appending.
+    fn ensure_open<'a>(slot: &'a mut Option<File>, path: &Path) -> std::io::Result<&'a mut File> {
+        if slot.is_none() {
+            if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent)?;
             }
-            let file = OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&self.path)?;
-            self.file = Some(file);
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            *slot = Some(file);
         }
-        Ok(self.file.as_mut().unwrap())
+        Ok(slot.as_mut().unwrap())
     }
 
     /// Logs an event record.
@@ -204,8 +242,20 @@ impl EventLogger {
     /// Uses a single `write_all` call to ensure the JSON line is written atomically.
     /// This prevents corruption when multiple processes append to the same file
     /// concurrently (e.g., during parallel merge queue processing).
+    ///
+    /// System events are routed according to [`EventLogger::with_system_events`].
     pub fn log(&mut self, record: &EventRecord) -> std::io::Result<()> {
-        let file = self.ensure_open()?;
+        let file = match self.system_events {
+            SystemEventsMode::Separate if is_system_topic(&record.topic) => {
+                let path = system_events_path(&self.path);
+                Self::ensure_open(&mut self.system_file, &path)?
+            }
+            SystemEventsMode::Suppress if is_system_topic(&record.topic) => {
+                debug!(topic = %record.topic, "System event suppressed");
+                return Ok(());
+            }
+            _ => Self::ensure_open(&mut self.file, &self.path)?,
+        };
         let mut json = serde_json::to_string(record)?;
         json.push('\n');
         // Single write_all ensures atomic append on POSIX with O_APPEND
@@ -288,6 +338,54 @@ impl EventHistory {
         Ok(records)
     }
 
+    /// Returns the path of the history file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads complete event records appended after byte `offset`.
+    ///
+    /// Returns the parsed records and the offset just past the last complete
+    /// line consumed. A trailing line without a newline is left unread so a
+    /// concurrent writer's partial append is picked up on the next call.
+    /// If the file is shorter than `offset` (truncated or replaced), reading
+    /// restarts from the beginning.
+    pub fn read_from(&self, offset: u64) -> std::io::Result<(Vec<EventRecord>, u64)> {
+        if !self.exists() {
+            return Ok((Vec::new(), 0));
+        }
+
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        let mut offset = if len < offset { 0 } else { offset };
+        file.seek(SeekFrom::Start(offset))?;
+
+        let mut reader = BufReader::new(file);
+        let mut records = Vec::new();
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 || !line.ends_with('\n') {
+                break;
+            }
+            offset += read as u64;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(record) => records.push(record),
+                Err(e) => {
+                    warn!(offset, error = %e, "Failed to parse event record");
+                }
+            }
+        }
+
+        Ok((records, offset))
+    }
+
     /// Reads the last N event records.
     pub fn read_last(&self, n: usize) -> std::io::Result<Vec<EventRecord>> {
         let all = self.read_all()?;
@@ -358,6 +456,64 @@ mod tests {
         assert_eq!(records[1].topic, "build.done");
     }
 
+    #[test]
+    fn test_separate_system_events_keep_main_file_clean() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events-20260101-000000.jsonl");
+        let mut logger = EventLogger::new(&path).with_system_events(SystemEventsMode::Separate);
+
+        for topic in [
+            "task.start",
+            "event.malformed",
+            "build.done",
+            "iteration.summary",
+            "builder.exhausted",
+            "loop.terminate",
+        ] {
+            logger
+                .log_event(1, "loop", &make_event(topic, ""), None)
+                .unwrap();
+        }
+
+        let topics = |path: &Path| -> Vec<String> {
+            EventHistory::new(path)
+                .read_all()
+                .unwrap()
+                .into_iter()
+                .map(|r| r.topic)
+                .collect()
+        };
+        assert_eq!(
+            topics(&path),
+            vec!["task.start", "build.done", "loop.terminate"]
+        );
+        let system_path = tmp.path().join("system-events-20260101-000000.jsonl");
+        assert_eq!(system_events_path(&path), system_path);
+        assert_eq!(
+            topics(&system_path),
+            vec!["event.malformed", "iteration.summary", "builder.exhausted"]
+        );
+    }
+
+    #[test]
+    fn test_suppressed_system_events_are_dropped() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let mut logger = EventLogger::new(&path).with_system_events(SystemEventsMode::Suppress);
+
+        logger
+            .log_event(1, "loop", &make_event("event.orphaned", ""), None)
+            .unwrap();
+        logger
+            .log_event(1, "builder", &make_event("build.done", ""), None)
+            .unwrap();
+
+        let records = EventHistory::new(&path).read_all().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "build.done");
+        assert!(!system_events_path(&path).exists());
+    }
+
     #[test]
     fn test_read_last() {
         let tmp = TempDir::new().unwrap();
@@ -402,6 +558,62 @@ mod tests {
         assert_eq!(blocked[0].iteration, 2);
     }
 
+    #[test]
+    fn test_read_from_returns_only_new_complete_lines() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path);
+        logger
+            .log_event(1, "hat", &make_event("build.done", "a"), None)
+            .unwrap();
+
+        let history = EventHistory::new(&path);
+        let (records, offset) = history.read_from(0).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(offset, fs::metadata(&path).unwrap().len());
+
+        logger
+            .log_event(2, "hat", &make_event("build.blocked", "b"), None)
+            .unwrap();
+        // Partial trailing line must not be consumed yet
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"ts\":\"partial").unwrap();
+
+        let (records, next) = history.read_from(offset).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "build.blocked");
+
+        let (records, again) = history.read_from(next).unwrap();
+        assert!(records.is_empty());
+        assert_eq!(again, next);
+    }
+
+    #[test]
+    fn test_read_from_restarts_after_truncation() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path);
+        for i in 1..=3 {
+            logger
+                .log_event(i, "hat", &make_event("test", "x"), None)
+                .unwrap();
+        }
+        let history = EventHistory::new(&path);
+        let (_, offset) = history.read_from(0).unwrap();
+
+        fs::remove_file(&path).unwrap();
+        let mut logger = EventLogger::new(&path);
+        logger
+            .log_event(9, "hat", &make_event("fresh", "y"), None)
+            .unwrap();
+
+        let (records, _) = history.read_from(offset).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "fresh");
+    }
+
     #[test]
     fn test_payload_truncation() {
         let long_payload = "x".repeat(1000);
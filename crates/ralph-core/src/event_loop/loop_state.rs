@@ -2,12 +2,197 @@
 //!
 //! This module contains the `LoopState` struct that tracks the current
 //! state of the orchestration loop including iteration count, failures,
-//! timing, and hat activation tracking.
+//! timing, and hat activation tracking, plus the versioned on-disk form
+//! that `ralph run --continue` restores.
 
 use ralph_proto::{Event, HatId};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
 use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Current version of the persisted loop state format.
+///
+/// Files without a version are v1 (iteration, cost and token peaks only) and
+/// load with the newer fields defaulted. Files from a newer version are
+/// ignored and the loop starts fresh.
+pub const LOOP_STATE_VERSION: u32 = 2;
+
+fn legacy_loop_state_version() -> u32 {
+    1
+}
+
+/// Durable subset of loop runtime state restored by `ralph run --continue`.
+///
+/// Covers the counters behind the loop's safeguards (iteration limit, cost
+/// limit, failure and thrashing detection) so a resume does not reset them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PersistedLoopState {
+    #[serde(default = "legacy_loop_state_version")]
+    pub version: u32,
+    pub iteration: u32,
+    pub total_cost_usd: f64,
+    pub last_hat: Option<String>,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub consecutive_blocked: u32,
+    #[serde(default)]
+    pub last_blocked_hat: Option<String>,
+    #[serde(default)]
+    pub task_block_counts: HashMap<String, u32>,
+    #[serde(default)]
+    pub abandoned_tasks: Vec<String>,
+    #[serde(default)]
+    pub abandoned_task_redispatches: u32,
+    #[serde(default)]
+    pub hat_activation_counts: HashMap<String, u32>,
+    #[serde(default)]
+    pub exhausted_hats: Vec<String>,
+    #[serde(default)]
+    pub peak_input_tokens: u64,
+    #[serde(default)]
+    pub last_input_tokens: Option<u64>,
+    #[serde(default)]
+    pub hat_peak_input_tokens: HashMap<String, u64>,
+    /// Byte offset in the events file up to which events were consumed.
+    ///
+    /// `--continue` only replays events past this offset, so work the prior
+    /// session already acted on is not handled twice.
+    #[serde(default)]
+    pub events_offset: Option<u64>,
+}
+
+impl PersistedLoopState {
+    /// Captures the durable fields of `state` (without an events offset).
+    pub fn from_state(state: &LoopState) -> Self {
+        let mut exhausted_hats: Vec<String> = state
+            .exhausted_hats
+            .iter()
+            .map(|hat| hat.as_str().to_string())
+            .collect();
+        exhausted_hats.sort();
+
+        Self {
+            version: LOOP_STATE_VERSION,
+            iteration: state.iteration,
+            total_cost_usd: state.cumulative_cost,
+            last_hat: state.last_hat.as_ref().map(|hat| hat.as_str().to_string()),
+            consecutive_failures: state.consecutive_failures,
+            consecutive_blocked: state.consecutive_blocked,
+            last_blocked_hat: state
+                .last_blocked_hat
+                .as_ref()
+                .map(|hat| hat.as_str().to_string()),
+            task_block_counts: state.task_block_counts.clone(),
+            abandoned_tasks: state.abandoned_tasks.clone(),
+            abandoned_task_redispatches: state.abandoned_task_redispatches,
+            hat_activation_counts: state
+                .hat_activation_counts
+                .iter()
+                .map(|(hat, count)| (hat.as_str().to_string(), *count))
+                .collect(),
+            exhausted_hats,
+            peak_input_tokens: state.peak_input_tokens,
+            last_input_tokens: state.last_input_tokens,
+            hat_peak_input_tokens: state
+                .hat_peak_input_tokens
+                .iter()
+                .map(|(hat, tokens)| (hat.as_str().to_string(), *tokens))
+                .collect(),
+            events_offset: None,
+        }
+    }
+
+    /// Copies the persisted fields into `state`, leaving the rest untouched.
+    pub fn apply_to(self, state: &mut LoopState) {
+        state.iteration = self.iteration;
+        state.cumulative_cost = self.total_cost_usd;
+        state.last_hat = self.last_hat.map(HatId::new);
+        state.consecutive_failures = self.consecutive_failures;
+        state.consecutive_blocked = self.consecutive_blocked;
+        state.last_blocked_hat = self.last_blocked_hat.map(HatId::new);
+        state.task_block_counts = self.task_block_counts;
+        state.abandoned_tasks = self.abandoned_tasks;
+        state.abandoned_task_redispatches = self.abandoned_task_redispatches;
+        state.hat_activation_counts = self
+            .hat_activation_counts
+            .into_iter()
+            .map(|(hat, count)| (HatId::new(hat), count))
+            .collect();
+        state.exhausted_hats = self.exhausted_hats.into_iter().map(HatId::new).collect();
+        state.peak_input_tokens = self.peak_input_tokens;
+        state.last_input_tokens = self.last_input_tokens;
+        state.hat_peak_input_tokens = self
+            .hat_peak_input_tokens
+            .into_iter()
+            .map(|(hat, tokens)| (HatId::new(hat), tokens))
+            .collect();
+    }
+
+    /// Writes the state as pretty JSON, creating parent directories.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let payload = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, payload)
+    }
+
+    /// Reads persisted state, or `None` if the file is missing or unusable.
+    ///
+    /// Unreadable, malformed and newer-version files are logged and skipped
+    /// rather than failing the resume.
+    pub fn load(path: &Path) -> Option<Self> {
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(error) => {
+                warn!(path = %path.display(), %error, "Failed to read persisted loop state; starting fresh");
+                return None;
+            }
+        };
+        let persisted: Self = match serde_json::from_str(&raw) {
+            Ok(persisted) => persisted,
+            Err(error) => {
+                warn!(path = %path.display(), %error, "Persisted loop state is malformed; starting fresh");
+                return None;
+            }
+        };
+        if persisted.version > LOOP_STATE_VERSION {
+            warn!(
+                path = %path.display(),
+                version = persisted.version,
+                supported = LOOP_STATE_VERSION,
+                "Persisted loop state is from a newer ralph; starting fresh"
+            );
+            return None;
+        }
+        Some(persisted)
+    }
+}
+
+/// Wall-clock span of one iteration and the hat that ran it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IterationTiming {
+    /// Iteration number (1-indexed).
+    pub iteration: u32,
+    /// Hat whose output the iteration produced.
+    pub hat: HatId,
+    /// When the iteration began (prompt built, or the previous iteration ended).
+    pub started_at: Instant,
+    /// When its output was processed.
+    pub ended_at: Instant,
+}
+
+impl IterationTiming {
+    /// Time the iteration took.
+    pub fn duration(&self) -> Duration {
+        self.ended_at.saturating_duration_since(self.started_at)
+    }
+}
 
 /// Fingerprint of the last emitted event for stale loop detection.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,7 +221,7 @@ pub struct LoopState {
     pub last_blocked_hat: Option<HatId>,
     /// Per-task block counts for task-level thrashing detection.
     pub task_block_counts: HashMap<String, u32>,
-    /// Tasks that have been abandoned after 3+ blocks.
+    /// Tasks that have been abandoned after `max_task_blocks` blocks.
     pub abandoned_tasks: Vec<String>,
     /// Count of times planner dispatched an already-abandoned task.
     pub abandoned_task_redispatches: u32,
@@ -82,6 +267,12 @@ pub struct LoopState {
 
     /// Human guidance messages that must be acknowledged before completion.
     pub unacknowledged_guidance: Vec<String>,
+
+    /// Per-iteration wall-clock timings, in iteration order.
+    pub iteration_timings: Vec<IterationTiming>,
+
+    /// Start of the iteration in progress, if one was marked.
+    pub current_iteration_started_at: Option<Instant>,
 }
 
 impl Default for LoopState {
@@ -111,6 +302,8 @@ impl Default for LoopState {
             last_input_tokens: None,
             hat_peak_input_tokens: HashMap::new(),
             unacknowledged_guidance: Vec::new(),
+            iteration_timings: Vec::new(),
+            current_iteration_started_at: None,
         }
     }
 }
@@ -126,6 +319,52 @@ impl LoopState {
         self.started_at.elapsed()
     }
 
+    /// Marks the start of the next iteration.
+    ///
+    /// Only the first mark counts, so rebuilding a prompt does not move it.
+    pub fn mark_iteration_start(&mut self) {
+        self.current_iteration_started_at
+            .get_or_insert_with(Instant::now);
+    }
+
+    /// Records the timing of the iteration that just finished for `hat`.
+    ///
+    /// Without a start mark the iteration is taken to have begun when the
+    /// previous one ended (or when the loop started).
+    pub fn record_iteration_timing(&mut self, hat: &HatId) {
+        let started_at = self.current_iteration_started_at.take().unwrap_or_else(|| {
+            self.iteration_timings
+                .last()
+                .map_or(self.started_at, |timing| timing.ended_at)
+        });
+        self.iteration_timings.push(IterationTiming {
+            iteration: self.iteration,
+            hat: hat.clone(),
+            started_at,
+            ended_at: Instant::now(),
+        });
+    }
+
+    /// Per-iteration timings recorded so far.
+    pub fn iteration_timings(&self) -> &[IterationTiming] {
+        &self.iteration_timings
+    }
+
+    /// Saves the durable subset of this state to `path`.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        PersistedLoopState::from_state(self).save(path)
+    }
+
+    /// Loads state saved by `save`, falling back to fresh state when the
+    /// file is missing or incompatible.
+    pub fn load(path: &Path) -> Self {
+        let mut state = Self::new();
+        if let Some(persisted) = PersistedLoopState::load(path) {
+            persisted.apply_to(&mut state);
+        }
+        state
+    }
+
     fn event_counts_toward_stale_loop(event: &Event) -> bool {
         !matches!(event.topic.as_str(), "task.complete")
     }
@@ -193,9 +432,74 @@ fn fingerprint_payload(payload: &str) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use super::LoopState;
+    use super::{LOOP_STATE_VERSION, LoopState};
     use ralph_proto::{Event, HatId};
 
+    #[test]
+    fn save_and_load_round_trip_safeguard_counters() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".ralph/api/loop-state.json");
+
+        let mut state = LoopState::new();
+        state.iteration = 12;
+        state.consecutive_failures = 2;
+        state.cumulative_cost = 3.5;
+        state.task_block_counts.insert("task-1".to_string(), 2);
+        state.abandoned_tasks.push("task-0".to_string());
+        state.hat_activation_counts.insert(HatId::new("builder"), 4);
+        state.exhausted_hats.insert(HatId::new("reviewer"));
+        state.save(&path).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(raw.contains(&format!("\"version\": {LOOP_STATE_VERSION}")));
+
+        let loaded = LoopState::load(&path);
+        assert_eq!(loaded.iteration, 12);
+        assert_eq!(loaded.consecutive_failures, 2);
+        assert!((loaded.cumulative_cost - 3.5).abs() < f64::EPSILON);
+        assert_eq!(loaded.task_block_counts.get("task-1"), Some(&2));
+        assert_eq!(loaded.abandoned_tasks, vec!["task-0".to_string()]);
+        assert_eq!(
+            loaded.hat_activation_counts.get(&HatId::new("builder")),
+            Some(&4)
+        );
+        assert!(loaded.exhausted_hats.contains(&HatId::new("reviewer")));
+    }
+
+    #[test]
+    fn load_falls_back_to_fresh_state() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        // Missing
+        let missing = LoopState::load(&temp_dir.path().join("missing.json"));
+        assert_eq!(missing.iteration, 0);
+
+        // Malformed
+        let malformed = temp_dir.path().join("malformed.json");
+        std::fs::write(&malformed, "{not json").unwrap();
+        assert_eq!(LoopState::load(&malformed).iteration, 0);
+
+        // Newer format version
+        let newer = temp_dir.path().join("newer.json");
+        std::fs::write(
+            &newer,
+            r#"{"version": 99, "iteration": 5, "total_cost_usd": 1.0, "last_hat": null}"#,
+        )
+        .unwrap();
+        assert_eq!(LoopState::load(&newer).iteration, 0);
+
+        // Unversioned v1 files still load
+        let legacy = temp_dir.path().join("legacy.json");
+        std::fs::write(
+            &legacy,
+            r#"{"iteration": 5, "total_cost_usd": 1.0, "last_hat": "builder"}"#,
+        )
+        .unwrap();
+        let loaded = LoopState::load(&legacy);
+        assert_eq!(loaded.iteration, 5);
+        assert_eq!(loaded.consecutive_failures, 0);
+    }
+
     #[test]
     fn repeated_task_complete_does_not_accumulate_stale_loop_count() {
         let mut state = LoopState::new();
@@ -272,6 +576,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn iteration_timing_starts_at_first_mark() {
+        let mut state = LoopState::new();
+        let hat = HatId::new("builder");
+
+        state.mark_iteration_start();
+        let marked = state.current_iteration_started_at.unwrap();
+        state.mark_iteration_start();
+        state.iteration = 1;
+        state.record_iteration_timing(&hat);
+
+        let timing = &state.iteration_timings()[0];
+        assert_eq!(timing.started_at, marked);
+        assert_eq!(timing.hat, hat);
+        assert!(timing.ended_at >= timing.started_at);
+        assert!(state.current_iteration_started_at.is_none());
+    }
+
     #[test]
     fn repeated_non_progress_topics_still_accumulate_stale_loop_count() {
         let mut state = LoopState::new();
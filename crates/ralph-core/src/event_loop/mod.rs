@@ -3,32 +3,46 @@
 //! The event loop coordinates the execution of hats via pub/sub messaging.
 
 mod loop_state;
+mod prompt_budget;
+mod replay;
+mod scratchpad_cache;
 #[cfg(test)]
 mod tests;
 
-pub use loop_state::LoopState;
+pub use loop_state::{IterationTiming, LOOP_STATE_VERSION, LoopState, PersistedLoopState};
+use prompt_budget::{char_len, fit_prompt_to_budget};
+pub use replay::ReplayOutcome;
+use scratchpad_cache::{ScratchpadCache, is_unfinished_task};
 
-use crate::config::{HatBackend, InjectMode, RalphConfig, ScratchpadConfig};
+use crate::config::{HatBackend, InjectMode, MemoryRanking, RalphConfig, ScratchpadConfig};
+use crate::drift_detection::DriftDetector;
+use crate::event_builder::EventBuilder;
 use crate::event_parser::{EventParser, MutationEvidence, MutationStatus};
 use crate::event_reader::EventReader;
 use crate::hat_registry::HatRegistry;
 use crate::hatless_ralph::HatlessRalph;
 use crate::instructions::InstructionBuilder;
 use crate::loop_context::LoopContext;
+use crate::memory_ranking::{rank_by_relevance, select_within_budget};
 use crate::memory_store::{MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget};
+use crate::prompt_fragments::{PROMPT_FRAGMENTS_DIR, PromptFragments};
+use crate::shutdown::ShutdownSignal;
 use crate::skill_registry::SkillRegistry;
-use crate::text::floor_char_boundary;
+use crate::task_definition::Verification;
+use crate::text::{elide_middle, floor_char_boundary, truncate_with_ellipsis};
+use crate::workspace::run_verification_in;
 use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService};
-use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
+/// Cap on verification output carried in a synthesized `build.blocked`.
+const BUILD_VERIFICATION_OUTPUT_MAX_BYTES: usize = 4000;
+
 /// Result of processing events from JSONL.
 #[derive(Debug, Clone)]
 pub struct ProcessedEvents {
@@ -52,20 +66,6 @@ pub struct ProcessedEventsWithWaves {
     pub wave_events: Vec<crate::event_reader::Event>,
 }
 
-/// Durable subset of loop runtime state restored by `ralph run --continue`.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct PersistedLoopState {
-    pub iteration: u32,
-    pub total_cost_usd: f64,
-    pub last_hat: Option<String>,
-    #[serde(default)]
-    pub peak_input_tokens: u64,
-    #[serde(default)]
-    pub last_input_tokens: Option<u64>,
-    #[serde(default)]
-    pub hat_peak_input_tokens: HashMap<String, u64>,
-}
-
 /// Reason the event loop terminated.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TerminationReason {
@@ -95,6 +95,9 @@ pub enum TerminationReason {
     WorkspaceGone,
     /// Loop was cancelled gracefully via loop.cancel event (human rejection, timeout).
     Cancelled,
+    /// An unrecoverable configuration problem was found while running
+    /// (e.g. the prompt file is gone on resume, a hat's command is missing).
+    ConfigError { detail: String },
 }
 
 impl TerminationReason {
@@ -113,7 +116,8 @@ impl TerminationReason {
             | TerminationReason::LoopStale
             | TerminationReason::ValidationFailure
             | TerminationReason::Stopped
-            | TerminationReason::WorkspaceGone => 1,
+            | TerminationReason::WorkspaceGone
+            | TerminationReason::ConfigError { .. } => 1,
             TerminationReason::MaxIterations
             | TerminationReason::MaxRuntime
             | TerminationReason::MaxCost => 2,
@@ -144,6 +148,7 @@ impl TerminationReason {
             TerminationReason::RestartRequested => "restart_requested",
             TerminationReason::WorkspaceGone => "workspace_gone",
             TerminationReason::Cancelled => "cancelled",
+            TerminationReason::ConfigError { .. } => "config_error",
         }
     }
 
@@ -151,6 +156,44 @@ impl TerminationReason {
     pub fn is_success(&self) -> bool {
         matches!(self, TerminationReason::CompletionPromise)
     }
+
+    /// Every reason, for parsing [`Self::as_str`] names back.
+    const ALL: [TerminationReason; 14] = [
+        TerminationReason::CompletionPromise,
+        TerminationReason::MaxIterations,
+        TerminationReason::MaxRuntime,
+        TerminationReason::MaxCost,
+        TerminationReason::ConsecutiveFailures,
+        TerminationReason::LoopThrashing,
+        TerminationReason::LoopStale,
+        TerminationReason::ValidationFailure,
+        TerminationReason::Stopped,
+        TerminationReason::Interrupted,
+        TerminationReason::RestartRequested,
+        TerminationReason::WorkspaceGone,
+        TerminationReason::Cancelled,
+        TerminationReason::ConfigError {
+            detail: String::new(),
+        },
+    ];
+}
+
+impl std::str::FromStr for TerminationReason {
+    type Err = String;
+
+    /// Parses an [`TerminationReason::as_str`] name; `ConfigError` gets an empty detail.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|reason| reason.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(TerminationReason::as_str).collect();
+                format!(
+                    "unknown termination reason '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
 }
 
 /// The main event loop orchestrator.
@@ -174,6 +217,18 @@ pub struct EventLoop {
     /// Robot service for human-in-the-loop communication.
     /// Injected externally when `human.enabled` is true and this is the primary loop.
     robot_service: Option<Box<dyn RobotService>>,
+    /// Objective-drift detector (None unless `features.drift_detection.enabled`).
+    drift_detector: Option<DriftDetector>,
+    /// Shared Ctrl-C state; a drain request stops the loop between iterations.
+    shutdown: Option<ShutdownSignal>,
+    /// Events-file offset restored from persisted loop state (replay guard).
+    resume_events_offset: Option<u64>,
+    /// Unrecoverable config problem reported by the runner; ends the loop.
+    config_error: Option<String>,
+    /// Scratchpad completion state, reused until the file changes.
+    scratchpad_cache: ScratchpadCache,
+    /// `user.steer` events waiting for the next coordinator prompt.
+    pending_steer: Vec<Event>,
 }
 
 impl EventLoop {
@@ -227,8 +282,11 @@ impl EventLoop {
         }
 
         let registry = HatRegistry::from_config(&config);
+        let prompt_fragments =
+            PromptFragments::load(&context.workspace().join(PROMPT_FRAGMENTS_DIR));
         let instruction_builder =
-            InstructionBuilder::with_events(config.core.clone(), config.events.clone());
+            InstructionBuilder::with_events(config.core.clone(), config.events.clone())
+                .with_prompt_fragments(prompt_fragments.clone());
 
         let mut bus = EventBus::new();
 
@@ -285,15 +343,17 @@ impl EventLoop {
             String::new()
         };
 
-        // When memories are enabled, add tasks CLI instructions alongside scratchpad
+        // When memories are enabled, add tasks CLI instructions alongside scratchpad.
+        // Ralph resolves agent files against the loop's workspace, not the cwd.
         let ralph = HatlessRalph::new(
             config.event_loop.completion_promise.clone(),
-            config.core.clone(),
+            config.core.clone().with_workspace_root(context.workspace()),
             &registry,
             config.event_loop.starting_event.clone(),
         )
         .with_memories_enabled(config.memories.enabled)
-        .with_skill_index(skill_index);
+        .with_skill_index(skill_index)
+        .with_prompt_fragments(prompt_fragments);
 
         // Read timestamped events path from marker file, fall back to default
         // The marker file contains a relative path like ".ralph/events-20260127-123456.jsonl"
@@ -319,6 +379,12 @@ impl EventLoop {
             loop_context: Some(context),
             skill_registry,
             robot_service: None,
+            drift_detector: None,
+            shutdown: None,
+            resume_events_offset: None,
+            config_error: None,
+            scratchpad_cache: ScratchpadCache::default(),
+            pending_steer: Vec::new(),
         }
     }
 
@@ -337,8 +403,10 @@ impl EventLoop {
         }
 
         let registry = HatRegistry::from_config(&config);
+        let prompt_fragments = PromptFragments::load(std::path::Path::new(PROMPT_FRAGMENTS_DIR));
         let instruction_builder =
-            InstructionBuilder::with_events(config.core.clone(), config.events.clone());
+            InstructionBuilder::with_events(config.core.clone(), config.events.clone())
+                .with_prompt_fragments(prompt_fragments.clone());
 
         let mut bus = EventBus::new();
 
@@ -404,7 +472,8 @@ impl EventLoop {
             config.event_loop.starting_event.clone(),
         )
         .with_memories_enabled(config.memories.enabled)
-        .with_skill_index(skill_index);
+        .with_skill_index(skill_index)
+        .with_prompt_fragments(prompt_fragments);
 
         // Read events path from marker file, fall back to default if not present
         // The marker file is written by run_loop_impl() at run startup
@@ -426,6 +495,12 @@ impl EventLoop {
             loop_context: None,
             skill_registry,
             robot_service: None,
+            drift_detector: None,
+            shutdown: None,
+            resume_events_offset: None,
+            config_error: None,
+            scratchpad_cache: ScratchpadCache::default(),
+            pending_steer: Vec::new(),
         }
     }
 
@@ -440,6 +515,15 @@ impl EventLoop {
         self.robot_service = Some(service);
     }
 
+    /// Injects the shared shutdown signal.
+    ///
+    /// Once a drain is requested, `check_termination` reports `Interrupted`
+    /// at the next iteration boundary, so the in-flight iteration finishes
+    /// and `loop.terminate` is published as usual.
+    pub fn set_shutdown_signal(&mut self, signal: ShutdownSignal) {
+        self.shutdown = Some(signal);
+    }
+
     /// Returns the loop context, if one was provided.
     pub fn loop_context(&self) -> Option<&LoopContext> {
         self.loop_context.as_ref()
@@ -500,55 +584,35 @@ impl EventLoop {
 
     /// Serializes the durable subset of runtime state.
     pub fn persisted_loop_state(&self) -> PersistedLoopState {
-        PersistedLoopState {
-            iteration: self.state.iteration,
-            total_cost_usd: self.state.cumulative_cost,
-            last_hat: self
-                .state
-                .last_hat
-                .as_ref()
-                .map(|hat| hat.as_str().to_string()),
-            peak_input_tokens: self.state.peak_input_tokens,
-            last_input_tokens: self.state.last_input_tokens,
-            hat_peak_input_tokens: self
-                .state
-                .hat_peak_input_tokens
-                .iter()
-                .map(|(hat, tokens)| (hat.as_str().to_string(), *tokens))
-                .collect(),
-        }
+        let mut persisted = PersistedLoopState::from_state(&self.state);
+        persisted.events_offset = Some(self.event_reader.position());
+        persisted
     }
 
-    /// Restores persisted `--continue` state from disk if present.
-    pub fn restore_loop_state(&mut self, path: &std::path::Path) -> std::io::Result<()> {
-        if !path.exists() {
-            return Ok(());
-        }
+    /// Restores persisted `--continue` state from disk.
+    ///
+    /// A missing or incompatible file leaves the loop with fresh state (see
+    /// `LoopState::load`).
+    pub fn restore_loop_state(&mut self, path: &std::path::Path) {
+        let Some(persisted) = PersistedLoopState::load(path) else {
+            return;
+        };
+        self.resume_events_offset = persisted.events_offset;
+        persisted.apply_to(&mut self.state);
+    }
 
-        let raw = std::fs::read_to_string(path)?;
-        let persisted: PersistedLoopState = serde_json::from_str(&raw).map_err(|error| {
-            std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string())
-        })?;
-        self.state.iteration = persisted.iteration;
-        self.state.cumulative_cost = persisted.total_cost_usd;
-        self.state.last_hat = persisted.last_hat.map(HatId::new);
-        self.state.peak_input_tokens = persisted.peak_input_tokens;
-        self.state.last_input_tokens = persisted.last_input_tokens;
-        self.state.hat_peak_input_tokens = persisted
-            .hat_peak_input_tokens
-            .into_iter()
-            .map(|(hat, tokens)| (HatId::new(hat), tokens))
-            .collect();
-        Ok(())
+    /// Drops the restored events offset so resume falls back to a full scan.
+    ///
+    /// Without an offset, `replay_resume_events_from_jsonl` republishes every
+    /// event after the last `loop.terminate`, which may repeat work the prior
+    /// session already consumed. Used by `ralph run --continue --rescan-events`.
+    pub fn clear_resume_events_offset(&mut self) {
+        self.resume_events_offset = None;
     }
 
     /// Saves persisted `--continue` state to disk.
     pub fn save_loop_state(&self, path: &std::path::Path) -> std::io::Result<()> {
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        let payload = serde_json::to_string_pretty(&self.persisted_loop_state())?;
-        std::fs::write(path, payload)
+        self.persisted_loop_state().save(path)
     }
 
     /// Removes stale persisted loop state for a fresh run.
@@ -585,6 +649,39 @@ impl EventLoop {
         self.diagnostics.log_hook_run(entry);
     }
 
+    /// Records a transient backend failure that is about to be retried.
+    pub fn log_backend_retry(
+        &self,
+        hat: &str,
+        attempt: u32,
+        max_retries: u32,
+        delay_ms: u64,
+        reason: &str,
+    ) {
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            hat,
+            crate::diagnostics::OrchestrationEvent::BackendRetry {
+                attempt,
+                max_retries,
+                delay_ms,
+                reason: reason.to_string(),
+            },
+        );
+    }
+
+    /// Logs child processes that outlived the process-group termination signal.
+    pub fn log_orphaned_processes(&self, pids: &[u32], killed: bool) {
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "loop",
+            crate::diagnostics::OrchestrationEvent::OrphanedProcesses {
+                pids: pids.to_vec(),
+                killed,
+            },
+        );
+    }
+
     /// Logs the full prompt for an iteration to the diagnostics session.
     pub fn log_prompt(&self, iteration: u32, hat: &str, prompt: &str) {
         self.diagnostics.log_prompt(iteration, hat, prompt);
@@ -625,6 +722,12 @@ impl EventLoop {
 
     /// Checks if any termination condition is met.
     pub fn check_termination(&self) -> Option<TerminationReason> {
+        if let Some(detail) = &self.config_error {
+            return Some(TerminationReason::ConfigError {
+                detail: detail.clone(),
+            });
+        }
+
         let cfg = &self.config.event_loop;
 
         if self.state.iteration >= cfg.max_iterations {
@@ -646,12 +749,16 @@ impl EventLoop {
         }
 
         // Check for loop thrashing: planner keeps dispatching abandoned tasks
-        if self.state.abandoned_task_redispatches >= 3 {
+        if cfg.max_abandoned_redispatches > 0
+            && self.state.abandoned_task_redispatches >= cfg.max_abandoned_redispatches
+        {
             return Some(TerminationReason::LoopThrashing);
         }
 
         // Check for validation failures: too many consecutive malformed JSONL lines
-        if self.state.consecutive_malformed_events >= 3 {
+        if cfg.max_malformed_events > 0
+            && self.state.consecutive_malformed_events >= cfg.max_malformed_events
+        {
             return Some(TerminationReason::ValidationFailure);
         }
 
@@ -671,6 +778,15 @@ impl EventLoop {
             return Some(TerminationReason::LoopStale);
         }
 
+        // Check for a Ctrl-C drain request (finish the iteration, then stop)
+        if self
+            .shutdown
+            .as_ref()
+            .is_some_and(ShutdownSignal::is_requested)
+        {
+            return Some(TerminationReason::Interrupted);
+        }
+
         // Check for stop signal from Telegram /stop or CLI stop-requested
         let stop_path =
             std::path::Path::new(&self.config.core.workspace_root).join(".ralph/stop-requested");
@@ -694,6 +810,16 @@ impl EventLoop {
         None
     }
 
+    /// Reports an unrecoverable configuration problem found at runtime.
+    ///
+    /// The next [`check_termination`](Self::check_termination) ends the loop
+    /// with [`TerminationReason::ConfigError`] carrying `detail`.
+    pub fn report_config_error(&mut self, detail: impl Into<String>) {
+        let detail = detail.into();
+        warn!("Configuration error: {}", detail);
+        self.config_error.get_or_insert(detail);
+    }
+
     /// Check if a loop.cancel event was detected.
     ///
     /// Unlike check_completion_event(), this does NOT validate required_events.
@@ -754,7 +880,8 @@ impl EventLoop {
                      Use loop.cancel to abort the workflow instead.",
                     missing
                 );
-                self.bus.publish(Event::new("task.resume", resume_payload));
+                self.bus
+                    .publish(EventBuilder::system("task.resume", resume_payload));
                 return None;
             }
         }
@@ -766,7 +893,7 @@ impl EventLoop {
                 "Rejecting LOOP_COMPLETE: human.guidance is unacknowledged"
             );
             self.state.completion_requested = false;
-            self.bus.publish(Event::new(
+            self.bus.publish(EventBuilder::system(
                 "task.resume",
                 format!(
                     "Completion rejected: {guidance_count} human.guidance message(s) remain unacknowledged. Address the guidance, then emit human.guidance.ack with a summary before emitting the completion promise."
@@ -790,7 +917,7 @@ impl EventLoop {
             );
 
             // Inject a task.resume event so the loop continues with an idle prompt
-            let resume_event = Event::new(
+            let resume_event = EventBuilder::system(
                 "task.resume",
                 "Persistent mode: loop staying alive after completion signal. \
                  Check for new tasks or await human guidance.",
@@ -809,7 +936,7 @@ impl EventLoop {
                     "Rejecting completion event with {} open task(s)",
                     open_tasks.len()
                 );
-                self.bus.publish(Event::new(
+                self.bus.publish(EventBuilder::system(
                     "task.resume",
                     format!(
                         "Completion rejected: runtime tasks remain open: {:?}. Close, fail, or reopen outstanding tasks before emitting the completion promise.",
@@ -819,6 +946,23 @@ impl EventLoop {
                 return None;
             }
         } else if let Ok(false) = self.verify_scratchpad_complete() {
+            if self.config.event_loop.completion_requires_tasks_done {
+                let pending = self.pending_scratchpad_tasks();
+                warn!(
+                    pending = ?pending,
+                    "Rejecting completion event with {} pending scratchpad task(s)",
+                    pending.len()
+                );
+                let event = EventBuilder::system(
+                    "task.resume",
+                    format!(
+                        "Completion rejected: scratchpad tasks remain pending:\n{}\nMark each one done (`- [x]`) or cancelled (`- [~]`) before emitting the completion promise.",
+                        pending.join("\n")
+                    ),
+                );
+                self.bus.publish(event);
+                return None;
+            }
             warn!("Completion event with pending scratchpad tasks - trusting agent decision");
         }
 
@@ -864,6 +1008,13 @@ impl EventLoop {
         // so without this the objective would be invisible to later hats.
         self.ralph.set_objective(prompt_content.to_string());
 
+        if self.config.features.drift_detection.enabled {
+            self.drift_detector = Some(DriftDetector::new(
+                prompt_content,
+                &self.config.features.drift_detection,
+            ));
+        }
+
         let start_event = Event::new(topic, prompt_content);
         self.bus.publish(start_event);
         debug!(topic = topic, "Published {} event", topic);
@@ -910,15 +1061,33 @@ impl EventLoop {
     ///
     /// Events at or before the last `loop.terminate` are state history only and
     /// must not be republished, otherwise `--continue` would rerun old work.
+    ///
+    /// When persisted loop state recorded an events offset (see
+    /// `PersistedLoopState::events_offset`), events before it were already
+    /// consumed and are never republished either; everything after it is new,
+    /// even if the prior session died without writing `loop.terminate`.
     pub fn replay_resume_events_from_jsonl(&mut self) -> std::io::Result<ProcessedEvents> {
         let path = self.event_reader.path().to_path_buf();
         let mut historical_reader = EventReader::new(&path);
-        let result = historical_reader.read_new_events()?;
+        let history = historical_reader.read_new_events()?;
 
-        for event in &result.events {
+        for event in &history.events {
             self.state.seen_topics.insert(event.topic.clone());
         }
 
+        let offset = self
+            .resume_events_offset
+            .take()
+            .filter(|offset| Self::is_valid_resume_offset(&path, *offset));
+        let result = match offset {
+            Some(offset) => {
+                let mut tail_reader = EventReader::new(&path);
+                tail_reader.set_position(offset);
+                tail_reader.read_new_events()?
+            }
+            None => history,
+        };
+
         let last_terminate_index = result
             .events
             .iter()
@@ -930,6 +1099,7 @@ impl EventLoop {
         };
         let replay_events = match last_terminate_index {
             Some(index) => result.events.into_iter().skip(index + 1).collect(),
+            None if offset.is_some() => result.events,
             None => Vec::new(),
         };
         let replay_malformed = match last_terminate_line {
@@ -938,6 +1108,7 @@ impl EventLoop {
                 .into_iter()
                 .filter(|malformed| malformed.line_number > line_number)
                 .collect(),
+            None if offset.is_some() => result.malformed,
             None => Vec::new(),
         };
 
@@ -953,6 +1124,29 @@ impl EventLoop {
         Ok(processed)
     }
 
+    /// A persisted offset is only trusted if it still lands on a line boundary
+    /// of the events file (the file may have been truncated or replaced).
+    fn is_valid_resume_offset(path: &Path, offset: u64) -> bool {
+        if offset == 0 {
+            return true;
+        }
+        let Ok(mut file) = std::fs::File::open(path) else {
+            return false;
+        };
+        let mut previous = [0u8; 1];
+        let valid = file.seek(SeekFrom::Start(offset - 1)).is_ok()
+            && file.read_exact(&mut previous).is_ok()
+            && previous[0] == b'\n';
+        if !valid {
+            warn!(
+                offset,
+                path = %path.display(),
+                "Ignoring persisted events offset that no longer matches the events file"
+            );
+        }
+        valid
+    }
+
     fn last_terminate_line_number(path: &Path) -> std::io::Result<Option<u64>> {
         if !path.exists() {
             return Ok(None);
@@ -1016,12 +1210,28 @@ impl EventLoop {
         S: Into<String>,
     {
         for message in messages {
-            let event = Event::new("human.guidance", message.into());
+            let event = EventBuilder::system("human.guidance", message);
             self.state.record_event(&event);
             self.bus.publish(event);
         }
     }
 
+    /// Queues `user.steer` messages for the next coordinator prompt.
+    ///
+    /// Steering is prepended to Ralph's prompt ahead of everything else, so it
+    /// takes priority over the events that triggered the iteration.
+    pub fn inject_steer<I, S>(&mut self, messages: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for message in messages {
+            let event = EventBuilder::system("user.steer", message);
+            self.state.record_event(&event);
+            self.pending_steer.push(event);
+        }
+    }
+
     /// Returns whether unread JSONL events include any semantic `plan.*` topics.
     ///
     /// This allows callers to dispatch `pre.plan.created` hooks before
@@ -1091,11 +1301,11 @@ impl EventLoop {
                     hat = %hat_id.as_str(),
                     "Injecting fallback event to recover - targeting last hat with task.resume"
                 );
-                Event::new("task.resume", payload).with_target(hat_id.clone())
+                EventBuilder::system("task.resume", payload).with_target(hat_id.clone())
             }
             _ => {
                 debug!("Injecting fallback event to recover - triggering Ralph with task.resume");
-                Event::new(
+                EventBuilder::system(
                     "task.resume",
                     "RECOVERY: Previous iteration did not publish an event. \
                      Review the scratchpad and either dispatch the next task or complete the loop.",
@@ -1121,6 +1331,16 @@ impl EventLoop {
     /// primed memories to the prompt context. If a scratchpad file exists and is
     /// non-empty, its content is also prepended (before memories).
     pub fn build_prompt(&mut self, hat_id: &HatId) -> Option<String> {
+        self.state.mark_iteration_start();
+        // The payload cap applies even when no prompt-specific limit is set
+        let payload_cap = self.config.event_loop.max_event_payload_chars;
+        let max_payload = Some(
+            self.config
+                .event_loop
+                .max_event_payload_in_prompt
+                .map_or(payload_cap, |max| max.min(payload_cap)),
+        );
+
         // Handle "ralph" hat - the constant coordinator
         // Per spec: "Hatless Ralph is constant — Cannot be replaced, overwritten, or configured away"
         if hat_id.as_str() == "ralph" {
@@ -1135,11 +1355,11 @@ impl EventLoop {
                     .into_iter()
                     .partition(|e| e.topic.as_str() == "human.guidance");
 
-                let events_context = regular_events
+                let event_lines = regular_events
                     .iter()
-                    .map(|e| Self::format_event(e))
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                    .map(|e| Self::format_event(e, max_payload))
+                    .collect::<Vec<_>>();
+                let events_context = event_lines.join("\n");
 
                 // Solo mode: set scratchpad and iteration before guidance persistence
                 self.ralph
@@ -1153,12 +1373,13 @@ impl EventLoop {
                 // Build base prompt and prepend memories + scratchpad + ready tasks
                 let base_prompt = self.ralph.build_prompt(&events_context, &[]);
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
+                let with_skills = self.prepend_auto_inject_skills(base_prompt, &events_context);
                 let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
+                let with_tasks = self.prepend_ready_tasks(with_scratchpad);
+                let final_prompt = self.prepend_steer(with_tasks);
 
                 debug!("build_prompt: routing to HatlessRalph (solo mode)");
-                return Some(final_prompt);
+                return Some(self.apply_prompt_budget(final_prompt, &event_lines));
             } else {
                 // Multi-hat mode: collect events and determine active hats
                 let mut all_hat_ids: Vec<HatId> = self.bus.hat_ids().cloned().collect();
@@ -1233,11 +1454,11 @@ impl EventLoop {
                 let active_hats = self.determine_active_hats(&regular_events);
 
                 // Format events for context
-                let events_context = effective_regular_events
+                let event_lines = effective_regular_events
                     .iter()
-                    .map(|e| Self::format_event(e))
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                    .map(|e| Self::format_event(e, max_payload))
+                    .collect::<Vec<_>>();
+                let events_context = event_lines.join("\n");
 
                 // Build base prompt and prepend memories + scratchpad if available
                 let base_prompt = self.ralph.build_prompt(&events_context, &active_hats);
@@ -1253,11 +1474,12 @@ impl EventLoop {
 
                 // Clear guidance after active_hats references are no longer needed
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
+                let with_skills = self.prepend_auto_inject_skills(base_prompt, &events_context);
                 let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
+                let with_tasks = self.prepend_ready_tasks(with_scratchpad);
+                let final_prompt = self.prepend_steer(with_tasks);
 
-                return Some(final_prompt);
+                return Some(self.apply_prompt_budget(final_prompt, &event_lines));
             }
         }
 
@@ -1265,11 +1487,11 @@ impl EventLoop {
         // next_hat() always returns "ralph" when custom hats are defined.
         // But we keep this code path for backward compatibility and tests.
         let events = self.bus.take_pending(&hat_id.clone());
-        let events_context = events
+        let event_lines = events
             .iter()
-            .map(|e| Self::format_event(e))
-            .collect::<Vec<_>>()
-            .join("\n");
+            .map(|e| Self::format_event(e, max_payload))
+            .collect::<Vec<_>>();
+        let events_context = event_lines.join("\n");
 
         let hat = self.registry.get(hat_id)?;
 
@@ -1293,10 +1515,41 @@ impl EventLoop {
             ScratchpadConfig::resolve(hat_scratchpad, &self.config.core.scratchpad);
         self.ralph
             .set_active_scratchpad(resolved_scratchpad.clone());
-        Some(
+        let prompt =
             self.instruction_builder
-                .build_custom_hat(hat, &events_context, &resolved_scratchpad),
-        )
+                .build_custom_hat(hat, &events_context, &resolved_scratchpad);
+        Some(self.apply_prompt_budget(prompt, &event_lines))
+    }
+
+    /// Trims an assembled prompt to `event_loop.prompt_budget_chars`, if set.
+    ///
+    /// Drops the context files list, then the oldest of `event_lines` (the
+    /// formatted pending events), and logs what was trimmed.
+    fn apply_prompt_budget(&self, prompt: String, event_lines: &[String]) -> String {
+        let Some(budget) = self.config.event_loop.prompt_budget_chars else {
+            return prompt;
+        };
+        let before = char_len(&prompt);
+        let (prompt, trim) = fit_prompt_to_budget(prompt, event_lines, budget);
+        let after = char_len(&prompt);
+        if !trim.is_empty() {
+            info!(
+                budget,
+                before,
+                after,
+                context_files = trim.context_files,
+                dropped_events = trim.dropped_events,
+                "Trimmed prompt to fit prompt_budget_chars"
+            );
+        }
+        if after > budget {
+            warn!(
+                budget,
+                chars = after,
+                "Prompt exceeds prompt_budget_chars after trimming; objective and hat instructions are kept whole"
+            );
+        }
+        prompt
     }
 
     /// Stores guidance payloads, persists them to scratchpad, and prepares them for prompt injection.
@@ -1399,11 +1652,14 @@ impl EventLoop {
     /// 1. Memory data + ralph-tools skill (special case: loads memory data from store, applies budget)
     /// 2. RObot interaction skill (gated by `robot.enabled`)
     /// 3. Other auto-inject skills from the registry (wrapped in XML tags)
-    fn prepend_auto_inject_skills(&self, prompt: String) -> String {
+    ///
+    /// `events_context` is the formatted pending events, used alongside the
+    /// objective to rank memories when `memories.ranking` is `relevance`.
+    fn prepend_auto_inject_skills(&self, prompt: String, events_context: &str) -> String {
         let mut prefix = String::new();
 
         // 1. Memory data + ralph-tools skill — special case with data loading
-        self.inject_memories_and_tools_skill(&mut prefix);
+        self.inject_memories_and_tools_skill(&mut prefix, events_context);
 
         // 2. RObot interaction skill — gated by robot.enabled
         self.inject_robot_skill(&mut prefix);
@@ -1422,12 +1678,15 @@ impl EventLoop {
 
     /// Injects memory data and the ralph-tools skill into the prefix.
     ///
-    /// Special case: loads memory entries from the store, applies budget
+    /// Special case: loads memory entries from the store, applies
+    /// `memories.filter`, optionally ranks them
+    /// by relevance to the objective and pending events, applies budget
     /// truncation, then appends the ralph-tools skill content (which covers
     /// both tasks and memories CLI usage).
     /// Memory data is gated by `memories.enabled && memories.inject == Auto`.
-    /// The ralph-tools skill is injected when either memories or tasks are enabled.
-    fn inject_memories_and_tools_skill(&self, prefix: &mut String) {
+    /// The ralph-tools skill is injected when either memories or tasks are enabled;
+    /// the memories skill additionally requires `memories.inject_skill`.
+    fn inject_memories_and_tools_skill(&self, prefix: &mut String, events_context: &str) {
         let memories_config = &self.config.memories;
 
         // Inject memory DATA if memories are enabled with auto-inject
@@ -1447,7 +1706,7 @@ impl EventLoop {
                 memories_path.exists()
             );
 
-            let memories = match store.load() {
+            let mut memories = match store.load() {
                 Ok(memories) => {
                     info!("Successfully loaded {} memories from store", memories.len());
                     memories
@@ -1461,9 +1720,38 @@ impl EventLoop {
                 }
             };
 
+            // Apply memories.filter (types, tags, recent) before ranking/budget
+            let today = chrono::Utc::now().date_naive();
+            let loaded = memories.len();
+            memories.retain(|m| memories_config.filter.matches(m, today));
+            if memories.len() < loaded {
+                debug!(
+                    "Memory filter kept {} of {} memories",
+                    memories.len(),
+                    loaded
+                );
+            }
+
             if memories.is_empty() {
                 info!("Memory store is empty - no memories to inject");
             } else {
+                let memories = if memories_config.ranking == MemoryRanking::Relevance {
+                    let context = format!(
+                        "{}\n{}",
+                        self.ralph.objective().unwrap_or_default(),
+                        events_context
+                    );
+                    let ranked = rank_by_relevance(memories, &context);
+                    let selected = select_within_budget(ranked, memories_config.budget);
+                    debug!(
+                        "Ranked memories by relevance; {} fit the budget",
+                        selected.len()
+                    );
+                    selected
+                } else {
+                    memories
+                };
+
                 let mut memories_content = format_memories_as_markdown(&memories);
 
                 if memories_config.budget > 0 {
@@ -1517,8 +1805,9 @@ impl EventLoop {
             debug!("Injected ralph-tools-tasks skill from registry");
         }
 
-        // Memories skill — only when memories are enabled
+        // Memories skill — only when memories are enabled and the skill isn't opted out
         if memories_config.enabled
+            && memories_config.inject_skill
             && let Some(skill) = self.skill_registry.get("ralph-tools-memories")
         {
             if !prefix.is_empty() {
@@ -1661,6 +1950,24 @@ impl EventLoop {
         final_prompt
     }
 
+    /// Prepends queued `user.steer` events to the coordinator prompt.
+    fn prepend_steer(&mut self, prompt: String) -> String {
+        if self.pending_steer.is_empty() {
+            return prompt;
+        }
+
+        let steer = std::mem::take(&mut self.pending_steer)
+            .iter()
+            .map(|e| Self::format_event(e, None))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "## STEERING\n\nThe user sent steering while the previous iteration ran. \
+             It takes priority over the events below.\n\n{}\n\n{}",
+            steer, prompt
+        )
+    }
+
     /// Prepends ready tasks to the prompt if tasks are enabled and any exist.
     ///
     /// Loads the task store and formats ready (unblocked, open) tasks into
@@ -1861,7 +2168,8 @@ impl EventLoop {
     ///
     /// For top-level prompts (task.start, task.resume), wraps the payload in
     /// `<top-level-prompt>` XML tags to clearly delineate the user's original request.
-    fn format_event(event: &Event) -> String {
+    /// Other payloads are truncated to `max_payload` characters when set.
+    fn format_event(event: &Event, max_payload: Option<usize>) -> String {
         let topic = &event.topic;
         let payload = &event.payload;
 
@@ -1870,6 +2178,12 @@ impl EventLoop {
                 "Event: {} - <top-level-prompt>\n{}\n</top-level-prompt>",
                 topic, payload
             )
+        } else if let Some(max) = max_payload {
+            format!(
+                "Event: {} - {}",
+                topic,
+                truncate_with_ellipsis(payload, max)
+            )
         } else {
             format!("Event: {} - {}", topic, payload)
         }
@@ -1914,13 +2228,12 @@ impl EventLoop {
             "Hat exhausted (max_activations reached)"
         );
 
-        (
-            true,
-            Some(Event::new(
-                format!("{}.exhausted", hat_id.as_str()),
-                payload,
-            )),
-        )
+        let exhausted_event = EventBuilder::new(format!("{}.exhausted", hat_id.as_str()))
+            .payload(payload)
+            .build()
+            .inspect_err(|e| warn!(hat = %hat_id.as_str(), "Cannot publish exhaustion event: {e}"))
+            .ok();
+        (true, exhausted_event)
     }
 
     fn record_hat_activations(&mut self, active_hat_ids: &[HatId]) {
@@ -1967,7 +2280,7 @@ impl EventLoop {
                     topic = %default_topic,
                     "default_publishes matches completion_promise — requiring explicit agent event"
                 );
-                let resume_event = Event::new(
+                let resume_event = EventBuilder::system(
                     "task.resume",
                     format!(
                         "Recovery: hat `{}` produced no events, and its default_publishes is the completion promise `{}`. Ralph will not complete silently; emit explicit completion evidence or a valid non-terminal event.",
@@ -2013,6 +2326,7 @@ impl EventLoop {
     ) -> Option<TerminationReason> {
         self.state.iteration += 1;
         self.state.last_hat = Some(hat_id.clone());
+        self.state.record_iteration_timing(hat_id);
 
         // Periodic robot check-in
         if let Some(interval_secs) = self.config.robot.checkin_interval_seconds
@@ -2064,7 +2378,7 @@ impl EventLoop {
             self.state.consecutive_failures += 1;
         }
 
-        let _ = output;
+        self.flag_misplaced_completion(hat_id, output);
 
         // File-modification audit: detect when a hat with disallowed Edit/Write tools
         // modified files. This is hard enforcement — emits a scope_violation event.
@@ -2078,6 +2392,84 @@ impl EventLoop {
         self.check_termination()
     }
 
+    /// Caps a hat's raw output at the hat's `max_output_bytes` budget.
+    ///
+    /// Call before `process_output` and completion-promise checks so a verbose
+    /// hat cannot inflate logs and downstream prompts. The middle of oversized
+    /// output is elided, keeping the head and the tail (where a completion
+    /// promise lives). When Ralph coordinates custom hats, the tightest budget
+    /// among the hats active this iteration applies.
+    pub fn cap_hat_output(&self, hat_id: &HatId, output: String) -> String {
+        let limit = if let Some(config) = self.registry.get_config(hat_id) {
+            config.max_output_bytes
+        } else {
+            self.state
+                .last_active_hat_ids
+                .iter()
+                .filter_map(|id| self.registry.get_config(id))
+                .filter_map(|c| c.max_output_bytes)
+                .min()
+        };
+
+        let Some(limit) = limit else {
+            return output;
+        };
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        if output.len() <= limit {
+            return output;
+        }
+
+        warn!(
+            hat = %hat_id.as_str(),
+            bytes = output.len(),
+            max_output_bytes = limit,
+            "Hat output exceeds max_output_bytes; truncating before processing"
+        );
+        let marker = format!(
+            "[... hat output truncated: {} bytes exceeded max_output_bytes ({}) ...]",
+            output.len(),
+            limit
+        );
+        elide_middle(&output, limit, &marker)
+    }
+
+    /// Warns when a hat other than Ralph outputs the completion promise.
+    ///
+    /// Only Ralph may complete the loop, so the promise is ignored either way;
+    /// this publishes a `completion.misplaced` event (and a diagnostics entry)
+    /// so the misconfiguration is visible instead of silently dropped.
+    fn flag_misplaced_completion(&mut self, hat_id: &HatId, output: &str) {
+        if hat_id.as_str() == "ralph" || !self.check_ralph_completion(output) {
+            return;
+        }
+
+        let promise = self.config.event_loop.completion_promise.clone();
+        warn!(
+            hat = %hat_id.as_str(),
+            promise = %promise,
+            "Completion promise output by a non-Ralph hat; ignoring"
+        );
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "loop",
+            crate::diagnostics::OrchestrationEvent::CompletionMisplaced {
+                hat: hat_id.to_string(),
+                topic: promise.clone(),
+            },
+        );
+        let event = EventBuilder::system(
+            "completion.misplaced",
+            format!(
+                "Hat '{}' output the completion promise '{}', but only Ralph may complete \
+                 the loop. The promise was ignored; have the hat publish its result event \
+                 and let Ralph decide when the work is done.",
+                hat_id.as_str(),
+                promise
+            ),
+        );
+        self.bus.publish(event);
+    }
+
     /// Audits file modifications after a hat iteration.
     ///
     /// If the hat has `Edit` or `Write` in its `disallowed_tools`, checks whether
@@ -2113,16 +2505,21 @@ impl EventLoop {
                     "Hat modified files despite tool restrictions (scope violation)"
                 );
 
-                let violation_topic = format!("{}.scope_violation", hat_id.as_str());
-                let violation = Event::new(
-                    violation_topic.as_str(),
-                    format!(
+                let violation = EventBuilder::new(format!("{}.scope_violation", hat_id.as_str()))
+                    .payload(format!(
                         "Hat '{}' modified files with Edit/Write disallowed:\n{}",
                         hat_id.as_str(),
                         diff_stat
-                    ),
-                );
-                self.bus.publish(violation);
+                    ))
+                    .build();
+                match violation {
+                    Ok(violation) => {
+                        self.bus.publish(violation);
+                    }
+                    Err(e) => {
+                        warn!(hat = %hat_id.as_str(), "Cannot publish scope violation event: {e}");
+                    }
+                }
             }
             Err(e) => {
                 debug!(error = %e, "Could not run git diff for file-modification audit");
@@ -2151,9 +2548,11 @@ impl EventLoop {
     ///
     /// Returns:
     /// - `Ok(true)` if all tasks are `[x]` or `[~]`, or if scratchpad is disabled
-    /// - `Ok(false)` if any tasks are `[ ]` (pending)
+    /// - `Ok(false)` if any tasks are `[ ]` (pending) or `[>]` (in progress)
     /// - `Err(...)` if scratchpad doesn't exist or can't be read
-    fn verify_scratchpad_complete(&self) -> Result<bool, std::io::Error> {
+    ///
+    /// The parsed result is cached until the file's size or mtime changes.
+    fn verify_scratchpad_complete(&mut self) -> Result<bool, std::io::Error> {
         // Nothing to verify when scratchpad is disabled
         if !self.ralph.active_scratchpad().enabled {
             return Ok(true);
@@ -2168,15 +2567,25 @@ impl EventLoop {
             ));
         }
 
-        let content = std::fs::read_to_string(scratchpad_path)?;
-
-        let has_pending = content
-            .lines()
-            .any(|line| line.trim_start().starts_with("- [ ]"));
+        let has_pending = self.scratchpad_cache.has_pending_tasks(&scratchpad_path)?;
 
         Ok(!has_pending)
     }
 
+    /// Returns the scratchpad's unfinished (`- [ ]` or `- [>]`) task lines, trimmed.
+    fn pending_scratchpad_tasks(&self) -> Vec<String> {
+        std::fs::read_to_string(self.scratchpad_path())
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| is_unfinished_task(line))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Reads the current loop ID from the marker file.
     ///
     /// Returns `None` if no marker exists or is empty, which means
@@ -2276,6 +2685,58 @@ impl EventLoop {
         vec![]
     }
 
+    /// Runs `event_loop.build_verification` and returns the event to publish:
+    /// the original `build.done` if the command passes, otherwise a
+    /// `build.blocked` carrying the command's output.
+    fn verify_build_done(&mut self, verification: &Verification, payload: &str) -> Event {
+        let workspace = Path::new(&self.config.core.workspace_root);
+        let failure = match run_verification_in(workspace, verification) {
+            Ok(result) if result.passed => {
+                info!(
+                    command = %verification.command,
+                    "build.done accepted: verification command passed"
+                );
+                if let Some(evidence) = EventParser::parse_backpressure_evidence(payload) {
+                    self.warn_on_mutation_evidence(&evidence);
+                }
+                return Event::new("build.done", payload);
+            }
+            Ok(result) => {
+                let output = format!("{}{}", result.stdout, result.stderr);
+                format!(
+                    "{}.\n\n{}",
+                    result.summary(),
+                    elide_middle(
+                        output.trim(),
+                        BUILD_VERIFICATION_OUTPUT_MAX_BYTES,
+                        "[... verification output truncated ...]"
+                    )
+                )
+            }
+            Err(e) => e.to_string(),
+        };
+
+        warn!(
+            command = %verification.command,
+            "build.done rejected: verification command failed"
+        );
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "jsonl",
+            crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
+                reason: format!("build verification failed: {}", verification.command),
+            },
+        );
+
+        EventBuilder::system(
+            "build.blocked",
+            format!(
+                "Build verification failed. Fix the failures reported by `{}` before emitting build.done.\n{}",
+                verification.command, failure
+            ),
+        )
+    }
+
     fn warn_on_mutation_evidence(&self, evidence: &crate::event_parser::BackpressureEvidence) {
         let threshold = self.config.event_loop.mutation_score_warn_threshold;
 
@@ -2432,7 +2893,7 @@ impl EventLoop {
                 "Line {}: {}\nContent: {}",
                 malformed.line_number, malformed.error, &malformed.content
             );
-            let event = Event::new("event.malformed", &payload);
+            let event = EventBuilder::system("event.malformed", payload);
             self.bus.publish(event);
             self.state.consecutive_malformed_events += 1;
             warn!(
@@ -2486,14 +2947,21 @@ impl EventLoop {
                     topic = %event.topic,
                     "Scope violation: active hat(s) cannot publish this topic — dropping event"
                 );
-                let violation_topic = format!("{}.scope_violation", violation_hat);
-                let violation_payload = format!(
-                    "Attempted to publish '{}': {}",
-                    event.topic,
-                    event.payload.clone().unwrap_or_default()
-                );
-                let violation = Event::new(violation_topic, violation_payload);
-                self.bus.publish(violation);
+                let violation = EventBuilder::new(format!("{}.scope_violation", violation_hat))
+                    .payload(format!(
+                        "Attempted to publish '{}': {}",
+                        event.topic,
+                        event.payload.clone().unwrap_or_default()
+                    ))
+                    .build();
+                match violation {
+                    Ok(violation) => {
+                        self.bus.publish(violation);
+                    }
+                    Err(e) => {
+                        warn!(hat = %violation_hat, "Cannot publish scope violation event: {e}");
+                    }
+                }
             }
 
             in_scope
@@ -2565,7 +3033,12 @@ impl EventLoop {
                 continue;
             }
 
-            if event.topic == "build.done" {
+            if event.topic == "build.done"
+                && let Some(verification) = self.config.event_loop.build_verification.clone()
+            {
+                // An independent check replaces the self-reported evidence
+                validated_events.push(self.verify_build_done(&verification, &payload));
+            } else if event.topic == "build.done" {
                 // Validate build.done events have backpressure evidence
                 if let Some(evidence) = EventParser::parse_backpressure_evidence(&payload) {
                     if evidence.all_passed() {
@@ -2620,7 +3093,7 @@ impl EventLoop {
                             },
                         );
 
-                        validated_events.push(Event::new(
+                        validated_events.push(EventBuilder::system(
                             "build.blocked",
                             "Backpressure checks failed. Fix tests/lint/typecheck/audit/coverage/complexity/duplication/specs before emitting build.done.",
                         ));
@@ -2637,7 +3110,7 @@ impl EventLoop {
                         },
                     );
 
-                    validated_events.push(Event::new(
+                    validated_events.push(EventBuilder::system(
                         "build.blocked",
                         "Missing backpressure evidence. Include 'tests: pass', 'lint: pass', 'typecheck: pass', 'audit: pass', 'coverage: pass', 'complexity: <score>', 'duplication: pass', 'performance: pass' (optional), 'specs: pass' (optional) in build.done payload.",
                     ));
@@ -2668,7 +3141,7 @@ impl EventLoop {
                             },
                         );
 
-                        validated_events.push(Event::new(
+                        validated_events.push(EventBuilder::system(
                             "review.blocked",
                             "Review verification failed. Run tests and build before emitting review.done.",
                         ));
@@ -2685,7 +3158,7 @@ impl EventLoop {
                         },
                     );
 
-                    validated_events.push(Event::new(
+                    validated_events.push(EventBuilder::system(
                         "review.blocked",
                         "Missing verification evidence. Include 'tests: pass' and 'build: pass' in review.done payload.",
                     ));
@@ -2715,7 +3188,7 @@ impl EventLoop {
                             },
                         );
 
-                        validated_events.push(Event::new(
+                        validated_events.push(EventBuilder::system(
                             "verify.failed",
                             "Quality thresholds failed. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity with thresholds in verify.passed payload.",
                         ));
@@ -2732,7 +3205,7 @@ impl EventLoop {
                         },
                     );
 
-                    validated_events.push(Event::new(
+                    validated_events.push(EventBuilder::system(
                         "verify.failed",
                         "Missing quality report. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity in verify.passed payload.",
                     ));
@@ -2754,27 +3227,35 @@ impl EventLoop {
             .filter(|e| e.topic == "build.blocked".into())
             .collect();
 
+        let max_task_blocks = self.config.event_loop.max_task_blocks;
         for blocked_event in &blocked_events {
             let task_id = Self::extract_task_id(&blocked_event.payload);
 
-            let count = self
-                .state
-                .task_block_counts
-                .entry(task_id.clone())
-                .or_insert(0);
-            *count += 1;
+            let count = {
+                let count = self
+                    .state
+                    .task_block_counts
+                    .entry(task_id.clone())
+                    .or_insert(0);
+                *count += 1;
+                *count
+            };
 
             debug!(
                 task_id = %task_id,
-                block_count = *count,
+                block_count = count,
                 "Task blocked"
             );
 
-            // After 3 blocks on same task, emit build.task.abandoned
-            if *count >= 3 && !self.state.abandoned_tasks.contains(&task_id) {
+            // After max_task_blocks blocks on same task, emit build.task.abandoned
+            if max_task_blocks > 0
+                && count >= max_task_blocks
+                && !self.state.abandoned_tasks.contains(&task_id)
+            {
                 warn!(
                     task_id = %task_id,
-                    "Task abandoned after 3 consecutive blocks"
+                    block_count = count,
+                    "Task abandoned after consecutive blocks"
                 );
 
                 self.state.abandoned_tasks.push(task_id.clone());
@@ -2784,17 +3265,17 @@ impl EventLoop {
                     "jsonl",
                     crate::diagnostics::OrchestrationEvent::TaskAbandoned {
                         reason: format!(
-                            "3 consecutive build.blocked events for task '{}'",
-                            task_id
+                            "{} consecutive build.blocked events for task '{}'",
+                            count, task_id
                         ),
                     },
                 );
 
-                let abandoned_event = Event::new(
+                let abandoned_event = EventBuilder::system(
                     "build.task.abandoned",
                     format!(
-                        "Task '{}' abandoned after 3 consecutive build.blocked events",
-                        task_id
+                        "Task '{}' abandoned after {} consecutive build.blocked events",
+                        task_id, count
                     ),
                 );
 
@@ -2802,6 +3283,26 @@ impl EventLoop {
             }
         }
 
+        // Objective-drift heuristic: compare this iteration's activity to the objective
+        if let Some(detector) = self.drift_detector.as_mut() {
+            let activity: Vec<String> = validated_events
+                .iter()
+                .filter(|e| !e.topic.as_str().starts_with("task."))
+                .map(|e| format!("{} {}", e.topic, e.payload))
+                .collect();
+            if let Some(report) = detector.observe_iteration(&activity) {
+                warn!(
+                    iterations = report.iterations,
+                    overlap = report.overlap,
+                    "Objective drift detected"
+                );
+                self.bus.publish(EventBuilder::system(
+                    crate::drift_detection::DRIFT_TOPIC,
+                    report.payload(),
+                ));
+            }
+        }
+
         // Track hat-level blocking for legacy thrashing detection
         let has_blocked_event = !blocked_events.is_empty();
 
@@ -2912,7 +3413,7 @@ impl EventLoop {
                                 Value::String("response".to_string()),
                             );
                             context.insert("response".to_string(), Value::String(response.clone()));
-                            let event = Event::new("human.response", &response);
+                            let event = EventBuilder::system("human.response", &response);
                             if robot_service.response_events_are_durable() {
                                 events_arrived_during_robot_wait.extend(
                                     self.skip_consumed_robot_response_event(
@@ -2949,7 +3450,7 @@ impl EventLoop {
                                 "timeout_seconds".to_string(),
                                 Value::from(robot_service.timeout_secs()),
                             );
-                            let timeout_event = Event::new(
+                            let timeout_event = EventBuilder::system(
                                 "human.timeout",
                                 format!(
                                     "No response after {}s. Original question: {}",
@@ -2969,7 +3470,7 @@ impl EventLoop {
                                 Value::String("wait_error".to_string()),
                             );
                             context.insert("error".to_string(), Value::String(e.to_string()));
-                            let timeout_event = Event::new(
+                            let timeout_event = EventBuilder::system(
                                 "human.timeout",
                                 format!(
                                     "Error waiting for response: {}. Original question: {}",
@@ -3307,7 +3808,7 @@ impl EventLoop {
         let elapsed = self.state.elapsed();
         let duration_str = format_duration(elapsed);
 
-        let payload = format!(
+        let mut payload = format!(
             "## Reason\n{}\n\n## Status\n{}\n\n## Summary\n- Iterations: {}\n- Duration: {}\n- Exit code: {}",
             reason.as_str(),
             termination_status_text(reason),
@@ -3315,12 +3816,24 @@ impl EventLoop {
             duration_str,
             reason.exit_code()
         );
+        if let TerminationReason::ConfigError { detail } = reason {
+            payload.push_str("\n\n## Detail\n");
+            payload.push_str(detail);
+        }
+        if let Some(timing) = format_iteration_timings(self.state.iteration_timings()) {
+            payload.push_str("\n\n## Timing\n");
+            payload.push_str(&timing);
+        }
 
-        let event = Event::new("loop.terminate", &payload);
+        let event = EventBuilder::system("loop.terminate", payload);
 
         // Publish to bus for observers (but no hat can trigger on this)
         self.bus.publish(event.clone());
 
+        // Every exit path publishes this event, so the run summary is written here.
+        self.diagnostics
+            .write_summary(self.state.iteration, reason.as_str());
+
         info!(
             reason = %reason.as_str(),
             iterations = self.state.iteration,
@@ -3423,6 +3936,43 @@ fn format_duration(d: Duration) -> String {
     }
 }
 
+/// Iterations listed individually in the `loop.terminate` timing section.
+const MAX_TIMED_ITERATIONS_IN_PAYLOAD: usize = 10;
+
+/// Compact per-iteration timing for the `loop.terminate` payload.
+///
+/// Short runs list every iteration; longer ones list the slowest
+/// [`MAX_TIMED_ITERATIONS_IN_PAYLOAD`] in iteration order.
+fn format_iteration_timings(timings: &[IterationTiming]) -> Option<String> {
+    if timings.is_empty() {
+        return None;
+    }
+    let mut shown: Vec<&IterationTiming> = timings.iter().collect();
+    let mut header = String::new();
+    if shown.len() > MAX_TIMED_ITERATIONS_IN_PAYLOAD {
+        shown.sort_by_key(|timing| std::cmp::Reverse(timing.duration()));
+        shown.truncate(MAX_TIMED_ITERATIONS_IN_PAYLOAD);
+        shown.sort_by_key(|timing| timing.iteration);
+        header = format!(
+            "Slowest {} of {} iterations:\n",
+            MAX_TIMED_ITERATIONS_IN_PAYLOAD,
+            timings.len()
+        );
+    }
+    let lines: Vec<String> = shown
+        .iter()
+        .map(|timing| {
+            format!(
+                "- #{} {}: {}",
+                timing.iteration,
+                timing.hat,
+                format_duration(timing.duration())
+            )
+        })
+        .collect();
+    Some(header + &lines.join("\n"))
+}
+
 /// Returns a human-readable status based on termination reason.
 fn termination_status_text(reason: &TerminationReason) -> &'static str {
     match reason {
@@ -3443,5 +3993,8 @@ fn termination_status_text(reason: &TerminationReason) -> &'static str {
         TerminationReason::RestartRequested => "Restarting by human request.",
         TerminationReason::WorkspaceGone => "Workspace directory removed externally.",
         TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout).",
+        TerminationReason::ConfigError { .. } => {
+            "Configuration error - fix the config and resume with `ralph run --continue`."
+        }
     }
 }
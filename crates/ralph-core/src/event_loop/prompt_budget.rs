@@ -0,0 +1,151 @@
+//! Overall size cap for the assembled prompt (`event_loop.prompt_budget_chars`).
+//!
+//! The cap is enforced after assembly by trimming the lowest-priority content
+//! first: the AVAILABLE CONTEXT FILES list, then pending events, oldest first.
+//! The newest pending event, the OBJECTIVE and the active hat's instructions
+//! are never trimmed, so a prompt can still end up over budget.
+
+use std::ops::Range;
+
+const CONTEXT_FILES_HEADING: &str = "### AVAILABLE CONTEXT FILES\n";
+
+/// What [`fit_prompt_to_budget`] removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PromptTrim {
+    /// The AVAILABLE CONTEXT FILES list was dropped.
+    pub context_files: bool,
+    /// Number of oldest pending events dropped.
+    pub dropped_events: usize,
+}
+
+impl PromptTrim {
+    pub fn is_empty(self) -> bool {
+        self == Self::default()
+    }
+}
+
+/// Trims `prompt` towards `budget` characters.
+///
+/// `events` are the formatted pending events, oldest first, exactly as they
+/// were joined into the prompt.
+pub(super) fn fit_prompt_to_budget(
+    mut prompt: String,
+    events: &[String],
+    budget: usize,
+) -> (String, PromptTrim) {
+    let mut trim = PromptTrim::default();
+    if char_len(&prompt) <= budget {
+        return (prompt, trim);
+    }
+
+    if let Some(range) = context_files_range(&prompt) {
+        prompt.replace_range(range, "");
+        trim.context_files = true;
+    }
+
+    let overflow = char_len(&prompt).saturating_sub(budget);
+    if overflow == 0 || events.len() < 2 {
+        return (prompt, trim);
+    }
+    let joined = events.join("\n");
+    // Scratchpad and steering are prepended, so the events section is the last copy
+    let Some(start) = prompt.rfind(&joined) else {
+        return (prompt, trim);
+    };
+
+    let original_len = char_len(&joined);
+    let mut replacement = joined.clone();
+    for dropped in 1..events.len() {
+        replacement = format!(
+            "({dropped} older events omitted to fit the prompt budget)\n{}",
+            events[dropped..].join("\n")
+        );
+        trim.dropped_events = dropped;
+        if original_len.saturating_sub(char_len(&replacement)) >= overflow {
+            break;
+        }
+    }
+    prompt.replace_range(start..start + joined.len(), &replacement);
+    (prompt, trim)
+}
+
+pub(super) fn char_len(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Byte range of the AVAILABLE CONTEXT FILES section: the heading, a blank
+/// line, then the intro and file list up to the next blank line.
+fn context_files_range(prompt: &str) -> Option<Range<usize>> {
+    let start = prompt.find(CONTEXT_FILES_HEADING)?;
+    let body = start + CONTEXT_FILES_HEADING.len() + 1;
+    let end = body + prompt.get(body..)?.find("\n\n")? + 2;
+    Some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTEXT_FILES: &str = "### AVAILABLE CONTEXT FILES\n\nContext files in `.ralph/agent/` (read if relevant to current work):\n- `.ralph/agent/notes.md`\n\n";
+
+    fn events(count: usize) -> Vec<String> {
+        (1..=count)
+            .map(|n| format!("Event: work.step - step {n} {}", "x".repeat(40)))
+            .collect()
+    }
+
+    fn prompt_with(events: &[String]) -> String {
+        format!(
+            "{CONTEXT_FILES}### GUARDRAILS\n999. Rule\n\n## OBJECTIVE\n\n> Ship it\n\n## PENDING EVENTS\n\nYou MUST handle these events in this iteration:\n\n{}\n\n## EVENT WRITING\n",
+            events.join("\n")
+        )
+    }
+
+    #[test]
+    fn prompt_within_budget_is_untouched() {
+        let events = events(2);
+        let prompt = prompt_with(&events);
+        let (fitted, trim) = fit_prompt_to_budget(prompt.clone(), &events, usize::MAX);
+        assert_eq!(fitted, prompt);
+        assert!(trim.is_empty());
+    }
+
+    #[test]
+    fn context_files_are_dropped_before_events() {
+        let events = events(3);
+        let prompt = prompt_with(&events);
+        let budget = char_len(&prompt) - 10;
+
+        let (fitted, trim) = fit_prompt_to_budget(prompt, &events, budget);
+        assert_eq!(
+            trim,
+            PromptTrim {
+                context_files: true,
+                dropped_events: 0
+            }
+        );
+        assert!(!fitted.contains("AVAILABLE CONTEXT FILES"));
+        assert!(fitted.starts_with("### GUARDRAILS"));
+        assert!(fitted.contains("step 1"));
+    }
+
+    #[test]
+    fn oldest_events_are_dropped_and_newest_kept() {
+        let events = events(4);
+        let prompt = prompt_with(&events);
+        let budget = char_len(&prompt) - CONTEXT_FILES.len() - 60;
+
+        let (fitted, trim) = fit_prompt_to_budget(prompt, &events, budget);
+        assert!(trim.context_files);
+        assert_eq!(trim.dropped_events, 2);
+        assert!(char_len(&fitted) <= budget);
+        assert!(fitted.contains("(2 older events omitted to fit the prompt budget)\n"));
+        assert!(!fitted.contains("step 2 "));
+        assert!(fitted.contains("step 3 ") && fitted.contains("step 4 "));
+        assert!(fitted.contains("> Ship it"));
+
+        let (fitted, trim) = fit_prompt_to_budget(prompt_with(&events), &events, 0);
+        assert_eq!(trim.dropped_events, 3);
+        assert!(fitted.contains("step 4 "));
+    }
+}
@@ -0,0 +1,217 @@
+//! Deterministic replay of a recorded events file.
+//!
+//! Drives the loop from the events a real run wrote instead of a backend, so
+//! routing, backpressure and completion bugs can be reproduced exactly. Each
+//! `iteration.summary` row the loop logged closes one iteration; the agent
+//! events before it are fed back as that iteration's output. Rows the loop
+//! wrote itself (`hat: "loop"`) and system topics are not replayed, since the
+//! replayed loop produces its own. Replay stops at the first `loop.terminate`.
+
+use super::{EventLoop, TerminationReason};
+use crate::event_logger::is_system_topic;
+use crate::event_reader::{Event, MalformedLine, ParseResult};
+use serde::Deserialize;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use tracing::debug;
+
+/// Result of [`EventLoop::replay_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayOutcome {
+    /// Iterations the replayed loop ran.
+    pub iterations: u32,
+    /// Why the loop stopped, or `None` if the recording ran out first.
+    pub termination: Option<TerminationReason>,
+}
+
+/// A recorded run, split into the agent output of each iteration.
+#[derive(Debug, Default)]
+struct Recording {
+    /// Payload of the start event the loop logged, if any.
+    prompt: Option<String>,
+    iterations: Vec<ParseResult>,
+}
+
+/// The fields of an events-file row that say who wrote it.
+#[derive(Deserialize)]
+struct RowOrigin {
+    #[serde(default)]
+    hat: String,
+}
+
+impl Recording {
+    fn load(path: &Path) -> std::io::Result<Self> {
+        let reader = BufReader::new(std::fs::File::open(path)?);
+        let mut recording = Self::default();
+        let mut current = ParseResult::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = match serde_json::from_str::<Event>(&line) {
+                Ok(event) => event,
+                Err(e) => {
+                    let line_number = index as u64 + 1;
+                    current
+                        .malformed
+                        .push(MalformedLine::new(line_number, &line, e.to_string()));
+                    continue;
+                }
+            };
+            let from_loop =
+                serde_json::from_str::<RowOrigin>(&line).is_ok_and(|origin| origin.hat == "loop");
+
+            match event.topic.as_str() {
+                "loop.terminate" => break,
+                "iteration.summary" => recording.iterations.push(std::mem::take(&mut current)),
+                _ if from_loop => {
+                    if recording.prompt.is_none() && recording.iterations.is_empty() {
+                        recording.prompt = event.payload;
+                    }
+                }
+                topic if is_system_topic(topic) => {}
+                _ => current.events.push(event),
+            }
+        }
+
+        if !current.events.is_empty() || !current.malformed.is_empty() {
+            recording.iterations.push(current);
+        }
+        Ok(recording)
+    }
+}
+
+impl EventLoop {
+    /// Replays a recorded events file through the loop without a backend.
+    ///
+    /// If nothing is pending yet, the loop is initialized with the recorded
+    /// start prompt. Each iteration then selects a hat and builds its prompt
+    /// as a live run would, and publishes the events that iteration recorded
+    /// in place of backend output. Intended for tests and debugging.
+    pub fn replay_events(&mut self, path: &Path) -> std::io::Result<ReplayOutcome> {
+        let recording = Recording::load(path)?;
+        if !self.has_pending_events() {
+            self.initialize(recording.prompt.as_deref().unwrap_or_default());
+        }
+
+        let mut iterations = recording.iterations.into_iter();
+        let termination = loop {
+            if let Some(reason) = self.check_termination() {
+                break Some(reason);
+            }
+            let hat_id = match self.next_hat() {
+                Some(hat_id) => hat_id.clone(),
+                None => {
+                    self.inject_fallback_event();
+                    continue;
+                }
+            };
+            let Some(recorded) = iterations.next() else {
+                break None;
+            };
+
+            let _ = self.build_prompt(&hat_id);
+            debug!(
+                iteration = self.state.iteration + 1,
+                hat = %hat_id,
+                events = recorded.events.len(),
+                "Replaying recorded iteration"
+            );
+            if let Some(reason) = self.process_output(&hat_id, "", true) {
+                break Some(reason);
+            }
+            self.process_parse_result(recorded)?;
+
+            if let Some(reason) = self
+                .check_cancellation_event()
+                .or_else(|| self.check_completion_event())
+            {
+                break Some(reason);
+            }
+        };
+
+        Ok(ReplayOutcome {
+            iterations: self.state.iteration,
+            termination,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RalphConfig;
+    use tempfile::TempDir;
+
+    fn write_recording(dir: &TempDir, lines: &[&str]) -> std::path::PathBuf {
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        path
+    }
+
+    #[test]
+    fn recording_splits_iterations_and_skips_loop_rows() {
+        let dir = TempDir::new().unwrap();
+        let path = write_recording(
+            &dir,
+            &[
+                r#"{"ts":"t","iteration":0,"hat":"loop","topic":"task.start","payload":"Build it"}"#,
+                r#"{"ts":"t","topic":"build.task","payload":"step 1"}"#,
+                r#"{"ts":"t","iteration":1,"hat":"loop","topic":"iteration.summary","payload":"{}"}"#,
+                r#"{"ts":"t","topic":"build.done"}"#,
+                "not json",
+                r#"{"ts":"t","topic":"event.orphaned","payload":"x"}"#,
+                r#"{"ts":"t","iteration":2,"hat":"loop","topic":"iteration.summary","payload":"{}"}"#,
+                r#"{"ts":"t","iteration":2,"hat":"loop","topic":"loop.terminate","payload":"done"}"#,
+                r#"{"ts":"t","topic":"after.terminate"}"#,
+            ],
+        );
+
+        let recording = Recording::load(&path).unwrap();
+        assert_eq!(recording.prompt.as_deref(), Some("Build it"));
+        assert_eq!(recording.iterations.len(), 2);
+        assert_eq!(recording.iterations[0].events[0].topic, "build.task");
+        assert_eq!(recording.iterations[1].events.len(), 1);
+        assert_eq!(recording.iterations[1].malformed[0].line_number, 5);
+    }
+
+    #[test]
+    fn replay_events_completes_when_recording_emits_completion() {
+        let dir = TempDir::new().unwrap();
+        let path = write_recording(
+            &dir,
+            &[
+                r#"{"ts":"t","iteration":0,"hat":"loop","topic":"task.start","payload":"Do it"}"#,
+                r#"{"ts":"t","topic":"work.progress","payload":"halfway"}"#,
+                r#"{"ts":"t","iteration":1,"hat":"loop","topic":"iteration.summary","payload":"{}"}"#,
+                r#"{"ts":"t","topic":"LOOP_COMPLETE","payload":"done"}"#,
+                r#"{"ts":"t","iteration":2,"hat":"loop","topic":"iteration.summary","payload":"{}"}"#,
+            ],
+        );
+
+        let mut event_loop = EventLoop::new(RalphConfig::default());
+        let outcome = event_loop.replay_events(&path).unwrap();
+        assert_eq!(outcome.iterations, 2);
+        assert_eq!(
+            outcome.termination,
+            Some(TerminationReason::CompletionPromise)
+        );
+        assert!(event_loop.state().seen_topics.contains("work.progress"));
+    }
+
+    #[test]
+    fn replay_events_reports_exhausted_recording() {
+        let dir = TempDir::new().unwrap();
+        let path = write_recording(
+            &dir,
+            &[r#"{"ts":"t","topic":"work.progress","payload":"step"}"#],
+        );
+
+        let mut event_loop = EventLoop::new(RalphConfig::default());
+        let outcome = event_loop.replay_events(&path).unwrap();
+        assert_eq!(outcome.iterations, 1);
+        assert_eq!(outcome.termination, None);
+    }
+}
@@ -0,0 +1,84 @@
+//! Cached scratchpad completion state.
+//!
+//! The completion check runs every time an agent emits the completion
+//! promise. Re-reading a large scratchpad each time is wasteful, so the
+//! parsed result is kept keyed by the file's path, size and mtime and only
+//! recomputed when one of those changes.
+//!
+//! An mtime within [`MTIME_GRANULARITY`] of the read that cached it is not
+//! trusted: a same-size write in the same filesystem timestamp tick would
+//! otherwise go unnoticed. Such entries are re-read on the next check, so the
+//! answer is always the one a full read would give.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Coarsest mtime resolution we expect from a filesystem.
+const MTIME_GRANULARITY: Duration = Duration::from_secs(1);
+
+/// Parsed completion state of one version of the scratchpad.
+#[derive(Debug, Clone)]
+struct CachedScratchpad {
+    path: PathBuf,
+    len: u64,
+    modified: SystemTime,
+    has_pending: bool,
+}
+
+/// Returns whether a scratchpad line is an unfinished task.
+///
+/// `- [ ]` (pending) and `- [>]` (in progress) are unfinished; `- [x]` (done)
+/// and `- [~]` (cancelled) are not.
+pub(crate) fn is_unfinished_task(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("- [ ]") || line.starts_with("- [>]")
+}
+
+/// Caches whether the scratchpad has unfinished tasks.
+#[derive(Debug, Default)]
+pub(crate) struct ScratchpadCache {
+    entry: Option<CachedScratchpad>,
+    /// Number of times the file was actually read.
+    #[cfg(test)]
+    pub(crate) reads: usize,
+}
+
+impl ScratchpadCache {
+    /// Returns whether the scratchpad at `path` has any pending tasks.
+    ///
+    /// # Errors
+    /// Returns an error if the file does not exist or cannot be read.
+    pub(crate) fn has_pending_tasks(&mut self, path: &Path) -> io::Result<bool> {
+        let metadata = std::fs::metadata(path)?;
+        let len = metadata.len();
+        let modified = metadata.modified()?;
+
+        if let Some(entry) = &self.entry
+            && entry.path == path
+            && entry.len == len
+            && entry.modified == modified
+        {
+            return Ok(entry.has_pending);
+        }
+
+        let read_at = SystemTime::now();
+        let content = std::fs::read_to_string(path)?;
+        #[cfg(test)]
+        {
+            self.reads += 1;
+        }
+        let has_pending = content.lines().any(is_unfinished_task);
+
+        let settled = modified
+            .checked_add(MTIME_GRANULARITY)
+            .is_some_and(|settled_at| settled_at < read_at);
+        self.entry = settled.then(|| CachedScratchpad {
+            path: path.to_path_buf(),
+            len,
+            modified,
+            has_pending,
+        });
+        Ok(has_pending)
+    }
+}
@@ -320,7 +320,7 @@ fn test_persisted_loop_state_round_trips_iteration_cost_and_last_hat() {
     event_loop.save_loop_state(&state_path).unwrap();
 
     let mut resumed = EventLoop::new(RalphConfig::default());
-    resumed.restore_loop_state(&state_path).unwrap();
+    resumed.restore_loop_state(&state_path);
 
     assert_eq!(resumed.state.iteration, 7);
     assert!((resumed.state.cumulative_cost - 1.23).abs() < f64::EPSILON);
@@ -516,6 +516,79 @@ fn test_resume_replay_reports_malformed_events_after_last_terminate() {
     assert!(prompt.contains("event.malformed"));
 }
 
+#[test]
+fn test_resume_replay_skips_events_before_persisted_offset() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    let state_path = temp_dir.path().join(".ralph/api/loop-state.json");
+
+    // Prior session: resumed after a park, consumed the first watchdog
+    // event, then died before writing loop.terminate.
+    write_event_to_jsonl(&events_path, "task.start", "original objective");
+    write_event_to_jsonl(&events_path, "loop.terminate", "parked");
+    write_event_to_jsonl(&events_path, "build.task", "already handled task");
+    let mut prior = EventLoop::new(RalphConfig::default());
+    prior.event_reader = crate::event_reader::EventReader::new(&events_path);
+    prior.sync_event_reader_to_file_end();
+    prior.save_loop_state(&state_path).unwrap();
+    write_event_to_jsonl(&events_path, "build.task", "genuinely new task");
+
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.restore_loop_state(&state_path);
+    event_loop.replay_resume_events_from_jsonl().unwrap();
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("genuinely new task"));
+    assert!(
+        !prompt.contains("already handled task"),
+        "events before the persisted offset must not be re-processed"
+    );
+
+    // Explicit rescan falls back to everything after the last loop.terminate
+    let mut rescan = EventLoop::new(RalphConfig::default());
+    rescan.event_reader = crate::event_reader::EventReader::new(&events_path);
+    rescan.restore_loop_state(&state_path);
+    rescan.clear_resume_events_offset();
+    rescan.replay_resume_events_from_jsonl().unwrap();
+
+    let prompt = rescan.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("already handled task"));
+    assert!(prompt.contains("genuinely new task"));
+}
+
+#[test]
+fn test_resume_replay_ignores_offset_past_end_of_events_file() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    let state_path = temp_dir.path().join(".ralph/api/loop-state.json");
+    std::fs::create_dir_all(state_path.parent().unwrap()).unwrap();
+    std::fs::write(
+        &state_path,
+        r#"{"iteration": 2, "total_cost_usd": 0.0, "last_hat": null, "events_offset": 99999}"#,
+    )
+    .unwrap();
+
+    write_event_to_jsonl(&events_path, "task.start", "original objective");
+    write_event_to_jsonl(&events_path, "loop.terminate", "parked");
+    write_event_to_jsonl(&events_path, "build.task", "watchdog task");
+
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.restore_loop_state(&state_path);
+    event_loop.replay_resume_events_from_jsonl().unwrap();
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(
+        prompt.contains("watchdog task"),
+        "a stale offset falls back to the loop.terminate marker"
+    );
+}
+
 #[test]
 fn test_completion_promise_detection() {
     use std::fs;
@@ -590,6 +663,55 @@ fn test_completion_promise_with_open_tasks_in_scratchpad_still_terminates() {
     );
 }
 
+#[test]
+fn test_completion_requires_tasks_done_rejects_pending_scratchpad_tasks() {
+    use std::fs;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let scratchpad_path = temp_dir.path().join("scratchpad.md");
+    fs::write(
+        &scratchpad_path,
+        "## Tasks\n- [x] Task 1 done\n- [ ] Task 2 still pending\n",
+    )
+    .unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.scratchpad.path = scratchpad_path.to_string_lossy().to_string();
+    config.memories.enabled = false;
+    config.event_loop.completion_requires_tasks_done = true;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_completion_event(), None);
+
+    let ralph = HatId::new("ralph");
+    let pending = event_loop.bus.take_pending(&ralph);
+    let resume = pending
+        .iter()
+        .find(|e| e.topic.as_str() == "task.resume")
+        .expect("task.resume injected");
+    assert!(resume.payload.contains("- [ ] Task 2 still pending"));
+    assert!(!resume.payload.contains("Task 1 done"));
+
+    fs::write(
+        &scratchpad_path,
+        "## Tasks\n- [x] Task 1 done\n- [x] Task 2 done\n",
+    )
+    .unwrap();
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
 #[test]
 fn test_completion_promise_with_pending_tasks_in_task_store_is_rejected() {
     use crate::loop_context::LoopContext;
@@ -1327,6 +1449,298 @@ fn test_planner_auto_cancellation_after_three_blocks() {
     );
 }
 
+#[test]
+fn test_task_abandonment_respects_configured_max_task_blocks() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.max_task_blocks = 2;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.initialize("Test task");
+
+    write_event_to_jsonl(
+        &events_path,
+        "build.blocked",
+        "Task X
+missing dependency",
+    );
+    let _ = event_loop.process_events_from_jsonl();
+    assert!(event_loop.state.abandoned_tasks.is_empty());
+
+    write_event_to_jsonl(
+        &events_path,
+        "build.blocked",
+        "Task X
+still missing",
+    );
+    let _ = event_loop.process_events_from_jsonl();
+    assert!(
+        event_loop
+            .state
+            .abandoned_tasks
+            .contains(&"Task X".to_string()),
+        "Task X should be abandoned after 2 blocks"
+    );
+}
+
+#[test]
+fn test_thrashing_thresholds_configurable_in_check_termination() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_malformed_events = 5;
+    config.event_loop.max_abandoned_redispatches = 1;
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.state.consecutive_malformed_events = 3;
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.state.consecutive_malformed_events = 5;
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::ValidationFailure)
+    );
+
+    event_loop.state.consecutive_malformed_events = 0;
+    event_loop.state.abandoned_task_redispatches = 1;
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::LoopThrashing)
+    );
+}
+
+#[test]
+fn test_zero_thrashing_threshold_disables_safeguard() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_malformed_events = 0;
+    config.event_loop.max_abandoned_redispatches = 0;
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.state.consecutive_malformed_events = 10;
+    event_loop.state.abandoned_task_redispatches = 10;
+    assert_eq!(event_loop.check_termination(), None);
+}
+
+fn drift_events_pending(event_loop: &EventLoop) -> usize {
+    event_loop
+        .bus
+        .peek_pending(&HatId::new("ralph"))
+        .map_or(0, |events| {
+            events
+                .iter()
+                .filter(|e| e.topic.as_str() == "objective.drift")
+                .count()
+        })
+}
+
+#[test]
+fn test_drift_detection_fires_only_for_sustained_off_topic_activity() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.features.drift_detection.enabled = true;
+    config.features.drift_detection.window = 2;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.initialize("Implement password reset emails for the login page");
+
+    // On-topic activity never fires
+    for _ in 0..3 {
+        write_event_to_jsonl(
+            &events_path,
+            "work.progress",
+            "password reset email implemented",
+        );
+        let _ = event_loop.process_events_from_jsonl();
+    }
+    assert_eq!(drift_events_pending(&event_loop), 0);
+
+    // A single off-topic iteration is tolerated
+    write_event_to_jsonl(&events_path, "work.progress", "tuned grafana dashboards");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(drift_events_pending(&event_loop), 0);
+
+    // Sustained off-topic activity fires once
+    write_event_to_jsonl(
+        &events_path,
+        "work.progress",
+        "migrated kubernetes helm charts",
+    );
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(drift_events_pending(&event_loop), 1);
+}
+
+#[test]
+fn test_drift_detection_disabled_by_default() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.initialize("Implement password reset emails for the login page");
+
+    for _ in 0..5 {
+        write_event_to_jsonl(&events_path, "work.progress", "tuned grafana dashboards");
+        let _ = event_loop.process_events_from_jsonl();
+    }
+    assert_eq!(drift_events_pending(&event_loop), 0);
+}
+
+fn memory_prompt_for_ranking(ranking: crate::config::MemoryRanking) -> String {
+    use crate::{MarkdownMemoryStore, Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    for i in 0..6 {
+        let mut filler = Memory::new(
+            MemoryType::Pattern,
+            format!("Grafana dashboard panel {i} uses the shared prometheus datasource"),
+            vec![],
+        );
+        filler.id = format!("mem-100{i}-0000");
+        store.append(&filler).unwrap();
+    }
+    let mut relevant = Memory::new(
+        MemoryType::Context,
+        "Password reset emails are rendered from templates/reset.html".to_string(),
+        vec![],
+    );
+    relevant.id = "mem-2000-beef".to_string();
+    store.append(&relevant).unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.memories.budget = 60;
+    config.memories.ranking = ranking;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Fix the password reset email template");
+
+    event_loop.build_prompt(&HatId::new("ralph")).unwrap()
+}
+
+#[test]
+fn test_memory_ranking_spends_budget_on_relevant_memories() {
+    let prompt = memory_prompt_for_ranking(crate::config::MemoryRanking::Relevance);
+    assert!(
+        prompt.contains("mem-2000-beef"),
+        "relevant memory should be injected"
+    );
+}
+
+#[test]
+fn test_memory_ranking_off_keeps_file_order() {
+    // Patterns precede Context in the file, so FIFO truncation drops the
+    // relevant memory under a tight budget.
+    let prompt = memory_prompt_for_ranking(crate::config::MemoryRanking::Off);
+    assert!(prompt.contains("Grafana dashboard panel"));
+    assert!(!prompt.contains("mem-2000-beef"));
+}
+
+#[test]
+fn test_memory_filter_excludes_types_from_injected_prompt() {
+    use crate::{MarkdownMemoryStore, Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    store
+        .append(&Memory::new(
+            MemoryType::Pattern,
+            "Builders use the repository pattern".to_string(),
+            vec![],
+        ))
+        .unwrap();
+    store
+        .append(&Memory::new(
+            MemoryType::Fix,
+            "Scratch note: flaky test rerun fixed it".to_string(),
+            vec![],
+        ))
+        .unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.memories.filter.types = vec!["pattern".to_string(), "decision".to_string()];
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Build the feature");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("Builders use the repository pattern"));
+    assert!(
+        !prompt.contains("Scratch note"),
+        "excluded memory type must never reach the prompt"
+    );
+    assert!(!prompt.contains("## Fixes"));
+}
+
+fn verbose_hat_loop() -> EventLoop {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds things verbosely"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+    max_output_bytes: 200
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop
+        .bus
+        .publish(Event::new("build.task", "go").with_source(HatId::new("ralph")));
+    let _ = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    event_loop
+}
+
+#[test]
+fn test_oversized_hat_output_is_truncated_before_processing() {
+    let event_loop = verbose_hat_loop();
+    let output = format!("starting build\n{}\nfinished", "noise ".repeat(10_000));
+
+    let capped = event_loop.cap_hat_output(&HatId::new("ralph"), output.clone());
+    assert!(
+        capped.len() < 400,
+        "capped output was {} bytes",
+        capped.len()
+    );
+    assert!(capped.starts_with("starting"));
+    assert!(capped.contains("max_output_bytes (200)"));
+    assert!(capped.ends_with("finished"));
+
+    // Output within budget, and hats without a budget, pass through untouched
+    assert_eq!(
+        event_loop.cap_hat_output(&HatId::new("ralph"), "short".to_string()),
+        "short"
+    );
+    let unlimited = EventLoop::new(RalphConfig::default());
+    assert_eq!(
+        unlimited.cap_hat_output(&HatId::new("ralph"), output.clone()),
+        output
+    );
+}
+
+#[test]
+fn test_completion_promise_survives_hat_output_truncation() {
+    let event_loop = verbose_hat_loop();
+    let promise = "LOOP_COMPLETE";
+    let output = format!(
+        "{}\nAll tasks done.\n{promise}\n",
+        "log line\n".repeat(5_000)
+    );
+
+    let capped = event_loop.cap_hat_output(&HatId::new("ralph"), output);
+    assert!(capped.len() < 400);
+    assert!(EventParser::contains_promise(&capped, promise));
+}
+
 #[test]
 fn test_default_publishes_injects_when_no_events() {
     use std::collections::HashMap;
@@ -1346,10 +1760,15 @@ fn test_default_publishes_injects_when_no_events() {
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1403,10 +1822,15 @@ fn test_default_publishes_completion_promise_requires_explicit_agent_event() {
             publishes: vec!["LOOP_COMPLETE".to_string()],
             instructions: "Verify the objective is complete".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend: None,
             default_publishes: Some("LOOP_COMPLETE".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1543,10 +1967,15 @@ fn test_default_publishes_not_injected_when_events_written() {
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1743,10 +2172,15 @@ fn test_default_publishes_skipped_when_non_orphan_event_written() {
             publishes: vec!["task.done".to_string()],
             instructions: "Do the task".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1814,10 +2248,15 @@ fn test_default_publishes_not_injected_when_not_configured() {
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             backend: None,
             default_publishes: None, // No default configured
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -2882,6 +3321,30 @@ fn test_stop_requested_termination_clears_signal() {
     );
 }
 
+#[test]
+fn test_shutdown_drain_terminates_at_iteration_boundary() {
+    use crate::ShutdownSignal;
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    let signal = ShutdownSignal::new();
+    event_loop.set_shutdown_signal(signal.clone());
+
+    assert_eq!(event_loop.check_termination(), None);
+
+    // First Ctrl-C: drain, reported as an interrupt at the next check
+    signal.request();
+    let reason = event_loop.check_termination();
+    assert_eq!(reason, Some(TerminationReason::Interrupted));
+
+    let terminate = event_loop.publish_terminate_event(&reason.unwrap());
+    assert_eq!(terminate.topic.as_str(), "loop.terminate");
+    assert!(terminate.payload.contains("interrupted"));
+}
+
 #[test]
 fn test_format_event_wraps_top_level_prompts() {
     // Kills: line 761 `==` → `!=` and `||` → `&&`
@@ -2911,6 +3374,115 @@ fn test_format_event_wraps_top_level_prompts() {
     );
 }
 
+#[test]
+fn test_max_event_payload_in_prompt_truncates_all_but_top_level() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_event_payload_in_prompt = Some(20);
+    let mut event_loop = EventLoop::new(config);
+    let objective = "Build a web server that serves static files from ./public";
+    event_loop.initialize(objective);
+
+    let ralph = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(
+        prompt.contains(objective),
+        "top-level prompt must not be truncated"
+    );
+
+    let long_payload = "x".repeat(500);
+    event_loop
+        .bus
+        .publish(Event::new("build.done", long_payload.as_str()));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains(&format!("Event: build.done - {}...", "x".repeat(17))));
+    assert!(!prompt.contains(&"x".repeat(18)));
+}
+
+#[test]
+fn test_max_event_payload_chars_caps_prompt_context() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_event_payload_chars = 20;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Serve static files");
+
+    let ralph = HatId::new("ralph");
+    event_loop.build_prompt(&ralph).unwrap();
+    event_loop
+        .bus
+        .publish(Event::new("build.done", "x".repeat(500).as_str()));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains(&format!("Event: build.done - {}...", "x".repeat(17))));
+    assert!(!prompt.contains(&"x".repeat(18)));
+}
+
+#[test]
+fn test_prompt_budget_chars_drops_oldest_events_and_keeps_objective() {
+    let objective = "Build a web server that serves static files from ./public";
+    let ralph = HatId::new("ralph");
+    let publish_steps = |event_loop: &mut EventLoop| {
+        for step in 1..=5 {
+            event_loop.bus.publish(Event::new(
+                "work.step",
+                format!("step {step} {}", "y".repeat(200)).as_str(),
+            ));
+        }
+    };
+
+    let mut unbounded = EventLoop::new(RalphConfig::default());
+    unbounded.initialize(objective);
+    unbounded.build_prompt(&ralph).unwrap();
+    publish_steps(&mut unbounded);
+    let full_len = unbounded.build_prompt(&ralph).unwrap().chars().count();
+
+    let mut config = RalphConfig::default();
+    config.event_loop.prompt_budget_chars = Some(full_len - 300);
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize(objective);
+    event_loop.build_prompt(&ralph).unwrap();
+    publish_steps(&mut event_loop);
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+
+    assert!(prompt.chars().count() <= full_len - 300);
+    assert!(prompt.contains(objective));
+    assert!(prompt.contains("older events omitted to fit the prompt budget"));
+    assert!(!prompt.contains("step 1 "));
+    assert!(prompt.contains("step 5 "));
+}
+
+#[test]
+fn test_steer_file_injected_as_user_steer_in_next_prompt() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let ctx = crate::LoopContext::primary(temp_dir.path().to_path_buf());
+    ctx.ensure_directories().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Build a web server");
+
+    let steer_file = ctx.steer_file_path();
+    std::fs::write(&steer_file, "Use axum instead of actix\n").unwrap();
+    let store = crate::UrgentSteerStore::new(ctx.urgent_steer_path());
+    let message = store.absorb_steer_file(&steer_file).unwrap().unwrap();
+    event_loop.inject_steer([message]);
+    assert!(!steer_file.exists(), "steer file should be consumed");
+
+    let ralph = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(
+        prompt.starts_with("## STEERING"),
+        "steering should lead the prompt"
+    );
+    assert!(prompt.contains("Event: user.steer - Use axum instead of actix"));
+
+    // Injected once: the next prompt no longer carries it.
+    event_loop
+        .bus
+        .publish(Event::new("build.done", "completed"));
+    assert_eq!(store.absorb_steer_file(&steer_file).unwrap(), None);
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(!prompt.contains("user.steer"));
+}
+
 #[test]
 fn test_check_ralph_completion_detection() {
     // Kills: line 1241 return `true` / `false`
@@ -3188,6 +3760,73 @@ fn test_build_done_backpressure_rejects_high_complexity() {
     );
 }
 
+/// Runs a single `build.done` through an event loop whose
+/// `build_verification` runs `command` in a temp workspace, returning the
+/// pending events.
+fn process_build_done_with_verification(command: &str, payload: &str) -> Vec<Event> {
+    use crate::task_definition::Verification;
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.build_verification = Some(Verification::new(command));
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", payload);
+    let _ = event_loop.process_events_from_jsonl();
+
+    let empty = Vec::new();
+    event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.peek_pending(id).unwrap_or(&empty).clone())
+        .collect()
+}
+
+#[test]
+fn test_build_verification_pass_accepts_build_done_without_evidence() {
+    let pending = process_build_done_with_verification("test -d .", "done, trust me");
+
+    let topics: Vec<String> = pending.iter().map(|e| e.topic.to_string()).collect();
+    assert!(
+        topics.contains(&"build.done".to_string()),
+        "passing verification should accept build.done. Got: {topics:?}"
+    );
+    assert!(!topics.contains(&"build.blocked".to_string()));
+}
+
+#[test]
+fn test_build_verification_failure_blocks_despite_passing_evidence() {
+    let payload = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 3\nduplication: pass";
+    let pending = process_build_done_with_verification(
+        "echo 'test auth::login failed' >&2; exit 101",
+        payload,
+    );
+
+    assert!(
+        !pending.iter().any(|e| e.topic == "build.done".into()),
+        "failing verification should reject build.done"
+    );
+    let blocked = pending
+        .iter()
+        .find(|e| e.topic == "build.blocked".into())
+        .expect("build.blocked should be synthesized");
+    assert!(
+        blocked.payload.contains("exit code 101"),
+        "{}",
+        blocked.payload
+    );
+    assert!(
+        blocked.payload.contains("test auth::login failed"),
+        "{}",
+        blocked.payload
+    );
+}
+
 #[test]
 fn test_build_done_backpressure_rejects_duplication() {
     use tempfile::tempdir;
@@ -3510,6 +4149,39 @@ fn test_verify_passed_backpressure_rejects_failed_thresholds() {
     );
 }
 
+#[test]
+fn test_memories_inject_skill_false_omits_only_the_skill() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let agent_dir = temp_dir.path().join(".ralph/agent");
+    std::fs::create_dir_all(&agent_dir).unwrap();
+    std::fs::write(
+        agent_dir.join("memories.md"),
+        "# Memories\n\n## Patterns\n\n### mem-1737372000-a1b2\n> Use tokio for async\n<!-- tags: rust | created: 2026-01-20 -->\n",
+    )
+    .unwrap();
+    let build = |inject_skill: bool| {
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp_dir.path().to_path_buf();
+        config.memories.inject_skill = inject_skill;
+        let mut event_loop = EventLoop::new(config);
+        event_loop.initialize("Test prompt");
+        event_loop.build_prompt(&HatId::new("ralph")).unwrap()
+    };
+
+    let prompt = build(true);
+    assert!(prompt.contains("<ralph-tools-memories-skill>"));
+    assert!(prompt.contains("Use tokio for async"));
+
+    let prompt = build(false);
+    assert!(!prompt.contains("<ralph-tools-memories-skill>"));
+    assert!(
+        prompt.contains("Use tokio for async"),
+        "memory data should still be injected"
+    );
+}
+
 // === RObot Interaction Skill Injection Tests ===
 
 #[test]
@@ -3892,7 +4564,7 @@ fn test_verify_scratchpad_complete_variants() {
 
     let temp_dir = tempfile::tempdir().unwrap();
     let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
-    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
 
     assert!(event_loop.verify_scratchpad_complete().is_err());
 
@@ -3905,6 +4577,69 @@ fn test_verify_scratchpad_complete_variants() {
     assert!(event_loop.verify_scratchpad_complete().unwrap());
 }
 
+#[test]
+fn test_verify_scratchpad_in_progress_tasks_are_not_complete() {
+    use crate::loop_context::LoopContext;
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    let scratchpad_path = temp_dir.path().join(".ralph/agent/scratchpad.md");
+    fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+    fs::write(
+        &scratchpad_path,
+        "## Tasks\n- [>] Wiring the parser\n  - [>] Nested step\n",
+    )
+    .unwrap();
+
+    assert!(!event_loop.verify_scratchpad_complete().unwrap());
+    assert_eq!(
+        event_loop.pending_scratchpad_tasks(),
+        vec!["- [>] Wiring the parser", "- [>] Nested step"]
+    );
+}
+
+#[test]
+fn test_verify_scratchpad_complete_caches_until_file_changes() {
+    use crate::loop_context::LoopContext;
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    let scratchpad_path = temp_dir.path().join(".ralph/agent/scratchpad.md");
+    fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+    let write_settled = |content: &str, age_secs: u64| {
+        fs::write(&scratchpad_path, content).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&scratchpad_path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+            .unwrap();
+    };
+
+    write_settled("## Tasks\n- [ ] Pending\n", 60);
+    assert!(!event_loop.verify_scratchpad_complete().unwrap());
+    assert!(!event_loop.verify_scratchpad_complete().unwrap());
+    assert_eq!(event_loop.scratchpad_cache.reads, 1);
+
+    // Same size, different mtime: re-parsed
+    write_settled("## Tasks\n- [x] Pending\n", 30);
+    assert!(event_loop.verify_scratchpad_complete().unwrap());
+    assert_eq!(event_loop.scratchpad_cache.reads, 2);
+
+    // A just-written file is not trusted from cache
+    fs::write(&scratchpad_path, "## Tasks\n- [ ] Reopened\n").unwrap();
+    assert!(!event_loop.verify_scratchpad_complete().unwrap());
+    assert!(!event_loop.verify_scratchpad_complete().unwrap());
+    assert_eq!(event_loop.scratchpad_cache.reads, 4);
+}
+
 #[test]
 fn test_termination_reason_exit_codes() {
     let cases = [
@@ -3925,6 +4660,88 @@ fn test_termination_reason_exit_codes() {
     }
 }
 
+#[test]
+fn test_reported_config_error_terminates_with_detail() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize_resume("");
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.report_config_error("Prompt file 'PROMPT.md' not found");
+    event_loop.report_config_error("later problems do not replace the first");
+
+    let reason = event_loop.check_termination().unwrap();
+    assert_eq!(
+        reason,
+        TerminationReason::ConfigError {
+            detail: "Prompt file 'PROMPT.md' not found".to_string()
+        }
+    );
+    assert_eq!(reason.exit_code(), 1);
+    assert_eq!(reason.as_str(), "config_error");
+
+    let event = event_loop.publish_terminate_event(&reason);
+    assert!(event.payload.contains("## Reason\nconfig_error"));
+    assert!(
+        event
+            .payload
+            .contains("## Detail\nPrompt file 'PROMPT.md' not found")
+    );
+}
+
+#[test]
+fn test_iteration_timings_recorded_and_reported_on_terminate() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let ralph = HatId::new("ralph");
+    event_loop.build_prompt(&ralph);
+    event_loop.process_output(&ralph, "output", true);
+    event_loop.process_output(&ralph, "output", true);
+
+    let timings = event_loop.state().iteration_timings();
+    assert_eq!(timings.len(), 2);
+    assert_eq!(timings[0].iteration, 1);
+    assert_eq!(timings[1].iteration, 2);
+    assert_eq!(timings[1].hat, ralph);
+    assert_eq!(timings[1].started_at, timings[0].ended_at);
+
+    let event = event_loop.publish_terminate_event(&TerminationReason::MaxIterations);
+    assert!(
+        event.payload.contains("## Timing\n- #1 ralph: "),
+        "{}",
+        event.payload
+    );
+    assert!(event.payload.contains("\n- #2 ralph: "));
+}
+
+#[test]
+fn test_format_iteration_timings_keeps_slowest_when_long() {
+    let base = std::time::Instant::now();
+    let timings: Vec<IterationTiming> = (1..=15u32)
+        .map(|i| IterationTiming {
+            iteration: i,
+            hat: HatId::new("builder"),
+            started_at: base,
+            ended_at: base + Duration::from_secs(u64::from(i)),
+        })
+        .collect();
+
+    assert_eq!(format_iteration_timings(&[]), None);
+    let out = format_iteration_timings(&timings).unwrap();
+    assert!(
+        out.starts_with("Slowest 10 of 15 iterations:\n- #6 builder: "),
+        "{out}"
+    );
+    assert!(out.ends_with("- #15 builder: 15s"), "{out}");
+    assert!(!out.contains("#5 "));
+}
+
 #[test]
 fn test_termination_reason_strings_and_flags() {
     let cases = [
@@ -4360,6 +5177,53 @@ hats:
     );
 }
 
+#[test]
+fn test_completion_promise_from_custom_hat_is_flagged_not_honored() {
+    use std::sync::{Arc, Mutex};
+
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let published = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&published);
+    event_loop
+        .bus
+        .add_observer(move |event: &Event| sink.lock().unwrap().push(event.clone()));
+
+    let output = r#"All done. <event topic="LOOP_COMPLETE">finished</event>"#;
+    let reason = event_loop.process_output(&HatId::new("builder"), output, true);
+
+    assert_eq!(reason, None, "a custom hat must not complete the loop");
+    assert!(!event_loop.state.completion_requested);
+    {
+        let published = published.lock().unwrap();
+        let warning = published
+            .iter()
+            .find(|e| e.topic.as_str() == "completion.misplaced")
+            .expect("completion.misplaced event should be published");
+        assert!(warning.payload.contains("Hat 'builder'"));
+        assert!(warning.payload.contains("only Ralph may complete"));
+    }
+
+    // Ralph outputting the promise is not flagged.
+    event_loop.process_output(&HatId::new("ralph"), output, true);
+    let misplaced = published
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|e| e.topic.as_str() == "completion.misplaced")
+        .count();
+    assert_eq!(misplaced, 1);
+}
+
 // ── Phase 2: Event Chain Validation + loop.cancel Tests ───────────────
 
 #[test]
@@ -4553,10 +5417,15 @@ fn test_default_publishes_satisfies_required_events_for_completion() {
             publishes: vec!["plan.draft".to_string()],
             instructions: "Plan".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             default_publishes: Some("plan.draft".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -4611,10 +5480,15 @@ fn test_default_publishes_completion_promise_injects_recovery() {
             publishes: vec!["LOOP_COMPLETE".to_string()],
             instructions: "Verify and complete".to_string(),
             extra_instructions: vec![],
+            instructions_file: None,
+            extends: None,
             backend: None,
             backend_args: None,
+            temperature: None,
+            max_tokens: None,
             default_publishes: Some("LOOP_COMPLETE".to_string()),
             max_activations: None,
+            max_output_bytes: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -216,6 +216,23 @@ impl QualityReport {
     }
 }
 
+/// Cost markers for Claude's stream-json result line.
+///
+/// Each marker is matched literally; the dollar amount following it is the
+/// cost.
+pub const CLAUDE_COST_MARKERS: &[&str] = &["\"total_cost_usd\":"];
+
+/// Cost markers recognized for `backend` when its adapter configures none.
+///
+/// Only Claude has built-in markers. Generic phrases like `Total cost:` also
+/// show up in agent prose, so other backends opt in via `cost_markers`.
+pub fn default_cost_markers(backend: &str) -> &'static [&'static str] {
+    match backend {
+        "claude" => CLAUDE_COST_MARKERS,
+        _ => &[],
+    }
+}
+
 /// Parser for extracting events from CLI output.
 #[derive(Debug, Default)]
 pub struct EventParser {
@@ -456,6 +473,51 @@ impl EventParser {
         }
     }
 
+    /// Parses the iteration cost in USD from `backend`'s output using
+    /// [`default_cost_markers`].
+    pub fn parse_cost(output: &str, backend: &str) -> Option<f64> {
+        Self::parse_cost_with_markers(output, default_cost_markers(backend))
+    }
+
+    /// Parses the iteration cost in USD from backend output.
+    ///
+    /// Looks for the amount after any of `markers` (e.g. `Total cost: $0.42`).
+    /// When the output reports cost more than once, the last report wins,
+    /// since backends print a running total at the end of a session.
+    pub fn parse_cost_with_markers<S: AsRef<str>>(output: &str, markers: &[S]) -> Option<f64> {
+        let clean_output = strip_ansi(output);
+        let mut cost = None;
+
+        for line in clean_output.lines() {
+            for marker in markers {
+                let marker = marker.as_ref();
+                if marker.is_empty() {
+                    continue;
+                }
+                let Some(idx) = line.rfind(marker) else {
+                    continue;
+                };
+                let amount = line[idx + marker.len()..]
+                    .trim_start_matches(|c: char| c.is_whitespace() || c == '$' || c == '"');
+                if let Some(value) = Self::extract_leading_number(amount) {
+                    cost = Some(value);
+                }
+            }
+        }
+
+        cost
+    }
+
+    fn extract_leading_number(segment: &str) -> Option<f64> {
+        let end = segment
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(segment.len());
+        segment[..end]
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
     fn extract_percentage(segment: &str) -> Option<f64> {
         let percent_idx = segment.find('%')?;
         let bytes = segment.as_bytes();
@@ -1141,6 +1203,62 @@ Still working..."#;
         assert!(!report.failed_dimensions().contains(&"specs"));
     }
 
+    #[test]
+    fn test_parse_cost_from_claude_stream_result() {
+        let output = concat!(
+            r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Done"}]}}"#,
+            "\n",
+            r#"{"type":"result","duration_ms":5000,"total_cost_usd":0.0526,"num_turns":2,"is_error":false}"#,
+            "\n",
+        );
+        assert_eq!(EventParser::parse_cost(output, "claude"), Some(0.0526));
+    }
+
+    #[test]
+    fn test_parse_cost_ignores_prose_by_default() {
+        let output = "\
+Picked the cheaper hosting plan. Total cost: $3 per month.
+<event topic=\"build.done\">tests: pass</event>
+";
+        assert_eq!(EventParser::parse_cost(output, "claude"), None);
+        assert_eq!(EventParser::parse_cost(output, "gemini"), None);
+    }
+
+    #[test]
+    fn test_parse_cost_last_report_wins() {
+        let output = "Cost: $0.10\nmore work\nCost: $0.25\n";
+        assert_eq!(
+            EventParser::parse_cost_with_markers(output, &["Cost: $"]),
+            Some(0.25)
+        );
+    }
+
+    #[test]
+    fn test_parse_cost_with_custom_markers() {
+        let output = "session ended\nspend=1.75 USD\nTotal cost: $9.00\n";
+        assert_eq!(
+            EventParser::parse_cost_with_markers(output, &["spend="]),
+            Some(1.75)
+        );
+        // An empty marker list disables cost parsing
+        assert_eq!(
+            EventParser::parse_cost_with_markers::<&str>(output, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cost_missing_or_malformed() {
+        assert_eq!(
+            EventParser::parse_cost("no cost reported here", "claude"),
+            None
+        );
+        assert_eq!(
+            EventParser::parse_cost("\"total_cost_usd\":null", "claude"),
+            None
+        );
+    }
+
     #[test]
     fn test_strip_ansi_function() {
         // Test the internal strip_ansi function via parse_backpressure_evidence
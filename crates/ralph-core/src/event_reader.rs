@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::File;
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use tracing::warn;
 
@@ -153,6 +153,11 @@ impl EventReader {
     /// validation - the caller can emit `event.malformed` events and
     /// track consecutive failures.
     ///
+    /// A last line without a terminating newline that doesn't parse is
+    /// assumed to be mid-write: it is left unread (not reported as malformed)
+    /// and retried on the next call. Only newline-terminated lines are ever
+    /// reported as malformed.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be opened or read.
@@ -164,14 +169,20 @@ impl EventReader {
         let mut file = File::open(&self.path)?;
         file.seek(SeekFrom::Start(self.position))?;
 
-        let reader = BufReader::new(file);
+        let mut reader = BufReader::new(file);
         let mut result = ParseResult::default();
         let mut current_pos = self.position;
         let mut line_number = self.count_lines_before_position();
+        let mut raw = String::new();
 
-        for line in reader.lines() {
-            let line = line?;
-            let line_bytes = line.len() as u64 + 1; // +1 for newline
+        loop {
+            raw.clear();
+            let line_bytes = reader.read_line(&mut raw)? as u64;
+            if line_bytes == 0 {
+                break;
+            }
+            let terminated = raw.ends_with('\n');
+            let line = raw.trim_end_matches(['\n', '\r']);
             line_number += 1;
 
             if line.trim().is_empty() {
@@ -179,13 +190,17 @@ impl EventReader {
                 continue;
             }
 
-            match serde_json::from_str::<Event>(&line) {
+            match serde_json::from_str::<Event>(line) {
                 Ok(event) => result.events.push(event),
+                Err(_) if !terminated => {
+                    // Torn write: wait for the rest of the line
+                    break;
+                }
                 Err(e) => {
                     warn!(error = %e, line_number = line_number, "Malformed JSON line");
                     result
                         .malformed
-                        .push(MalformedLine::new(line_number, &line, e.to_string()));
+                        .push(MalformedLine::new(line_number, line, e.to_string()));
                 }
             }
 
@@ -214,25 +229,19 @@ impl EventReader {
             return 0;
         }
         // Read file up to position and count newlines
-        if let Ok(file) = File::open(&self.path) {
-            let reader = BufReader::new(file);
-            let mut count = 0u64;
-            let mut bytes_read = 0u64;
-            for line in reader.lines() {
-                if let Ok(line) = line {
-                    bytes_read += line.len() as u64 + 1;
-                    if bytes_read > self.position {
-                        break;
-                    }
-                    count += 1;
-                } else {
-                    break;
-                }
+        let Ok(file) = File::open(&self.path) else {
+            return 0;
+        };
+        let mut reader = BufReader::new(file.take(self.position));
+        let mut count = 0u64;
+        let mut line = Vec::new();
+        while matches!(reader.read_until(b'\n', &mut line), Ok(n) if n > 0) {
+            if line.ends_with(b"\n") {
+                count += 1;
             }
-            count
-        } else {
-            0
+            line.clear();
         }
+        count
     }
 
     /// Returns the path to the events file.
@@ -361,6 +370,52 @@ mod tests {
         assert!(!result.malformed[0].error.is_empty());
     }
 
+    #[test]
+    fn test_defers_torn_last_line_until_terminated() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"topic":"first","ts":"2024-01-01T00:00:00Z"}}"#).unwrap();
+        write!(file, r#"{{"topic":"second","ts":"2024-"#).unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert!(result.malformed.is_empty(), "torn line is not malformed");
+
+        // Nothing new until the writer finishes the line
+        let result = reader.read_new_events().unwrap();
+        assert!(result.events.is_empty());
+        assert!(result.malformed.is_empty());
+
+        writeln!(file, r#"01-01T00:00:01Z"}}"#).unwrap();
+        writeln!(file, "{{corrupt").unwrap();
+        file.flush().unwrap();
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "second");
+        assert_eq!(result.malformed.len(), 1);
+        assert_eq!(result.malformed[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_accepts_complete_last_line_without_newline() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, r#"{{"topic":"first","ts":"2024-01-01T00:00:00Z"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 1);
+
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"topic":"second","ts":"2024-01-01T00:00:01Z"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "second");
+    }
+
     #[test]
     fn test_empty_file() {
         let file = NamedTempFile::new().unwrap();
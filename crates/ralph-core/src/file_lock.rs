@@ -113,7 +113,7 @@ impl FileLock {
             match Flock::lock(file, arg) {
                 Ok(flock) => Ok(LockGuard {
                     _flock: flock,
-                    _lock_type: lock_type,
+                    lock_type,
                 }),
                 Err((_, errno)) => Err(io::Error::new(
                     io::ErrorKind::Other,
@@ -149,7 +149,7 @@ impl FileLock {
             match Flock::lock(file, arg) {
                 Ok(flock) => Ok(Some(LockGuard {
                     _flock: flock,
-                    _lock_type: lock_type,
+                    lock_type,
                 })),
                 Err((_, errno)) if errno == Errno::EWOULDBLOCK || errno == Errno::EAGAIN => {
                     Ok(None)
@@ -189,7 +189,7 @@ impl FileLock {
 
 /// Type of lock to acquire.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum LockType {
+pub enum LockType {
     /// Shared (read) lock - multiple holders allowed.
     Shared,
     /// Exclusive (write) lock - single holder only.
@@ -204,7 +204,14 @@ pub struct LockGuard {
     _flock: nix::fcntl::Flock<File>,
 
     /// The type of lock held.
-    _lock_type: LockType,
+    lock_type: LockType,
+}
+
+impl LockGuard {
+    /// Returns the type of lock this guard holds.
+    pub fn lock_type(&self) -> LockType {
+        self.lock_type
+    }
 }
 
 /// A locked file that provides safe read/write access.
@@ -213,13 +220,34 @@ pub struct LockGuard {
 /// read/write operations in a single API.
 pub struct LockedFile {
     lock: FileLock,
+    path: PathBuf,
 }
 
 impl LockedFile {
     /// Creates a new locked file handle for the given path.
     pub fn new(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
         Ok(Self {
             lock: FileLock::new(path)?,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Acquires a shared lock held across several reads.
+    ///
+    /// Writes through the returned guard are rejected.
+    pub fn lock_shared(&self) -> io::Result<LockedFileGuard<'_>> {
+        Ok(LockedFileGuard {
+            path: &self.path,
+            guard: self.lock.shared()?,
+        })
+    }
+
+    /// Acquires an exclusive lock held across several reads and writes.
+    pub fn lock_exclusive(&self) -> io::Result<LockedFileGuard<'_>> {
+        Ok(LockedFileGuard {
+            path: &self.path,
+            guard: self.lock.exclusive()?,
         })
     }
 
@@ -264,6 +292,52 @@ impl LockedFile {
     }
 }
 
+/// A held lock on a [`LockedFile`] that knows which mode it holds.
+///
+/// The lock is released when the guard is dropped.
+#[derive(Debug)]
+pub struct LockedFileGuard<'a> {
+    path: &'a Path,
+    guard: LockGuard,
+}
+
+impl LockedFileGuard<'_> {
+    /// Returns the type of lock held.
+    pub fn lock_type(&self) -> LockType {
+        self.guard.lock_type()
+    }
+
+    /// Reads the file contents under the held lock.
+    ///
+    /// If the file doesn't exist, returns an empty string.
+    pub fn read(&self) -> io::Result<String> {
+        if self.path.exists() {
+            std::fs::read_to_string(self.path)
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    /// Writes content to the file under the held lock.
+    ///
+    /// Fails with [`io::ErrorKind::PermissionDenied`] when only a shared lock is held.
+    pub fn write(&self, content: &str) -> io::Result<()> {
+        if self.lock_type() != LockType::Exclusive {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "cannot write {} while holding a shared lock",
+                    self.path.display()
+                ),
+            ));
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(self.path, content)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,6 +470,29 @@ mod tests {
         assert_eq!(content, "Hello, World!");
     }
 
+    #[test]
+    fn test_locked_file_guards_track_mode() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("memories.md");
+        let locked = LockedFile::new(&file_path).unwrap();
+        let other = LockedFile::new(&file_path).unwrap();
+
+        {
+            let writer = locked.lock_exclusive().unwrap();
+            assert_eq!(writer.lock_type(), LockType::Exclusive);
+            writer.write("# Memories\n").unwrap();
+        }
+
+        let reader = locked.lock_shared().unwrap();
+        let second_reader = other.lock_shared().unwrap();
+        assert_eq!(reader.lock_type(), LockType::Shared);
+        assert_eq!(second_reader.read().unwrap(), "# Memories\n");
+
+        let err = reader.write("overwritten").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(reader.read().unwrap(), "# Memories\n");
+    }
+
     #[test]
     fn test_locked_file_read_nonexistent() {
         let temp_dir = TempDir::new().unwrap();
@@ -330,6 +330,31 @@ pub fn is_working_tree_clean(path: impl AsRef<Path>) -> Result<bool, GitOpsError
     has_uncommitted_changes(path).map(|has_changes| !has_changes)
 }
 
+/// Check whether `branch` has commits not reachable from the checked-out HEAD.
+///
+/// Used before deleting a loop branch, so work that was committed but never
+/// merged is not thrown away.
+///
+/// # Arguments
+///
+/// * `path` - Path to the git repository whose HEAD is the base branch
+/// * `branch` - The branch to compare against HEAD
+pub fn has_unmerged_commits(path: impl AsRef<Path>, branch: &str) -> Result<bool, GitOpsError> {
+    let path = path.as_ref();
+    let output = Command::new("git")
+        .args(["rev-list", "--count", &format!("HEAD..{branch}")])
+        .current_dir(path)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitOpsError::Git(stderr.to_string()));
+    }
+
+    let count = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(count != "0")
+}
+
 /// Get a short summary of the HEAD commit.
 ///
 /// Returns a string like "abc1234: commit message subject"
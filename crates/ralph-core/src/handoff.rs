@@ -8,7 +8,13 @@
 //!
 //! This enables clean session boundaries and seamless handoffs between
 //! Ralph loops, supporting the "land the plane" pattern.
+//!
+//! Loops can also append a timestamped section to the shared handoff log
+//! (`.ralph/agent/handoff-log.md` in the main repo) so parallel loops leave a
+//! single coherent record.
 
+use crate::config::FormattingConfig;
+use crate::file_lock::FileLock;
 use crate::git_ops::{get_commit_summary, get_current_branch, get_head_sha, get_recent_files};
 use crate::loop_context::LoopContext;
 use crate::task::{Task, TaskStatus};
@@ -17,12 +23,24 @@ use crate::text::floor_char_boundary;
 use std::io;
 use std::path::PathBuf;
 
+/// How a handoff write touched its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandoffWriteMode {
+    /// The file was created (or overwritten).
+    Created,
+    /// A section was appended to an existing file.
+    Appended,
+}
+
 /// Result of generating a handoff file.
 #[derive(Debug, Clone)]
 pub struct HandoffResult {
     /// Path to the generated handoff file.
     pub path: PathBuf,
 
+    /// Whether the file was created or appended to.
+    pub mode: HandoffWriteMode,
+
     /// Number of completed tasks mentioned.
     pub completed_tasks: usize,
 
@@ -44,12 +62,22 @@ pub enum HandoffError {
 /// Generates handoff files for session continuity.
 pub struct HandoffWriter {
     context: LoopContext,
+    formatting: FormattingConfig,
 }
 
 impl HandoffWriter {
     /// Creates a new handoff writer for the given loop context.
     pub fn new(context: LoopContext) -> Self {
-        Self { context }
+        Self {
+            context,
+            formatting: FormattingConfig::default(),
+        }
+    }
+
+    /// Sets the formatting policy (`core.formatting`) for written files.
+    pub fn with_formatting(mut self, formatting: FormattingConfig) -> Self {
+        self.formatting = formatting;
+        self
     }
 
     /// Generates the handoff file with session context.
@@ -74,16 +102,66 @@ impl HandoffWriter {
         // Count tasks for result
         let (completed_tasks, open_tasks) = self.count_tasks();
 
-        std::fs::write(&path, &content)?;
+        std::fs::write(&path, self.formatting.finish(content))?;
 
         Ok(HandoffResult {
             path,
+            mode: HandoffWriteMode::Created,
             completed_tasks,
             open_tasks,
             has_continuation_prompt: open_tasks > 0,
         })
     }
 
+    /// Appends a loop's summary to the shared handoff log.
+    ///
+    /// The section is headed by the loop name and a UTC timestamp. The log is
+    /// rewritten under an exclusive [`FileLock`] so loops finishing at the same
+    /// time never interleave their sections, and so the trailing-newline policy
+    /// holds however the previous section ended.
+    pub fn append_section(
+        &self,
+        loop_name: &str,
+        summary: &str,
+    ) -> Result<HandoffResult, HandoffError> {
+        let path = self.context.shared_handoff_path();
+        let lock = FileLock::new(&path)?;
+        let _guard = lock.exclusive()?;
+
+        let mut content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let existing = !content.is_empty();
+        if !existing {
+            content.push_str("# Loop Handoff Log\n\n");
+        } else if !content.ends_with('\n') {
+            content.push_str("\n\n");
+        }
+        content.push_str(&format!(
+            "## {} — {}\n\n",
+            loop_name,
+            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+        content.push_str(summary.trim_end());
+        content.push_str("\n\n");
+        std::fs::write(&path, self.formatting.finish(content))?;
+
+        let (completed_tasks, open_tasks) = self.count_tasks();
+        Ok(HandoffResult {
+            path,
+            mode: if existing {
+                HandoffWriteMode::Appended
+            } else {
+                HandoffWriteMode::Created
+            },
+            completed_tasks,
+            open_tasks,
+            has_continuation_prompt: false,
+        })
+    }
+
     /// Generates the handoff markdown content.
     fn generate_content(&self, original_prompt: &str) -> String {
         let mut content = String::new();
@@ -117,11 +195,12 @@ impl HandoffWriter {
     /// Writes git context (branch, commit, status).
     fn write_git_context(&self, content: &mut String) {
         let workspace = self.context.workspace();
+        let marker = self.formatting.list_marker.as_str();
 
         // Branch
         match get_current_branch(workspace) {
-            Ok(branch) => content.push_str(&format!("- **Branch:** `{}`\n", branch)),
-            Err(_) => content.push_str("- **Branch:** _(unknown)_\n"),
+            Ok(branch) => content.push_str(&format!("{} **Branch:** `{}`\n", marker, branch)),
+            Err(_) => content.push_str(&format!("{} **Branch:** _(unknown)_\n", marker)),
         }
 
         // Commit
@@ -129,17 +208,21 @@ impl HandoffWriter {
             Ok(sha) => {
                 let summary = get_commit_summary(workspace).unwrap_or_default();
                 if summary.is_empty() {
-                    content.push_str(&format!("- **HEAD:** `{}`\n", &sha[..7.min(sha.len())]));
+                    content.push_str(&format!(
+                        "{} **HEAD:** `{}`\n",
+                        marker,
+                        &sha[..7.min(sha.len())]
+                    ));
                 } else {
-                    content.push_str(&format!("- **HEAD:** {}\n", summary));
+                    content.push_str(&format!("{} **HEAD:** {}\n", marker, summary));
                 }
             }
-            Err(_) => content.push_str("- **HEAD:** _(no commits)_\n"),
+            Err(_) => content.push_str(&format!("{} **HEAD:** _(no commits)_\n", marker)),
         }
 
         // Loop ID if worktree
         if let Some(loop_id) = self.context.loop_id() {
-            content.push_str(&format!("- **Loop ID:** `{}`\n", loop_id));
+            content.push_str(&format!("{} **Loop ID:** `{}`\n", marker, loop_id));
         }
     }
 
@@ -159,6 +242,7 @@ impl HandoffWriter {
             content.push_str("_No tasks tracked in this session._\n");
             return;
         }
+        let marker = self.formatting.list_marker.as_str();
 
         // Completed tasks
         let completed: Vec<&Task> = tasks
@@ -169,7 +253,7 @@ impl HandoffWriter {
         if !completed.is_empty() {
             content.push_str("### Completed\n\n");
             for task in &completed {
-                content.push_str(&format!("- [x] {}\n", task.title));
+                content.push_str(&format!("{} [x] {}\n", marker, task.title));
             }
             content.push('\n');
         }
@@ -192,7 +276,10 @@ impl HandoffWriter {
                 } else {
                     format!(" _(blocked by: {})_", task.blocked_by.join(", "))
                 };
-                content.push_str(&format!("- {} {}{}\n", status_marker, task.title, blocked));
+                content.push_str(&format!(
+                    "{} {} {}{}\n",
+                    marker, status_marker, task.title, blocked
+                ));
             }
         }
     }
@@ -202,8 +289,9 @@ impl HandoffWriter {
         match get_recent_files(self.context.workspace(), 10) {
             Ok(files) if !files.is_empty() => {
                 content.push_str("Recently modified:\n\n");
+                let marker = self.formatting.list_marker.as_str();
                 for file in files {
-                    content.push_str(&format!("- `{}`\n", file));
+                    content.push_str(&format!("{} `{}`\n", marker, file));
                 }
             }
             _ => {
@@ -368,6 +456,87 @@ mod tests {
         assert!(content.contains("Remaining tasks"));
     }
 
+    #[test]
+    fn test_append_section_creates_then_appends() {
+        let (_temp, ctx) = setup_test_context();
+        let writer = HandoffWriter::new(ctx.clone());
+
+        let first = writer
+            .append_section("bright-maple", "Implemented the parser.\n")
+            .unwrap();
+        assert_eq!(first.mode, HandoffWriteMode::Created);
+        assert_eq!(first.path, ctx.shared_handoff_path());
+
+        let second = writer
+            .append_section("quiet-harbor", "Wired up the CLI.")
+            .unwrap();
+        assert_eq!(second.mode, HandoffWriteMode::Appended);
+
+        let content = fs::read_to_string(ctx.shared_handoff_path()).unwrap();
+        assert_eq!(content.matches("# Loop Handoff Log").count(), 1);
+        let first_idx = content.find("## bright-maple — ").unwrap();
+        let second_idx = content.find("## quiet-harbor — ").unwrap();
+        assert!(first_idx < second_idx);
+        assert!(content.contains("Implemented the parser.\n\n## quiet-harbor"));
+        assert!(content.ends_with("Wired up the CLI.\n\n"));
+    }
+
+    #[test]
+    fn test_writes_follow_formatting_policy() {
+        let (_temp, ctx) = setup_test_context();
+        let formatting = FormattingConfig {
+            trailing_newline: false,
+            list_marker: crate::config::ListMarker::Asterisk,
+            ..FormattingConfig::default()
+        };
+        let writer = HandoffWriter::new(ctx.clone()).with_formatting(formatting);
+
+        writer.write("Test prompt").unwrap();
+        let content = fs::read_to_string(ctx.handoff_path()).unwrap();
+        assert!(!content.ends_with('\n'));
+        assert!(content.contains("* **Branch:**"));
+        assert!(!content.contains("- **Branch:**"));
+
+        writer.append_section("bright-maple", "First.").unwrap();
+        writer.append_section("quiet-harbor", "Second.").unwrap();
+        let log = fs::read_to_string(ctx.shared_handoff_path()).unwrap();
+        assert!(log.contains("First.\n\n## quiet-harbor"), "{log}");
+        assert!(log.ends_with("Second."));
+    }
+
+    #[test]
+    fn test_append_section_concurrent_loops_do_not_interleave() {
+        let (_temp, ctx) = setup_test_context();
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let ctx = ctx.clone();
+                std::thread::spawn(move || {
+                    let summary = format!("line a {i}\nline b {i}\nline c {i}");
+                    HandoffWriter::new(ctx)
+                        .append_section(&format!("loop-{i}"), &summary)
+                        .unwrap()
+                })
+            })
+            .collect();
+        let created = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|r| r.mode == HandoffWriteMode::Created)
+            .count();
+        assert_eq!(created, 1);
+
+        let content = fs::read_to_string(ctx.shared_handoff_path()).unwrap();
+        for i in 0..8 {
+            let header = content.find(&format!("## loop-{i} — ")).unwrap();
+            let body = &content[header..];
+            let body = &body[body.find('\n').unwrap()..];
+            assert!(
+                body.starts_with(&format!("\n\nline a {i}\nline b {i}\nline c {i}\n\n")),
+                "{content}"
+            );
+        }
+    }
+
     #[test]
     fn test_truncate_prompt_short() {
         let result = truncate_prompt("short prompt", 100);
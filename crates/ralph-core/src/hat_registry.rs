@@ -3,6 +3,7 @@
 use crate::config::{HatConfig, RalphConfig};
 use ralph_proto::{Hat, HatId, Topic};
 use std::collections::{BTreeMap, HashSet};
+use tracing::warn;
 
 /// Registry for managing and creating hats from configuration.
 #[derive(Debug, Default)]
@@ -24,12 +25,19 @@ impl HatRegistry {
     /// Creates a registry from configuration.
     ///
     /// Empty config → empty registry (HatlessRalph is the fallback, not default hats).
+    /// Each hat's `extends` and `instructions_file` are resolved into its instructions.
     pub fn from_config(config: &RalphConfig) -> Self {
         let mut registry = Self::new();
 
         for (id, hat_config) in &config.hats {
-            let hat = Self::hat_from_config(id, hat_config);
-            registry.register_with_config(hat, hat_config.clone());
+            let mut hat_config = hat_config.clone();
+            // `validate()` rejects unresolvable instructions; fall back if it was skipped
+            match config.resolve_hat_instructions(id) {
+                Ok(instructions) => hat_config.instructions = instructions,
+                Err(e) => warn!(hat = %id, error = %e, "Using the hat's own instructions"),
+            }
+            let hat = Self::hat_from_config(id, &hat_config);
+            registry.register_with_config(hat, hat_config);
         }
 
         registry
@@ -180,6 +188,68 @@ mod tests {
         assert_eq!(registry.len(), 0);
     }
 
+    #[test]
+    fn test_from_config_resolves_extends_and_instructions_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("rubric.md"),
+            "Score each change 1-5.\n",
+        )
+        .unwrap();
+        let yaml = r#"
+hats:
+  base_reviewer:
+    name: "Base Reviewer"
+    triggers: ["review.base"]
+    instructions_file: "rubric.md"
+    instructions: "Be specific."
+  security_reviewer:
+    name: "Security Reviewer"
+    triggers: ["review.security"]
+    extends: base_reviewer
+    instructions: "Focus on auth.\n"
+"#;
+        let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        config.core.workspace_root = temp_dir.path().to_path_buf();
+        let registry = HatRegistry::from_config(&config);
+
+        let security = registry.get(&HatId::new("security_reviewer")).unwrap();
+        assert_eq!(
+            security.instructions,
+            "Score each change 1-5.\n\nBe specific.\n\nFocus on auth.\n"
+        );
+        let config_instructions = &registry
+            .get_config(&HatId::new("security_reviewer"))
+            .unwrap()
+            .instructions;
+        assert_eq!(config_instructions, &security.instructions);
+    }
+
+    #[test]
+    fn test_extends_cycle_is_rejected_and_falls_back() {
+        let yaml = r#"
+hats:
+  a:
+    name: "A"
+    triggers: ["a.start"]
+    extends: b
+    instructions: "A rules"
+  b:
+    name: "B"
+    triggers: ["b.start"]
+    extends: a
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let err = config.resolve_hat_instructions("a").unwrap_err();
+        assert!(err.to_string().contains("'extends' cycle: a -> b -> a"));
+
+        let registry = HatRegistry::from_config(&config);
+        assert_eq!(
+            registry.get(&HatId::new("a")).unwrap().instructions,
+            "A rules"
+        );
+    }
+
     #[test]
     fn test_custom_hats_from_config() {
         let yaml = r#"
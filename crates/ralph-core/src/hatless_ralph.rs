@@ -4,9 +4,9 @@
 
 use crate::config::{CoreConfig, ScratchpadConfig};
 use crate::hat_registry::HatRegistry;
+use crate::prompt_fragments::{PromptFragments, PromptSection};
 use ralph_proto::{HatId, Topic};
 use std::collections::HashMap;
-use std::path::Path;
 
 /// Hatless Ralph - the constant coordinator.
 pub struct HatlessRalph {
@@ -33,6 +33,8 @@ pub struct HatlessRalph {
     /// Current iteration number, set by EventLoop before build_prompt().
     /// Used to determine if this is the first iteration (fresh start).
     iteration: u32,
+    /// User overrides for built-in sections (`.ralph/prompts/*.md`).
+    fragments: PromptFragments,
 }
 
 /// Hat topology for multi-hat mode prompt generation.
@@ -40,6 +42,31 @@ pub struct HatTopology {
     hats: Vec<HatInfo>,
 }
 
+/// Structural issues found by [`HatTopology::analyze`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopologyReport {
+    /// Hats none of whose triggers is ever published.
+    pub unreachable_hats: Vec<String>,
+    /// Published events no hat subscribes to; only Ralph's fallback sees them.
+    pub dead_end_events: Vec<DeadEndEvent>,
+    /// Groups of hats that can trigger each other (including self-loops).
+    pub cycles: Vec<Vec<String>>,
+}
+
+impl TopologyReport {
+    /// Returns true when some hat can never run.
+    pub fn has_errors(&self) -> bool {
+        !self.unreachable_hats.is_empty()
+    }
+}
+
+/// An event published by a hat that no hat subscribes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadEndEvent {
+    pub hat: String,
+    pub topic: String,
+}
+
 /// Information about a hat that receives an event.
 #[derive(Debug, Clone)]
 pub struct EventReceiver {
@@ -216,6 +243,192 @@ impl HatTopology {
 
         Self { hats }
     }
+
+    /// Returns the hats in registration order.
+    pub fn hats(&self) -> &[HatInfo] {
+        &self.hats
+    }
+
+    /// Analyzes the event flow between hats.
+    ///
+    /// Events are published by the loop itself (`starting_event`, or
+    /// `task.start` when unset, and the other system topics) and by hats (their
+    /// `publishes`). A hat is unreachable when none of its trigger patterns
+    /// matches any of those. Events equal to `completion_promise` end the loop
+    /// and are never dead ends.
+    pub fn analyze(
+        &self,
+        starting_event: Option<&str>,
+        completion_promise: &str,
+    ) -> TopologyReport {
+        use std::collections::HashSet;
+
+        let mut published: HashSet<&str> =
+            crate::topic_check::SYSTEM_TOPICS.iter().copied().collect();
+        published.insert(starting_event.unwrap_or("task.start"));
+        for hat in &self.hats {
+            published.extend(hat.publishes.iter().map(String::as_str));
+        }
+
+        let unreachable_hats = self
+            .hats
+            .iter()
+            .filter(|hat| {
+                !hat.subscribes_to.iter().any(|trigger| {
+                    let pattern = Topic::new(trigger.as_str());
+                    published.iter().any(|topic| pattern.matches_str(topic))
+                })
+            })
+            .map(|hat| hat.name.clone())
+            .collect();
+
+        let dead_end_events = self
+            .hats
+            .iter()
+            .flat_map(|hat| {
+                hat.publishes
+                    .iter()
+                    .filter(|topic| topic.as_str() != completion_promise)
+                    .filter(|topic| hat.event_receivers.get(*topic).is_none_or(Vec::is_empty))
+                    .map(|topic| DeadEndEvent {
+                        hat: hat.name.clone(),
+                        topic: topic.clone(),
+                    })
+            })
+            .collect();
+
+        TopologyReport {
+            unreachable_hats,
+            dead_end_events,
+            cycles: self.cycles(),
+        }
+    }
+
+    /// Groups hats that can trigger each other, directly or transitively.
+    fn cycles(&self) -> Vec<Vec<String>> {
+        use std::collections::HashSet;
+
+        let successors = |name: &str| -> Vec<&str> {
+            self.hats
+                .iter()
+                .filter(|hat| hat.name == name)
+                .flat_map(|hat| hat.event_receivers.values().flatten())
+                .map(|receiver| receiver.name.as_str())
+                .collect()
+        };
+        let reach: HashMap<&str, HashSet<&str>> = self
+            .hats
+            .iter()
+            .map(|hat| {
+                let mut seen = HashSet::new();
+                let mut stack = successors(&hat.name);
+                while let Some(next) = stack.pop() {
+                    if seen.insert(next) {
+                        stack.extend(successors(next));
+                    }
+                }
+                (hat.name.as_str(), seen)
+            })
+            .collect();
+
+        let mut assigned: HashSet<&str> = HashSet::new();
+        let mut cycles = Vec::new();
+        for hat in &self.hats {
+            let name = hat.name.as_str();
+            if assigned.contains(name) || !reach[name].contains(name) {
+                continue;
+            }
+            let members: Vec<&str> = self
+                .hats
+                .iter()
+                .map(|other| other.name.as_str())
+                .filter(|other| reach[name].contains(other) && reach[other].contains(name))
+                .collect();
+            assigned.extend(members.iter().copied());
+            cycles.push(members.into_iter().map(String::from).collect());
+        }
+        cycles
+    }
+
+    /// Lists event edges in diagram order: Ralph to hats (Ralph publishes
+    /// every hat trigger), hats back to Ralph, then direct hat-to-hat flow.
+    /// `None` stands for Ralph.
+    fn flow_edges(&self) -> Vec<(Option<&HatInfo>, &str, Option<&HatInfo>)> {
+        let mut edges = Vec::new();
+        for hat in &self.hats {
+            for trigger in &hat.subscribes_to {
+                edges.push((None, trigger.as_str(), Some(hat)));
+            }
+        }
+        for hat in &self.hats {
+            for pub_event in &hat.publishes {
+                edges.push((Some(hat), pub_event.as_str(), None));
+            }
+        }
+        for source_hat in &self.hats {
+            for pub_event in &source_hat.publishes {
+                for target_hat in &self.hats {
+                    if target_hat.name != source_hat.name
+                        && target_hat.subscribes_to.contains(pub_event)
+                    {
+                        edges.push((Some(source_hat), pub_event.as_str(), Some(target_hat)));
+                    }
+                }
+            }
+        }
+        edges
+    }
+
+    /// Generates Mermaid flowchart source showing event flow between hats.
+    ///
+    /// Returns bare source (no code fence), suitable for a `.mmd` file.
+    pub fn mermaid_diagram(&self) -> String {
+        // Sanitized Mermaid node IDs (strip emojis/special chars)
+        let node_id = |hat: &HatInfo| -> String {
+            hat.name.chars().filter(|c| c.is_alphanumeric()).collect()
+        };
+
+        let mut diagram = String::from("flowchart LR\n");
+
+        // Entry point: task.start -> Ralph
+        diagram.push_str("    task.start((task.start)) --> Ralph\n");
+
+        for (source, event, target) in self.flow_edges() {
+            let from = source.map_or_else(|| "Ralph".to_string(), node_id);
+            let to = match target {
+                None => "Ralph".to_string(),
+                // Label Ralph's delegation edges with the display name once
+                Some(hat) if source.is_none() && node_id(hat) != hat.name => {
+                    format!("{}[{}]", node_id(hat), hat.name)
+                }
+                Some(hat) => node_id(hat),
+            };
+            diagram.push_str(&format!("    {} -->|{}| {}\n", from, event, to));
+        }
+
+        diagram
+    }
+
+    /// Generates a Graphviz DOT digraph of the same event flow as
+    /// [`HatTopology::mermaid_diagram`].
+    pub fn dot_diagram(&self) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let node = |hat: Option<&HatInfo>| quote(hat.map_or("Ralph", |h| h.name.as_str()));
+
+        let mut diagram = String::from("digraph hats {\n    rankdir=LR;\n");
+        diagram.push_str("    \"task.start\" [shape=circle];\n");
+        diagram.push_str("    \"task.start\" -> \"Ralph\";\n");
+        for (source, event, target) in self.flow_edges() {
+            diagram.push_str(&format!(
+                "    {} -> {} [label={}];\n",
+                node(source),
+                node(target),
+                quote(event)
+            ));
+        }
+        diagram.push_str("}\n");
+        diagram
+    }
 }
 
 impl HatlessRalph {
@@ -250,6 +463,7 @@ impl HatlessRalph {
             robot_guidance: Vec::new(),
             active_scratchpad,
             iteration: 0,
+            fragments: PromptFragments::default(),
         }
     }
 
@@ -292,6 +506,20 @@ impl HatlessRalph {
         self
     }
 
+    /// Uses `fragments` in place of the matching built-in sections
+    /// (GUARDRAILS, WORKFLOW, EVENT WRITING).
+    pub fn with_prompt_fragments(mut self, fragments: PromptFragments) -> Self {
+        self.fragments = fragments;
+        self
+    }
+
+    /// Returns the override for `section` formatted as a prompt section.
+    fn fragment_section(&self, section: PromptSection) -> Option<String> {
+        self.fragments
+            .get(section)
+            .map(|fragment| format!("{fragment}\n\n"))
+    }
+
     /// Stores the user's original objective so it persists across all iterations.
     ///
     /// Called once during initialization. The objective is injected into every
@@ -301,6 +529,11 @@ impl HatlessRalph {
         self.objective = Some(objective);
     }
 
+    /// Returns the stored objective, if initialized.
+    pub fn objective(&self) -> Option<&str> {
+        self.objective.as_deref()
+    }
+
     /// Sets robot guidance messages collected from `human.guidance` events.
     ///
     /// Called by `EventLoop::build_prompt()` before `HatlessRalph::build_prompt()`.
@@ -387,14 +620,20 @@ impl HatlessRalph {
             .any(|h| !h.instructions.trim().is_empty());
 
         if !has_custom_workflow {
-            prompt.push_str(&self.workflow_section());
+            let workflow = self
+                .fragment_section(PromptSection::Workflow)
+                .unwrap_or_else(|| self.workflow_section());
+            prompt.push_str(&workflow);
         }
 
         if let Some(topology) = &self.hat_topology {
             prompt.push_str(&self.hats_section(topology, active_hats));
         }
 
-        prompt.push_str(&self.event_writing_section());
+        let event_writing = self
+            .fragment_section(PromptSection::EventWriting)
+            .unwrap_or_else(|| self.event_writing_section());
+        prompt.push_str(&event_writing);
 
         // Only show completion instructions when Ralph is coordinating (no active hat).
         // Hats should publish events and stop — only Ralph decides when the loop is done.
@@ -446,7 +685,10 @@ You MUST NOT get distracted by workflow mechanics — they serve this goal.
         // When scratchpad is enabled, check if it already exists
         // (existing scratchpad means resumed session, not fresh)
         if self.active_scratchpad.enabled {
-            return !Path::new(&self.active_scratchpad.path).exists();
+            return !self
+                .core
+                .resolve_path(&self.active_scratchpad.path)
+                .exists();
         }
 
         // First iteration + scratchpad disabled = fresh start
@@ -457,8 +699,7 @@ You MUST NOT get distracted by workflow mechanics — they serve this goal.
         // Adapt guardrails based on whether scratchpad or memories mode is active
         let guardrails = self
             .core
-            .guardrails
-            .iter()
+            .all_guardrails()
             .enumerate()
             .map(|(i, g)| {
                 // Replace scratchpad reference with memories reference when memories are enabled
@@ -530,6 +771,18 @@ Its content is auto-injected in `<scratchpad>` tags at the top of your context e
 ",
                 scratchpad = self.active_scratchpad.path,
             ));
+
+            // Without runtime tasks, completion is judged from scratchpad markers
+            if !self.memories_enabled {
+                prompt.push_str(
+                    "**Task markers** (completion waits for every task to be done or cancelled):\n\
+- `- [ ]` pending\n\
+- `- [>]` in progress (still counts as not done)\n\
+- `- [x]` done\n\
+- `- [~]` cancelled\n\
+\n",
+                );
+            }
         }
 
         // TASKS section removed — now injected via skills auto-injection pipeline
@@ -580,7 +833,7 @@ Keep temporary artifacts where later steps can still inspect them, such as a rep
         }
 
         // List available context files in .ralph/agent/
-        if let Ok(entries) = std::fs::read_dir(".ralph/agent") {
+        if let Ok(entries) = std::fs::read_dir(self.core.resolve_path(".ralph/agent")) {
             let md_files: Vec<String> = entries
                 .filter_map(|e| e.ok())
                 .filter_map(|e| {
@@ -611,13 +864,16 @@ Keep temporary artifacts where later steps can still inspect them, such as a rep
             }
         }
 
-        prompt.push_str(&format!(
-            r"### GUARDRAILS
+        match self.fragment_section(PromptSection::Guardrails) {
+            Some(fragment) => prompt.push_str(&fragment),
+            None => prompt.push_str(&format!(
+                r"### GUARDRAILS
 {guardrails}
 
 ",
-            guardrails = guardrails,
-        ));
+                guardrails = guardrails,
+            )),
+        }
 
         prompt
     }
@@ -877,7 +1133,9 @@ You MUST continue.\n\
             section.push('\n');
 
             // Generate Mermaid topology diagram
-            section.push_str(&self.generate_mermaid_diagram(topology, &ralph_publishes));
+            section.push_str("```mermaid\n");
+            section.push_str(&topology.mermaid_diagram());
+            section.push_str("```\n");
             section.push('\n');
 
             // Add explicit constraint listing valid events Ralph can publish
@@ -942,111 +1200,26 @@ You MUST continue.\n\
         section
     }
 
-    /// Generates a Mermaid flowchart showing event flow between hats.
-    fn generate_mermaid_diagram(&self, topology: &HatTopology, ralph_publishes: &[&str]) -> String {
-        // Pre-compute sanitized Mermaid node IDs (strip emojis/special chars)
-        let node_ids: std::collections::HashMap<&str, String> = topology
-            .hats
-            .iter()
-            .map(|h| {
-                let id = h
-                    .name
-                    .chars()
-                    .filter(|c| c.is_alphanumeric())
-                    .collect::<String>();
-                (h.name.as_str(), id)
-            })
-            .collect();
-
-        let mut diagram = String::from("```mermaid\nflowchart LR\n");
-
-        // Entry point: task.start -> Ralph
-        diagram.push_str("    task.start((task.start)) --> Ralph\n");
-
-        // Ralph -> hats (via ralph_publishes which are hat triggers)
-        for hat in &topology.hats {
-            let node_id = &node_ids[hat.name.as_str()];
-            for trigger in &hat.subscribes_to {
-                if ralph_publishes.contains(&trigger.as_str()) {
-                    if node_id == &hat.name {
-                        diagram.push_str(&format!("    Ralph -->|{}| {}\n", trigger, hat.name));
-                    } else {
-                        diagram.push_str(&format!(
-                            "    Ralph -->|{}| {}[{}]\n",
-                            trigger, node_id, hat.name
-                        ));
-                    }
-                }
-            }
-        }
-
-        // Hats -> Ralph (via hat publishes)
-        for hat in &topology.hats {
-            let node_id = &node_ids[hat.name.as_str()];
-            for pub_event in &hat.publishes {
-                diagram.push_str(&format!("    {} -->|{}| Ralph\n", node_id, pub_event));
-            }
-        }
-
-        // Hat -> Hat connections (when one hat publishes what another triggers on)
-        for source_hat in &topology.hats {
-            let source_id = &node_ids[source_hat.name.as_str()];
-            for pub_event in &source_hat.publishes {
-                for target_hat in &topology.hats {
-                    if target_hat.name != source_hat.name
-                        && target_hat.subscribes_to.contains(pub_event)
-                    {
-                        let target_id = &node_ids[target_hat.name.as_str()];
-                        diagram.push_str(&format!(
-                            "    {} -->|{}| {}\n",
-                            source_id, pub_event, target_id
-                        ));
-                    }
-                }
-            }
-        }
-
-        diagram.push_str("```\n");
-        diagram
-    }
-
-    /// Validates that all hats are reachable from task.start.
-    /// Logs warnings for unreachable hats but doesn't fail.
+    /// Logs warnings for hats that can never be triggered.
+    ///
+    /// `ralph hats validate` reports the same analysis (plus dead-end events
+    /// and cycles) without running a loop.
     fn validate_topology_reachability(&self, topology: &HatTopology) {
-        use std::collections::HashSet;
         use tracing::warn;
 
-        // Collect all events that are published (reachable)
-        let mut reachable_events: HashSet<&str> = HashSet::new();
-        reachable_events.insert("task.start");
-
-        // Ralph publishes all hat triggers, so add those
-        for hat in &topology.hats {
-            for trigger in &hat.subscribes_to {
-                reachable_events.insert(trigger.as_str());
-            }
-        }
-
-        // Now add all events published by hats (they become reachable after hat runs)
-        for hat in &topology.hats {
-            for pub_event in &hat.publishes {
-                reachable_events.insert(pub_event.as_str());
-            }
-        }
-
-        // Check each hat's triggers - warn if none of them are reachable
-        for hat in &topology.hats {
-            let hat_reachable = hat
-                .subscribes_to
+        let report = topology.analyze(self.starting_event.as_deref(), &self.completion_promise);
+        for name in &report.unreachable_hats {
+            let triggers = topology
+                .hats
                 .iter()
-                .any(|t| reachable_events.contains(t.as_str()));
-            if !hat_reachable {
-                warn!(
-                    hat = %hat.name,
-                    triggers = ?hat.subscribes_to,
-                    "Hat has triggers that are never published - it may be unreachable"
-                );
-            }
+                .find(|hat| &hat.name == name)
+                .map(|hat| hat.subscribes_to.clone())
+                .unwrap_or_default();
+            warn!(
+                hat = %name,
+                triggers = ?triggers,
+                "Hat has triggers that are never published - it may be unreachable"
+            );
         }
     }
 
@@ -1141,6 +1314,24 @@ mod tests {
     use super::*;
     use crate::config::RalphConfig;
 
+    #[test]
+    fn test_context_files_listed_from_workspace_root() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let agent_dir = temp.path().join(".ralph/agent");
+        std::fs::create_dir_all(&agent_dir).unwrap();
+        std::fs::write(agent_dir.join("research-notes.md"), "notes").unwrap();
+        std::fs::write(agent_dir.join("scratchpad.md"), "scratch").unwrap();
+
+        let core = RalphConfig::default().core.with_workspace_root(temp.path());
+        let registry = HatRegistry::new();
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", core, &registry, None);
+
+        // The workspace is not the cwd, as under ralph-bench
+        let prompt = ralph.build_prompt("", &[]);
+        assert!(prompt.contains("- `.ralph/agent/research-notes.md`"));
+        assert!(!prompt.contains("- `.ralph/agent/scratchpad.md`"));
+    }
+
     #[test]
     fn test_prompt_without_hats() {
         let config = RalphConfig::default();
@@ -1184,6 +1375,34 @@ mod tests {
         assert!(prompt.contains("LOOP_COMPLETE"));
     }
 
+    #[test]
+    fn test_prompt_fragments_override_sections() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("workflow.md"),
+            "## WORKFLOW\n\nFollow the team playbook.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("event_writing.md"),
+            "## EVENT WRITING\n\nEmit with `ralph emit` only.",
+        )
+        .unwrap();
+        let config = RalphConfig::default();
+        let registry = HatRegistry::new();
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", config.core.clone(), &registry, None)
+            .with_prompt_fragments(crate::PromptFragments::load(temp.path()));
+
+        let prompt = ralph.build_prompt("", &[]);
+
+        assert!(prompt.contains("## WORKFLOW\n\nFollow the team playbook.\n\n"));
+        assert!(!prompt.contains("### 2. PLAN"));
+        assert!(prompt.contains("## EVENT WRITING\n\nEmit with `ralph emit` only.\n\n"));
+        assert!(!prompt.contains("You MUST NOT use echo/cat"));
+        // No guardrails.md: built-in section kept
+        assert!(prompt.contains("### GUARDRAILS\n999."));
+    }
+
     #[test]
     fn test_prompt_with_hats() {
         // Test multi-hat mode WITHOUT starting_event (no fast path)
@@ -1749,6 +1968,82 @@ hats:
         );
     }
 
+    #[test]
+    fn test_topology_analyze_reports_issues() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done", "LOOP_COMPLETE"]
+  reviewer:
+    name: "Reviewer"
+    triggers: ["build.done"]
+    publishes: ["build.task", "review.note"]
+  orphan:
+    name: "Orphan"
+    triggers: []
+    publishes: ["orphan.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let topology = HatTopology::from_registry(&registry);
+
+        let report = topology.analyze(None, "LOOP_COMPLETE");
+
+        assert!(report.has_errors());
+        assert_eq!(report.unreachable_hats, vec!["Orphan".to_string()]);
+        let dead_ends: Vec<(&str, &str)> = report
+            .dead_end_events
+            .iter()
+            .map(|d| (d.hat.as_str(), d.topic.as_str()))
+            .collect();
+        assert_eq!(dead_ends.len(), 2, "{dead_ends:?}");
+        assert!(dead_ends.contains(&("Reviewer", "review.note")));
+        assert!(dead_ends.contains(&("Orphan", "orphan.done")));
+        assert_eq!(report.cycles.len(), 1);
+        let mut cycle = report.cycles[0].clone();
+        cycle.sort();
+        assert_eq!(cycle, vec!["Builder".to_string(), "Reviewer".to_string()]);
+
+        let diagram = topology.mermaid_diagram();
+        assert!(diagram.starts_with("flowchart LR\n"));
+        assert!(diagram.contains("Builder -->|build.done| Reviewer"));
+    }
+
+    #[test]
+    fn test_topology_analyze_flags_hat_whose_trigger_nobody_publishes() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["plan.start"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.*"]
+    publishes: ["LOOP_COMPLETE"]
+  deployer:
+    name: "Deployer"
+    triggers: ["deploy.start"]
+    publishes: ["LOOP_COMPLETE"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let topology = HatTopology::from_registry(&registry);
+
+        // Nobody publishes deploy.start or plan.start; build.* matches build.task
+        let report = topology.analyze(None, "LOOP_COMPLETE");
+        assert_eq!(
+            report.unreachable_hats,
+            vec!["Deployer".to_string(), "Planner".to_string()]
+        );
+
+        // The starting event reaches Planner
+        let report = topology.analyze(Some("plan.start"), "LOOP_COMPLETE");
+        assert_eq!(report.unreachable_hats, vec!["Deployer".to_string()]);
+    }
+
     #[test]
     fn test_topology_table_only_when_ralph_coordinating() {
         // Topology table + Mermaid shown only when Ralph is coordinating (no active hats)
@@ -1833,6 +2128,24 @@ hats:
         );
     }
 
+    #[test]
+    fn test_scratchpad_task_markers_listed_without_memories() {
+        let config = RalphConfig::default();
+        let registry = HatRegistry::new();
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", config.core.clone(), &registry, None);
+
+        let prompt = ralph.build_prompt("", &[]);
+        assert!(prompt.contains("**Task markers**"));
+        assert!(prompt.contains("- `- [>]` in progress (still counts as not done)"));
+
+        let ralph = ralph.with_memories_enabled(true);
+        let prompt = ralph.build_prompt("", &[]);
+        assert!(
+            !prompt.contains("**Task markers**"),
+            "Runtime tasks replace scratchpad markers when memories are enabled"
+        );
+    }
+
     #[test]
     fn test_scratchpad_included_with_memories_enabled() {
         // When memories are enabled, scratchpad should STILL be included (not excluded)
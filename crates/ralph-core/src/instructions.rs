@@ -6,6 +6,7 @@
 //! - 999+: Guardrails (higher = more important)
 
 use crate::config::{CoreConfig, EventMetadata, ScratchpadConfig};
+use crate::prompt_fragments::{PromptFragments, PromptSection};
 use ralph_proto::Hat;
 use std::collections::HashMap;
 
@@ -22,6 +23,8 @@ pub struct InstructionBuilder {
     core: CoreConfig,
     /// Event metadata for deriving instructions from pub/sub contracts.
     events: HashMap<String, EventMetadata>,
+    /// User overrides for built-in sections (`.ralph/prompts/*.md`).
+    fragments: PromptFragments,
 }
 
 impl InstructionBuilder {
@@ -30,12 +33,25 @@ impl InstructionBuilder {
         Self {
             core,
             events: HashMap::new(),
+            fragments: PromptFragments::default(),
         }
     }
 
     /// Creates a new instruction builder with event metadata for custom hats.
     pub fn with_events(core: CoreConfig, events: HashMap<String, EventMetadata>) -> Self {
-        Self { core, events }
+        Self {
+            core,
+            events,
+            fragments: PromptFragments::default(),
+        }
+    }
+
+    /// Uses `fragments` in place of the matching built-in sections.
+    ///
+    /// Only the `guardrails` section appears in custom hat instructions.
+    pub fn with_prompt_fragments(mut self, fragments: PromptFragments) -> Self {
+        self.fragments = fragments;
+        self
     }
 
     /// Derives instructions from a hat's pub/sub contract and event metadata.
@@ -78,8 +94,7 @@ impl InstructionBuilder {
 
     fn guardrails(&self, scratchpad: &ScratchpadConfig) -> String {
         self.core
-            .guardrails
-            .iter()
+            .all_guardrails()
             .enumerate()
             .map(|(i, guardrail)| {
                 let guardrail = if !scratchpad.enabled && guardrail == DEFAULT_SCRATCHPAD_GUARDRAIL
@@ -208,7 +223,10 @@ impl InstructionBuilder {
         events_context: &str,
         scratchpad: &ScratchpadConfig,
     ) -> String {
-        let guardrails = self.guardrails(scratchpad);
+        let guardrails = match self.fragments.get(PromptSection::Guardrails) {
+            Some(fragment) => fragment.to_string(),
+            None => format!("### GUARDRAILS\n{}", self.guardrails(scratchpad)),
+        };
         let role_instructions = if hat.instructions.is_empty() {
             self.derive_instructions_from_contract(hat, scratchpad)
         } else {
@@ -270,7 +288,6 @@ You MUST NOT close tasks unless ALL conditions are met:
 You MUST publish a result event with evidence using `ralph emit`.
 {publish_topics}{must_publish}
 
-### GUARDRAILS
 {guardrails}
 
 ---
@@ -363,7 +380,9 @@ mod tests {
             scratchpad: scratchpad.clone(),
             specs_dir: "./specifications/".to_string(),
             guardrails: vec!["Custom rule one".to_string(), "Custom rule two".to_string()],
+            extra_guardrails: Vec::new(),
             workspace_root: std::path::PathBuf::from("."),
+            formatting: crate::config::FormattingConfig::default(),
         };
         let builder = InstructionBuilder::new(custom_core);
 
@@ -375,6 +394,25 @@ mod tests {
         assert!(instructions.contains("1000. Custom rule two"));
     }
 
+    #[test]
+    fn test_guardrails_fragment_overrides_builtin() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("guardrails.md"),
+            "### TEAM RULES\n1. Never touch migrations\n",
+        )
+        .unwrap();
+        let builder =
+            default_builder().with_prompt_fragments(crate::PromptFragments::load(temp.path()));
+
+        let hat = Hat::new("worker", "Worker").with_instructions("Do the work.");
+        let instructions = builder.build_custom_hat(&hat, "context", &ScratchpadConfig::default());
+
+        assert!(instructions.contains("### TEAM RULES\n1. Never touch migrations\n\n---"));
+        assert!(!instructions.contains("### GUARDRAILS"));
+        assert!(!instructions.contains("999."));
+    }
+
     #[test]
     fn test_must_publish_injected_for_explicit_instructions() {
         use ralph_proto::Topic;
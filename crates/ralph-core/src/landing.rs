@@ -9,6 +9,7 @@
 //! This pattern ensures clean session boundaries and enables seamless
 //! handoffs between Ralph loops.
 
+use crate::config::FormattingConfig;
 use crate::git_ops::{
     AutoCommitResult, auto_commit_changes, clean_stashes, is_working_tree_clean, prune_remote_refs,
 };
@@ -89,20 +90,28 @@ impl Default for LandingConfig {
 pub struct LandingHandler {
     context: LoopContext,
     config: LandingConfig,
+    formatting: FormattingConfig,
 }
 
 impl LandingHandler {
     /// Creates a new landing handler for the given loop context.
     pub fn new(context: LoopContext) -> Self {
+        Self::with_config(context, LandingConfig::default())
+    }
+
+    /// Creates a landing handler with custom configuration.
+    pub fn with_config(context: LoopContext, config: LandingConfig) -> Self {
         Self {
             context,
-            config: LandingConfig::default(),
+            config,
+            formatting: FormattingConfig::default(),
         }
     }
 
-    /// Creates a landing handler with custom configuration.
-    pub fn with_config(context: LoopContext, config: LandingConfig) -> Self {
-        Self { context, config }
+    /// Sets the formatting policy (`core.formatting`) for the handoff files.
+    pub fn with_formatting(mut self, formatting: FormattingConfig) -> Self {
+        self.formatting = formatting;
+        self
     }
 
     /// Executes the landing sequence.
@@ -181,7 +190,8 @@ impl LandingHandler {
 
         // Step 4: Generate handoff prompt
         let handoff_path = if self.config.generate_handoff {
-            let writer = HandoffWriter::new(self.context.clone());
+            let writer =
+                HandoffWriter::new(self.context.clone()).with_formatting(self.formatting.clone());
             match writer.write(prompt) {
                 Ok(result) => {
                     info!(
@@ -191,6 +201,19 @@ impl LandingHandler {
                         open = result.open_tasks,
                         "Generated handoff file"
                     );
+                    // Worktree loops also record themselves in the shared log
+                    if let Some(loop_name) = self.context.loop_id() {
+                        let marker = self.formatting.list_marker.as_str();
+                        let summary = format!(
+                            "{marker} **Completed tasks:** {}\n{marker} **Open tasks:** {}\n{marker} **Handoff:** `{}`",
+                            result.completed_tasks,
+                            result.open_tasks,
+                            result.path.display()
+                        );
+                        if let Err(e) = writer.append_section(loop_name, &summary) {
+                            warn!(loop_id = %loop_id, error = %e, "Failed to append to shared handoff log");
+                        }
+                    }
                     result.path
                 }
                 Err(e) => {
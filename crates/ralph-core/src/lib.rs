@@ -13,7 +13,11 @@
 #[cfg(feature = "recording")]
 mod cli_capture;
 mod config;
+mod config_migration;
+mod coverage;
 pub mod diagnostics;
+pub mod drift_detection;
+mod event_builder;
 mod event_logger;
 mod event_loop;
 mod event_parser;
@@ -34,15 +38,19 @@ mod loop_name;
 pub mod loop_registry;
 mod memory;
 pub mod memory_parser;
+mod memory_ranking;
 mod memory_store;
 pub mod merge_queue;
 pub mod planning_session;
 pub mod preflight;
 pub mod preset_source;
+mod prompt_fragments;
+mod run_manifest;
 #[cfg(feature = "recording")]
 mod session_player;
 #[cfg(feature = "recording")]
 mod session_recorder;
+mod shutdown;
 pub mod skill;
 pub mod skill_registry;
 mod summary_writer;
@@ -51,6 +59,7 @@ pub mod task_definition;
 pub mod task_store;
 pub mod testing;
 mod text;
+pub mod topic_check;
 mod urgent_steer;
 pub mod utils;
 pub mod wave_detection;
@@ -60,30 +69,36 @@ pub mod workspace;
 pub mod worktree;
 
 #[cfg(feature = "recording")]
-pub use cli_capture::{CliCapture, CliCapturePair};
+pub use cli_capture::{CaptureMode, CliCapture, CliCapturePair};
 pub use config::{
-    CliConfig, ConfigError, CoreConfig, EventLoopConfig, EventMetadata, FeaturesConfig, HatBackend,
-    HatConfig, InjectMode, MemoriesConfig, MemoriesFilter, RalphConfig, RobotMode,
-    ScratchpadConfig, SkillOverride, SkillsConfig, resolve_context_window,
-    resolve_context_window_for_backend,
+    CliConfig, ConfigError, CoreConfig, DriftDetectionConfig, EventLoopConfig, EventMetadata,
+    FeaturesConfig, FormattingConfig, HatBackend, HatConfig, InjectMode, ListMarker,
+    MemoriesConfig, MemoriesFilter, MemoryRanking, MergeConfig, RalphConfig, RobotMode,
+    ScratchpadConfig, SkillOverride, SkillsConfig, SummarySection, SystemEventsMode,
+    TopicCheckMode, resolve_context_window, resolve_context_window_for_backend,
 };
+pub use config_migration::{ConfigMigration, migrate_v1_config};
 // Re-export loop_name types (also available via FeaturesConfig.loop_naming)
 pub use diagnostics::DiagnosticsCollector;
-pub use event_logger::{EventHistory, EventLogger, EventRecord};
+pub use event_builder::{EventBuildError, EventBuilder, validate_topic};
+pub use event_logger::{
+    EventHistory, EventLogger, EventRecord, is_system_topic, system_events_path,
+};
 pub use event_loop::{
-    EventLoop, LoopState, ProcessedEvents, ProcessedEventsWithWaves, TerminationReason, UserPrompt,
+    EventLoop, IterationTiming, LOOP_STATE_VERSION, LoopState, PersistedLoopState, ProcessedEvents,
+    ProcessedEventsWithWaves, ReplayOutcome, TerminationReason, UserPrompt,
 };
-pub use event_parser::EventParser;
+pub use event_parser::{CLAUDE_COST_MARKERS, EventParser, default_cost_markers};
 pub use event_reader::{Event, EventReader, MalformedLine, ParseResult};
-pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockedFile};
+pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockType, LockedFile, LockedFileGuard};
 pub use git_ops::{
     AutoCommitResult, GitOpsError, auto_commit_changes, clean_stashes, get_commit_summary,
     get_current_branch, get_head_sha, get_recent_files, has_uncommitted_changes,
-    is_working_tree_clean, prune_remote_refs,
+    has_unmerged_commits, is_working_tree_clean, prune_remote_refs,
 };
-pub use handoff::{HandoffError, HandoffResult, HandoffWriter};
+pub use handoff::{HandoffError, HandoffResult, HandoffWriteMode, HandoffWriter};
 pub use hat_registry::HatRegistry;
-pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph};
+pub use hatless_ralph::{DeadEndEvent, HatInfo, HatTopology, HatlessRalph, TopologyReport};
 pub use hooks::{
     HookDefaults, HookEngine, HookExecutor, HookExecutorContract, HookExecutorError,
     HookInvocationPayload, HookMutationConfig, HookOnError, HookPayloadBuilderInput,
@@ -96,18 +111,25 @@ pub use instructions::InstructionBuilder;
 pub use landing::{LandingConfig, LandingError, LandingHandler, LandingResult};
 pub use loop_completion::{CompletionAction, CompletionError, LoopCompletionHandler};
 pub use loop_context::LoopContext;
-pub use loop_history::{HistoryError, HistoryEvent, HistoryEventType, HistorySummary, LoopHistory};
+pub use loop_history::{
+    HistoryCounts, HistoryError, HistoryEvent, HistoryEventType, HistoryFilter, HistorySummary,
+    LoopHistory,
+};
 pub use loop_lock::{LockError, LockGuard, LockMetadata, LoopLock};
-pub use loop_name::{LoopNameGenerator, LoopNamingConfig};
+pub use loop_name::{LoopNameGenerator, LoopNameTheme, LoopNamingConfig};
 pub use loop_registry::{LoopEntry, LoopRegistry, RegistryError};
 pub use memory::{Memory, MemoryType};
+pub use memory_ranking::{rank_by_relevance, select_within_budget};
 pub use memory_store::{
-    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget,
+    DEFAULT_MEMORIES_PATH, DuplicateGroup, MarkdownMemoryStore, StaleMemory,
+    extract_path_references, format_memories_as_markdown, truncate_to_budget,
 };
 pub use merge_queue::{
     MergeButtonState, MergeEntry, MergeEvent, MergeEventType, MergeOption, MergeQueue,
-    MergeQueueError, MergeState, SteeringDecision, merge_button_state, merge_execution_summary,
-    merge_needs_steering, smart_merge_summary,
+    MergeQueueError, MergeState, MergeStrategy, SteeringDecision, merge_button_state,
+    merge_execution_summary, merge_loop_env, merge_needs_steering,
+    merge_needs_steering_with_strategy, smart_merge_summary, squash_commit_message,
+    squash_history_loss,
 };
 pub use planning_session::{
     ConversationEntry, ConversationType, PlanningSession, PlanningSessionError, SessionMetadata,
@@ -120,29 +142,41 @@ pub use preflight::{
 pub use preset_source::{
     PresetRegistry, PresetSource, PresetSourceError, TomlPresetSource, YamlPresetSource,
 };
+pub use prompt_fragments::{PROMPT_FRAGMENTS_DIR, PromptFragments, PromptSection};
+pub use run_manifest::{
+    RUN_MANIFEST_VERSION, RunManifest, RunManifestError, content_hash, generate_seed,
+};
 #[cfg(feature = "recording")]
-pub use session_player::{PlayerConfig, ReplayMode, SessionPlayer, TimestampedRecord};
+pub use session_player::{
+    PlayerConfig, ReplayMode, SessionHeader, SessionPlayer, StartPoint, TimestampedRecord,
+    UnsupportedRecordVersion,
+};
 #[cfg(feature = "recording")]
-pub use session_recorder::{Record, SessionRecorder};
+pub use session_recorder::{
+    RECORD_FORMAT_VERSION, Record, SessionRecorder, SessionWriter, is_gzip_path,
+};
+pub use shutdown::{DEFAULT_FORCE_QUIT_WINDOW, ShutdownPhase, ShutdownSignal};
 pub use skill::{SkillEntry, SkillFrontmatter, SkillSource, parse_frontmatter};
 pub use skill_registry::SkillRegistry;
-pub use summary_writer::SummaryWriter;
+pub use summary_writer::{
+    LoopOverview, Summary, SummaryWriter, build_loop_overview, summarize_loops,
+};
 pub use task::{Task, TaskStatus};
 pub use task_definition::{
     TaskDefinition, TaskDefinitionError, TaskSetup, TaskSuite, Verification,
 };
-pub use task_store::TaskStore;
-pub use text::{floor_char_boundary, truncate_with_ellipsis};
+pub use task_store::{DependencyCycle, TaskStore};
+pub use text::{elide_middle, floor_char_boundary, truncate_with_ellipsis};
 pub use urgent_steer::{UrgentSteerRecord, UrgentSteerStore};
 pub use wave_detection::{DetectedWave, detect_wave_events};
 pub use wave_prompt::{WaveWorkerContext, build_wave_worker_prompt};
 pub use wave_tracker::{CompletedWave, WaveFailure, WaveProgress, WaveResult, WaveTracker};
 pub use workspace::{
-    CleanupPolicy, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
-    WorkspaceManager,
+    CleanupPolicy, TaskWorkspace, VerificationFailure, VerificationResult, WorkspaceError,
+    WorkspaceInfo, WorkspaceManager,
 };
 pub use worktree::{
     SyncStats, Worktree, WorktreeConfig, WorktreeError, create_worktree, ensure_gitignore,
-    list_ralph_worktrees, list_worktrees, remove_worktree, sync_working_directory_to_worktree,
-    worktree_exists,
+    list_ralph_worktrees, list_worktrees, loop_branch_exists, remove_worktree,
+    sync_working_directory_to_worktree, worktree_exists,
 };
@@ -33,10 +33,11 @@
 //! assert!(matches!(action, CompletionAction::Enqueued { .. }));
 //! ```
 
+use crate::config::FormattingConfig;
 use crate::git_ops::auto_commit_changes;
 use crate::landing::{LandingHandler, LandingResult};
 use crate::loop_context::LoopContext;
-use crate::merge_queue::{MergeQueue, MergeQueueError};
+use crate::merge_queue::{MergeQueue, MergeQueueError, MergeStrategy};
 use tracing::{debug, info, warn};
 
 /// Action taken upon loop completion.
@@ -109,6 +110,12 @@ pub enum CompletionError {
 pub struct LoopCompletionHandler {
     /// Whether auto-merge is enabled (default: true).
     auto_merge: bool,
+
+    /// Strategy recorded when a worktree loop is enqueued.
+    merge_strategy: MergeStrategy,
+
+    /// Formatting policy (`core.formatting`) for the landing's handoff files.
+    formatting: FormattingConfig,
 }
 
 impl Default for LoopCompletionHandler {
@@ -125,7 +132,23 @@ impl LoopCompletionHandler {
     /// * `auto_merge` - If true, completed worktree loops are enqueued for merge-ralph.
     ///   If false, worktrees are left for manual merge.
     pub fn new(auto_merge: bool) -> Self {
-        Self { auto_merge }
+        Self {
+            auto_merge,
+            merge_strategy: MergeStrategy::default(),
+            formatting: FormattingConfig::default(),
+        }
+    }
+
+    /// Sets the merge strategy recorded for enqueued loops (`merge.strategy`).
+    pub fn with_merge_strategy(mut self, strategy: MergeStrategy) -> Self {
+        self.merge_strategy = strategy;
+        self
+    }
+
+    /// Sets the formatting policy (`core.formatting`) for landing output.
+    pub fn with_formatting(mut self, formatting: FormattingConfig) -> Self {
+        self.formatting = formatting;
+        self
     }
 
     /// Handles loop completion, taking appropriate action based on context.
@@ -199,7 +222,7 @@ impl LoopCompletionHandler {
 
             // Enqueue to merge queue for automatic merge-ralph processing
             let queue = MergeQueue::new(context.repo_root());
-            queue.enqueue(&loop_id, prompt)?;
+            queue.enqueue_with_strategy(&loop_id, prompt, self.merge_strategy)?;
 
             info!(
                 loop_id = %loop_id,
@@ -229,7 +252,7 @@ impl LoopCompletionHandler {
     ///
     /// Returns the landing result if successful, or None if landing failed.
     fn execute_landing(&self, context: &LoopContext, prompt: &str) -> Option<LandingResult> {
-        let handler = LandingHandler::new(context.clone());
+        let handler = LandingHandler::new(context.clone()).with_formatting(self.formatting.clone());
 
         match handler.land(prompt) {
             Ok(result) => {
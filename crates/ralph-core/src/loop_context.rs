@@ -175,6 +175,14 @@ impl LoopContext {
         self.ralph_dir().join("current-events")
     }
 
+    /// Path to the current-recording marker file.
+    ///
+    /// Written when the run has `--record-session`; contains the recording path
+    /// so `ralph loops attach` can find it.
+    pub fn current_recording_marker(&self) -> PathBuf {
+        self.ralph_dir().join("current-recording")
+    }
+
     /// Path to the urgent-steer marker file.
     ///
     /// This file is created when `!` arrives during an active iteration so
@@ -183,6 +191,14 @@ impl LoopContext {
         self.ralph_dir().join("urgent-steer.json")
     }
 
+    /// Path to the plain-text steer file.
+    ///
+    /// A message written here is picked up at the next iteration boundary and
+    /// prepended to the coordinator prompt as a `user.steer` event.
+    pub fn steer_file_path(&self) -> PathBuf {
+        self.agent_dir().join("steer.txt")
+    }
+
     /// Path to the tasks JSONL file.
     ///
     /// Each loop has its own isolated tasks file.
@@ -269,6 +285,22 @@ impl LoopContext {
         self.agent_dir().join("handoff.md")
     }
 
+    /// Path to the shared cross-loop handoff log in the main repository.
+    ///
+    /// Every completing loop appends a section here, so worktree loops share
+    /// one log rather than each keeping its own.
+    pub fn shared_handoff_path(&self) -> PathBuf {
+        self.repo_root
+            .join(".ralph")
+            .join("agent")
+            .join("handoff-log.md")
+    }
+
+    /// Path to the reproducibility manifest written at loop startup.
+    pub fn run_manifest_path(&self) -> PathBuf {
+        self.agent_dir().join("run-manifest.json")
+    }
+
     /// Path to the diagnostics directory.
     ///
     /// Each loop has its own diagnostics output.
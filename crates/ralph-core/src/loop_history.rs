@@ -6,7 +6,9 @@
 //! - **Debugging**: Replay loop execution to understand failures
 //! - **Auditing**: Complete trace of what happened and when
 //! - **Source of truth**: Registry state can be derived from history
+//! - **Analysis**: [`LoopHistory::query`] filters runs by date and outcome
 
+use std::collections::BTreeMap;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -15,6 +17,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::TerminationReason;
 use crate::file_lock::FileLock;
 
 /// Errors that can occur during history operations.
@@ -61,6 +64,87 @@ impl HistoryEvent {
             data: Some(data),
         }
     }
+
+    /// Returns the [`TerminationReason::as_str`] name of the run's outcome,
+    /// if this event ended a run.
+    ///
+    /// Completions are recorded as `completion_promise` and interrupts as a
+    /// `LoopTerminated` signal; both are mapped back to reason names.
+    pub fn outcome(&self) -> Option<&str> {
+        match &self.event_type {
+            HistoryEventType::LoopCompleted { reason } if reason == "completion_promise" => {
+                Some(TerminationReason::CompletionPromise.as_str())
+            }
+            HistoryEventType::LoopCompleted { reason } => Some(reason),
+            HistoryEventType::LoopTerminated { .. } => {
+                Some(TerminationReason::Interrupted.as_str())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Criteria for [`LoopHistory::query`]. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    /// Only events at or after this time.
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only events before this time.
+    pub until: Option<DateTime<Utc>>,
+
+    /// Only run outcomes (completion or termination) with this reason.
+    pub reason: Option<TerminationReason>,
+
+    /// Only events from the history of the loop with this name.
+    pub loop_name: Option<String>,
+}
+
+impl HistoryFilter {
+    /// Returns true if `event` falls in the time range and matches the reason.
+    pub fn matches(&self, event: &HistoryEvent) -> bool {
+        self.since.is_none_or(|since| event.timestamp >= since)
+            && self.until.is_none_or(|until| event.timestamp < until)
+            && self
+                .reason
+                .as_ref()
+                .is_none_or(|reason| event.outcome() == Some(reason.as_str()))
+    }
+}
+
+/// Aggregate counts over a set of history events.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HistoryCounts {
+    /// Number of events counted.
+    pub events: usize,
+
+    /// Number of runs started.
+    pub runs_started: usize,
+
+    /// Number of runs that ended, by [`HistoryEvent::outcome`].
+    pub outcomes: BTreeMap<String, usize>,
+}
+
+impl HistoryCounts {
+    /// Counts `events`.
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a HistoryEvent>) -> Self {
+        let mut counts = Self::default();
+        for event in events {
+            counts.events += 1;
+            if matches!(event.event_type, HistoryEventType::LoopStarted { .. }) {
+                counts.runs_started += 1;
+            }
+            if let Some(outcome) = event.outcome() {
+                *counts.outcomes.entry(outcome.to_string()).or_default() += 1;
+            }
+        }
+        counts
+    }
+
+    /// Number of runs that ended, whatever the outcome.
+    pub fn runs_ended(&self) -> usize {
+        self.outcomes.values().sum()
+    }
 }
 
 /// Types of events that can be recorded in loop history.
@@ -109,6 +193,7 @@ pub enum HistoryEventType {
 /// Wraps an append-only JSONL file for recording loop events.
 pub struct LoopHistory {
     path: PathBuf,
+    loop_name: Option<String>,
 }
 
 impl LoopHistory {
@@ -116,12 +201,42 @@ impl LoopHistory {
     pub fn new(path: impl AsRef<Path>) -> Self {
         Self {
             path: path.as_ref().to_path_buf(),
+            loop_name: None,
         }
     }
 
     /// Create a loop history from a loop context.
+    ///
+    /// Worktree loops are named after their loop ID.
     pub fn from_context(context: &crate::LoopContext) -> Self {
-        Self::new(context.history_path())
+        let history = Self::new(context.history_path());
+        match context.loop_id() {
+            Some(loop_id) => history.with_loop_name(loop_id),
+            None => history,
+        }
+    }
+
+    /// Histories of the primary loop under `repo_root` and every `ralph/*`
+    /// worktree loop, the latter named after their loop ID.
+    pub fn for_workspace(repo_root: &Path) -> Vec<Self> {
+        let mut histories = vec![Self::new(repo_root.join(".ralph/history.jsonl"))];
+        for worktree in crate::worktree::list_ralph_worktrees(repo_root).unwrap_or_default() {
+            let loop_id = worktree
+                .branch
+                .strip_prefix("ralph/")
+                .unwrap_or(&worktree.branch)
+                .to_string();
+            histories.push(
+                Self::new(worktree.path.join(".ralph/history.jsonl")).with_loop_name(loop_id),
+            );
+        }
+        histories
+    }
+
+    /// Names the loop this history belongs to, for [`HistoryFilter::loop_name`].
+    pub fn with_loop_name(mut self, name: impl Into<String>) -> Self {
+        self.loop_name = Some(name.into());
+        self
     }
 
     /// Get the path to the history file.
@@ -129,6 +244,27 @@ impl LoopHistory {
         &self.path
     }
 
+    /// Name of the loop this history belongs to, if known.
+    pub fn loop_name(&self) -> Option<&str> {
+        self.loop_name.as_deref()
+    }
+
+    /// Returns the events matching `filter`, in recorded order.
+    ///
+    /// With [`HistoryFilter::loop_name`] set, a history for a different (or
+    /// unnamed) loop returns no events.
+    pub fn query(&self, filter: &HistoryFilter) -> Result<Vec<HistoryEvent>, HistoryError> {
+        if let Some(name) = &filter.loop_name
+            && self.loop_name.as_deref() != Some(name.as_str())
+        {
+            return Ok(Vec::new());
+        }
+
+        let mut events = self.read_all()?;
+        events.retain(|event| filter.matches(event));
+        Ok(events)
+    }
+
     /// Append an event to the history file.
     ///
     /// This is thread-safe via file locking.
@@ -586,4 +722,89 @@ mod tests {
             HistoryEventType::LoopStarted { prompt } if prompt == "test"
         ));
     }
+
+    #[test]
+    fn test_query_filters_by_time_reason_and_loop_name() {
+        let (_dir, history) = temp_history();
+        let history = history.with_loop_name("fix-header");
+        let at = |day: u32| {
+            DateTime::parse_from_rfc3339(&format!("2026-01-{day:02}T12:00:00Z"))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let append = |day, event_type| {
+            let mut event = HistoryEvent::new(event_type);
+            event.timestamp = at(day);
+            history.append(event).unwrap();
+        };
+        append(
+            1,
+            HistoryEventType::LoopStarted {
+                prompt: "a".to_string(),
+            },
+        );
+        append(
+            2,
+            HistoryEventType::LoopCompleted {
+                reason: "max_iterations".to_string(),
+            },
+        );
+        append(
+            8,
+            HistoryEventType::LoopStarted {
+                prompt: "b".to_string(),
+            },
+        );
+        append(
+            9,
+            HistoryEventType::LoopCompleted {
+                reason: "completion_promise".to_string(),
+            },
+        );
+
+        let this_week = HistoryFilter {
+            since: Some(at(7)),
+            ..HistoryFilter::default()
+        };
+        assert_eq!(history.query(&this_week).unwrap().len(), 2);
+
+        let completed = HistoryFilter {
+            reason: Some("completed".parse().unwrap()),
+            ..HistoryFilter::default()
+        };
+        let events = history.query(&completed).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].timestamp, at(9));
+
+        let other_loop = HistoryFilter {
+            loop_name: Some("other".to_string()),
+            ..HistoryFilter::default()
+        };
+        assert!(history.query(&other_loop).unwrap().is_empty());
+
+        let counts = HistoryCounts::from_events(&history.read_all().unwrap());
+        assert_eq!(counts.runs_started, 2);
+        assert_eq!(counts.runs_ended(), 2);
+        assert_eq!(counts.outcomes.get("completed"), Some(&1));
+        assert_eq!(counts.outcomes.get("max_iterations"), Some(&1));
+    }
+
+    #[test]
+    fn test_outcome_maps_recorded_reasons_to_termination_reasons() {
+        let terminated = HistoryEvent::new(HistoryEventType::LoopTerminated {
+            signal: "SIGTERM".to_string(),
+        });
+        assert_eq!(terminated.outcome(), Some("interrupted"));
+        assert!(
+            "bogus"
+                .parse::<TerminationReason>()
+                .unwrap_err()
+                .contains("max_iterations"),
+            "error should list valid reasons"
+        );
+        assert_eq!(
+            HistoryEvent::new(HistoryEventType::MergeQueued).outcome(),
+            None
+        );
+    }
 }
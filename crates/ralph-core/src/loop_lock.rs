@@ -32,6 +32,7 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::time::{Duration, Instant};
 
 /// Metadata stored in the lock file, readable by other processes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +87,15 @@ pub enum LockError {
     #[error("IO error: {0}")]
     Io(#[from] io::Error),
 
+    /// The lock did not become available before the deadline.
+    #[error("Timed out after {}s waiting for loop lock held by PID {}", .waited.as_secs(), .holder.pid)]
+    Timeout {
+        /// How long the caller waited.
+        waited: Duration,
+        /// The holder at the time the wait gave up.
+        holder: LockMetadata,
+    },
+
     /// Failed to parse lock metadata.
     #[error("Failed to parse lock metadata: {0}")]
     ParseError(String),
@@ -117,6 +127,9 @@ impl LoopLock {
     /// * `Ok(LockGuard)` - Lock acquired successfully
     /// * `Err(LockError::AlreadyLocked(metadata))` - Another process holds the lock
     /// * `Err(LockError::Io(_))` - IO error
+    ///
+    /// A holder that has exited (even by crashing) has already released its
+    /// `flock()`, so its leftover lock file never blocks acquisition.
     pub fn try_acquire(
         workspace_root: impl AsRef<Path>,
         prompt: &str,
@@ -241,6 +254,42 @@ impl LoopLock {
         }
     }
 
+    /// Acquire the loop lock, waiting at most `timeout`.
+    ///
+    /// Like [`acquire_blocking`](Self::acquire_blocking), but polls instead of
+    /// blocking so a wedged holder cannot hang the caller forever. A holder
+    /// that exits while we wait releases its `flock()`, so the next poll
+    /// acquires the lock.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LockGuard)` - Lock acquired successfully
+    /// * `Err(LockError::Timeout { .. })` - The lock was still held at the deadline
+    /// * `Err(LockError::Io(_))` - IO error
+    pub fn acquire_blocking_timeout(
+        workspace_root: impl AsRef<Path>,
+        prompt: &str,
+        timeout: Duration,
+    ) -> Result<LockGuard, LockError> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        let workspace_root = workspace_root.as_ref();
+        let start = Instant::now();
+
+        loop {
+            let holder = match Self::try_acquire(workspace_root, prompt) {
+                Err(LockError::AlreadyLocked(holder)) => holder,
+                result => return result,
+            };
+
+            let waited = start.elapsed();
+            if waited >= timeout {
+                return Err(LockError::Timeout { waited, holder });
+            }
+            std::thread::sleep(POLL_INTERVAL.min(timeout.saturating_sub(waited)));
+        }
+    }
+
     /// Read the metadata from an existing lock file.
     ///
     /// This can be used to check who holds the lock without acquiring it.
@@ -381,6 +430,32 @@ mod tests {
         assert!(guard.is_ok());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_acquire_takes_over_lock_file_left_by_dead_process() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // A crashed loop leaves its metadata behind but no flock
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let dead_pid = child.id();
+        child.wait().unwrap();
+        let stale = LockMetadata {
+            pid: dead_pid,
+            started: Utc::now(),
+            prompt: "crashed loop".to_string(),
+        };
+        let lock_path = temp_dir.path().join(LoopLock::LOCK_FILE);
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+        fs::write(&lock_path, serde_json::to_string(&stale).unwrap()).unwrap();
+
+        let _guard = LoopLock::try_acquire(temp_dir.path(), "new loop").unwrap();
+
+        let metadata: LockMetadata =
+            serde_json::from_str(&fs::read_to_string(&lock_path).unwrap()).unwrap();
+        assert_eq!(metadata.pid, process::id());
+        assert_eq!(metadata.prompt, "new loop");
+    }
+
     #[test]
     fn test_is_locked() {
         let temp_dir = TempDir::new().unwrap();
@@ -395,6 +470,26 @@ mod tests {
         // might not work as expected in single-process context
     }
 
+    #[test]
+    fn test_acquire_blocking_timeout_times_out_on_live_holder() {
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = LoopLock::try_acquire(temp_dir.path(), "holder").unwrap();
+
+        let result = LoopLock::acquire_blocking_timeout(
+            temp_dir.path(),
+            "waiter",
+            Duration::from_millis(150),
+        );
+
+        match result {
+            Err(LockError::Timeout { waited, holder }) => {
+                assert!(waited >= Duration::from_millis(150));
+                assert_eq!(holder.prompt, "holder");
+            }
+            other => panic!("expected timeout, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_read_existing_no_file() {
         let temp_dir = TempDir::new().unwrap();
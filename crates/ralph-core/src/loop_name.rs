@@ -7,8 +7,93 @@
 //! - `fix-header-swift-peacock`
 //! - `add-auth-clever-badger`
 //! - `refactor-api-calm-falcon`
+//!
+//! The suffix words come from the configured [`LoopNameTheme`], e.g.
+//! `amber-willow` (colors) or `tango-echo` (nato).
 
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
+
+/// Attempts at a fresh timestamp ID before giving up on `exists`.
+const TIMESTAMP_ID_ATTEMPTS: usize = 16;
+
+/// Word theme used for the memorable part of loop names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoopNameTheme {
+    /// Adjective + nature noun (`swift-falcon`, `calm-willow`).
+    #[default]
+    Default,
+    /// Adjective + animal (`clever-narwhal`).
+    Animals,
+    /// Color + nature noun (`amber-willow`).
+    Colors,
+    /// Two NATO phonetic alphabet words (`tango-echo`).
+    Nato,
+    /// Adjective + star, constellation or moon (`bright-orion`).
+    Celestial,
+}
+
+impl LoopNameTheme {
+    /// All themes, in the order they are documented.
+    pub const ALL: [Self; 5] = [
+        Self::Default,
+        Self::Animals,
+        Self::Colors,
+        Self::Nato,
+        Self::Celestial,
+    ];
+
+    /// Config/CLI spelling of the theme.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Animals => "animals",
+            Self::Colors => "colors",
+            Self::Nato => "nato",
+            Self::Celestial => "celestial",
+        }
+    }
+
+    /// The first and second word lists names are drawn from.
+    fn words(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            Self::Default => (ADJECTIVES, NOUNS),
+            Self::Animals => (ADJECTIVES, ANIMALS),
+            Self::Colors => (COLORS, NOUNS),
+            Self::Nato => (NATO, NATO),
+            Self::Celestial => (ADJECTIVES, CELESTIAL),
+        }
+    }
+}
+
+impl fmt::Display for LoopNameTheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LoopNameTheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|theme| theme.as_str()).collect();
+                format!(
+                    "unknown loop name theme '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
 
 /// Configuration for loop naming.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +105,10 @@ pub struct LoopNamingConfig {
     /// Maximum length for generated names.
     #[serde(default = "default_max_length")]
     pub max_length: usize,
+
+    /// Word theme for the memorable part of names.
+    #[serde(default)]
+    pub theme: LoopNameTheme,
 }
 
 fn default_format() -> String {
@@ -35,6 +124,7 @@ impl Default for LoopNamingConfig {
         Self {
             format: default_format(),
             max_length: default_max_length(),
+            theme: LoopNameTheme::default(),
         }
     }
 }
@@ -83,7 +173,7 @@ impl LoopNameGenerator {
     /// to timestamp format.
     pub fn generate_unique(&self, prompt: &str, exists: impl Fn(&str) -> bool) -> String {
         if self.config.format == "timestamp" {
-            return generate_timestamp_id();
+            return generate_unique_timestamp_id(exists);
         }
 
         let keywords = self.extract_keywords(prompt);
@@ -105,7 +195,7 @@ impl LoopNameGenerator {
         }
 
         // Fallback to timestamp format
-        generate_timestamp_id()
+        generate_unique_timestamp_id(exists)
     }
 
     /// Generate a memorable name (adjective-noun only, no keywords).
@@ -117,21 +207,28 @@ impl LoopNameGenerator {
 
     /// Generate a unique memorable name, using `exists` to check for collisions.
     ///
-    /// Tries up to 10 times with different suffixes before falling back
-    /// to timestamp format.
+    /// Re-rolls up to 10 times with different suffixes, then appends a
+    /// numeric suffix (`swift-falcon-2`, `swift-falcon-3`, ...) to the last
+    /// roll, and only falls back to timestamp format if that fails too.
     pub fn generate_memorable_unique(&self, exists: impl Fn(&str) -> bool) -> String {
+        let mut name = self.generate_suffix();
         // Try up to 10 times with different suffixes
         for _ in 0..10 {
-            let name = self.generate_suffix();
             if !exists(&name) {
                 return name;
             }
-            // Small delay to get different nanosecond value
-            std::thread::sleep(std::time::Duration::from_micros(1));
+            name = self.generate_suffix();
+        }
+
+        // Small themes (nato has 26*26 combinations) can run out of rolls
+        for n in 2..100 {
+            let numbered = format!("{name}-{n}");
+            if !exists(&numbered) {
+                return numbered;
+            }
         }
 
-        // Fallback to timestamp format (very unlikely with 50*50 = 2500 combinations)
-        generate_timestamp_id()
+        generate_unique_timestamp_id(exists)
     }
 
     /// Extract keywords from a prompt.
@@ -174,18 +271,13 @@ impl LoopNameGenerator {
 
     /// Generate a random adjective-noun suffix.
     fn generate_suffix(&self) -> String {
-        use std::time::SystemTime;
+        let random = next_random();
 
-        // Use nanoseconds for randomness
-        let nanos = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map(|d| d.as_nanos())
-            .unwrap_or(0);
+        let (first, second) = self.config.theme.words();
+        let first_idx = (random % first.len() as u64) as usize;
+        let second_idx = ((random >> 32) % second.len() as u64) as usize;
 
-        let adj_idx = (nanos % ADJECTIVES.len() as u128) as usize;
-        let noun_idx = ((nanos / 1000) % NOUNS.len() as u128) as usize;
-
-        format!("{}-{}", ADJECTIVES[adj_idx], NOUNS[noun_idx])
+        format!("{}-{}", first[first_idx], second[second_idx])
     }
 
     /// Truncate name to max length, preserving word boundaries where possible.
@@ -219,19 +311,47 @@ impl LoopNameGenerator {
     }
 }
 
+/// Returns a pseudo-random value that differs on every call.
+///
+/// Hashes an atomic counter with a per-process random key, so values drawn
+/// in quick succession (or by processes started together) don't repeat the
+/// way clock readings do.
+fn next_random() -> u64 {
+    static KEY: LazyLock<RandomState> = LazyLock::new(RandomState::new);
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut hasher = KEY.build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
 /// Generate a timestamp-based ID (legacy format).
+///
+/// The 4-hex-digit suffix starts at a random per-process offset and then
+/// counts up, so a process never repeats an ID within the same second.
 fn generate_timestamp_id() -> String {
-    use std::time::SystemTime;
+    static OFFSET: LazyLock<u16> = LazyLock::new(|| next_random() as u16);
+    static COUNTER: AtomicU16 = AtomicU16::new(0);
 
     let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+    let suffix = OFFSET.wrapping_add(COUNTER.fetch_add(1, Ordering::Relaxed));
 
-    // Generate 4-character random hex suffix
-    let random_suffix: u16 = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .map(|d| (d.as_nanos() & 0xFFFF) as u16)
-        .unwrap_or(0);
+    format!("ralph-{}-{:04x}", timestamp, suffix)
+}
 
-    format!("ralph-{}-{:04x}", timestamp, random_suffix)
+/// Generate a timestamp-based ID that `exists` doesn't report as taken.
+///
+/// Re-rolls up to [`TIMESTAMP_ID_ATTEMPTS`] times; if every attempt is
+/// taken, returns the last one.
+fn generate_unique_timestamp_id(exists: impl Fn(&str) -> bool) -> String {
+    let mut id = generate_timestamp_id();
+    for _ in 1..TIMESTAMP_ID_ATTEMPTS {
+        if !exists(&id) {
+            break;
+        }
+        id = generate_timestamp_id();
+    }
+    id
 }
 
 /// Sanitize text for git branch/worktree names.
@@ -334,6 +454,74 @@ const NOUNS: &[&str] = &[
     "tulip", "orchid", "lotus", "ivy", "palm", "cork", "teak",
 ];
 
+/// Animals for the `animals` theme.
+const ANIMALS: &[&str] = &[
+    "otter", "badger", "falcon", "heron", "lynx", "marten", "panda", "koala", "ibis", "gecko",
+    "puffin", "walrus", "bison", "moose", "ferret", "beaver", "raccoon", "osprey", "condor",
+    "pelican", "narwhal", "dolphin", "orca", "seal", "hare", "mole", "shrew", "weasel", "stoat",
+    "ocelot", "jaguar", "cougar", "coyote", "jackal", "yak", "llama", "alpaca", "tapir", "okapi",
+    "zebra",
+];
+
+/// Colors for the `colors` theme.
+const COLORS: &[&str] = &[
+    "amber", "azure", "cobalt", "coral", "crimson", "cyan", "ebony", "emerald", "fuchsia", "gold",
+    "indigo", "ivory", "jade", "lavender", "lemon", "lilac", "magenta", "maroon", "mauve", "ochre",
+    "olive", "onyx", "peach", "pearl", "ruby", "russet", "saffron", "scarlet", "sienna", "teal",
+    "umber", "violet",
+];
+
+/// NATO phonetic alphabet for the `nato` theme.
+const NATO: &[&str] = &[
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliett",
+    "kilo", "lima", "mike", "november", "oscar", "papa", "quebec", "romeo", "sierra", "tango",
+    "uniform", "victor", "whiskey", "xray", "yankee", "zulu",
+];
+
+/// Stars, constellations and moons for the `celestial` theme.
+const CELESTIAL: &[&str] = &[
+    "comet",
+    "nebula",
+    "quasar",
+    "pulsar",
+    "nova",
+    "orbit",
+    "eclipse",
+    "aurora",
+    "meteor",
+    "galaxy",
+    "zenith",
+    "equinox",
+    "solstice",
+    "cosmos",
+    "vega",
+    "sirius",
+    "rigel",
+    "altair",
+    "deneb",
+    "polaris",
+    "orion",
+    "lyra",
+    "cygnus",
+    "draco",
+    "andromeda",
+    "cassiopeia",
+    "perseus",
+    "pegasus",
+    "phoenix",
+    "halley",
+    "titan",
+    "europa",
+    "io",
+    "callisto",
+    "ganymede",
+    "oberon",
+    "triton",
+    "luna",
+    "sol",
+    "kepler",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,6 +601,7 @@ mod tests {
         let config = LoopNamingConfig {
             format: "human-readable".to_string(),
             max_length: 30,
+            ..LoopNamingConfig::default()
         };
         let generator = LoopNameGenerator::new(config);
 
@@ -425,6 +614,7 @@ mod tests {
         let config = LoopNamingConfig {
             format: "timestamp".to_string(),
             max_length: 50,
+            ..LoopNamingConfig::default()
         };
         let generator = LoopNameGenerator::new(config);
 
@@ -498,6 +688,54 @@ mod tests {
         assert!(name1.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
     }
 
+    #[test]
+    fn test_themes_draw_from_their_own_word_lists() {
+        for theme in LoopNameTheme::ALL {
+            let generator = LoopNameGenerator::new(LoopNamingConfig {
+                theme,
+                ..LoopNamingConfig::default()
+            });
+            let (first, second) = theme.words();
+
+            let name = generator.generate_memorable();
+            let (a, b) = name.split_once('-').unwrap();
+            assert!(first.contains(&a), "{theme}: {name}");
+            assert!(second.contains(&b), "{theme}: {name}");
+            assert!(name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+        }
+    }
+
+    #[test]
+    fn test_theme_parses_from_config_and_cli() {
+        let config: LoopNamingConfig = serde_yaml::from_str("theme: nato").unwrap();
+        assert_eq!(config.theme, LoopNameTheme::Nato);
+        assert_eq!(config.format, "human-readable");
+
+        assert_eq!(
+            "Celestial".parse::<LoopNameTheme>(),
+            Ok(LoopNameTheme::Celestial)
+        );
+        assert!(
+            "planets"
+                .parse::<LoopNameTheme>()
+                .unwrap_err()
+                .contains("nato")
+        );
+    }
+
+    #[test]
+    fn test_generate_memorable_unique_appends_numeric_suffix() {
+        let generator = LoopNameGenerator::new(LoopNamingConfig::default());
+
+        // Every plain adjective-noun name is taken, as is the first numbered one
+        let name =
+            generator.generate_memorable_unique(|n| n.split('-').count() == 2 || n.ends_with("-2"));
+
+        let parts: Vec<&str> = name.split('-').collect();
+        assert_eq!(parts.len(), 3, "Expected adjective-noun-N format: {name}");
+        assert_eq!(parts[2], "3");
+    }
+
     #[test]
     fn test_generate_memorable_unique_falls_back_to_timestamp() {
         let generator = LoopNameGenerator::new(LoopNamingConfig::default());
@@ -508,4 +746,41 @@ mod tests {
         // Should fall back to timestamp format
         assert!(name.starts_with("ralph-"));
     }
+
+    #[test]
+    fn test_timestamp_ids_are_unique_under_rapid_generation() {
+        let generator = LoopNameGenerator::new(LoopNamingConfig {
+            format: "timestamp".to_string(),
+            ..LoopNamingConfig::default()
+        });
+
+        let ids: HashSet<String> = (0..5000)
+            .map(|_| generator.generate("Fix header"))
+            .collect();
+        assert_eq!(ids.len(), 5000);
+    }
+
+    #[test]
+    fn test_unique_generators_reroll_against_taken_ids() {
+        let generator = LoopNameGenerator::new(LoopNamingConfig {
+            theme: LoopNameTheme::Nato,
+            ..LoopNamingConfig::default()
+        });
+
+        // Far more names than the nato theme has plain combinations (26 * 26),
+        // so the numbered and timestamp fallbacks get exercised too.
+        let mut taken = HashSet::new();
+        for _ in 0..2000 {
+            let name = generator.generate_memorable_unique(|n| taken.contains(n));
+            assert!(taken.insert(name.clone()), "duplicate loop id {name}");
+        }
+
+        let timestamp = LoopNameGenerator::new(LoopNamingConfig {
+            format: "timestamp".to_string(),
+            ..LoopNamingConfig::default()
+        });
+        let first = timestamp.generate_unique("x", |_| false);
+        let second = timestamp.generate_unique("x", |n| n == first);
+        assert_ne!(first, second);
+    }
 }
@@ -8,6 +8,8 @@
 //! - **JSON persistence**: Single JSON file at `.ralph/loops.json`
 //! - **File locking**: Uses `flock()` for concurrent access safety
 //! - **PID-based stale detection**: Automatically cleans up entries for dead processes
+//! - **Heartbeats**: Running loops refresh `last_seen` so a crashed loop whose
+//!   PID was reused is still recognizable as dead
 //!
 //! # Example
 //!
@@ -38,6 +40,7 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::time::Duration;
 
 /// Metadata for a registered loop.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -60,6 +63,17 @@ pub struct LoopEntry {
 
     /// The workspace root where the loop is running.
     pub workspace: String,
+
+    /// ID of the loop that was running in the main workspace when this one
+    /// started (the loop it was spawned alongside or from).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+
+    /// Last heartbeat from the loop (set on registration, then refreshed via
+    /// [`LoopRegistry::heartbeat`]). `None` for entries written before
+    /// heartbeats existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<DateTime<Utc>>,
 }
 
 impl LoopEntry {
@@ -74,6 +88,8 @@ impl LoopEntry {
             workspace: std::env::current_dir()
                 .map(|p| p.display().to_string())
                 .unwrap_or_default(),
+            parent_id: None,
+            last_seen: None,
         }
     }
 
@@ -90,6 +106,8 @@ impl LoopEntry {
             prompt: prompt.into(),
             worktree_path: worktree_path.map(Into::into),
             workspace: workspace.into(),
+            parent_id: None,
+            last_seen: None,
         }
     }
 
@@ -110,9 +128,18 @@ impl LoopEntry {
             prompt: prompt.into(),
             worktree_path: worktree_path.map(Into::into),
             workspace: workspace.into(),
+            parent_id: None,
+            last_seen: None,
         }
     }
 
+    /// Records the loop this one was spawned from.
+    #[must_use]
+    pub fn with_parent(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+        self
+    }
+
     /// Generates a unique loop ID: loop-{timestamp}-{hex_suffix}
     fn generate_id() -> String {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -124,6 +151,18 @@ impl LoopEntry {
         format!("loop-{}-{}", timestamp, hex_suffix)
     }
 
+    /// Returns true if the loop has a heartbeat older than
+    /// [`LoopRegistry::HEARTBEAT_STALE_AFTER`] as of `now`.
+    ///
+    /// Entries without any heartbeat are never considered stale.
+    pub fn is_heartbeat_stale(&self, now: DateTime<Utc>) -> bool {
+        self.last_seen.is_some_and(|last_seen| {
+            now.signed_duration_since(last_seen)
+                .to_std()
+                .is_ok_and(|age| age > LoopRegistry::HEARTBEAT_STALE_AFTER)
+        })
+    }
+
     /// Checks if the process for this loop is still running.
     ///
     /// For worktree loops, also verifies the worktree directory still exists.
@@ -218,6 +257,12 @@ impl LoopRegistry {
     /// The relative path to the registry file within the workspace.
     pub const REGISTRY_FILE: &'static str = ".ralph/loops.json";
 
+    /// How often a running loop refreshes its `last_seen` timestamp.
+    pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Heartbeat age after which a loop is considered possibly dead.
+    pub const HEARTBEAT_STALE_AFTER: Duration = Duration::from_secs(30);
+
     /// Creates a new registry instance for the given workspace.
     pub fn new(workspace_root: impl AsRef<Path>) -> Self {
         Self {
@@ -228,8 +273,9 @@ impl LoopRegistry {
     /// Registers a new loop entry.
     ///
     /// Returns the entry's ID for later deregistration.
-    pub fn register(&self, entry: LoopEntry) -> Result<String, RegistryError> {
+    pub fn register(&self, mut entry: LoopEntry) -> Result<String, RegistryError> {
         let id = entry.id.clone();
+        entry.last_seen = Some(Utc::now());
         self.with_lock(|data| {
             // Remove any existing entry with the same PID (stale from crash)
             data.loops.retain(|e| e.pid != entry.pid);
@@ -252,6 +298,31 @@ impl LoopRegistry {
         Ok(())
     }
 
+    /// Refreshes the `last_seen` timestamp of the loop with the given ID.
+    ///
+    /// Returns [`RegistryError::NotFound`] once the loop has been deregistered.
+    pub fn heartbeat(&self, id: &str) -> Result<(), RegistryError> {
+        let mut found = false;
+        self.with_lock(|data| {
+            if let Some(entry) = data.loops.iter_mut().find(|e| e.id == id) {
+                entry.last_seen = Some(Utc::now());
+                found = true;
+            }
+        })?;
+        if !found {
+            return Err(RegistryError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Returns whether `entry` is alive: its process is running (and its
+    /// worktree exists) and its heartbeat, if any, is recent.
+    ///
+    /// The heartbeat check catches crashed loops whose PID has been reused.
+    pub fn is_alive(&self, entry: &LoopEntry) -> bool {
+        entry.is_alive() && !entry.is_heartbeat_stale(Utc::now())
+    }
+
     /// Gets a loop entry by ID.
     pub fn get(&self, id: &str) -> Result<Option<LoopEntry>, RegistryError> {
         let mut result = None;
@@ -417,6 +488,48 @@ mod tests {
         assert!(entry.worktree_path.is_none());
     }
 
+    #[test]
+    fn test_heartbeat_refreshes_last_seen() {
+        let temp_dir = TempDir::new().unwrap();
+        let registry = LoopRegistry::new(temp_dir.path());
+
+        let mut entry = LoopEntry::new("test prompt", None::<String>);
+        entry.last_seen = Some(Utc::now() - chrono::Duration::minutes(5));
+        let id = registry.register(entry).unwrap();
+        let registered = registry.get(&id).unwrap().unwrap();
+        assert!(
+            registry.is_alive(&registered),
+            "registration counts as a heartbeat"
+        );
+
+        let before = registered.last_seen.unwrap();
+        registry.heartbeat(&id).unwrap();
+        assert!(registry.get(&id).unwrap().unwrap().last_seen.unwrap() >= before);
+
+        registry.deregister(&id).unwrap();
+        assert!(matches!(
+            registry.heartbeat(&id),
+            Err(RegistryError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_stale_heartbeat_marks_live_pid_as_dead() {
+        let registry = LoopRegistry::new(".");
+        let now = Utc::now();
+        let mut entry = LoopEntry::new("test prompt", None::<String>);
+        assert!(!entry.is_heartbeat_stale(now), "no heartbeat is not stale");
+
+        entry.last_seen = Some(now - chrono::Duration::seconds(10));
+        assert!(!entry.is_heartbeat_stale(now));
+        assert!(registry.is_alive(&entry));
+
+        entry.last_seen = Some(now - chrono::Duration::minutes(2));
+        assert!(entry.is_heartbeat_stale(now));
+        assert!(entry.is_alive(), "our PID is still running");
+        assert!(!registry.is_alive(&entry));
+    }
+
     #[test]
     fn test_loop_entry_with_worktree() {
         let entry = LoopEntry::new("test prompt", Some("/path/to/worktree"));
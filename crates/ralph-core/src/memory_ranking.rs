@@ -0,0 +1,170 @@
+//! Relevance ranking for memory auto-injection.
+//!
+//! Scores memories by TF-IDF overlap between their content and the current
+//! context (objective plus pending events), so a tight injection budget is
+//! spent on the memories most likely to help the current iteration.
+//!
+//! Enabled via `memories.ranking: relevance`; `off` keeps file order.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::memory::Memory;
+use crate::memory_store::format_memories_as_markdown;
+
+/// Splits text into lowercase terms of at least three characters.
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Orders memories by relevance to `context`, most relevant first.
+///
+/// Each memory's score sums, over the distinct context terms it contains,
+/// the term's frequency in the memory (normalized by memory length) weighted
+/// by its inverse document frequency across all memories. Ties, including
+/// memories with no overlap at all, keep their original order.
+#[must_use]
+pub fn rank_by_relevance(memories: Vec<Memory>, context: &str) -> Vec<Memory> {
+    let context_terms: HashSet<String> = terms(context).into_iter().collect();
+    if context_terms.is_empty() || memories.len() < 2 {
+        return memories;
+    }
+
+    let documents: Vec<Vec<String>> = memories
+        .iter()
+        .map(|m| terms(&format!("{} {}", m.content, m.tags.join(" "))))
+        .collect();
+
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for document in &documents {
+        let unique: HashSet<&str> = document.iter().map(String::as_str).collect();
+        for term in unique {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+
+    let total = documents.len() as f64;
+    let scores: Vec<f64> = documents
+        .iter()
+        .map(|document| {
+            if document.is_empty() {
+                return 0.0;
+            }
+            let mut term_counts: HashMap<&str, usize> = HashMap::new();
+            for term in document {
+                *term_counts.entry(term.as_str()).or_default() += 1;
+            }
+            let length = document.len() as f64;
+            term_counts
+                .iter()
+                .filter(|(term, _)| context_terms.contains(**term))
+                .map(|(term, count)| {
+                    let df = document_frequency.get(term).copied().unwrap_or(1) as f64;
+                    let idf = (1.0 + total / df).ln();
+                    (*count as f64 / length) * idf
+                })
+                .sum()
+        })
+        .collect();
+
+    let mut ranked: Vec<(f64, Memory)> = scores.into_iter().zip(memories).collect();
+    ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+    ranked.into_iter().map(|(_, memory)| memory).collect()
+}
+
+/// Keeps memories, in order, while their formatted markdown fits `budget`.
+///
+/// Uses the same ~4 characters per token estimate as `truncate_to_budget`.
+/// A memory too large to fit is skipped so smaller ones further down the
+/// list can still use the remaining budget. A budget of 0 keeps everything.
+#[must_use]
+pub fn select_within_budget(memories: Vec<Memory>, budget: usize) -> Vec<Memory> {
+    if budget == 0 {
+        return memories;
+    }
+
+    let char_budget = budget * 4;
+    let mut selected: Vec<Memory> = Vec::new();
+    for memory in memories {
+        selected.push(memory);
+        if format_memories_as_markdown(&selected).len() > char_budget {
+            selected.pop();
+        }
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryType;
+
+    fn memory(id: &str, content: &str) -> Memory {
+        Memory {
+            id: id.to_string(),
+            memory_type: MemoryType::Pattern,
+            content: content.to_string(),
+            tags: vec![],
+            created: "2025-01-01".to_string(),
+        }
+    }
+
+    fn ids(memories: &[Memory]) -> Vec<&str> {
+        memories.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn ranks_overlapping_memories_first() {
+        let memories = vec![
+            memory("mem-1", "Use anyhow for CLI error handling"),
+            memory("mem-2", "Grafana dashboards live in ops/"),
+            memory(
+                "mem-3",
+                "Login handler validates the session token in auth.rs",
+            ),
+        ];
+
+        let ranked = rank_by_relevance(memories, "Fix the login session bug in auth");
+        assert_eq!(ranked[0].id, "mem-3");
+    }
+
+    #[test]
+    fn rare_terms_outweigh_common_ones() {
+        let memories = vec![
+            memory("mem-1", "tests tests tests run with cargo"),
+            memory("mem-2", "tests for the webhook retry queue"),
+            memory("mem-3", "tests use tempfile"),
+        ];
+
+        // "tests" appears everywhere; "webhook" singles out mem-2
+        let ranked = rank_by_relevance(memories, "add tests for webhook delivery");
+        assert_eq!(ranked[0].id, "mem-2");
+    }
+
+    #[test]
+    fn no_overlap_preserves_original_order() {
+        let memories = vec![memory("mem-1", "alpha"), memory("mem-2", "beta")];
+        let ranked = rank_by_relevance(memories.clone(), "something unrelated");
+        assert_eq!(ids(&ranked), vec!["mem-1", "mem-2"]);
+
+        let ranked = rank_by_relevance(memories, "");
+        assert_eq!(ids(&ranked), vec!["mem-1", "mem-2"]);
+    }
+
+    #[test]
+    fn select_within_budget_skips_oversized_memories() {
+        let memories = vec![
+            memory("mem-1", "short"),
+            memory("mem-2", &"long ".repeat(200)),
+            memory("mem-3", "also short"),
+        ];
+
+        let selected = select_within_budget(memories.clone(), 40);
+        assert_eq!(ids(&selected), vec!["mem-1", "mem-3"]);
+
+        let unlimited = select_within_budget(memories, 0);
+        assert_eq!(unlimited.len(), 3);
+    }
+}
@@ -14,6 +14,7 @@
 //! The `MarkdownMemoryStore` is Clone because it doesn't hold the lock;
 //! locks are acquired for each operation.
 
+use std::collections::HashSet;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -27,6 +28,25 @@ use crate::memory_parser::parse_memories;
 /// Default path for the memories file relative to the workspace root.
 pub const DEFAULT_MEMORIES_PATH: &str = ".ralph/agent/memories.md";
 
+/// A set of near-identical memories that `dedup` collapses into one entry.
+#[derive(Debug, Clone)]
+pub struct DuplicateGroup {
+    /// The surviving memory: the earliest entry's ID, type, content, and
+    /// timestamp, with tags merged from every duplicate.
+    pub kept: Memory,
+    /// The later duplicates that are removed.
+    pub removed: Vec<Memory>,
+}
+
+/// A memory that references files which no longer exist.
+#[derive(Debug, Clone)]
+pub struct StaleMemory {
+    /// The stale memory.
+    pub memory: Memory,
+    /// Referenced paths missing from the workspace.
+    pub missing_paths: Vec<String>,
+}
+
 /// A store for managing memories in markdown format.
 ///
 /// This store uses a single markdown file (`.ralph/agent/memories.md`) to persist
@@ -205,6 +225,106 @@ impl MarkdownMemoryStore {
             .collect())
     }
 
+    /// Finds groups of near-identical memories without modifying the file.
+    ///
+    /// Memories are duplicates when their content matches after collapsing
+    /// whitespace and ignoring case. Used by `dedup` and its dry-run mode.
+    pub fn find_duplicates(&self) -> io::Result<Vec<DuplicateGroup>> {
+        let memories = self.load()?;
+        Ok(plan_dedup(&memories))
+    }
+
+    /// Collapses near-identical memories into a single entry.
+    ///
+    /// The earliest memory of each group survives, keeping its type and
+    /// timestamp; tags from the removed duplicates are merged into it.
+    /// Returns the number of memories removed. The file is only rewritten
+    /// when duplicates exist. Uses an exclusive lock to prevent concurrent writes.
+    pub fn dedup(&self) -> io::Result<usize> {
+        if !self.exists() {
+            return Ok(0);
+        }
+
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = fs::read_to_string(&self.path)?;
+        let memories = parse_memories(&content);
+        let groups = plan_dedup(&memories);
+        if groups.is_empty() {
+            return Ok(0);
+        }
+
+        let removed_ids: HashSet<&str> = groups
+            .iter()
+            .flat_map(|g| g.removed.iter().map(|m| m.id.as_str()))
+            .collect();
+
+        let remaining: Vec<Memory> = memories
+            .iter()
+            .filter(|m| !removed_ids.contains(m.id.as_str()))
+            .map(|m| {
+                groups
+                    .iter()
+                    .find(|g| g.kept.id == m.id)
+                    .map_or_else(|| m.clone(), |g| g.kept.clone())
+            })
+            .collect();
+
+        self.write_all_internal(&remaining)?;
+        Ok(removed_ids.len())
+    }
+
+    /// Finds memories whose referenced file paths no longer exist under `root`.
+    ///
+    /// Only clear path-looking tokens are considered (see
+    /// [`extract_path_references`]), so prose never gets a memory flagged.
+    pub fn find_stale(&self, root: &Path) -> io::Result<Vec<StaleMemory>> {
+        let memories = self.load()?;
+        Ok(plan_gc(&memories, root))
+    }
+
+    /// Moves stale memories into the archive file next to the memories file.
+    ///
+    /// Archived memories keep their IDs and sections in `memories-archive.md`,
+    /// so a wrongly flagged memory can be restored by hand. Returns the
+    /// archived memories. Uses an exclusive lock to prevent concurrent writes.
+    pub fn archive_stale(&self, root: &Path) -> io::Result<Vec<StaleMemory>> {
+        if !self.exists() {
+            return Ok(Vec::new());
+        }
+
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = fs::read_to_string(&self.path)?;
+        let memories = parse_memories(&content);
+        let stale = plan_gc(&memories, root);
+        if stale.is_empty() {
+            return Ok(stale);
+        }
+
+        let archive = MarkdownMemoryStore::new(self.archive_path());
+        for entry in &stale {
+            archive.append(&entry.memory)?;
+        }
+
+        let stale_ids: HashSet<&str> = stale.iter().map(|s| s.memory.id.as_str()).collect();
+        let remaining: Vec<Memory> = memories
+            .into_iter()
+            .filter(|m| !stale_ids.contains(m.id.as_str()))
+            .collect();
+        self.write_all_internal(&remaining)?;
+
+        Ok(stale)
+    }
+
+    /// Returns the path of the archive file used by `archive_stale`.
+    #[must_use]
+    pub fn archive_path(&self) -> PathBuf {
+        self.path.with_file_name("memories-archive.md")
+    }
+
     /// Writes all memories to the file, replacing existing content.
     ///
     /// This is used internally for operations like delete that need
@@ -271,6 +391,126 @@ impl MarkdownMemoryStore {
     }
 }
 
+/// Normalizes memory content for duplicate detection.
+fn normalize_content(content: &str) -> String {
+    content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Sort key placing older memories first: creation date, then the Unix
+/// timestamp embedded in the ID (`mem-{timestamp}-{hex}`).
+fn age_key(memory: &Memory) -> (&str, u64) {
+    let timestamp = memory
+        .id
+        .strip_prefix("mem-")
+        .and_then(|rest| rest.split('-').next())
+        .and_then(|ts| ts.parse().ok())
+        .unwrap_or(u64::MAX);
+    (memory.created.as_str(), timestamp)
+}
+
+/// Groups duplicate memories, keeping the earliest of each group.
+fn plan_dedup(memories: &[Memory]) -> Vec<DuplicateGroup> {
+    let mut by_content: Vec<(String, Vec<&Memory>)> = Vec::new();
+    for memory in memories {
+        let key = normalize_content(&memory.content);
+        match by_content.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(memory),
+            None => by_content.push((key, vec![memory])),
+        }
+    }
+
+    by_content
+        .into_iter()
+        .filter(|(_, group)| group.len() > 1)
+        .map(|(_, mut group)| {
+            group.sort_by(|a, b| age_key(a).cmp(&age_key(b)));
+            let mut kept = group[0].clone();
+            for duplicate in &group[1..] {
+                for tag in &duplicate.tags {
+                    if !kept.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                        kept.tags.push(tag.clone());
+                    }
+                }
+            }
+            DuplicateGroup {
+                kept,
+                removed: group[1..].iter().map(|m| (*m).clone()).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Extracts file path references from memory content.
+///
+/// Deliberately conservative: a token counts only when it is relative,
+/// contains a `/`, and ends in a file name with an extension (e.g.
+/// `src/main.rs` or `` `crates/core/lib.rs:42` ``). URLs, absolute paths,
+/// globs, and placeholders are skipped.
+pub fn extract_path_references(content: &str) -> Vec<String> {
+    let mut paths: Vec<String> = Vec::new();
+    for token in content.split_whitespace() {
+        let token = token
+            .trim_start_matches(|c: char| matches!(c, '`' | '"' | '\'' | '(' | '['))
+            .trim_end_matches(|c: char| {
+                matches!(
+                    c,
+                    '`' | '"' | '\'' | ')' | ']' | ',' | ';' | ':' | '.' | '!' | '?'
+                )
+            });
+        // Drop a trailing `:line` or `:line:col` location
+        let token = token.split(':').next().unwrap_or_default();
+        let token = token.strip_prefix("./").unwrap_or(token);
+
+        if is_path_reference(token) && !paths.iter().any(|p| p == token) {
+            paths.push(token.to_string());
+        }
+    }
+    paths
+}
+
+fn is_path_reference(token: &str) -> bool {
+    if !token.contains('/')
+        || token.starts_with('/')
+        || token.starts_with('~')
+        || token.contains("//")
+        || token.contains("..")
+        || token
+            .chars()
+            .any(|c| matches!(c, '*' | '?' | '{' | '}' | '<' | '>' | '$' | '|' | '='))
+    {
+        return false;
+    }
+    let file_name = token.rsplit('/').next().unwrap_or_default();
+    let Some((stem, extension)) = file_name.rsplit_once('.') else {
+        return false;
+    };
+    !stem.is_empty()
+        && (1..=8).contains(&extension.len())
+        && extension.chars().all(|c| c.is_ascii_alphanumeric())
+        && extension.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+/// Flags memories referencing at least one path missing under `root`.
+fn plan_gc(memories: &[Memory], root: &Path) -> Vec<StaleMemory> {
+    memories
+        .iter()
+        .filter_map(|memory| {
+            let missing_paths: Vec<String> = extract_path_references(&memory.content)
+                .into_iter()
+                .filter(|path| !root.join(path).exists())
+                .collect();
+            (!missing_paths.is_empty()).then(|| StaleMemory {
+                memory: memory.clone(),
+                missing_paths,
+            })
+        })
+        .collect()
+}
+
 /// Formats memories as markdown for context injection.
 ///
 /// This produces a markdown document suitable for including in agent prompts:
@@ -677,6 +917,113 @@ mod tests {
         assert_eq!(loaded.content, "Line 1\nLine 2\nLine 3");
     }
 
+    #[test]
+    fn test_dedup_collapses_near_identical_memories() {
+        let (_temp, store) = create_temp_store();
+
+        let original = Memory {
+            id: "mem-1000-aaaa".to_string(),
+            memory_type: MemoryType::Fix,
+            content: "Run  cargo test\nbefore committing".to_string(),
+            tags: vec!["testing".to_string()],
+            created: "2025-01-01".to_string(),
+        };
+        let relearned = Memory {
+            id: "mem-2000-bbbb".to_string(),
+            memory_type: MemoryType::Pattern,
+            content: "run cargo test before   COMMITTING".to_string(),
+            tags: vec!["Testing".to_string(), "ci".to_string()],
+            created: "2025-02-01".to_string(),
+        };
+        let unrelated = Memory::new(MemoryType::Pattern, "Use anyhow".to_string(), vec![]);
+
+        // Append newest first; append inserts at the top of each section
+        store.append(&relearned).unwrap();
+        store.append(&original).unwrap();
+        store.append(&unrelated).unwrap();
+
+        let groups = store.find_duplicates().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].kept.id, "mem-1000-aaaa");
+        assert_eq!(groups[0].removed[0].id, "mem-2000-bbbb");
+        // find_duplicates does not write
+        assert_eq!(store.load().unwrap().len(), 3);
+
+        assert_eq!(store.dedup().unwrap(), 1);
+
+        let memories = store.load().unwrap();
+        assert_eq!(memories.len(), 2);
+        let kept = store.get("mem-1000-aaaa").unwrap().unwrap();
+        assert_eq!(kept.memory_type, MemoryType::Fix);
+        assert_eq!(kept.created, "2025-01-01");
+        assert_eq!(kept.tags, vec!["testing", "ci"]);
+        assert!(store.get("mem-2000-bbbb").unwrap().is_none());
+
+        // Idempotent
+        assert_eq!(store.dedup().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_extract_path_references_is_conservative() {
+        let content = "See `src/lib.rs:42` and ./docs/guide.md, not https://example.com/a.html, \
+                       /etc/hosts, src/*.rs, v1.2/3.4, the and/or case, or Cargo.toml.";
+        assert_eq!(
+            extract_path_references(content),
+            vec!["src/lib.rs", "docs/guide.md"]
+        );
+    }
+
+    #[test]
+    fn test_gc_flags_and_archives_only_memories_with_deleted_paths() {
+        let (temp_dir, store) = create_temp_store();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/parser.rs"), "").unwrap();
+
+        let live = Memory {
+            id: "mem-1000-aaaa".to_string(),
+            memory_type: MemoryType::Pattern,
+            content: "Parser entry point lives in `src/parser.rs`".to_string(),
+            tags: vec![],
+            created: "2025-01-01".to_string(),
+        };
+        let stale = Memory {
+            id: "mem-2000-bbbb".to_string(),
+            memory_type: MemoryType::Fix,
+            content: "Retry logic is in src/legacy/retry.rs and src/parser.rs".to_string(),
+            tags: vec!["retry".to_string()],
+            created: "2025-01-02".to_string(),
+        };
+        let prose = Memory::new(
+            MemoryType::Decision,
+            "Prefer composition over inheritance".to_string(),
+            vec![],
+        );
+        store.append(&live).unwrap();
+        store.append(&stale).unwrap();
+        store.append(&prose).unwrap();
+
+        let flagged = store.find_stale(root).unwrap();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].memory.id, "mem-2000-bbbb");
+        assert_eq!(flagged[0].missing_paths, vec!["src/legacy/retry.rs"]);
+        // find_stale does not write
+        assert_eq!(store.load().unwrap().len(), 3);
+
+        let archived = store.archive_stale(root).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert!(store.get("mem-2000-bbbb").unwrap().is_none());
+        assert_eq!(store.load().unwrap().len(), 2);
+
+        let archive = MarkdownMemoryStore::new(store.archive_path());
+        let moved = archive.get("mem-2000-bbbb").unwrap().unwrap();
+        assert_eq!(moved.memory_type, MemoryType::Fix);
+        assert_eq!(moved.tags, vec!["retry"]);
+
+        // Idempotent
+        assert!(store.archive_stale(root).unwrap().is_empty());
+    }
+
     #[test]
     fn test_format_memories_as_markdown_empty() {
         let output = format_memories_as_markdown(&[]);
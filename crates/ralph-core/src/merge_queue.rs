@@ -36,8 +36,10 @@
 //! }
 //! ```
 
+use crate::git_ops::get_commit_summary;
 use crate::loop_lock::LoopLock;
 use crate::text::truncate_with_ellipsis;
+use crate::workspace::{VerificationFailure, VerificationResult};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs::{self, File, OpenOptions};
@@ -58,6 +60,36 @@ pub struct MergeEvent {
     pub event: MergeEventType,
 }
 
+/// How a completed loop's branch is integrated into main (`merge.strategy`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeStrategy {
+    /// Merge commit (`git merge --no-ff`), keeping the branch's commits.
+    #[default]
+    Merge,
+    /// Rebase the branch onto main, then fast-forward main to it.
+    Rebase,
+    /// Collapse the branch into a single commit on main.
+    Squash,
+}
+
+impl MergeStrategy {
+    /// Returns the config/CLI name of this strategy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Rebase => "rebase",
+            Self::Squash => "squash",
+        }
+    }
+}
+
+impl std::fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Types of merge events.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -66,6 +98,10 @@ pub enum MergeEventType {
     Queued {
         /// The prompt that was executed in this loop.
         prompt: String,
+
+        /// Strategy the merge loop should use (absent in older logs).
+        #[serde(default)]
+        strategy: MergeStrategy,
     },
 
     /// Merge operation has started.
@@ -86,6 +122,25 @@ pub enum MergeEventType {
         reason: String,
     },
 
+    /// The pre-merge command failed in the loop's worktree.
+    VerificationFailed {
+        /// The command that was run (`merge.pre_merge_command`).
+        command: String,
+
+        /// Exit code of the command (-1 if killed by a signal).
+        exit_code: i32,
+
+        /// Tail of the command's stdout.
+        stdout: String,
+
+        /// Tail of the command's stderr.
+        stderr: String,
+
+        /// Why the command failed, when it did not simply exit non-zero.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        failure_kind: Option<VerificationFailure>,
+    },
+
     /// Loop was manually discarded.
     Discarded {
         /// Reason for discarding (optional).
@@ -131,6 +186,8 @@ pub enum MergeState {
     Merged,
     /// Needs manual review.
     NeedsReview,
+    /// Pre-merge verification failed; the merge was skipped and needs steering.
+    VerificationFailed,
     /// Discarded by user.
     Discarded,
 }
@@ -143,6 +200,11 @@ impl MergeState {
     pub fn is_terminal(self) -> bool {
         matches!(self, Self::Merged | Self::Discarded)
     }
+
+    /// Returns true if the merge stopped and waits for the user to retry or discard.
+    pub fn needs_steering(self) -> bool {
+        matches!(self, Self::NeedsReview | Self::VerificationFailed)
+    }
 }
 
 /// Summary of a loop's merge status.
@@ -160,6 +222,9 @@ pub struct MergeEntry {
     /// When the loop was queued.
     pub queued_at: DateTime<Utc>,
 
+    /// Strategy recorded when the loop was queued.
+    pub strategy: MergeStrategy,
+
     /// PID of merge-ralph if merging.
     pub merge_pid: Option<u32>,
 
@@ -210,6 +275,9 @@ impl MergeQueue {
     /// The relative path to the merge queue file within the workspace.
     pub const QUEUE_FILE: &'static str = ".ralph/merge-queue.jsonl";
 
+    /// Bytes of each output stream kept in a `verification_failed` event.
+    pub const MAX_VERIFICATION_OUTPUT: usize = 4096;
+
     /// Creates a new merge queue instance for the given workspace.
     pub fn new(workspace_root: impl AsRef<Path>) -> Self {
         Self {
@@ -217,18 +285,29 @@ impl MergeQueue {
         }
     }
 
-    /// Enqueues a completed loop for merging.
+    /// Enqueues a completed loop for merging with the default strategy.
     ///
     /// # Arguments
     ///
     /// * `loop_id` - The loop identifier
     /// * `prompt` - The prompt that was executed
     pub fn enqueue(&self, loop_id: &str, prompt: &str) -> Result<(), MergeQueueError> {
+        self.enqueue_with_strategy(loop_id, prompt, MergeStrategy::default())
+    }
+
+    /// Enqueues a completed loop for merging with the given strategy.
+    pub fn enqueue_with_strategy(
+        &self,
+        loop_id: &str,
+        prompt: &str,
+        strategy: MergeStrategy,
+    ) -> Result<(), MergeQueueError> {
         let event = MergeEvent {
             ts: Utc::now(),
             loop_id: loop_id.to_string(),
             event: MergeEventType::Queued {
                 prompt: prompt.to_string(),
+                strategy,
             },
         };
         self.append_event(&event)
@@ -241,10 +320,10 @@ impl MergeQueue {
     /// * `loop_id` - The loop identifier
     /// * `pid` - PID of the merge-ralph process
     pub fn mark_merging(&self, loop_id: &str, pid: u32) -> Result<(), MergeQueueError> {
-        // Verify loop is in queued or needs_review state
+        // Verify loop is queued or waiting on steering
         let entry = self.get_entry(loop_id)?;
         match entry {
-            Some(e) if e.state == MergeState::Queued || e.state == MergeState::NeedsReview => {}
+            Some(e) if e.state == MergeState::Queued || e.state.needs_steering() => {}
             Some(e) => {
                 return Err(MergeQueueError::InvalidTransition(
                     loop_id.to_string(),
@@ -325,6 +404,43 @@ impl MergeQueue {
         self.append_event(&event)
     }
 
+    /// Marks a queued loop as having failed pre-merge verification.
+    ///
+    /// The command's output is recorded in the event, keeping only the last
+    /// [`Self::MAX_VERIFICATION_OUTPUT`] bytes of each stream.
+    pub fn mark_verification_failed(
+        &self,
+        loop_id: &str,
+        command: &str,
+        result: &VerificationResult,
+    ) -> Result<(), MergeQueueError> {
+        let entry = self.get_entry(loop_id)?;
+        match entry {
+            Some(e) if e.state == MergeState::Queued => {}
+            Some(e) => {
+                return Err(MergeQueueError::InvalidTransition(
+                    loop_id.to_string(),
+                    e.state,
+                    MergeState::VerificationFailed,
+                ));
+            }
+            None => return Err(MergeQueueError::NotFound(loop_id.to_string())),
+        }
+
+        let event = MergeEvent {
+            ts: Utc::now(),
+            loop_id: loop_id.to_string(),
+            event: MergeEventType::VerificationFailed {
+                command: command.to_string(),
+                exit_code: result.exit_code,
+                stdout: output_tail(&result.stdout, Self::MAX_VERIFICATION_OUTPUT),
+                stderr: output_tail(&result.stderr, Self::MAX_VERIFICATION_OUTPUT),
+                failure_kind: result.failure_kind,
+            },
+        };
+        self.append_event(&event)
+    }
+
     /// Marks a loop as discarded.
     ///
     /// # Arguments
@@ -332,10 +448,10 @@ impl MergeQueue {
     /// * `loop_id` - The loop identifier
     /// * `reason` - Optional reason for discarding
     pub fn discard(&self, loop_id: &str, reason: Option<&str>) -> Result<(), MergeQueueError> {
-        // Can discard from queued or needs_review states
+        // Can discard from queued or steering states
         let entry = self.get_entry(loop_id)?;
         match entry {
-            Some(e) if e.state == MergeState::Queued || e.state == MergeState::NeedsReview => {}
+            Some(e) if e.state == MergeState::Queued || e.state.needs_steering() => {}
             Some(e) => {
                 return Err(MergeQueueError::InvalidTransition(
                     loop_id.to_string(),
@@ -425,6 +541,7 @@ impl MergeQueue {
                     prompt: String::new(),
                     state: MergeState::Queued,
                     queued_at: event.ts,
+                    strategy: MergeStrategy::default(),
                     merge_pid: None,
                     merge_commit: None,
                     failure_reason: None,
@@ -432,8 +549,9 @@ impl MergeQueue {
                 });
 
             match &event.event {
-                MergeEventType::Queued { prompt } => {
+                MergeEventType::Queued { prompt, strategy } => {
                     entry.prompt = prompt.clone();
+                    entry.strategy = *strategy;
                     entry.state = MergeState::Queued;
                     entry.queued_at = event.ts;
                 }
@@ -449,6 +567,27 @@ impl MergeQueue {
                     entry.state = MergeState::NeedsReview;
                     entry.failure_reason = Some(reason.clone());
                 }
+                MergeEventType::VerificationFailed {
+                    command,
+                    exit_code,
+                    stderr,
+                    failure_kind,
+                    ..
+                } => {
+                    entry.state = MergeState::VerificationFailed;
+                    entry.failure_reason = Some(match failure_kind {
+                        Some(VerificationFailure::TimedOut) => {
+                            format!("Pre-merge command `{command}` timed out")
+                        }
+                        Some(VerificationFailure::NotRun) => {
+                            format!("Pre-merge command `{command}` did not run: {stderr}")
+                        }
+                        _ => format!(
+                            "Pre-merge command `{}` failed (exit code {})",
+                            command, exit_code
+                        ),
+                    });
+                }
                 MergeEventType::Discarded { reason } => {
                     entry.state = MergeState::Discarded;
                     entry.discard_reason = reason.clone();
@@ -670,10 +809,138 @@ fn extract_summary_from_line(line: &str) -> String {
     }
 }
 
+/// Returns the last `max_bytes` of `output`, on a char boundary.
+fn output_tail(output: &str, max_bytes: usize) -> String {
+    if output.len() <= max_bytes {
+        return output.to_string();
+    }
+    let mut start = output.len() - max_bytes;
+    while !output.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("...{}", &output[start..])
+}
+
+/// Builds the commit message for a squash merge of `loop_id`.
+///
+/// The summary is the subject of the branch's latest commit (via
+/// [`get_commit_summary`] in the loop's worktree), falling back to
+/// [`smart_merge_summary`] when the worktree is gone. Uses the same
+/// `merge(ralph): <summary> (loop <id>)` format as merge commits.
+pub fn squash_commit_message(repo_root: &Path, loop_id: &str) -> String {
+    let branch_name = format!("ralph/{}", loop_id);
+    let max_summary_len = 72usize.saturating_sub(14 + 8 + loop_id.len());
+
+    let summary = crate::worktree::list_ralph_worktrees(repo_root)
+        .ok()
+        .and_then(|worktrees| worktrees.into_iter().find(|wt| wt.branch == branch_name))
+        .and_then(|wt| get_commit_summary(&wt.path).ok())
+        // get_commit_summary returns "<short sha>: <subject>"
+        .map(|summary| match summary.split_once(": ") {
+            Some((_, subject)) => subject.to_string(),
+            None => summary,
+        })
+        .filter(|subject| !subject.is_empty())
+        .or_else(|| smart_merge_summary(repo_root, loop_id).ok())
+        .filter(|summary| !summary.is_empty())
+        .unwrap_or_else(|| "squash loop changes".to_string());
+
+    format!(
+        "merge(ralph): {} (loop {})",
+        truncate_with_ellipsis(&summary, max_summary_len),
+        loop_id
+    )
+}
+
+/// Environment passed to a merge-ralph process for `loop_id`.
+///
+/// The merge-loop preset reads `RALPH_MERGE_STRATEGY` to pick its git
+/// commands and, for squash merges, `RALPH_MERGE_COMMIT_MESSAGE` for the
+/// single commit it creates.
+pub fn merge_loop_env(
+    repo_root: &Path,
+    loop_id: &str,
+    strategy: MergeStrategy,
+) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("RALPH_MERGE_LOOP_ID", loop_id.to_string()),
+        ("RALPH_MERGE_STRATEGY", strategy.as_str().to_string()),
+    ];
+    if strategy == MergeStrategy::Squash {
+        env.push((
+            "RALPH_MERGE_COMMIT_MESSAGE",
+            squash_commit_message(repo_root, loop_id),
+        ));
+    }
+    env
+}
+
+/// Checks whether squashing `loop_id`'s branch would lose meaningful history.
+///
+/// History is considered meaningful when the branch contains merge commits or
+/// commits from more than one author. Returns a description of what would be
+/// lost, or `None` when a squash is safe.
+pub fn squash_history_loss(
+    workspace: &Path,
+    loop_id: &str,
+) -> Result<Option<String>, MergeQueueError> {
+    let range = format!("main..ralph/{}", loop_id);
+
+    let merges = Command::new("git")
+        .args(["rev-list", "--merges", "--count", &range])
+        .current_dir(workspace)
+        .output()?;
+    let merge_count = String::from_utf8_lossy(&merges.stdout)
+        .trim()
+        .parse::<usize>()
+        .unwrap_or(0);
+
+    let authors = Command::new("git")
+        .args(["log", "--format=%ae", &range])
+        .current_dir(workspace)
+        .output()?;
+    let authors: std::collections::BTreeSet<String> = String::from_utf8_lossy(&authors.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    let mut lost = Vec::new();
+    if merge_count > 0 {
+        lost.push(format!(
+            "{} merge commit{}",
+            merge_count,
+            if merge_count == 1 { "" } else { "s" }
+        ));
+    }
+    if authors.len() > 1 {
+        lost.push(format!("commits from {} authors", authors.len()));
+    }
+
+    Ok((!lost.is_empty()).then(|| format!("Squashing would flatten {}", lost.join(" and "))))
+}
+
 /// Check if a merge needs user steering (e.g., due to conflicts).
+///
+/// Uses the strategy recorded in the merge queue for `loop_id`, if any.
 pub fn merge_needs_steering(
     workspace: &Path,
     loop_id: &str,
+) -> Result<SteeringDecision, MergeQueueError> {
+    let strategy = MergeQueue::new(workspace)
+        .get_entry(loop_id)?
+        .map(|entry| entry.strategy)
+        .unwrap_or_default();
+    merge_needs_steering_with_strategy(workspace, loop_id, strategy)
+}
+
+/// Check if a merge with `strategy` needs user steering.
+///
+/// Conflicts always need steering. A squash additionally needs steering when
+/// it would lose meaningful history (see [`squash_history_loss`]).
+pub fn merge_needs_steering_with_strategy(
+    workspace: &Path,
+    loop_id: &str,
+    strategy: MergeStrategy,
 ) -> Result<SteeringDecision, MergeQueueError> {
     let branch_name = format!("ralph/{}", loop_id);
 
@@ -718,6 +985,24 @@ pub fn merge_needs_steering(
                 },
             ],
         })
+    } else if strategy == MergeStrategy::Squash
+        && let Some(reason) = squash_history_loss(workspace, loop_id)?
+    {
+        Ok(SteeringDecision {
+            needs_input: true,
+            reason,
+            options: vec![
+                MergeOption {
+                    label: "Merge instead (keep history)".to_string(),
+                },
+                MergeOption {
+                    label: "Squash anyway".to_string(),
+                },
+                MergeOption {
+                    label: "Manual resolution".to_string(),
+                },
+            ],
+        })
     } else {
         Ok(SteeringDecision {
             needs_input: false,
@@ -1017,19 +1302,92 @@ mod tests {
             loop_id: "loop-test".to_string(),
             event: MergeEventType::Queued {
                 prompt: "test prompt".to_string(),
+                strategy: MergeStrategy::Squash,
             },
         };
 
         let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""strategy":"squash""#));
         let parsed: MergeEvent = serde_json::from_str(&json).unwrap();
 
         assert_eq!(parsed.loop_id, event.loop_id);
         match parsed.event {
-            MergeEventType::Queued { prompt } => assert_eq!(prompt, "test prompt"),
+            MergeEventType::Queued { prompt, strategy } => {
+                assert_eq!(prompt, "test prompt");
+                assert_eq!(strategy, MergeStrategy::Squash);
+            }
             _ => panic!("Wrong event type"),
         }
     }
 
+    #[test]
+    fn test_verification_failed_records_output_and_allows_retry() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+        queue.enqueue("loop-1", "prompt").unwrap();
+
+        let result = VerificationResult {
+            passed: false,
+            exit_code: 101,
+            expected_exit_code: 0,
+            stdout: "x".repeat(MergeQueue::MAX_VERIFICATION_OUTPUT + 10),
+            stderr: "test failed".to_string(),
+            coverage: None,
+            failure_kind: Some(crate::workspace::VerificationFailure::ExitCode),
+            failed_assertion: None,
+        };
+        queue
+            .mark_verification_failed("loop-1", "cargo test", &result)
+            .unwrap();
+
+        let entry = queue.get_entry("loop-1").unwrap().unwrap();
+        assert_eq!(entry.state, MergeState::VerificationFailed);
+        assert!(entry.state.needs_steering());
+        assert_eq!(
+            entry.failure_reason.as_deref(),
+            Some("Pre-merge command `cargo test` failed (exit code 101)")
+        );
+
+        let log = fs::read_to_string(temp_dir.path().join(MergeQueue::QUEUE_FILE)).unwrap();
+        let event: MergeEvent = serde_json::from_str(log.lines().last().unwrap()).unwrap();
+        match event.event {
+            MergeEventType::VerificationFailed { stdout, stderr, .. } => {
+                assert_eq!(stdout.len(), MergeQueue::MAX_VERIFICATION_OUTPUT + 3);
+                assert_eq!(stderr, "test failed");
+            }
+            other => panic!("Wrong event type: {other:?}"),
+        }
+
+        // Verification failures can't be re-verified, only retried or discarded
+        assert!(
+            queue
+                .mark_verification_failed("loop-1", "cargo test", &result)
+                .is_err()
+        );
+        queue.mark_merging("loop-1", 42).unwrap();
+    }
+
+    #[test]
+    fn test_enqueue_records_strategy_and_defaults_for_old_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        queue
+            .enqueue_with_strategy("loop-rebase", "prompt", MergeStrategy::Rebase)
+            .unwrap();
+        let legacy = r#"{"ts":"2025-01-24T00:00:00Z","loop_id":"loop-old","event":{"type":"queued","prompt":"p"}}"#;
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(temp_dir.path().join(MergeQueue::QUEUE_FILE))
+            .unwrap();
+        writeln!(file, "{legacy}").unwrap();
+
+        let rebase = queue.get_entry("loop-rebase").unwrap().unwrap();
+        assert_eq!(rebase.strategy, MergeStrategy::Rebase);
+        let old = queue.get_entry("loop-old").unwrap().unwrap();
+        assert_eq!(old.strategy, MergeStrategy::Merge);
+    }
+
     #[test]
     fn test_creates_ralph_directory() {
         let temp_dir = TempDir::new().unwrap();
@@ -42,6 +42,18 @@ pub enum SessionStatus {
     Failed,
 }
 
+impl std::fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::WaitingForInput { prompt_id } => write!(f, "waiting_for_input ({prompt_id})"),
+            Self::Completed => write!(f, "completed"),
+            Self::TimedOut => write!(f, "timed_out"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
 /// A single entry in the planning conversation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversationEntry {
@@ -66,6 +78,16 @@ pub enum ConversationType {
     UserResponse,
 }
 
+impl ConversationType {
+    /// Heading used for this entry in exported transcripts.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::UserPrompt => "Agent prompt",
+            Self::UserResponse => "User response",
+        }
+    }
+}
+
 /// Metadata for a planning session.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionMetadata {
@@ -301,6 +323,47 @@ impl PlanningSession {
 
         Ok(entries)
     }
+
+    /// Render the session as a readable markdown transcript.
+    ///
+    /// Starts with a front-matter block built from the session metadata,
+    /// followed by the original prompt and every conversation entry in the
+    /// order it was recorded, each under a heading naming its role.
+    pub fn export_markdown(&self) -> Result<String, PlanningSessionError> {
+        let entries = self.load_conversation()?;
+        let meta = &self.metadata;
+        // JSON string literals are valid YAML scalars, so quoting this way keeps
+        // colons and quotes in values from breaking the front matter.
+        let quote = |value: &str| serde_json::Value::from(value).to_string();
+
+        let mut out = String::from("---\n");
+        out.push_str(&format!("session: {}\n", quote(&meta.id)));
+        out.push_str(&format!("status: {}\n", quote(&meta.status.to_string())));
+        out.push_str(&format!("started_at: {}\n", quote(&meta.created_at)));
+        out.push_str(&format!("updated_at: {}\n", quote(&meta.updated_at)));
+        out.push_str(&format!("iterations: {}\n", meta.iterations));
+        if let Some(config) = &meta.config {
+            out.push_str(&format!("config: {}\n", quote(config)));
+        }
+        out.push_str("---\n\n");
+
+        out.push_str(&format!("# Planning session {}\n\n", meta.id));
+        out.push_str("## Idea\n\n");
+        out.push_str(meta.prompt.trim_end());
+        out.push('\n');
+
+        for entry in &entries {
+            out.push_str(&format!(
+                "\n## {} ({})\n\n_{}_\n\n{}\n",
+                entry.entry_type.label(),
+                entry.id,
+                entry.ts,
+                entry.text.trim_end()
+            ));
+        }
+
+        Ok(out)
+    }
 }
 
 #[cfg(test)]
@@ -452,6 +515,35 @@ mod tests {
         assert_eq!(session.metadata.iterations, 2);
     }
 
+    #[test]
+    fn test_export_markdown_renders_metadata_and_conversation() {
+        let (_temp, ctx) = create_test_context();
+        let mut session =
+            PlanningSession::new("Add OAuth login", &ctx, Some("ralph.yml".to_string())).unwrap();
+        session
+            .append_prompt("q1", "Which providers: GitHub?")
+            .unwrap();
+        session.append_response("q1", "GitHub and Google").unwrap();
+        session.set_status(SessionStatus::Completed).unwrap();
+
+        let md = session.export_markdown().unwrap();
+
+        assert!(md.starts_with(&format!("---\nsession: \"{}\"\n", session.id())));
+        assert!(md.contains("status: \"completed\"\n"));
+        assert!(md.contains(&format!(
+            "started_at: \"{}\"\n",
+            session.metadata.created_at
+        )));
+        assert!(md.contains("config: \"ralph.yml\"\n---\n"));
+        assert!(md.contains("## Idea\n\nAdd OAuth login\n"));
+
+        let prompt = md.find("## Agent prompt (q1)").unwrap();
+        let response = md.find("## User response (q1)").unwrap();
+        assert!(prompt < response);
+        assert!(md.contains("Which providers: GitHub?\n"));
+        assert!(md.ends_with("GitHub and Google\n"));
+    }
+
     #[test]
     fn test_artifacts_directory_created() {
         let (_temp, ctx) = create_test_context();
@@ -0,0 +1,119 @@
+//! User overrides for built-in prompt sections.
+//!
+//! Ralph's prompt sections are embedded in the binary. A team can replace
+//! individual sections without forking by dropping a markdown file named
+//! after the section into `.ralph/prompts/`:
+//!
+//! ```text
+//! .ralph/prompts/guardrails.md     replaces ### GUARDRAILS
+//! .ralph/prompts/workflow.md       replaces ## WORKFLOW
+//! .ralph/prompts/event_writing.md  replaces ## EVENT WRITING
+//! ```
+//!
+//! A present, non-empty file wins and is used verbatim (including its
+//! heading); a missing or blank file falls back to the built-in text.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use tracing::{debug, warn};
+
+/// Directory, relative to the workspace root, holding prompt overrides.
+pub const PROMPT_FRAGMENTS_DIR: &str = ".ralph/prompts";
+
+/// A prompt section that can be overridden by a fragment file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptSection {
+    Guardrails,
+    Workflow,
+    EventWriting,
+}
+
+impl PromptSection {
+    /// All overridable sections.
+    pub const ALL: [PromptSection; 3] = [
+        PromptSection::Guardrails,
+        PromptSection::Workflow,
+        PromptSection::EventWriting,
+    ];
+
+    /// Section name, which is also the fragment file stem.
+    pub fn name(self) -> &'static str {
+        match self {
+            PromptSection::Guardrails => "guardrails",
+            PromptSection::Workflow => "workflow",
+            PromptSection::EventWriting => "event_writing",
+        }
+    }
+}
+
+/// Loaded prompt overrides, keyed by section.
+#[derive(Debug, Clone, Default)]
+pub struct PromptFragments {
+    overrides: HashMap<PromptSection, String>,
+}
+
+impl PromptFragments {
+    /// Loads `<dir>/<section>.md` for each section that has one.
+    ///
+    /// Unreadable files are skipped with a warning so a bad override never
+    /// stops a loop; the built-in text is used instead.
+    pub fn load(dir: &Path) -> Self {
+        let mut overrides = HashMap::new();
+        for section in PromptSection::ALL {
+            let path = dir.join(format!("{}.md", section.name()));
+            match std::fs::read_to_string(&path) {
+                Ok(content) if !content.trim().is_empty() => {
+                    debug!("Using prompt override {}", path.display());
+                    overrides.insert(section, content.trim().to_string());
+                }
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => warn!(
+                    "Ignoring prompt override {}: {}; using built-in {} section",
+                    path.display(),
+                    e,
+                    section.name()
+                ),
+            }
+        }
+        Self { overrides }
+    }
+
+    /// Returns the override text for `section`, trimmed, if one was loaded.
+    pub fn get(&self, section: PromptSection) -> Option<&str> {
+        self.overrides.get(&section).map(String::as_str)
+    }
+
+    /// Returns true when no sections are overridden.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn loads_present_fragments_and_skips_blank_ones() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("guardrails.md"),
+            "\n### GUARDRAILS\n1. Never push to main\n\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("workflow.md"), "  \n").unwrap();
+
+        let fragments = PromptFragments::load(temp.path());
+
+        assert_eq!(
+            fragments.get(PromptSection::Guardrails),
+            Some("### GUARDRAILS\n1. Never push to main")
+        );
+        assert_eq!(fragments.get(PromptSection::Workflow), None);
+        assert_eq!(fragments.get(PromptSection::EventWriting), None);
+        assert!(PromptFragments::load(&temp.path().join("missing")).is_empty());
+    }
+}
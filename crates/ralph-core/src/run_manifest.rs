@@ -0,0 +1,212 @@
+//! Reproducibility manifest for a run.
+//!
+//! At startup the loop writes `.ralph/agent/run-manifest.json` recording
+//! everything that affects its behavior: the resolved config (and its hash),
+//! the backend and its version, the run seed, the git SHA the run started
+//! from, and a hash of the prompt. `ralph run --from-manifest FILE` reads it
+//! back to restore the config and seed for a reproduction attempt.
+//!
+//! Hashes are 64-bit FNV-1a over canonical JSON (object keys sorted), so they
+//! are stable across processes and platforms.
+
+use crate::config::RalphConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+
+/// Current manifest format version.
+pub const RUN_MANIFEST_VERSION: u32 = 1;
+
+/// Errors reading or writing a run manifest.
+#[derive(Debug, thiserror::Error)]
+pub enum RunManifestError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid manifest: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("unsupported manifest version {0} (expected {RUN_MANIFEST_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("config hash mismatch: manifest says {expected}, embedded config hashes to {actual}")]
+    ConfigHashMismatch { expected: String, actual: String },
+}
+
+/// Inputs that determine a run's behavior.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub loop_id: String,
+    /// Seed for the run, exported to the backend as `RALPH_SEED`.
+    pub seed: u64,
+    pub config_hash: String,
+    pub backend: String,
+    /// First line of `<backend> --version`, when it could be determined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend_version: Option<String>,
+    /// HEAD at startup; absent outside a git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_sha: Option<String>,
+    pub prompt_hash: String,
+    /// The resolved config, so it can be restored exactly.
+    pub config: serde_json::Value,
+}
+
+impl RunManifest {
+    /// Captures the manifest for a run of `config` with `prompt`.
+    ///
+    /// The git SHA is read from the config's workspace root; the backend
+    /// version is left for the caller to fill in.
+    pub fn capture(
+        config: &RalphConfig,
+        loop_id: &str,
+        seed: u64,
+        prompt: &str,
+    ) -> Result<Self, RunManifestError> {
+        let config_value = serde_json::to_value(config)?;
+        Ok(Self {
+            version: RUN_MANIFEST_VERSION,
+            created_at: Utc::now(),
+            loop_id: loop_id.to_string(),
+            seed,
+            config_hash: content_hash(&config_value.to_string()),
+            backend: config.cli.backend.clone(),
+            backend_version: None,
+            git_sha: crate::get_head_sha(&config.core.workspace_root).ok(),
+            prompt_hash: content_hash(prompt),
+            config: config_value,
+        })
+    }
+
+    /// Writes the manifest as pretty JSON, creating parent directories.
+    ///
+    /// The file is replaced atomically, so readers never see a partial
+    /// manifest when it is rewritten during a run.
+    pub fn write(&self, path: &Path) -> Result<(), RunManifestError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string_pretty(self)? + "\n")?;
+        if let Err(e) = std::fs::rename(&temp_path, path) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Loads a manifest, rejecting unknown versions.
+    pub fn load(path: &Path) -> Result<Self, RunManifestError> {
+        let manifest: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if manifest.version != RUN_MANIFEST_VERSION {
+            return Err(RunManifestError::UnsupportedVersion(manifest.version));
+        }
+        Ok(manifest)
+    }
+
+    /// Restores the recorded config after checking it against `config_hash`.
+    ///
+    /// `core.workspace_root` is not serialized; callers set it as they would
+    /// for a freshly loaded config.
+    pub fn restore_config(&self) -> Result<RalphConfig, RunManifestError> {
+        let actual = content_hash(&self.config.to_string());
+        if actual != self.config_hash {
+            return Err(RunManifestError::ConfigHashMismatch {
+                expected: self.config_hash.clone(),
+                actual,
+            });
+        }
+        Ok(serde_json::from_value(self.config.clone())?)
+    }
+
+    /// Reads just the seed from a manifest file, if one is readable.
+    pub fn read_seed(path: &Path) -> Option<u64> {
+        let raw = std::fs::read_to_string(path).ok()?;
+        let value: serde_json::Value = serde_json::from_str(&raw).ok()?;
+        value.get("seed")?.as_u64()
+    }
+}
+
+/// Generates a fresh run seed.
+pub fn generate_seed() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    // splitmix64 finalizer spreads the low-entropy inputs across all bits
+    #[allow(clippy::cast_possible_truncation)]
+    let mut x = (nanos as u64) ^ (u64::from(std::process::id()) << 32);
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Stable 64-bit FNV-1a hash of `content`, as 16 hex digits.
+pub fn content_hash(content: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn writes_expected_fields_and_restores_seed_and_config() {
+        let temp = TempDir::new().unwrap();
+        let mut config: RalphConfig =
+            serde_yaml::from_str("cli:\n  backend: claude\nevent_loop:\n  max_iterations: 7\n")
+                .unwrap();
+        config.core.workspace_root = temp.path().to_path_buf();
+        let path = temp.path().join(".ralph/agent/run-manifest.json");
+
+        let manifest = RunManifest::capture(&config, "primary-1", 42, "Build the thing").unwrap();
+        manifest.write(&path).unwrap();
+
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        for field in [
+            "version",
+            "created_at",
+            "loop_id",
+            "seed",
+            "config_hash",
+            "backend",
+            "prompt_hash",
+            "config",
+        ] {
+            assert!(raw.get(field).is_some(), "missing {field}: {raw}");
+        }
+        assert_eq!(raw["backend"], "claude");
+        assert_eq!(raw["prompt_hash"], content_hash("Build the thing"));
+        // Not a git repository
+        assert!(raw.get("git_sha").is_none());
+
+        let loaded = RunManifest::load(&path).unwrap();
+        assert_eq!(loaded.seed, 42);
+        assert_eq!(loaded.config_hash, manifest.config_hash);
+        assert_eq!(RunManifest::read_seed(&path), Some(42));
+
+        let restored = loaded.restore_config().unwrap();
+        assert_eq!(restored.event_loop.max_iterations, 7);
+        let rehashed = RunManifest::capture(&restored, "primary-2", 1, "").unwrap();
+        assert_eq!(rehashed.config_hash, manifest.config_hash);
+    }
+
+    #[test]
+    fn rejects_tampered_config() {
+        let config = RalphConfig::default();
+        let mut manifest = RunManifest::capture(&config, "primary-1", 1, "prompt").unwrap();
+        manifest.config["event_loop"]["max_iterations"] = serde_json::json!(1);
+
+        assert!(matches!(
+            manifest.restore_config(),
+            Err(RunManifestError::ConfigHashMismatch { .. })
+        ));
+    }
+}
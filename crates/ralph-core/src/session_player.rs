@@ -3,12 +3,22 @@
 //! `SessionPlayer` reads events from JSONL files and replays them with
 //! configurable timing. Supports terminal output replay (with ANSI colors),
 //! plain text mode (ANSI stripped), and step-through debugging.
+//! Gzip-compressed recordings are decompressed transparently, and recordings
+//! in older format versions are migrated as they are read.
 
+use chrono::{DateTime, Utc};
+use flate2::bufread::MultiGzDecoder;
 use ralph_proto::{TerminalWrite, UxEvent};
 use std::io::{self, BufRead, Write};
 use std::time::Duration;
 
-use crate::session_recorder::Record;
+use crate::session_recorder::{RECORD_FORMAT_VERSION, Record};
+
+/// Leading bytes of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Event name of the header record a recording starts with.
+const LOOP_START_EVENT: &str = "_meta.loop_start";
 
 /// Replay mode for session playback.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +29,15 @@ pub enum ReplayMode {
     Text,
 }
 
+/// A position in a recording, used to bound the playback window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartPoint {
+    /// The `_meta.iteration` marker for this iteration number.
+    Iteration(u32),
+    /// The first record at or after this wall-clock time.
+    Timestamp(DateTime<Utc>),
+}
+
 /// Configuration for session playback.
 #[derive(Debug, Clone)]
 pub struct PlayerConfig {
@@ -33,6 +52,15 @@ pub struct PlayerConfig {
 
     /// Filter to specific event types (empty = all events).
     pub event_filter: Vec<String>,
+
+    /// Skip records before this point without waiting (None = from the start).
+    pub start_at: Option<StartPoint>,
+
+    /// Stop before records past this point (None = to the end).
+    ///
+    /// An iteration bound includes that whole iteration; a timestamp bound
+    /// includes records at exactly that time.
+    pub end_at: Option<StartPoint>,
 }
 
 impl Default for PlayerConfig {
@@ -42,6 +70,8 @@ impl Default for PlayerConfig {
             step_mode: false,
             replay_mode: ReplayMode::Terminal,
             event_filter: Vec::new(),
+            start_at: None,
+            end_at: None,
         }
     }
 }
@@ -77,6 +107,38 @@ impl PlayerConfig {
         self.event_filter = events;
         self
     }
+
+    /// Starts playback at the given point.
+    pub fn with_start_at(mut self, start: StartPoint) -> Self {
+        self.start_at = Some(start);
+        self
+    }
+
+    /// Ends playback at the given point.
+    pub fn with_end_at(mut self, end: StartPoint) -> Self {
+        self.end_at = Some(end);
+        self
+    }
+}
+
+/// A recording in a format version newer than this build understands.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "session recording uses format version {found}, but this build supports up to version {supported}; upgrade Ralph to replay it"
+)]
+pub struct UnsupportedRecordVersion {
+    pub found: u32,
+    pub supported: u32,
+}
+
+/// The loop start header of a recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionHeader {
+    /// Format version the recording was written in.
+    pub version: u32,
+    pub prompt_file: Option<String>,
+    pub max_iterations: Option<u32>,
+    pub ux_mode: Option<String>,
 }
 
 /// A parsed record with timing information for replay.
@@ -118,26 +180,62 @@ pub struct SessionPlayer {
 
     /// Current playback position.
     position: usize,
+
+    /// Format version the recording was written in.
+    version: u32,
 }
 
 impl SessionPlayer {
     /// Creates a player from a JSONL reader.
-    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+    ///
+    /// Gzip input is detected from its magic bytes and decompressed.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> io::Result<Self> {
+        if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            return Self::from_jsonl(io::BufReader::new(MultiGzDecoder::new(reader)));
+        }
+        Self::from_jsonl(reader)
+    }
+
+    /// Parses uncompressed JSONL records.
+    ///
+    /// The version in the header is checked before the header is parsed as a
+    /// [`Record`], so a recording from a newer Ralph fails with
+    /// [`UnsupportedRecordVersion`] rather than a parse error.
+    fn from_jsonl<R: BufRead>(reader: R) -> io::Result<Self> {
         let mut records = Vec::new();
         let mut first_ts: Option<u64> = None;
+        let mut version: Option<u32> = None;
 
         for line in reader.lines() {
-            let line = line?;
+            let line = match line {
+                Ok(line) => line,
+                // A gzip recording from a crashed run has no trailer; every
+                // sync-flushed record before the cut is still usable.
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            };
             if line.trim().is_empty() {
                 continue;
             }
 
-            let record: Record = serde_json::from_str(&line).map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Invalid JSON record: {}", e),
-                )
-            })?;
+            let value: serde_json::Value = serde_json::from_str(&line).map_err(invalid_record)?;
+            if version.is_none()
+                && let Some(found) = header_version(&value)
+            {
+                if found > RECORD_FORMAT_VERSION {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        UnsupportedRecordVersion {
+                            found,
+                            supported: RECORD_FORMAT_VERSION,
+                        },
+                    ));
+                }
+                version = Some(found);
+            }
+
+            let mut record: Record = serde_json::from_value(value).map_err(invalid_record)?;
+            migrate_record(&mut record, version.unwrap_or(1));
 
             // Calculate offset from session start
             let ts = record.ts;
@@ -151,6 +249,7 @@ impl SessionPlayer {
             records,
             config: PlayerConfig::default(),
             position: 0,
+            version: version.unwrap_or(1),
         })
     }
 
@@ -159,6 +258,33 @@ impl SessionPlayer {
         Self::from_reader(io::BufReader::new(bytes))
     }
 
+    /// Returns the format version the recording was written in.
+    ///
+    /// Recordings without a header are treated as version 1.
+    pub fn format_version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the recording's loop start header, if it has one.
+    pub fn header(&self) -> Option<SessionHeader> {
+        let data = &self
+            .records
+            .iter()
+            .find(|r| r.record.event == LOOP_START_EVENT)?
+            .record
+            .data;
+        let text = |key: &str| data.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        Some(SessionHeader {
+            version: self.version,
+            prompt_file: text("prompt_file"),
+            max_iterations: data
+                .get("max_iterations")
+                .and_then(|v| v.as_u64())
+                .and_then(|n| u32::try_from(n).ok()),
+            ux_mode: text("ux_mode"),
+        })
+    }
+
     /// Sets the playback configuration.
     pub fn with_config(mut self, config: PlayerConfig) -> Self {
         self.config = config;
@@ -208,11 +334,17 @@ impl SessionPlayer {
     /// This is a synchronous replay that respects timing delays adjusted
     /// by the speed multiplier. In step mode, it waits for Enter after
     /// each event.
+    ///
+    /// Records outside the `start_at`/`end_at` window are skipped without
+    /// sleeping; timing resumes from the start of the window.
     pub fn replay_terminal<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
         self.reset();
-        let mut last_offset_ms: u64 = 0;
+        let window = self.window();
+        let mut last_offset_ms = self.records.get(window.start).map_or(0, |r| r.offset_ms);
 
-        let terminal_writes = self.terminal_writes();
+        let terminal_writes = self.records[window]
+            .iter()
+            .filter(|r| r.record.event.starts_with("ux.terminal.write"));
         for record in terminal_writes {
             // Calculate delay from previous event
             let delay_ms = record.offset_ms.saturating_sub(last_offset_ms);
@@ -242,6 +374,103 @@ impl SessionPlayer {
         writer.flush()
     }
 
+    /// Writes the terminal output as an asciinema v2 cast.
+    ///
+    /// Emits a header line followed by one `[seconds, "o", data]` event per
+    /// terminal write, with ANSI sequences kept intact. Times are relative to
+    /// the start of the playback window (see `start_at`/`end_at`), and
+    /// recorded resizes become `"r"` events. The header size comes from the
+    /// first recorded resize, or 80x24 if none was captured.
+    pub fn export_asciicast<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let window = self.window();
+        let records = &self.records[window];
+        let base_offset_ms = records.first().map_or(0, |r| r.offset_ms);
+
+        let (width, height) = records
+            .iter()
+            .find_map(|r| match Self::parse_ux_event(&r.record) {
+                Ok(UxEvent::TerminalResize(resize)) => Some((resize.width, resize.height)),
+                _ => None,
+            })
+            .unwrap_or((80, 24));
+
+        let mut header = serde_json::json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+        });
+        if let Some(first) = records.first() {
+            header["timestamp"] = serde_json::json!(first.record.ts / 1000);
+        }
+        writeln!(writer, "{header}")?;
+
+        // Bytes of a UTF-8 character split across two writes.
+        let mut pending: Vec<u8> = Vec::new();
+        for record in records {
+            let time = record.offset_ms.saturating_sub(base_offset_ms) as f64 / 1000.0;
+            let event = match Self::parse_ux_event(&record.record) {
+                Ok(UxEvent::TerminalWrite(write)) => {
+                    pending.extend(write.decode_bytes().map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("Failed to decode base64: {}", e),
+                        )
+                    })?);
+                    let data = take_utf8_prefix(&mut pending);
+                    if data.is_empty() {
+                        continue;
+                    }
+                    serde_json::json!([time, "o", data])
+                }
+                Ok(UxEvent::TerminalResize(resize)) => {
+                    serde_json::json!([time, "r", format!("{}x{}", resize.width, resize.height)])
+                }
+                _ => continue,
+            };
+            writeln!(writer, "{event}")?;
+        }
+
+        writer.flush()
+    }
+
+    /// Returns the range of records inside the configured playback window.
+    fn window(&self) -> std::ops::Range<usize> {
+        let start = self.config.start_at.map_or(0, |point| {
+            self.records
+                .iter()
+                .position(|r| Self::is_at_or_after(r, point, false))
+                .unwrap_or(self.records.len())
+        });
+        let end = self.config.end_at.map_or(self.records.len(), |point| {
+            self.records[start..]
+                .iter()
+                .position(|r| Self::is_at_or_after(r, point, true))
+                .map_or(self.records.len(), |offset| start + offset)
+        });
+        start..end
+    }
+
+    /// Whether `record` lies at (or, when `past` is set, strictly beyond) `point`.
+    fn is_at_or_after(record: &TimestampedRecord, point: StartPoint, past: bool) -> bool {
+        match point {
+            StartPoint::Iteration(n) => {
+                record.record.event == "_meta.iteration"
+                    && record.record.data["n"].as_u64().is_some_and(|i| {
+                        let n = u64::from(n);
+                        if past { i > n } else { i >= n }
+                    })
+            }
+            StartPoint::Timestamp(ts) => {
+                let ts = u64::try_from(ts.timestamp_millis()).unwrap_or(0);
+                if past {
+                    record.record.ts > ts
+                } else {
+                    record.record.ts >= ts
+                }
+            }
+        }
+    }
+
     /// Outputs a terminal write event based on replay mode.
     fn output_terminal_write<W: Write>(
         &self,
@@ -322,7 +551,7 @@ impl SessionPlayer {
 ///
 /// Handles CSI sequences (\x1b[...m), OSC sequences (\x1b]...\x07),
 /// and simple escape sequences (\x1b followed by a single char).
-fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
+pub(crate) fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
     let mut result = Vec::with_capacity(bytes.len());
     let mut i = 0;
 
@@ -374,6 +603,21 @@ fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
     result
 }
 
+/// Drains the decodable prefix of `bytes` as a string.
+///
+/// An incomplete UTF-8 sequence at the end stays in `bytes` for the next
+/// chunk; invalid bytes elsewhere are replaced.
+fn take_utf8_prefix(bytes: &mut Vec<u8>) -> String {
+    let complete = match std::str::from_utf8(bytes) {
+        Ok(_) => bytes.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => bytes.len(),
+    };
+    let text = String::from_utf8_lossy(&bytes[..complete]).into_owned();
+    bytes.drain(..complete);
+    text
+}
+
 /// Escapes ANSI sequences for visibility in snapshots.
 ///
 /// Converts \x1b to `\x1b` literal string for readable diff comparisons.
@@ -381,6 +625,35 @@ fn escape_ansi(s: &str) -> String {
     s.replace('\x1b', "\\x1b")
 }
 
+fn invalid_record(e: serde_json::Error) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid JSON record: {}", e),
+    )
+}
+
+/// Returns the format version declared by `value` if it is a header record.
+///
+/// Version 1 headers carry no version field.
+fn header_version(value: &serde_json::Value) -> Option<u32> {
+    if value.get("event").and_then(|e| e.as_str()) != Some(LOOP_START_EVENT) {
+        return None;
+    }
+    let version = value.pointer("/data/version").and_then(|v| v.as_u64());
+    Some(version.map_or(1, |v| u32::try_from(v).unwrap_or(u32::MAX)))
+}
+
+/// Upgrades a record written in format `version` to the current format.
+fn migrate_record(record: &mut Record, version: u32) {
+    // Version 1 headers could omit the UX mode, which always meant the CLI.
+    if version < 2
+        && record.event == LOOP_START_EVENT
+        && let Some(data) = record.data.as_object_mut()
+    {
+        data.entry("ux_mode").or_insert_with(|| "cli".into());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +681,83 @@ mod tests {
         assert_eq!(player.records[1].offset_ms, 100);
     }
 
+    #[test]
+    fn test_player_reads_gzip_recording() {
+        use crate::session_recorder::SessionRecorder;
+        use ralph_proto::Event;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl.gz");
+        {
+            let recorder = SessionRecorder::create(&path).unwrap();
+            recorder.record_bus_event(&Event::new("task.start", "Begin"));
+            recorder.record_bus_event(&Event::new("build.done", "Done"));
+        }
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(&GZIP_MAGIC));
+
+        let player = SessionPlayer::from_bytes(&bytes).unwrap();
+        assert_eq!(player.record_count(), 2);
+        assert_eq!(player.bus_events().len(), 2);
+    }
+
+    #[test]
+    fn test_gzip_recording_without_trailer_loads_written_records() {
+        use crate::session_recorder::SessionRecorder;
+        use ralph_proto::Event;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl.gz");
+        let recorder = SessionRecorder::create(&path).unwrap();
+        recorder.record_bus_event(&Event::new("task.start", "Begin"));
+        recorder.record_bus_event(&Event::new("build.done", "Done"));
+        // Simulate a crash: the gzip encoder is never dropped, so its
+        // trailer is never written
+        std::mem::forget(recorder);
+
+        let bytes = std::fs::read(&path).unwrap();
+        let player = SessionPlayer::from_bytes(&bytes).unwrap();
+        assert_eq!(player.record_count(), 2);
+        assert_eq!(player.bus_events().len(), 2);
+    }
+
+    #[test]
+    fn test_header_reports_current_version() {
+        let header =
+            serde_json::to_string(&Record::meta_loop_start("PROMPT.md", 50, None)).unwrap();
+        let player = SessionPlayer::from_bytes(header.as_bytes()).unwrap();
+
+        assert_eq!(player.format_version(), RECORD_FORMAT_VERSION);
+        let header = player.header().unwrap();
+        assert_eq!(header.prompt_file.as_deref(), Some("PROMPT.md"));
+        assert_eq!(header.max_iterations, Some(50));
+        assert_eq!(header.ux_mode.as_deref(), Some("cli"));
+    }
+
+    #[test]
+    fn test_unversioned_header_is_migrated_from_version_1() {
+        let meta = r#"{"ts":1000,"event":"_meta.loop_start","data":{"prompt_file":"PROMPT.md"}}"#;
+        let player = SessionPlayer::from_bytes(meta.as_bytes()).unwrap();
+
+        assert_eq!(player.format_version(), 1);
+        assert_eq!(player.header().unwrap().ux_mode.as_deref(), Some("cli"));
+    }
+
+    #[test]
+    fn test_future_version_is_rejected_before_record_parsing() {
+        // A future format may reshape records; the version error must win.
+        let meta = r#"{"ts":"1000","event":"_meta.loop_start","data":{"version":99}}"#;
+        let err = SessionPlayer::from_bytes(meta.as_bytes()).unwrap_err();
+
+        let inner = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<UnsupportedRecordVersion>())
+            .unwrap();
+        assert_eq!(inner.found, 99);
+        assert!(err.to_string().contains("format version 99"));
+    }
+
     #[test]
     fn test_filter_by_event() {
         let write = make_write_record(b"test", true, 0, 1000);
@@ -515,6 +865,133 @@ mod tests {
         assert_eq!(config.event_filter, vec!["ux."]);
     }
 
+    /// Three iterations, one minute apart, each writing one line.
+    fn three_iteration_session() -> String {
+        let mut lines = Vec::new();
+        for n in 1..=3u64 {
+            let offset = (n - 1) * 60_000;
+            lines.push(format!(
+                r#"{{"ts":{},"event":"_meta.iteration","data":{{"n":{n},"elapsed_ms":{offset},"hat":"builder"}}}}"#,
+                1000 + offset
+            ));
+            lines.push(make_write_record(
+                format!("iter{n};").as_bytes(),
+                true,
+                offset + 5,
+                1000,
+            ));
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_replay_seeks_to_iteration_without_waiting() {
+        let mut player = SessionPlayer::from_bytes(three_iteration_session().as_bytes())
+            .unwrap()
+            .with_config(PlayerConfig::terminal().with_start_at(StartPoint::Iteration(3)));
+
+        let started = std::time::Instant::now();
+        let mut output = Vec::new();
+        player.replay_terminal(&mut output).unwrap();
+
+        // Iterations 1-2 span two minutes at 1x speed; seeking must skip them
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(String::from_utf8(output).unwrap(), "iter3;");
+    }
+
+    #[test]
+    fn test_replay_window_bounded_by_iteration_and_timestamp() {
+        let session = three_iteration_session();
+
+        let mut player = SessionPlayer::from_bytes(session.as_bytes())
+            .unwrap()
+            .with_config(
+                PlayerConfig::terminal()
+                    .with_speed(1000.0)
+                    .with_start_at(StartPoint::Iteration(1))
+                    .with_end_at(StartPoint::Iteration(2)),
+            );
+        let mut output = Vec::new();
+        player.replay_terminal(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "iter1;iter2;");
+
+        // Iteration 2's write lands at ts 61_005
+        let start = DateTime::from_timestamp_millis(61_005).unwrap();
+        let mut player = SessionPlayer::from_bytes(session.as_bytes())
+            .unwrap()
+            .with_config(
+                PlayerConfig::terminal()
+                    .with_speed(1000.0)
+                    .with_start_at(StartPoint::Timestamp(start)),
+            );
+        let mut output = Vec::new();
+        player.replay_terminal(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "iter2;iter3;");
+    }
+
+    #[test]
+    fn test_replay_start_past_end_plays_nothing() {
+        let mut player = SessionPlayer::from_bytes(three_iteration_session().as_bytes())
+            .unwrap()
+            .with_config(PlayerConfig::terminal().with_start_at(StartPoint::Iteration(9)));
+        let mut output = Vec::new();
+        player.replay_terminal(&mut output).unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_export_asciicast_keeps_ansi_and_relative_timing() {
+        let resize = Record {
+            ts: 1000,
+            event: "ux.terminal.resize".to_string(),
+            data: serde_json::to_value(ralph_proto::TerminalResize::new(120, 40, 0)).unwrap(),
+        };
+        // "é" split across two writes
+        let lines = [
+            serde_json::to_string(&resize).unwrap(),
+            make_write_record(b"\x1b[32mok\x1b[0m ", true, 0, 1000),
+            make_write_record(b"caf\xc3", true, 1500, 1000),
+            make_write_record(b"\xa9\n", true, 1750, 1000),
+        ];
+        let player = SessionPlayer::from_bytes(lines.join("\n").as_bytes()).unwrap();
+
+        let mut cast = Vec::new();
+        player.export_asciicast(&mut cast).unwrap();
+        let cast = String::from_utf8(cast).unwrap();
+        let events: Vec<serde_json::Value> = cast
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(events[0]["version"], 2);
+        assert_eq!(events[0]["width"], 120);
+        assert_eq!(events[0]["height"], 40);
+        assert_eq!(events[1], serde_json::json!([0.0, "r", "120x40"]));
+        assert_eq!(
+            events[2],
+            serde_json::json!([0.0, "o", "\x1b[32mok\x1b[0m "])
+        );
+        assert_eq!(events[3], serde_json::json!([1.5, "o", "caf"]));
+        assert_eq!(events[4], serde_json::json!([1.75, "o", "é\n"]));
+    }
+
+    #[test]
+    fn test_export_asciicast_respects_window() {
+        let player = SessionPlayer::from_bytes(three_iteration_session().as_bytes())
+            .unwrap()
+            .with_config(PlayerConfig::terminal().with_start_at(StartPoint::Iteration(2)));
+
+        let mut cast = Vec::new();
+        player.export_asciicast(&mut cast).unwrap();
+        let cast = String::from_utf8(cast).unwrap();
+        let lines: Vec<&str> = cast.lines().collect();
+
+        assert!(lines[0].contains(r#""width":80"#), "{cast}");
+        assert_eq!(lines[1], r#"[0.005,"o","iter2;"]"#);
+        assert_eq!(lines[2], r#"[60.005,"o","iter3;"]"#);
+        assert_eq!(lines.len(), 3);
+    }
+
     #[test]
     fn test_empty_input() {
         let player = SessionPlayer::from_bytes(b"").unwrap();
@@ -2,14 +2,23 @@
 //!
 //! `SessionRecorder` captures events from both the EventBus (routing events)
 //! and UX captures (terminal output) into a unified JSONL format for replay
-//! and analysis.
+//! and analysis. Recordings written to a `.gz` path are gzip-compressed.
 
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use ralph_proto::{Event, UxEvent};
 use serde::{Deserialize, Serialize};
-use std::io::{self, Write};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 use std::sync::Mutex;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+/// Version of the session recording format, written in the loop start header.
+///
+/// Version 1 is the original format, whose header carried no version field.
+pub const RECORD_FORMAT_VERSION: u32 = 2;
+
 /// A timestamped record in the JSONL session file.
 ///
 /// Records use internal tagging to distinguish event types while maintaining
@@ -59,10 +68,13 @@ impl Record {
     }
 
     /// Creates a metadata record for loop start.
+    ///
+    /// This is the recording's header and carries [`RECORD_FORMAT_VERSION`].
     pub fn meta_loop_start(prompt_file: &str, max_iterations: u32, ux_mode: Option<&str>) -> Self {
         Self::new(
             "_meta.loop_start",
             serde_json::json!({
+                "version": RECORD_FORMAT_VERSION,
                 "prompt_file": prompt_file,
                 "max_iterations": max_iterations,
                 "ux_mode": ux_mode.unwrap_or("cli"),
@@ -101,6 +113,52 @@ impl Record {
     }
 }
 
+/// A session recording file, gzip-compressed when its path ends in `.gz`.
+pub enum SessionWriter {
+    /// Plain JSONL.
+    Plain(BufWriter<File>),
+    /// Gzip-compressed JSONL. The gzip trailer is written when dropped.
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl SessionWriter {
+    /// Creates (or truncates) the recording file at `path`.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(if is_gzip_path(path) {
+            Self::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            Self::Plain(file)
+        })
+    }
+
+    /// Returns true if this writer compresses its output.
+    pub fn is_compressed(&self) -> bool {
+        matches!(self, Self::Gzip(_))
+    }
+}
+
+impl Write for SessionWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            Self::Gzip(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            Self::Gzip(w) => w.flush(),
+        }
+    }
+}
+
+/// Returns true if `path` names a gzip-compressed recording.
+pub fn is_gzip_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "gz")
+}
+
 /// Records session events to a JSONL output.
 ///
 /// The recorder is thread-safe and can be used as an EventBus observer.
@@ -204,6 +262,17 @@ impl<W: Write> SessionRecorder<W> {
     }
 }
 
+impl SessionRecorder<SessionWriter> {
+    /// Creates a recorder writing to the file at `path`.
+    ///
+    /// Paths ending in `.gz` (e.g. `session.jsonl.gz`) are gzip-compressed.
+    /// Each record is sync-flushed, so a crashed run's recording stays
+    /// readable up to the last record written.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        SessionWriter::create(path).map(Self::new)
+    }
+}
+
 impl<W: Write + Send + 'static> SessionRecorder<W> {
     /// Creates an observer closure suitable for EventBus::set_observer.
     ///
@@ -0,0 +1,180 @@
+//! Two-phase interrupt handling.
+//!
+//! The first Ctrl-C asks the loop to finish the current iteration and then
+//! stop, so a backend is never killed halfway through applying a change. A
+//! second Ctrl-C within the force-quit window escalates to an immediate
+//! interrupt. The state is shared between the signal handler, the event loop
+//! (which checks it in `check_termination`) and the TUI (which shows it).
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long after the first Ctrl-C a second one force-quits.
+pub const DEFAULT_FORCE_QUIT_WINDOW: Duration = Duration::from_secs(5);
+
+/// Current shutdown phase of the loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownPhase {
+    /// No shutdown requested.
+    Running,
+    /// Finishing the current iteration, then stopping.
+    Draining,
+    /// Stop immediately, killing the backend.
+    ForceQuit,
+}
+
+impl ShutdownPhase {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => ShutdownPhase::Running,
+            1 => ShutdownPhase::Draining,
+            _ => ShutdownPhase::ForceQuit,
+        }
+    }
+
+    fn as_u8(self) -> u8 {
+        match self {
+            ShutdownPhase::Running => 0,
+            ShutdownPhase::Draining => 1,
+            ShutdownPhase::ForceQuit => 2,
+        }
+    }
+
+    /// Status line to show while in this phase, if any.
+    pub fn status_message(self) -> Option<&'static str> {
+        match self {
+            ShutdownPhase::Running => None,
+            ShutdownPhase::Draining => {
+                Some("finishing current iteration (press Ctrl-C again to force quit)")
+            }
+            ShutdownPhase::ForceQuit => Some("force quitting"),
+        }
+    }
+}
+
+/// Shared, cloneable shutdown state.
+#[derive(Debug, Clone)]
+pub struct ShutdownSignal {
+    phase: Arc<AtomicU8>,
+    drain_started: Arc<Mutex<Option<Instant>>>,
+    force_quit_window: Duration,
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownSignal {
+    /// Creates a signal with the default force-quit window.
+    pub fn new() -> Self {
+        Self::with_force_quit_window(DEFAULT_FORCE_QUIT_WINDOW)
+    }
+
+    /// Creates a signal with a custom force-quit window.
+    pub fn with_force_quit_window(force_quit_window: Duration) -> Self {
+        Self {
+            phase: Arc::new(AtomicU8::new(ShutdownPhase::Running.as_u8())),
+            drain_started: Arc::new(Mutex::new(None)),
+            force_quit_window,
+        }
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> ShutdownPhase {
+        ShutdownPhase::from_u8(self.phase.load(Ordering::SeqCst))
+    }
+
+    /// Returns true once a shutdown (graceful or forced) has been requested.
+    pub fn is_requested(&self) -> bool {
+        self.phase() != ShutdownPhase::Running
+    }
+
+    /// Records a Ctrl-C and returns the resulting phase.
+    ///
+    /// The first request starts draining. A second request within the
+    /// force-quit window escalates to `ForceQuit`; one arriving after the
+    /// window has passed restarts the window instead, so a stray keypress
+    /// long after the first never kills the backend unexpectedly.
+    pub fn request(&self) -> ShutdownPhase {
+        self.request_at(Instant::now())
+    }
+
+    fn request_at(&self, now: Instant) -> ShutdownPhase {
+        let mut drain_started = self
+            .drain_started
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let next = match (self.phase(), *drain_started) {
+            (ShutdownPhase::ForceQuit, _) => ShutdownPhase::ForceQuit,
+            (ShutdownPhase::Draining, Some(started))
+                if now.saturating_duration_since(started) <= self.force_quit_window =>
+            {
+                ShutdownPhase::ForceQuit
+            }
+            _ => {
+                *drain_started = Some(now);
+                ShutdownPhase::Draining
+            }
+        };
+        self.phase.store(next.as_u8(), Ordering::SeqCst);
+        next
+    }
+
+    /// Escalates straight to `ForceQuit` (SIGTERM/SIGHUP).
+    pub fn force_quit(&self) {
+        self.phase
+            .store(ShutdownPhase::ForceQuit.as_u8(), Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_request_within_window_force_quits() {
+        let signal = ShutdownSignal::with_force_quit_window(Duration::from_secs(3));
+        let start = Instant::now();
+        assert_eq!(signal.phase(), ShutdownPhase::Running);
+        assert!(!signal.is_requested());
+
+        assert_eq!(signal.request_at(start), ShutdownPhase::Draining);
+        assert!(signal.is_requested());
+        assert_eq!(
+            signal.request_at(start + Duration::from_secs(2)),
+            ShutdownPhase::ForceQuit
+        );
+        // Stays forced
+        assert_eq!(
+            signal.request_at(start + Duration::from_mins(1)),
+            ShutdownPhase::ForceQuit
+        );
+    }
+
+    #[test]
+    fn late_second_request_restarts_the_window() {
+        let signal = ShutdownSignal::with_force_quit_window(Duration::from_secs(3));
+        let start = Instant::now();
+
+        signal.request_at(start);
+        let late = start + Duration::from_secs(10);
+        assert_eq!(signal.request_at(late), ShutdownPhase::Draining);
+        assert_eq!(
+            signal.request_at(late + Duration::from_secs(1)),
+            ShutdownPhase::ForceQuit
+        );
+    }
+
+    #[test]
+    fn clones_share_state() {
+        let signal = ShutdownSignal::new();
+        let observer = signal.clone();
+        signal.request();
+        assert_eq!(observer.phase(), ShutdownPhase::Draining);
+        observer.force_quit();
+        assert_eq!(signal.phase(), ShutdownPhase::ForceQuit);
+    }
+}
@@ -3,16 +3,37 @@
 //! Per spec: "On termination, the orchestrator writes `.ralph/agent/summary.md`"
 //! with status, iterations, duration, task list, events summary, and commit info.
 
+use crate::config::{FormattingConfig, SummarySection};
 use crate::event_logger::EventHistory;
 use crate::event_loop::{LoopState, TerminationReason};
+use crate::git_ops;
 use crate::landing::LandingResult;
 use crate::loop_context::LoopContext;
-use std::collections::HashMap;
+use crate::loop_history::LoopHistory;
+use crate::merge_queue::{MergeEntry, MergeQueue, MergeState};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// Maximum number of recently committed files captured per [`Summary`].
+const SNAPSHOT_FILE_LIMIT: usize = 50;
+
+/// Point-in-time snapshot of loop progress, diffed by
+/// [`SummaryWriter::write_delta`] to show what changed between iterations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Iteration the snapshot was taken at.
+    pub iteration: u32,
+    /// Scratchpad task lines without their list marker (e.g. `[x] Add tokens`).
+    pub tasks: Vec<String>,
+    /// Event counts by topic.
+    pub event_counts: BTreeMap<String, usize>,
+    /// Files touched by recent commits, per `git_ops::get_recent_files`.
+    pub files: Vec<String>,
+}
+
 /// Writes the loop summary file on termination.
 ///
 /// Per spec section "Exit Summary":
@@ -43,6 +64,10 @@ pub struct SummaryWriter {
     /// Path to the events file for reading history.
     /// If None, uses the default path relative to current directory.
     events_path: Option<PathBuf>,
+    /// Formatting policy (`core.formatting`).
+    formatting: FormattingConfig,
+    /// Snapshot from the previous [`SummaryWriter::record_iteration`] call.
+    previous: Option<Summary>,
 }
 
 impl Default for SummaryWriter {
@@ -57,6 +82,8 @@ impl SummaryWriter {
         Self {
             path: path.into(),
             events_path: None,
+            formatting: FormattingConfig::default(),
+            previous: None,
         }
     }
 
@@ -69,7 +96,106 @@ impl SummaryWriter {
         Self {
             path: context.summary_path(),
             events_path: Some(context.events_path()),
+            formatting: FormattingConfig::default(),
+            previous: None,
+        }
+    }
+
+    /// Sets the formatting policy (`core.formatting`) for the summary.
+    #[must_use]
+    pub fn with_formatting(mut self, formatting: FormattingConfig) -> Self {
+        self.formatting = formatting;
+        self
+    }
+
+    /// Sets the events file to read history from.
+    #[must_use]
+    pub fn with_events_path(mut self, events_path: impl Into<PathBuf>) -> Self {
+        self.events_path = Some(events_path.into());
+        self
+    }
+
+    /// Captures the current tasks, event counts and recently committed files.
+    pub fn snapshot(
+        &self,
+        state: &LoopState,
+        scratchpad_path: Option<&Path>,
+        repo_path: &Path,
+    ) -> Summary {
+        Summary {
+            iteration: state.iteration,
+            tasks: scratchpad_path
+                .map(|p| self.task_lines(p))
+                .unwrap_or_default(),
+            event_counts: self.read_topic_counts().unwrap_or_default(),
+            files: git_ops::get_recent_files(repo_path, SNAPSHOT_FILE_LIMIT).unwrap_or_default(),
+        }
+    }
+
+    /// Stores `curr` as the previous snapshot and returns its delta against
+    /// the snapshot it replaces, if there was one.
+    pub fn record_iteration(&mut self, curr: Summary) -> Option<String> {
+        let delta = self
+            .previous
+            .as_ref()
+            .map(|prev| self.write_delta(prev, &curr));
+        self.previous = Some(curr);
+        delta
+    }
+
+    /// Renders what changed between two snapshots: tasks completed, events
+    /// fired and files newly touched by commits.
+    pub fn write_delta(&self, prev: &Summary, curr: &Summary) -> String {
+        let marker = self.formatting.list_marker.as_str();
+        let mut content = format!(
+            "## Iteration {} (since iteration {})\n",
+            curr.iteration, prev.iteration
+        );
+
+        let completed: Vec<&String> = curr
+            .tasks
+            .iter()
+            .filter(|task| task.starts_with("[x]") && !prev.tasks.contains(task))
+            .collect();
+        let fired: Vec<(&String, usize)> = curr
+            .event_counts
+            .iter()
+            .filter_map(|(topic, &count)| {
+                let before = prev.event_counts.get(topic).copied().unwrap_or(0);
+                (count > before).then(|| (topic, count - before))
+            })
+            .collect();
+        let changed: Vec<&String> = curr
+            .files
+            .iter()
+            .filter(|file| !prev.files.contains(file))
+            .collect();
+
+        if completed.is_empty() && fired.is_empty() && changed.is_empty() {
+            content.push_str("\n_No changes._\n");
+            return self.formatting.finish(content);
+        }
+
+        if !completed.is_empty() {
+            content.push_str("\n### Tasks Completed\n\n");
+            for task in completed {
+                content.push_str(&format!("{marker} {task}\n"));
+            }
+        }
+        if !fired.is_empty() {
+            content.push_str("\n### Events\n\n");
+            for (topic, count) in fired {
+                content.push_str(&format!("{marker} {count} {topic}\n"));
+            }
         }
+        if !changed.is_empty() {
+            content.push_str("\n### Files Changed\n\n");
+            for file in changed {
+                content.push_str(&format!("{marker} {file}\n"));
+            }
+        }
+
+        self.formatting.finish(content)
     }
 
     /// Writes the summary file based on loop state and termination reason.
@@ -139,72 +265,82 @@ impl SummaryWriter {
             content.push_str(&format!("**Est. cost:** ${:.2}\n", state.cumulative_cost));
         }
 
-        // Tasks section (read from scratchpad if available)
-        content.push('\n');
-        content.push_str("## Tasks\n\n");
-        if let Some(tasks) = scratchpad_path.and_then(|p| self.extract_tasks(p)) {
-            content.push_str(&tasks);
-        } else {
-            content.push_str("_No scratchpad found._\n");
-        }
-
-        // Events section
-        content.push('\n');
-        content.push_str("## Events\n\n");
-        content.push_str(&self.summarize_events());
-
-        // Final commit section
-        if let Some(commit) = final_commit {
-            content.push('\n');
-            content.push_str("## Final Commit\n\n");
-            content.push_str(commit);
-            content.push('\n');
-        }
-
-        // Landing section (if landing was performed)
-        if let Some(landing_result) = landing {
-            content.push('\n');
-            content.push_str("## Landing\n\n");
-
-            if landing_result.committed {
-                content.push_str(&format!(
-                    "- **Auto-committed:** Yes ({})\n",
-                    landing_result.commit_sha.as_deref().unwrap_or("unknown")
-                ));
-            } else {
-                content.push_str("- **Auto-committed:** No (working tree was clean)\n");
-            }
-
-            content.push_str(&format!(
-                "- **Handoff:** `{}`\n",
-                landing_result.handoff_path.display()
-            ));
-
-            if !landing_result.open_tasks.is_empty() {
-                content.push_str(&format!(
-                    "- **Open tasks:** {}\n",
-                    landing_result.open_tasks.len()
-                ));
-            }
-
-            if landing_result.stashes_cleared > 0 {
-                content.push_str(&format!(
-                    "- **Stashes cleared:** {}\n",
-                    landing_result.stashes_cleared
-                ));
-            }
-
-            content.push_str(&format!(
-                "- **Working tree clean:** {}\n",
-                if landing_result.working_tree_clean {
-                    "Yes"
-                } else {
-                    "No"
+        let marker = self.formatting.list_marker.as_str();
+        for section in &self.formatting.summary_sections {
+            match section {
+                SummarySection::Tasks => {
+                    // Tasks section (read from scratchpad if available)
+                    content.push('\n');
+                    content.push_str("## Tasks\n\n");
+                    if let Some(tasks) = scratchpad_path.and_then(|p| self.extract_tasks(p)) {
+                        content.push_str(&tasks);
+                    } else {
+                        content.push_str("_No scratchpad found._\n");
+                    }
+                }
+                SummarySection::Events => {
+                    content.push('\n');
+                    content.push_str("## Events\n\n");
+                    content.push_str(&self.summarize_events());
                 }
-            ));
+                SummarySection::FinalCommit => {
+                    if let Some(commit) = final_commit {
+                        content.push('\n');
+                        content.push_str("## Final Commit\n\n");
+                        content.push_str(commit);
+                        content.push('\n');
+                    }
+                }
+                SummarySection::Landing => {
+                    // Landing section (if landing was performed)
+                    if let Some(landing_result) = landing {
+                        content.push('\n');
+                        content.push_str("## Landing\n\n");
+
+                        if landing_result.committed {
+                            content.push_str(&format!(
+                                "{marker} **Auto-committed:** Yes ({})\n",
+                                landing_result.commit_sha.as_deref().unwrap_or("unknown")
+                            ));
+                        } else {
+                            content.push_str(&format!(
+                                "{marker} **Auto-committed:** No (working tree was clean)\n"
+                            ));
+                        }
+
+                        content.push_str(&format!(
+                            "{marker} **Handoff:** `{}`\n",
+                            landing_result.handoff_path.display()
+                        ));
+
+                        if !landing_result.open_tasks.is_empty() {
+                            content.push_str(&format!(
+                                "{marker} **Open tasks:** {}\n",
+                                landing_result.open_tasks.len()
+                            ));
+                        }
+
+                        if landing_result.stashes_cleared > 0 {
+                            content.push_str(&format!(
+                                "{marker} **Stashes cleared:** {}\n",
+                                landing_result.stashes_cleared
+                            ));
+                        }
+
+                        content.push_str(&format!(
+                            "{marker} **Working tree clean:** {}\n",
+                            if landing_result.working_tree_clean {
+                                "Yes"
+                            } else {
+                                "No"
+                            }
+                        ));
+                    }
+                }
+            }
         }
 
-        content
+        self.formatting.finish(content)
     }
 
     /// Returns a human-readable status based on termination reason.
@@ -223,30 +359,57 @@ impl SummaryWriter {
             TerminationReason::RestartRequested => "Restarting by human request",
             TerminationReason::WorkspaceGone => "Failed: workspace directory removed",
             TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout)",
+            TerminationReason::ConfigError { .. } => "Failed: configuration error",
         }
     }
 
     /// Extracts task lines from the scratchpad file.
     ///
-    /// Looks for lines matching `- [ ]`, `- [x]`, or `- [~]` patterns.
+    /// Looks for lines matching `- [ ]`, `- [>]`, `- [x]`, or `- [~]` patterns.
     fn extract_tasks(&self, scratchpad_path: &Path) -> Option<String> {
-        let content = fs::read_to_string(scratchpad_path).ok()?;
         let mut tasks = String::new();
-
-        for line in content.lines() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("- [ ]")
-                || trimmed.starts_with("- [x]")
-                || trimmed.starts_with("- [~]")
-            {
-                tasks.push_str(trimmed);
-                tasks.push('\n');
-            }
+        for line in self.task_lines(scratchpad_path) {
+            tasks.push_str(self.formatting.list_marker.as_str());
+            tasks.push(' ');
+            tasks.push_str(&line);
+            tasks.push('\n');
         }
 
         if tasks.is_empty() { None } else { Some(tasks) }
     }
 
+    /// Returns the scratchpad's task lines with the leading `- ` stripped.
+    fn task_lines(&self, scratchpad_path: &Path) -> Vec<String> {
+        let Ok(content) = fs::read_to_string(scratchpad_path) else {
+            return Vec::new();
+        };
+
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| {
+                ["- [ ]", "- [>]", "- [x]", "- [~]"]
+                    .iter()
+                    .any(|marker| line.starts_with(marker))
+            })
+            .map(|line| line[2..].to_string())
+            .collect()
+    }
+
+    /// Counts recorded events by topic, or `None` if the history is unreadable.
+    fn read_topic_counts(&self) -> Option<BTreeMap<String, usize>> {
+        let history = match &self.events_path {
+            Some(path) => EventHistory::new(path),
+            None => EventHistory::default_path(),
+        };
+
+        let mut counts = BTreeMap::new();
+        for record in history.read_all().ok()? {
+            *counts.entry(record.topic).or_insert(0) += 1;
+        }
+        Some(counts)
+    }
+
     /// Summarizes events from the event history file.
     fn summarize_events(&self) -> String {
         let history = match &self.events_path {
@@ -269,20 +432,98 @@ impl SummaryWriter {
             *topic_counts.entry(record.topic.clone()).or_insert(0) += 1;
         }
 
-        let mut summary = format!("- {} total events\n", records.len());
+        let marker = self.formatting.list_marker.as_str();
+        let mut summary = format!("{marker} {} total events\n", records.len());
 
         // Sort by count descending for consistent output
         let mut sorted: Vec<_> = topic_counts.into_iter().collect();
         sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
 
         for (topic, count) in sorted {
-            summary.push_str(&format!("- {} {}\n", count, topic));
+            summary.push_str(&format!("{marker} {} {}\n", count, topic));
         }
 
         summary
     }
 }
 
+/// One finished loop in the roll-up built by [`summarize_loops`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopOverview {
+    /// Loop name (`primary` for the loop in the main workspace).
+    pub name: String,
+    /// Prompt the loop was started with.
+    pub objective: Option<String>,
+    /// Completion reason or termination signal; `None` once the worktree
+    /// (and its history) is gone.
+    pub termination_reason: Option<String>,
+    /// Iterations completed; `None` once the worktree is gone.
+    pub iterations: Option<u32>,
+    /// State in the merge queue; `None` if the loop was never queued.
+    pub merge_state: Option<MergeState>,
+    /// Merge commit SHA, once merged.
+    pub merge_commit: Option<String>,
+}
+
+/// Rolls up every finished loop under `repo_root`: the primary loop and each
+/// `ralph/*` worktree loop with a completed history, plus merge queue entries
+/// whose worktree was already removed.
+pub fn summarize_loops(repo_root: &Path) -> Vec<LoopOverview> {
+    let histories = LoopHistory::for_workspace(repo_root);
+    let merges = MergeQueue::new(repo_root).list().unwrap_or_default();
+    build_loop_overview(&histories, &merges)
+}
+
+/// Joins loop histories with merge queue entries; see [`summarize_loops`].
+///
+/// Loops still running (no completion or termination recorded) are skipped.
+pub fn build_loop_overview(histories: &[LoopHistory], merges: &[MergeEntry]) -> Vec<LoopOverview> {
+    let mut overview = Vec::new();
+
+    for history in histories {
+        let Ok(summary) = history.summary() else {
+            continue;
+        };
+        if !summary.completed && !summary.terminated {
+            continue;
+        }
+        let name = history.loop_name().unwrap_or("primary");
+        let merge = merges.iter().find(|entry| entry.loop_id == name);
+        overview.push(LoopOverview {
+            name: name.to_string(),
+            objective: summary
+                .prompt
+                .or_else(|| merge.map(|entry| entry.prompt.clone())),
+            termination_reason: summary.completion_reason.or(summary.termination_signal),
+            iterations: Some(summary.iterations_completed),
+            merge_state: merge.map(|entry| entry.state),
+            merge_commit: merge
+                .and_then(|entry| entry.merge_commit.clone())
+                .or(summary.merge_commit),
+        });
+    }
+
+    // Merged or discarded loops whose worktree (and history) is gone
+    for entry in merges {
+        if overview
+            .iter()
+            .any(|existing| existing.name == entry.loop_id)
+        {
+            continue;
+        }
+        overview.push(LoopOverview {
+            name: entry.loop_id.clone(),
+            objective: Some(entry.prompt.clone()),
+            termination_reason: None,
+            iterations: None,
+            merge_state: Some(entry.state),
+            merge_commit: entry.merge_commit.clone(),
+        });
+    }
+
+    overview
+}
+
 /// Formats a duration as human-readable string (e.g., "23m 45s" or "1h 5m 30s").
 fn format_duration(d: Duration) -> String {
     let total_secs = d.as_secs();
@@ -331,9 +572,71 @@ mod tests {
             last_input_tokens: None,
             hat_peak_input_tokens: std::collections::HashMap::new(),
             unacknowledged_guidance: Vec::new(),
+            iteration_timings: Vec::new(),
+            current_iteration_started_at: None,
         }
     }
 
+    #[test]
+    fn test_build_loop_overview_joins_history_and_merge_queue() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = |name: &str| {
+            LoopHistory::new(temp_dir.path().join(format!("{name}.jsonl"))).with_loop_name(name)
+        };
+
+        let done = history("quiet-fox");
+        done.record_started("Add auth").unwrap();
+        done.record_iteration_completed(1, true).unwrap();
+        done.record_iteration_completed(2, true).unwrap();
+        done.record_completed("completion_promise").unwrap();
+
+        let killed = history("lazy-owl");
+        killed.record_started("Fix flaky test").unwrap();
+        killed.record_iteration_completed(1, false).unwrap();
+        killed.record_terminated("SIGTERM").unwrap();
+
+        let running = history("busy-bee");
+        running.record_started("Still going").unwrap();
+
+        let queue = MergeQueue::new(temp_dir.path());
+        queue.enqueue("quiet-fox", "Add auth").unwrap();
+        queue.enqueue("gone-cat", "Old work").unwrap();
+        queue.mark_merging("gone-cat", 1).unwrap();
+        queue.mark_merged("gone-cat", "abc1234").unwrap();
+
+        let overview = build_loop_overview(&[done, killed, running], &queue.list().unwrap());
+
+        assert_eq!(
+            overview,
+            vec![
+                LoopOverview {
+                    name: "quiet-fox".to_string(),
+                    objective: Some("Add auth".to_string()),
+                    termination_reason: Some("completion_promise".to_string()),
+                    iterations: Some(2),
+                    merge_state: Some(MergeState::Queued),
+                    merge_commit: None,
+                },
+                LoopOverview {
+                    name: "lazy-owl".to_string(),
+                    objective: Some("Fix flaky test".to_string()),
+                    termination_reason: Some("SIGTERM".to_string()),
+                    iterations: Some(1),
+                    merge_state: None,
+                    merge_commit: None,
+                },
+                LoopOverview {
+                    name: "gone-cat".to_string(),
+                    objective: Some("Old work".to_string()),
+                    termination_reason: None,
+                    iterations: None,
+                    merge_state: Some(MergeState::Merged),
+                    merge_commit: Some("abc1234".to_string()),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_status_text() {
         let writer = SummaryWriter::default();
@@ -413,6 +716,38 @@ More text here.
         assert!(content.contains("abc1234: feat(auth): add tokens"));
     }
 
+    #[test]
+    fn test_generate_content_follows_formatting_policy() {
+        let tmp = TempDir::new().unwrap();
+        let scratchpad = tmp.path().join("scratchpad.md");
+        fs::write(&scratchpad, "- [x] Done thing\n- [ ] Open thing\n").unwrap();
+
+        let writer =
+            SummaryWriter::new(tmp.path().join("summary.md")).with_formatting(FormattingConfig {
+                trailing_newline: false,
+                list_marker: crate::config::ListMarker::Asterisk,
+                summary_sections: vec![SummarySection::FinalCommit, SummarySection::Tasks],
+            });
+        let content = writer.generate_content_with_landing(
+            &TerminationReason::CompletionPromise,
+            &test_state(),
+            Some(&scratchpad),
+            Some("abc1234: feat: done"),
+            None,
+        );
+
+        assert!(content.contains("* [x] Done thing\n* [ ] Open thing"));
+        assert!(
+            !content.contains("## Events"),
+            "unlisted sections are omitted"
+        );
+        assert!(
+            content.find("## Final Commit").unwrap() < content.find("## Tasks").unwrap(),
+            "sections follow the configured order"
+        );
+        assert!(!content.ends_with('\n'), "{content:?}");
+    }
+
     #[test]
     fn test_write_creates_directory() {
         let tmp = TempDir::new().unwrap();
@@ -465,4 +800,57 @@ More text here.
         assert!(content.contains("**Stashes cleared:** 2"));
         assert!(content.contains("**Working tree clean:** Yes"));
     }
+
+    #[test]
+    fn test_write_delta_reports_completed_tasks_events_and_files() {
+        let writer = SummaryWriter::default();
+        let prev = Summary {
+            iteration: 2,
+            tasks: vec!["[x] Add tokens".into(), "[ ] Add rate limiting".into()],
+            event_counts: BTreeMap::from([("build.task".into(), 2)]),
+            files: vec!["src/auth.rs".into()],
+        };
+        let curr = Summary {
+            iteration: 3,
+            tasks: vec!["[x] Add tokens".into(), "[x] Add rate limiting".into()],
+            event_counts: BTreeMap::from([("build.task".into(), 3), ("build.done".into(), 1)]),
+            files: vec!["src/auth.rs".into(), "src/limit.rs".into()],
+        };
+
+        let delta = writer.write_delta(&prev, &curr);
+
+        assert!(delta.contains("## Iteration 3 (since iteration 2)"));
+        assert!(delta.contains("- [x] Add rate limiting"));
+        assert!(
+            !delta.contains("Add tokens"),
+            "already done before: {delta}"
+        );
+        assert!(delta.contains("- 1 build.task"));
+        assert!(delta.contains("- 1 build.done"));
+        assert!(delta.contains("- src/limit.rs"));
+        assert!(!delta.contains("src/auth.rs"), "{delta}");
+    }
+
+    #[test]
+    fn test_record_iteration_diffs_against_previous_snapshot() {
+        let tmp = TempDir::new().unwrap();
+        let scratchpad = tmp.path().join("scratchpad.md");
+        fs::write(&scratchpad, "- [ ] Open thing\n").unwrap();
+
+        let mut writer = SummaryWriter::new(tmp.path().join("summary.md"))
+            .with_events_path(tmp.path().join("events.jsonl"));
+        let mut state = test_state();
+        let baseline = writer.snapshot(&state, Some(&scratchpad), tmp.path());
+        assert!(writer.record_iteration(baseline).is_none());
+
+        fs::write(&scratchpad, "- [x] Open thing\n").unwrap();
+        state.iteration += 1;
+        let snapshot = writer.snapshot(&state, Some(&scratchpad), tmp.path());
+        let delta = writer.record_iteration(snapshot).unwrap();
+        assert!(delta.contains("- [x] Open thing"), "{delta}");
+
+        let snapshot = writer.snapshot(&state, Some(&scratchpad), tmp.path());
+        let delta = writer.record_iteration(snapshot).unwrap();
+        assert!(delta.contains("_No changes._"), "{delta}");
+    }
 }
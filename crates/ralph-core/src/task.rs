@@ -55,6 +55,11 @@ pub struct Task {
     #[serde(default)]
     pub blocked_by: Vec<String>,
 
+    /// Free-form labels for slicing the backlog (e.g. `backend`, `urgent`).
+    /// Absent in task files written before tags existed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Loop ID that created this task (from RALPH_LOOP_ID env var).
     /// Used to filter tasks by ownership when multiple loops share a task list.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,6 +88,7 @@ impl Task {
             status: TaskStatus::Open,
             priority: priority.clamp(1, 5),
             blocked_by: Vec::new(),
+            tags: Vec::new(),
             loop_id: None,
             created: chrono::Utc::now().to_rfc3339(),
             started: None,
@@ -138,6 +144,19 @@ impl Task {
         self
     }
 
+    /// Adds a tag, ignoring duplicates.
+    pub fn with_tag(mut self, tag: String) -> Self {
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Returns true if the task carries `tag` (case-insensitive).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
     /// Marks the task as in progress and records a start timestamp if absent.
     pub fn start(&mut self) {
         self.status = TaskStatus::InProgress;
@@ -165,6 +184,7 @@ mod tests {
         assert_eq!(task.priority, 2);
         assert_eq!(task.status, TaskStatus::Open);
         assert!(task.blocked_by.is_empty());
+        assert!(task.tags.is_empty());
         assert!(task.key.is_none());
         assert!(task.started.is_none());
     }
@@ -212,6 +232,19 @@ mod tests {
         assert!(task.is_ready(std::slice::from_ref(&blocker)));
     }
 
+    #[test]
+    fn test_is_not_ready_with_failed_blocker() {
+        let mut blocker = Task::new("Blocker".to_string(), 1);
+        blocker.status = TaskStatus::Failed;
+
+        let mut task = Task::new("Test".to_string(), 1);
+        task.blocked_by.push(blocker.id.clone());
+
+        // A failed blocker keeps its dependents waiting until it is reopened
+        // and closed.
+        assert!(!task.is_ready(std::slice::from_ref(&blocker)));
+    }
+
     #[test]
     fn test_is_not_ready_when_not_open() {
         let mut task = Task::new("Test".to_string(), 1);
@@ -257,4 +290,23 @@ mod tests {
         assert_eq!(task.status, TaskStatus::Open);
         assert!(task.closed.is_none());
     }
+
+    #[test]
+    fn test_with_tag_dedupes_and_matches_case_insensitively() {
+        let task = Task::new("Test".to_string(), 1)
+            .with_tag("backend".to_string())
+            .with_tag("Backend".to_string())
+            .with_tag("urgent".to_string());
+        assert_eq!(task.tags, vec!["backend", "urgent"]);
+        assert!(task.has_tag("URGENT"));
+        assert!(!task.has_tag("frontend"));
+    }
+
+    #[test]
+    fn test_tags_default_when_missing_from_json() {
+        let json = r#"{"id":"task-1-abcd","title":"Old","status":"open","priority":3,"created":"2025-01-01T00:00:00Z"}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert!(task.tags.is_empty());
+        assert!(!serde_json::to_string(&task).unwrap().contains("tags"));
+    }
 }
@@ -235,6 +235,27 @@ impl TaskDefinition {
             )));
         }
 
+        if let Some(min) = self.verification.min_coverage {
+            if !(0.0..=100.0).contains(&min) {
+                return Err(TaskDefinitionError::Validation(format!(
+                    "verification.min_coverage {min} must be between 0 and 100"
+                )));
+            }
+            if self.verification.coverage_report.is_none() {
+                return Err(TaskDefinitionError::MissingField(
+                    "verification.coverage_report".to_string(),
+                ));
+            }
+        }
+
+        if let Some(pattern) = &self.verification.stdout_matches
+            && let Err(e) = regex::Regex::new(pattern)
+        {
+            return Err(TaskDefinitionError::Validation(format!(
+                "verification.stdout_matches is not a valid regex: {e}"
+            )));
+        }
+
         Ok(())
     }
 
@@ -392,6 +413,33 @@ pub struct Verification {
     /// Exit code that indicates success (default: 0).
     #[serde(default)]
     pub success_exit_code: i32,
+
+    /// Kill the command and fail verification after this many seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+
+    /// Text stdout must contain for verification to pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout_contains: Option<String>,
+
+    /// Text stdout must not contain for verification to pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout_not_contains: Option<String>,
+
+    /// Regex stdout must match for verification to pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout_matches: Option<String>,
+
+    /// Coverage report (lcov or Cobertura) the command writes, relative to the
+    /// workspace. Its line coverage is recorded in the verification result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_report: Option<String>,
+
+    /// Minimum line coverage percentage (0-100) read from `coverage_report`.
+    ///
+    /// Verification fails below it, or when the report is missing or unparseable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_coverage: Option<f64>,
 }
 
 impl Verification {
@@ -399,7 +447,7 @@ impl Verification {
     pub fn new(command: impl Into<String>) -> Self {
         Self {
             command: command.into(),
-            success_exit_code: 0,
+            ..Self::default()
         }
     }
 
@@ -408,6 +456,7 @@ impl Verification {
         Self {
             command: command.into(),
             success_exit_code: exit_code,
+            ..Self::default()
         }
     }
 }
@@ -541,6 +590,36 @@ mod tests {
         assert!(matches!(err, TaskDefinitionError::Validation(_)));
     }
 
+    #[test]
+    fn test_task_validation_min_coverage() {
+        let mut task = TaskDefinition::builder("test", "prompt.md", "DONE")
+            .verification_command("cargo llvm-cov --lcov --output-path lcov.info")
+            .build();
+        task.verification.min_coverage = Some(80.0);
+        let err = task.validate().unwrap_err();
+        assert!(matches!(err, TaskDefinitionError::MissingField(_)));
+
+        task.verification.coverage_report = Some("lcov.info".to_string());
+        assert!(task.validate().is_ok());
+
+        task.verification.min_coverage = Some(180.0);
+        let err = task.validate().unwrap_err();
+        assert!(matches!(err, TaskDefinitionError::Validation(_)));
+    }
+
+    #[test]
+    fn test_task_validation_stdout_matches_regex() {
+        let mut task = TaskDefinition::builder("test", "prompt.md", "DONE")
+            .verification_command("cargo test")
+            .build();
+        task.verification.stdout_matches = Some(r"test result: ok\. \d+ passed".to_string());
+        assert!(task.validate().is_ok());
+
+        task.verification.stdout_matches = Some("passed (".to_string());
+        let err = task.validate().unwrap_err();
+        assert!(matches!(err, TaskDefinitionError::Validation(_)));
+    }
+
     #[test]
     fn test_iteration_delta() {
         let task = TaskDefinition::builder("test", "prompt.md", "DONE")
@@ -13,11 +13,30 @@
 //!
 //! Use `load()` and `save()` for simple single-operation access, or use
 //! `with_exclusive_lock()` for read-modify-write operations that need atomicity.
-
+//!
+//! # Change Notification
+//!
+//! Every write that changes the file's contents bumps a sequence number in
+//! `{path}.changed` (e.g. `tasks.jsonl.changed`). Observers such as the TUI can
+//! watch that file (or compare [`TaskStore::change_sequence`]) to refresh
+//! reactively instead of polling and re-parsing the tasks file.
+//!
+//! # Dependency Cycles
+//!
+//! A cycle in `blocked_by` (A blocked by B, B blocked by A) means none of its
+//! tasks can ever become ready. Only closed tasks stop blocking their
+//! dependents, so closed tasks are never part of a cycle; a failed task keeps
+//! its dependents waiting until it is reopened and closed, so it can be.
+//! [`TaskStore::try_add`] and [`TaskStore::try_ensure`] reject changes that
+//! would close one, and loading a file that already contains one logs a
+//! warning naming it.
+
+use crate::config::FormattingConfig;
 use crate::file_lock::FileLock;
 use crate::task::{Task, TaskStatus};
+use std::collections::HashSet;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
 /// A store for managing tasks with JSONL persistence and file locking.
@@ -25,8 +44,15 @@ pub struct TaskStore {
     path: std::path::PathBuf,
     tasks: Vec<Task>,
     lock: FileLock,
+    formatting: FormattingConfig,
 }
 
+/// A cycle in the tasks' `blocked_by` graph, listed as the task IDs along it
+/// with the first ID repeated at the end (`a -> b -> a`).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("task dependency cycle: {}", .0.join(" -> "))]
+pub struct DependencyCycle(pub Vec<String>);
+
 /// Parses a JSONL line into a Task, logging a warning on failure.
 fn parse_task_line(line: &str) -> Option<Task> {
     match serde_json::from_str(line) {
@@ -64,11 +90,23 @@ impl TaskStore {
             Vec::new()
         };
 
-        Ok(Self {
+        let store = Self {
             path: path.to_path_buf(),
             tasks,
             lock,
-        })
+            formatting: FormattingConfig::default(),
+        };
+        if let Some(cycle) = store.find_cycle() {
+            warn!(path = %path.display(), %cycle, "Tasks on a dependency cycle can never become ready");
+        }
+        Ok(store)
+    }
+
+    /// Sets the formatting policy (`core.formatting`) used when saving.
+    #[must_use]
+    pub fn with_formatting(mut self, formatting: FormattingConfig) -> Self {
+        self.formatting = formatting;
+        self
     }
 
     /// Saves all tasks to the JSONL file.
@@ -77,7 +115,12 @@ impl TaskStore {
     /// Uses an exclusive lock to prevent concurrent writes.
     pub fn save(&self) -> io::Result<()> {
         let _guard = self.lock.exclusive()?;
+        self.write_locked()
+    }
 
+    /// Writes tasks to disk, bumping the change notification if the contents
+    /// differ from what is on disk. Caller must hold the exclusive lock.
+    fn write_locked(&self) -> io::Result<()> {
         if let Some(parent) = self.path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -94,14 +137,39 @@ impl TaskStore {
             })
             .collect::<Result<Vec<_>, _>>()?
             .join("\n");
-        std::fs::write(
-            &self.path,
-            if content.is_empty() {
-                String::new()
-            } else {
-                content + "\n"
-            },
-        )
+        let content = self.formatting.finish(content);
+
+        let unchanged =
+            std::fs::read_to_string(&self.path).is_ok_and(|existing| existing == content);
+        std::fs::write(&self.path, content)?;
+
+        if !unchanged {
+            let notify_path = Self::change_notification_path(&self.path);
+            let next = Self::change_sequence(&self.path) + 1;
+            std::fs::write(notify_path, format!("{next}\n"))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the change-notification file for a tasks file.
+    pub fn change_notification_path(path: &Path) -> PathBuf {
+        path.with_extension(format!(
+            "{}.changed",
+            path.extension()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default()
+        ))
+    }
+
+    /// Returns the current change sequence for a tasks file.
+    ///
+    /// Increments by one for every write that mutates the tasks file.
+    /// Returns 0 if no change has been recorded yet.
+    pub fn change_sequence(path: &Path) -> u64 {
+        std::fs::read_to_string(Self::change_notification_path(path))
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0)
     }
 
     /// Reloads tasks from disk, useful after external modifications.
@@ -161,30 +229,7 @@ impl TaskStore {
         let result = f(self);
 
         // Save changes
-        if let Some(parent) = self.path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        let content: String = self
-            .tasks
-            .iter()
-            .map(|t| {
-                serde_json::to_string(t).map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("task serialization failed: {e}"),
-                    )
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .join("\n");
-        std::fs::write(
-            &self.path,
-            if content.is_empty() {
-                String::new()
-            } else {
-                content + "\n"
-            },
-        )?;
+        self.write_locked()?;
 
         Ok(result)
     }
@@ -195,6 +240,17 @@ impl TaskStore {
         self.tasks.last().unwrap()
     }
 
+    /// Adds a new task unless its blockers would close a dependency cycle.
+    pub fn try_add(&mut self, task: Task) -> Result<&Task, DependencyCycle> {
+        let id = task.id.clone();
+        self.tasks.push(task);
+        if let Some(cycle) = self.cycle_through(&id) {
+            self.tasks.pop();
+            return Err(cycle);
+        }
+        Ok(self.tasks.last().unwrap())
+    }
+
     /// Gets a task by ID (immutable reference).
     pub fn get(&self, id: &str) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
@@ -255,6 +311,15 @@ impl TaskStore {
         None
     }
 
+    /// Changes a task's priority by ID and returns a reference to it.
+    pub fn set_priority(&mut self, id: &str, priority: u8) -> Option<&Task> {
+        if let Some(task) = self.get_mut(id) {
+            task.priority = priority;
+            return self.get(id);
+        }
+        None
+    }
+
     /// Ensures a task exists for a stable key, returning the existing or created task.
     ///
     /// If a task with the same key already exists, its non-lifecycle metadata is refreshed and
@@ -275,6 +340,9 @@ impl TaskStore {
             if !task.blocked_by.is_empty() {
                 existing.blocked_by = task.blocked_by;
             }
+            if !task.tags.is_empty() {
+                existing.tags = task.tags;
+            }
             return &self.tasks[existing_idx];
         }
 
@@ -282,6 +350,67 @@ impl TaskStore {
         self.tasks.last().unwrap()
     }
 
+    /// Like [`TaskStore::ensure`], but leaves the store unchanged if the
+    /// (possibly refreshed) blockers would close a dependency cycle.
+    pub fn try_ensure(&mut self, task: Task) -> Result<&Task, DependencyCycle> {
+        let before = self.tasks.clone();
+        let id = self.ensure(task).id.clone();
+        if let Some(cycle) = self.cycle_through(&id) {
+            self.tasks = before;
+            return Err(cycle);
+        }
+        Ok(self.get(&id).unwrap())
+    }
+
+    /// Returns the first dependency cycle in the store, if any.
+    pub fn find_cycle(&self) -> Option<DependencyCycle> {
+        self.tasks.iter().find_map(|t| self.cycle_through(&t.id))
+    }
+
+    /// Returns a cycle that leads from `id` back to itself via `blocked_by`.
+    /// Blockers that are not in the store or already closed are ignored.
+    fn cycle_through(&self, id: &str) -> Option<DependencyCycle> {
+        fn visit<'a>(
+            store: &'a TaskStore,
+            current: &'a str,
+            target: &str,
+            path: &mut Vec<&'a str>,
+            visited: &mut HashSet<&'a str>,
+        ) -> bool {
+            let Some(task) = store.get(current) else {
+                return false;
+            };
+            for blocker in &task.blocked_by {
+                if store
+                    .get(blocker)
+                    .is_some_and(|b| b.status == TaskStatus::Closed)
+                {
+                    continue;
+                }
+                if blocker == target {
+                    path.push(blocker);
+                    return true;
+                }
+                if visited.insert(blocker) {
+                    path.push(blocker);
+                    if visit(store, blocker, target, path, visited) {
+                        return true;
+                    }
+                    path.pop();
+                }
+            }
+            false
+        }
+
+        if self.get(id).is_some_and(|t| t.status == TaskStatus::Closed) {
+            return None;
+        }
+        let mut path = vec![id];
+        let mut visited = HashSet::new();
+        visit(self, id, id, &mut path, &mut visited)
+            .then(|| DependencyCycle(path.into_iter().map(String::from).collect()))
+    }
+
     /// Returns all tasks as a slice.
     pub fn all(&self) -> &[Task] {
         &self.tasks
@@ -295,12 +424,28 @@ impl TaskStore {
             .collect()
     }
 
+    /// Returns all tasks carrying `tag` (case-insensitive), in any status.
+    pub fn by_tag(&self, tag: &str) -> Vec<&Task> {
+        self.tasks.iter().filter(|t| t.has_tag(tag)).collect()
+    }
+
     /// Returns all ready tasks (open with no pending blockers).
+    ///
+    /// Sorted by priority, then creation time, then ID, so agents that pick
+    /// the first ready task behave the same on every run.
     pub fn ready(&self) -> Vec<&Task> {
-        self.tasks
+        let mut ready: Vec<&Task> = self
+            .tasks
             .iter()
             .filter(|t| t.is_ready(&self.tasks))
-            .collect()
+            .collect();
+        ready.sort_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| a.created.cmp(&b.created))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        ready
     }
 
     /// Returns true if there are any open tasks.
@@ -347,6 +492,38 @@ mod tests {
         assert_eq!(loaded.all()[0].title, "Test task");
     }
 
+    #[test]
+    fn test_save_applies_trailing_newline_policy() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+
+        let mut store = TaskStore::load(&path).unwrap();
+        store.add(Task::new("First".to_string(), 1));
+        store.add(Task::new("Second".to_string(), 2));
+        store.save().unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            content.ends_with("}\n"),
+            "default keeps the trailing newline"
+        );
+        assert_eq!(content.lines().count(), 2);
+
+        let formatting = FormattingConfig {
+            trailing_newline: false,
+            ..FormattingConfig::default()
+        };
+        let mut store = TaskStore::load(&path).unwrap().with_formatting(formatting);
+        store
+            .with_exclusive_lock(|s| s.add(Task::new("Third".to_string(), 3)).id.clone())
+            .unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.ends_with('}'), "{content:?}");
+        assert_eq!(content.lines().count(), 3);
+
+        // Files without a trailing newline still load
+        assert_eq!(TaskStore::load(&path).unwrap().all().len(), 3);
+    }
+
     #[test]
     fn test_get_task() {
         let tmp = TempDir::new().unwrap();
@@ -450,6 +627,172 @@ mod tests {
         assert_eq!(ready[0].title, "Ready");
     }
 
+    #[test]
+    fn test_ready_orders_by_priority_then_created_then_id() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let task = |id: &str, priority: u8, created: &str| {
+            let mut task = Task::new(id.to_string(), priority);
+            task.id = id.to_string();
+            task.created = created.to_string();
+            task
+        };
+        store.add(task("task-d", 2, "2025-01-02T00:00:00+00:00"));
+        store.add(task("task-c", 2, "2025-01-01T00:00:00+00:00"));
+        store.add(task("task-b", 2, "2025-01-01T00:00:00+00:00"));
+        store.add(task("task-a", 3, "2024-01-01T00:00:00+00:00"));
+        store.add(task("task-e", 1, "2025-06-01T00:00:00+00:00"));
+        store.save().unwrap();
+
+        let expected = ["task-e", "task-b", "task-c", "task-d", "task-a"];
+        let ids: Vec<&str> = store.ready().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, expected);
+
+        // Same order after a round trip, regardless of file order
+        let reloaded = TaskStore::load(&path).unwrap();
+        let ids: Vec<&str> = reloaded.ready().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn test_by_tag_survives_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+        store.add(
+            Task::new("API".to_string(), 1)
+                .with_tag("backend".to_string())
+                .with_tag("urgent".to_string()),
+        );
+        store.add(Task::new("Button".to_string(), 2).with_tag("frontend".to_string()));
+        store.add(Task::new("Untagged".to_string(), 3));
+        store.save().unwrap();
+
+        // A task line from before tags existed still loads
+        let mut content = std::fs::read_to_string(&path).unwrap();
+        content.push_str(
+            r#"{"id":"task-1-abcd","title":"Legacy","status":"open","priority":3,"created":"2025-01-01T00:00:00Z"}"#,
+        );
+        std::fs::write(&path, content).unwrap();
+
+        let loaded = TaskStore::load(&path).unwrap();
+        assert_eq!(loaded.all().len(), 4);
+        let backend = loaded.by_tag("Backend");
+        assert_eq!(backend.len(), 1);
+        assert_eq!(backend[0].title, "API");
+        assert_eq!(backend[0].tags, vec!["backend", "urgent"]);
+        assert!(loaded.by_tag("ops").is_empty());
+    }
+
+    #[test]
+    fn test_try_add_rejects_dependency_cycle() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let a = Task::new("A".to_string(), 1);
+        let a_id = a.id.clone();
+        let mut b = Task::new("B".to_string(), 1).with_blocker(a_id.clone());
+        b.id = "task-b".to_string();
+        store.add(a);
+        store.try_add(b).unwrap();
+        assert!(store.find_cycle().is_none());
+
+        // A task blocked by itself is the smallest cycle
+        let mut self_blocked = Task::new("Self".to_string(), 1);
+        self_blocked.blocked_by.push(self_blocked.id.clone());
+        let err = store.try_add(self_blocked.clone()).unwrap_err();
+        assert_eq!(
+            err.0,
+            vec![self_blocked.id.clone(), self_blocked.id.clone()]
+        );
+        assert_eq!(store.all().len(), 2, "rejected task is not kept");
+
+        // An edited file can still contain a cycle: A -> B -> A
+        store
+            .get_mut(&a_id)
+            .unwrap()
+            .blocked_by
+            .push("task-b".to_string());
+        let cycle = store.find_cycle().unwrap();
+        assert_eq!(
+            cycle.0,
+            vec![a_id.clone(), "task-b".to_string(), a_id.clone()]
+        );
+        assert_eq!(
+            cycle.to_string(),
+            format!("task dependency cycle: {a_id} -> task-b -> {a_id}")
+        );
+        assert!(store.ready().is_empty());
+    }
+
+    #[test]
+    fn test_closed_tasks_do_not_form_cycles() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        // A -> B -> A, but B is closed and so no longer blocks A
+        let mut a = Task::new("A".to_string(), 1).with_blocker("task-b".to_string());
+        a.id = "task-a".to_string();
+        let mut b = Task::new("B".to_string(), 1).with_blocker("task-a".to_string());
+        b.id = "task-b".to_string();
+        store.add(a);
+        store.add(b);
+        assert!(store.find_cycle().is_some());
+
+        store.close("task-b");
+        assert!(store.find_cycle().is_none());
+
+        // A failed task still blocks its dependents, so it can close a cycle
+        store.get_mut("task-b").unwrap().status = TaskStatus::Failed;
+        assert!(store.find_cycle().is_some());
+        let mut c = Task::new("C".to_string(), 1).with_blocker("task-b".to_string());
+        c.id = "task-c".to_string();
+        store
+            .get_mut("task-b")
+            .unwrap()
+            .blocked_by
+            .push("task-c".to_string());
+        assert!(store.try_add(c).is_err());
+    }
+
+    #[test]
+    fn test_try_ensure_leaves_store_unchanged_on_cycle() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let setup_id = store
+            .ensure(Task::new("Setup".to_string(), 1).with_key(Some("setup".to_string())))
+            .id
+            .clone();
+        let routes_id = store
+            .try_ensure(
+                Task::new("Routes".to_string(), 2)
+                    .with_key(Some("routes".to_string()))
+                    .with_blocker(setup_id.clone()),
+            )
+            .unwrap()
+            .id
+            .clone();
+
+        let err = store
+            .try_ensure(
+                Task::new("Setup v2".to_string(), 1)
+                    .with_key(Some("setup".to_string()))
+                    .with_blocker(routes_id.clone()),
+            )
+            .unwrap_err();
+        assert_eq!(err.0, vec![setup_id.clone(), routes_id, setup_id.clone()]);
+
+        let setup = store.get(&setup_id).unwrap();
+        assert_eq!(setup.title, "Setup");
+        assert!(setup.blocked_by.is_empty());
+    }
+
     #[test]
     fn test_ensure_deduplicates_by_key() {
         let tmp = TempDir::new().unwrap();
@@ -630,4 +973,39 @@ mod tests {
         assert_eq!(loaded.all().len(), 1);
         assert_eq!(loaded.all()[0].title, "Valid task");
     }
+
+    #[test]
+    fn test_change_notification_fires_once_per_mutation() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 0);
+
+        let task = Task::new("Test".to_string(), 2);
+        let id = task.id.clone();
+        store.add(task);
+        store.save().unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 1, "add");
+
+        store.close(&id);
+        store.save().unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 2, "close");
+
+        store.reopen(&id);
+        store.save().unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 3, "reopen");
+
+        store
+            .with_exclusive_lock(|s| {
+                s.set_priority(&id, 1);
+            })
+            .unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 4, "reprioritize");
+
+        // Saving without a mutation must not signal a change
+        store.save().unwrap();
+        store.with_exclusive_lock(|_| {}).unwrap();
+        assert_eq!(TaskStore::change_sequence(&path), 4);
+        assert!(TaskStore::change_notification_path(&path).ends_with("tasks.jsonl.changed"));
+    }
 }
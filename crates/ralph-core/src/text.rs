@@ -86,10 +86,54 @@ pub fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Shortens `s` to roughly `max_bytes` by eliding its middle.
+///
+/// The head is cut with [`truncate_with_ellipsis`], `marker` is placed on its
+/// own line, and the tail is kept verbatim so the final lines of the text
+/// survive. Returns `s` unchanged when it already fits.
+///
+/// # Examples
+///
+/// ```
+/// use ralph_core::elide_middle;
+///
+/// assert_eq!(elide_middle("short", 10, "[cut]"), "short");
+/// assert_eq!(
+///     elide_middle("abcdefghijklmnopqrst", 10, "[cut]"),
+///     "ab...\n[cut]\npqrst"
+/// );
+/// ```
+pub fn elide_middle(s: &str, max_bytes: usize, marker: &str) -> String {
+    if s.len() <= max_bytes {
+        return s.to_string();
+    }
+
+    let half = max_bytes / 2;
+    let head_chars = s[..floor_char_boundary(s, half)].chars().count();
+    let head = truncate_with_ellipsis(s, head_chars);
+
+    let mut tail_start = s.len() - (max_bytes - half);
+    while !s.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+
+    format!("{}\n{}\n{}", head, marker, &s[tail_start..])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_elide_middle_keeps_head_and_tail_on_char_boundaries() {
+        let s = format!("{}{}", "é".repeat(50), "🎉".repeat(50));
+        let elided = elide_middle(&s, 41, "[cut]");
+        assert!(elided.starts_with('é'));
+        assert!(elided.ends_with('🎉'));
+        assert!(elided.contains("...\n[cut]\n"));
+        assert!(elided.len() <= 41 + "\n[cut]\n".len());
+    }
+
     #[test]
     fn test_floor_char_boundary_ascii() {
         let s = "hello";
@@ -0,0 +1,172 @@
+//! Checks emitted topics against the topics a config actually routes.
+//!
+//! In a multi-hat setup, a topic no hat subscribes to silently falls back to
+//! Ralph. That is almost always a typo (`buld.done`), so `ralph emit` can
+//! check the topic against the union of every hat's triggers and publishes
+//! plus the topics the loop itself understands, and suggest the closest match.
+
+use crate::RalphConfig;
+use ralph_proto::Topic;
+
+/// Topics the event loop handles itself, independent of any hat.
+pub const SYSTEM_TOPICS: &[&str] = &[
+    "task.start",
+    "task.resume",
+    "build.blocked",
+    "human.interact",
+    "human.response",
+    "human.guidance",
+    "user.prompt",
+    "user.steer",
+    "event.malformed",
+    "loop.terminate",
+];
+
+/// A topic that matches nothing the config knows about.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown event topic '{topic}'{}", suggestion_hint(.suggestion.as_deref()))]
+pub struct UnknownTopic {
+    pub topic: String,
+    /// Closest known topic, when one is near enough to be a likely typo.
+    pub suggestion: Option<String>,
+}
+
+fn suggestion_hint(suggestion: Option<&str>) -> String {
+    suggestion
+        .map(|s| format!(" (did you mean '{s}'?)"))
+        .unwrap_or_default()
+}
+
+/// Returns every topic or topic pattern `config` routes, sorted and deduplicated.
+///
+/// Includes hat triggers, publishes and default publishes, each hat's
+/// `<hat>.exhausted` event, the loop's completion, cancellation, starting and
+/// required events, and [`SYSTEM_TOPICS`].
+pub fn known_topics(config: &RalphConfig) -> Vec<String> {
+    let event_loop = &config.event_loop;
+    let mut topics: Vec<String> = SYSTEM_TOPICS.iter().map(|t| (*t).to_string()).collect();
+    for (id, hat) in &config.hats {
+        topics.extend(hat.triggers.iter().cloned());
+        topics.extend(hat.publishes.iter().cloned());
+        topics.extend(hat.default_publishes.iter().cloned());
+        topics.push(format!("{id}.exhausted"));
+    }
+    topics.push(event_loop.completion_promise.clone());
+    topics.push(event_loop.cancellation_promise.clone());
+    topics.extend(event_loop.starting_event.iter().cloned());
+    topics.extend(event_loop.required_events.iter().cloned());
+
+    topics.retain(|t| !t.is_empty());
+    topics.sort();
+    topics.dedup();
+    topics
+}
+
+/// Checks `topic` against `known` topics, which may contain `*` patterns.
+pub fn check_topic(topic: &str, known: &[String]) -> Result<(), UnknownTopic> {
+    if known
+        .iter()
+        .any(|pattern| Topic::new(pattern.as_str()).matches_str(topic))
+    {
+        return Ok(());
+    }
+
+    // Only suggest close matches: at most a third of the topic may differ.
+    let max_distance = (topic.chars().count() / 3).max(1);
+    let suggestion = known
+        .iter()
+        .filter(|candidate| !candidate.contains('*'))
+        .map(|candidate| (edit_distance(topic, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.clone());
+
+    Err(UnknownTopic {
+        topic: topic.to_string(),
+        suggestion,
+    })
+}
+
+/// Levenshtein distance between `a` and `b`, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RalphConfig {
+        RalphConfig::parse_yaml(
+            r#"
+hats:
+  builder:
+    name: Builder
+    description: Builds
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  reviewer:
+    name: Reviewer
+    description: Reviews
+    triggers: ["review.*"]
+    publishes: ["review.approved"]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn known_topics_cover_hats_and_system_topics() {
+        let known = known_topics(&config());
+        for topic in [
+            "build.task",
+            "build.done",
+            "review.*",
+            "builder.exhausted",
+            "LOOP_COMPLETE",
+            "task.start",
+        ] {
+            assert!(known.contains(&topic.to_string()), "missing {topic}");
+        }
+    }
+
+    #[test]
+    fn check_topic_accepts_known_and_pattern_matched_topics() {
+        let known = known_topics(&config());
+        assert!(check_topic("build.done", &known).is_ok());
+        assert!(check_topic("review.requested", &known).is_ok());
+        assert!(check_topic("human.interact", &known).is_ok());
+    }
+
+    #[test]
+    fn check_topic_suggests_closest_known_topic() {
+        let known = known_topics(&config());
+        let err = check_topic("buld.done", &known).unwrap_err();
+        assert_eq!(err.suggestion.as_deref(), Some("build.done"));
+        assert_eq!(
+            err.to_string(),
+            "unknown event topic 'buld.done' (did you mean 'build.done'?)"
+        );
+
+        let err = check_topic("deploy.finished", &known).unwrap_err();
+        assert_eq!(err.suggestion, None);
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("build.done", "build.done"), 0);
+        assert_eq!(edit_distance("buld.done", "build.done"), 1);
+        assert_eq!(edit_distance("build.dnoe", "build.done"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -80,6 +80,25 @@ impl UrgentSteerStore {
         self.clear()?;
         Ok(record)
     }
+
+    /// Moves a plain-text steer file (e.g. `.ralph/agent/steer.txt`) into the
+    /// store, removing the file so each message is taken once. Returns the
+    /// appended message, or `None` if the file is missing or blank.
+    pub fn absorb_steer_file(&self, steer_file: &Path) -> io::Result<Option<String>> {
+        let content = match fs::read_to_string(steer_file) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        fs::remove_file(steer_file)?;
+
+        let message = content.trim();
+        if message.is_empty() {
+            return Ok(None);
+        }
+        self.append_message(message)?;
+        Ok(Some(message.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -108,4 +127,21 @@ mod tests {
         assert_eq!(record.messages, vec!["steer now"]);
         assert!(store.load().expect("load after take").is_none());
     }
+
+    #[test]
+    fn absorb_steer_file_appends_message_and_removes_file() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let store = UrgentSteerStore::new(temp_dir.path().join("urgent-steer.json"));
+        let steer_file = temp_dir.path().join("agent/steer.txt");
+        fs::create_dir_all(steer_file.parent().unwrap()).expect("agent dir");
+        fs::write(&steer_file, "Use axum instead of actix\n").expect("write steer");
+
+        let message = store.absorb_steer_file(&steer_file).expect("absorb");
+        assert_eq!(message.as_deref(), Some("Use axum instead of actix"));
+        assert!(!steer_file.exists(), "steer file should be consumed");
+        let record = store.load().expect("load").expect("record");
+        assert_eq!(record.messages, vec!["Use axum instead of actix"]);
+
+        assert_eq!(store.absorb_steer_file(&steer_file).expect("absorb"), None);
+    }
 }
@@ -28,12 +28,17 @@
 //! # Ok::<(), ralph_core::workspace::WorkspaceError>(())
 //! ```
 
+use crate::coverage;
 use crate::task_definition::{TaskDefinition, Verification};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Cleanup policy for workspace directories.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -102,16 +107,22 @@ impl TaskWorkspace {
     ///
     /// Returns `WorkspaceError` if directory creation or git init fails.
     pub fn create(task: &TaskDefinition, base_dir: &Path) -> Result<Self, WorkspaceError> {
-        let timestamp = SystemTime::now()
+        let mut timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_millis() as u64)
             .unwrap_or(0);
 
-        let dir_name = format!("ralph-bench-{}-{}", task.name, timestamp);
-        let path = base_dir.join(&dir_name);
-
-        // Create workspace directory
-        fs::create_dir_all(&path)?;
+        // Create workspace directory. Runs of the same task started within the
+        // same millisecond (e.g. `--repeat`) take the next free timestamp.
+        fs::create_dir_all(base_dir)?;
+        let path = loop {
+            let path = base_dir.join(format!("ralph-bench-{}-{}", task.name, timestamp));
+            match fs::create_dir(&path) {
+                Ok(()) => break path,
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => timestamp += 1,
+                Err(e) => return Err(e.into()),
+            }
+        };
 
         // Create .ralph/agent directory with empty scratchpad
         let agent_dir = path.join(".ralph").join("agent");
@@ -313,6 +324,37 @@ pub struct VerificationResult {
 
     /// Stderr output from the command.
     pub stderr: String,
+
+    /// Line coverage percentage read from `Verification::coverage_report`;
+    /// `None` when no report is configured or it could not be read.
+    pub coverage: Option<f64>,
+
+    /// Why verification failed; `None` when it passed.
+    pub failure_kind: Option<VerificationFailure>,
+
+    /// The stdout assertion that failed, e.g. `stdout does not contain "ok"`.
+    pub failed_assertion: Option<String>,
+}
+
+/// Classifies a failed verification, so benchmark runs can tell flaky
+/// timeouts apart from real breakage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationFailure {
+    /// The shell could not find the command (exit code 127).
+    CommandNotFound,
+    /// The command was killed after `Verification::timeout_seconds`.
+    TimedOut,
+    /// The command exited with an unexpected exit code.
+    ExitCode,
+    /// A `stdout_contains`, `stdout_not_contains` or `stdout_matches`
+    /// assertion failed.
+    OutputMismatch,
+    /// The exit code matched but coverage missed `Verification::min_coverage`.
+    CoverageBelowMinimum,
+    /// The command never ran: it could not be spawned, or there was nowhere
+    /// to run it.
+    NotRun,
 }
 
 impl VerificationResult {
@@ -320,6 +362,20 @@ impl VerificationResult {
     pub fn summary(&self) -> String {
         if self.passed {
             format!("PASSED (exit code {})", self.exit_code)
+        } else if self.failure_kind == Some(VerificationFailure::TimedOut) {
+            "FAILED (timed out)".to_string()
+        } else if self.failure_kind == Some(VerificationFailure::NotRun) {
+            "FAILED (not run)".to_string()
+        } else if self.failure_kind == Some(VerificationFailure::CommandNotFound) {
+            format!("FAILED (command not found, exit code {})", self.exit_code)
+        } else if let Some(assertion) = &self.failed_assertion {
+            format!("FAILED ({assertion})")
+        } else if self.exit_code == self.expected_exit_code {
+            // The exit code matched, so the coverage minimum failed
+            match self.coverage {
+                Some(coverage) => format!("FAILED (coverage {coverage:.1}% below minimum)"),
+                None => "FAILED (coverage report missing or unreadable)".to_string(),
+            }
         } else {
             format!(
                 "FAILED (exit code {}, expected {})",
@@ -350,53 +406,197 @@ impl TaskWorkspace {
         &self,
         verification: &Verification,
     ) -> Result<VerificationResult, WorkspaceError> {
-        if verification.command.is_empty() {
-            // No verification command - consider it passed
-            return Ok(VerificationResult {
-                passed: true,
-                exit_code: 0,
-                expected_exit_code: 0,
-                stdout: String::new(),
-                stderr: String::new(),
-            });
-        }
+        run_verification_in(&self.path, verification)
+    }
+}
 
-        tracing::debug!(
-            "Running verification in {}: {}",
-            self.path.display(),
-            verification.command
-        );
+/// Runs a verification command via `bash -c` in `dir`.
+///
+/// An empty command counts as passed. Returns `WorkspaceError::Verification`
+/// only if the command cannot be executed at all.
+pub fn run_verification_in(
+    dir: &Path,
+    verification: &Verification,
+) -> Result<VerificationResult, WorkspaceError> {
+    if verification.command.is_empty() {
+        // No verification command - consider it passed
+        return Ok(VerificationResult {
+            passed: true,
+            exit_code: 0,
+            expected_exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            coverage: None,
+            failure_kind: None,
+            failed_assertion: None,
+        });
+    }
 
-        let output = Command::new("bash")
-            .args(["-c", &verification.command])
-            .current_dir(&self.path)
-            .output()
-            .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
+    tracing::debug!(
+        "Running verification in {}: {}",
+        dir.display(),
+        verification.command
+    );
+
+    let mut command = Command::new("bash");
+    command
+        .args(["-c", &verification.command])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // Own process group, so a timeout kills everything the command started
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    let mut child = command
+        .spawn()
+        .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
+
+    let stdout_reader = read_pipe(child.stdout.take());
+    let stderr_reader = read_pipe(child.stderr.take());
+    let timeout = verification.timeout_seconds.map(Duration::from_secs);
+    let (status, timed_out) = wait_with_timeout(&mut child, timeout)
+        .map_err(|e| WorkspaceError::Verification(format!("Failed to wait: {}", e)))?;
+    let stdout = join_pipe(stdout_reader);
+    let stderr = join_pipe(stderr_reader);
+
+    let exit_code = status.code().unwrap_or(-1);
+    let exit_ok = !timed_out && exit_code == verification.success_exit_code;
+    // Stdout assertions only matter once the command itself succeeded
+    let failed_assertion = if exit_ok {
+        check_stdout(verification, &stdout)?
+    } else {
+        None
+    };
+    let coverage = verification
+        .coverage_report
+        .as_deref()
+        .and_then(|report| coverage::read_coverage_report(&dir.join(report)));
+    let coverage_ok = verification
+        .min_coverage
+        .is_none_or(|min| coverage.is_some_and(|coverage| coverage >= min));
+    let failure_kind = if timed_out {
+        Some(VerificationFailure::TimedOut)
+    } else if exit_code != verification.success_exit_code {
+        // bash reports a missing command with 127
+        Some(if exit_code == 127 {
+            VerificationFailure::CommandNotFound
+        } else {
+            VerificationFailure::ExitCode
+        })
+    } else if failed_assertion.is_some() {
+        Some(VerificationFailure::OutputMismatch)
+    } else if !coverage_ok {
+        Some(VerificationFailure::CoverageBelowMinimum)
+    } else {
+        None
+    };
+    let passed = failure_kind.is_none();
+
+    tracing::debug!(
+        "Verification result: {} (exit code {}, expected {})",
+        if passed { "PASSED" } else { "FAILED" },
+        exit_code,
+        verification.success_exit_code
+    );
+
+    Ok(VerificationResult {
+        passed,
+        exit_code,
+        expected_exit_code: verification.success_exit_code,
+        stdout,
+        stderr,
+        coverage,
+        failure_kind,
+        failed_assertion,
+    })
+}
 
-        let exit_code = output.status.code().unwrap_or(-1);
-        let passed = exit_code == verification.success_exit_code;
+/// Checks the stdout assertions of `verification`, returning the first that
+/// failed.
+fn check_stdout(
+    verification: &Verification,
+    stdout: &str,
+) -> Result<Option<String>, WorkspaceError> {
+    if let Some(expected) = &verification.stdout_contains
+        && !stdout.contains(expected.as_str())
+    {
+        return Ok(Some(format!("stdout does not contain {expected:?}")));
+    }
+    if let Some(unexpected) = &verification.stdout_not_contains
+        && stdout.contains(unexpected.as_str())
+    {
+        return Ok(Some(format!("stdout contains {unexpected:?}")));
+    }
+    if let Some(pattern) = &verification.stdout_matches {
+        let regex = regex::Regex::new(pattern).map_err(|e| {
+            WorkspaceError::Verification(format!("Invalid stdout_matches regex: {}", e))
+        })?;
+        if !regex.is_match(stdout) {
+            return Ok(Some(format!("stdout does not match /{pattern}/")));
+        }
+    }
+    Ok(None)
+}
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+/// Waits for `child`, killing it once `timeout` elapses.
+///
+/// Returns the exit status and whether the timeout fired.
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+) -> io::Result<(std::process::ExitStatus, bool)> {
+    let Some(timeout) = timeout else {
+        return Ok((child.wait()?, false));
+    };
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((status, false));
+        }
+        if Instant::now() >= deadline {
+            kill_process_tree(child);
+            return Ok((child.wait()?, true));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
 
-        tracing::debug!(
-            "Verification result: {} (exit code {}, expected {})",
-            if passed { "PASSED" } else { "FAILED" },
-            exit_code,
-            verification.success_exit_code
-        );
+#[cfg(unix)]
+fn kill_process_tree(child: &mut Child) {
+    use nix::sys::signal::{Signal, killpg};
+    use nix::unistd::Pid;
 
-        Ok(VerificationResult {
-            passed,
-            exit_code,
-            expected_exit_code: verification.success_exit_code,
-            stdout,
-            stderr,
+    let _ = killpg(Pid::from_raw(child.id() as i32), Signal::SIGKILL);
+}
+
+#[cfg(not(unix))]
+fn kill_process_tree(child: &mut Child) {
+    let _ = child.kill();
+}
+
+/// Drains a child pipe on a thread so neither stream can fill up and block it.
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> Option<JoinHandle<Vec<u8>>> {
+    pipe.map(|mut pipe| {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            buf
         })
-    }
+    })
+}
+
+fn join_pipe(reader: Option<JoinHandle<Vec<u8>>>) -> String {
+    let bytes = reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    String::from_utf8_lossy(&bytes).to_string()
 }
 
 /// Manages workspace cleanup according to a policy.
+///
+/// Safe to share between concurrently running tasks: rotation and quota
+/// eviction are serialized and never remove a workspace that is still in use
+/// (created but not yet passed to `apply_cleanup`).
 #[derive(Debug)]
 pub struct WorkspaceManager {
     /// Base directory for workspaces.
@@ -404,6 +604,13 @@ pub struct WorkspaceManager {
 
     /// Cleanup policy to apply.
     policy: CleanupPolicy,
+
+    /// Disk quota across all workspaces, enforced before creating a new one.
+    max_total_bytes: Option<u64>,
+
+    /// Workspaces handed out by `create_workspace` and not yet cleaned up.
+    /// The lock also serializes rotation and eviction.
+    in_use: Mutex<HashSet<PathBuf>>,
 }
 
 impl WorkspaceManager {
@@ -412,9 +619,22 @@ impl WorkspaceManager {
         Self {
             base_dir: base_dir.into(),
             policy,
+            max_total_bytes: None,
+            in_use: Mutex::new(HashSet::new()),
         }
     }
 
+    /// Caps the total size of all workspaces.
+    ///
+    /// When the quota is exceeded, `create_workspace` evicts the oldest
+    /// workspaces first. With `CleanupPolicy::Rotate(n)` the newest `n` are
+    /// never evicted, even if that leaves the quota exceeded.
+    #[must_use]
+    pub fn with_max_total_bytes(mut self, max_total_bytes: u64) -> Self {
+        self.max_total_bytes = Some(max_total_bytes);
+        self
+    }
+
     /// Returns the base directory.
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
@@ -425,9 +645,94 @@ impl WorkspaceManager {
         self.policy
     }
 
-    /// Creates a workspace for the given task.
+    /// Creates a workspace for the given task, enforcing the disk quota first.
     pub fn create_workspace(&self, task: &TaskDefinition) -> Result<TaskWorkspace, WorkspaceError> {
-        TaskWorkspace::create(task, &self.base_dir)
+        let mut in_use = self.lock_in_use();
+        if let Some(max_total_bytes) = self.max_total_bytes {
+            self.enforce_quota(max_total_bytes, &in_use)?;
+        }
+        let workspace = TaskWorkspace::create(task, &self.base_dir)?;
+        in_use.insert(workspace.path().to_path_buf());
+        Ok(workspace)
+    }
+
+    /// Marks a workspace as no longer in use, so rotation and quota eviction
+    /// may remove it. `apply_cleanup` does this itself.
+    pub fn release(&self, workspace: &TaskWorkspace) {
+        self.lock_in_use().remove(workspace.path());
+    }
+
+    fn lock_in_use(&self) -> MutexGuard<'_, HashSet<PathBuf>> {
+        // The set stays consistent even if a holder panicked
+        self.in_use.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the total size in bytes of all workspaces.
+    ///
+    /// Best effort: files that cannot be read while walking are skipped.
+    pub fn total_disk_usage(&self) -> u64 {
+        self.list_workspaces()
+            .unwrap_or_default()
+            .iter()
+            .map(|workspace| dir_size(&workspace.path))
+            .sum()
+    }
+
+    /// Evicts the oldest workspaces until the total is within `max_total_bytes`,
+    /// skipping those `in_use`.
+    ///
+    /// Returns the evicted workspace paths, oldest first.
+    fn enforce_quota(
+        &self,
+        max_total_bytes: u64,
+        in_use: &HashSet<PathBuf>,
+    ) -> Result<Vec<PathBuf>, WorkspaceError> {
+        let keep_last_n = match self.policy {
+            CleanupPolicy::Rotate(keep_last_n) => keep_last_n,
+            _ => 0,
+        };
+
+        let mut sized: Vec<(PathBuf, u64)> = self
+            .list_workspaces()?
+            .into_iter()
+            .filter(|workspace| workspace.timestamp.is_some())
+            .map(|workspace| {
+                let size = dir_size(&workspace.path);
+                (workspace.path, size)
+            })
+            .collect();
+        let mut total: u64 = sized.iter().map(|(_, size)| size).sum();
+
+        let mut evicted = Vec::new();
+        while total > max_total_bytes && sized.len() > keep_last_n {
+            // Sorted newest first, so the oldest is last
+            let Some((path, size)) = sized.pop() else {
+                break;
+            };
+            if in_use.contains(&path) {
+                continue;
+            }
+            fs::remove_dir_all(&path)?;
+            tracing::info!(
+                "Evicted workspace {} ({} bytes) to stay within the {} byte quota",
+                path.display(),
+                size,
+                max_total_bytes
+            );
+            total = total.saturating_sub(size);
+            evicted.push(path);
+        }
+
+        if total > max_total_bytes {
+            tracing::warn!(
+                "Workspaces use {} bytes, over the {} byte quota, but the last {} are kept",
+                total,
+                max_total_bytes,
+                keep_last_n
+            );
+        }
+
+        Ok(evicted)
     }
 
     /// Applies cleanup policy after a task run.
@@ -445,6 +750,7 @@ impl WorkspaceManager {
         workspace: &mut TaskWorkspace,
         success: bool,
     ) -> Result<bool, WorkspaceError> {
+        self.release(workspace);
         match self.policy {
             CleanupPolicy::Always => {
                 workspace.cleanup()?;
@@ -467,8 +773,9 @@ impl WorkspaceManager {
         }
     }
 
-    /// Rotates old workspaces, keeping only the last N.
+    /// Rotates old workspaces, keeping only the last N and any still in use.
     pub fn rotate_workspaces(&self, keep_last_n: usize) -> Result<(), WorkspaceError> {
+        let in_use = self.lock_in_use();
         if !self.base_dir.exists() {
             return Ok(());
         }
@@ -499,6 +806,9 @@ impl WorkspaceManager {
 
         // Delete workspaces beyond keep_last_n
         for (path, _) in workspaces.into_iter().skip(keep_last_n) {
+            if in_use.contains(&path) {
+                continue;
+            }
             tracing::debug!("Rotating old workspace: {}", path.display());
             fs::remove_dir_all(&path)?;
         }
@@ -603,6 +913,21 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
     Ok(())
 }
 
+/// Returns the total size of the files under `path`, without following symlinks.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
 /// Extracts timestamp from workspace directory name.
 ///
 /// Format: `ralph-bench-{task_name}-{timestamp}`
@@ -652,6 +977,21 @@ mod tests {
         assert_eq!(workspace.task_name(), "hello-world");
     }
 
+    #[test]
+    fn test_workspace_create_same_task_twice_gets_distinct_dirs() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("repeat");
+
+        let ws1 = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        let ws2 = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+
+        assert_ne!(ws1.path(), ws2.path());
+        assert_eq!(
+            extract_task_name(&ws2.path().file_name().unwrap().to_string_lossy()).as_deref(),
+            Some("repeat")
+        );
+    }
+
     #[test]
     fn test_workspace_cleanup() {
         let temp_dir = TempDir::new().unwrap();
@@ -809,7 +1149,14 @@ mod tests {
         assert!(ws2.path().exists());
         assert!(ws3.path().exists());
 
+        // Workspaces still in use are never rotated away
+        manager.rotate_workspaces(2).unwrap();
+        assert!(ws1.path().exists());
+
         // Rotate should keep only 2
+        for ws in [&ws1, &ws2, &ws3] {
+            manager.release(ws);
+        }
         manager.rotate_workspaces(2).unwrap();
 
         // ws1 should be deleted (oldest)
@@ -819,6 +1166,57 @@ mod tests {
         assert!(ws3.path().exists());
     }
 
+    #[test]
+    fn test_workspace_manager_rotates_concurrently_without_removing_in_use() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Rotate(1));
+
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let manager = &manager;
+                scope.spawn(move || {
+                    let task = make_test_task(&format!("concurrent-{i}"));
+                    let mut ws = manager.create_workspace(&task).unwrap();
+                    fs::write(ws.path().join("work.txt"), "busy").unwrap();
+                    manager.rotate_workspaces(0).unwrap();
+                    // Other threads' rotations must leave this one alone
+                    assert!(ws.path().join("work.txt").exists());
+                    manager.apply_cleanup(&mut ws, false).unwrap();
+                });
+            }
+        });
+
+        assert_eq!(manager.list_workspaces().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_workspace_manager_quota_evicts_oldest() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("quota-test");
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Rotate(1));
+
+        let ws1 = manager.create_workspace(&task).unwrap();
+        fs::write(ws1.path().join("big.bin"), vec![0u8; 4096]).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let ws2 = manager.create_workspace(&task).unwrap();
+        fs::write(ws2.path().join("big.bin"), vec![0u8; 4096]).unwrap();
+        manager.release(&ws1);
+        manager.release(&ws2);
+
+        let usage = manager.total_disk_usage();
+        assert!(usage >= 8192, "usage {usage}");
+
+        // Over quota: the oldest goes, but Rotate(1) keeps the newest
+        let manager = manager.with_max_total_bytes(1024);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let ws3 = manager.create_workspace(&task).unwrap();
+
+        assert!(!ws1.path().exists());
+        assert!(ws2.path().exists());
+        assert!(ws3.path().exists());
+        assert!(manager.total_disk_usage() < usage);
+    }
+
     #[test]
     fn test_workspace_manager_apply_cleanup_always() {
         let temp_dir = TempDir::new().unwrap();
@@ -914,7 +1312,7 @@ mod tests {
 
         let verification = Verification {
             command: "cat hello.txt | grep -q 'Hello, World!'".to_string(),
-            success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -932,7 +1330,7 @@ mod tests {
         // File doesn't exist, grep will fail
         let verification = Verification {
             command: "cat nonexistent.txt".to_string(),
-            success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -950,6 +1348,7 @@ mod tests {
         let verification = Verification {
             command: "exit 42".to_string(),
             success_exit_code: 42,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -966,7 +1365,7 @@ mod tests {
 
         let verification = Verification {
             command: String::new(),
-            success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -981,7 +1380,7 @@ mod tests {
 
         let verification = Verification {
             command: "echo 'stdout message' && echo 'stderr message' >&2".to_string(),
-            success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -990,6 +1389,180 @@ mod tests {
         assert!(result.stderr.contains("stderr message"));
     }
 
+    #[test]
+    fn test_run_verification_kills_command_after_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-timeout");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+
+        let start = Instant::now();
+        let result = workspace
+            .run_verification(&Verification {
+                timeout_seconds: Some(1),
+                ..Verification::new("sleep 30; echo done")
+            })
+            .unwrap();
+        assert!(!result.passed);
+        assert_eq!(result.failure_kind, Some(VerificationFailure::TimedOut));
+        assert!(!result.stdout.contains("done"));
+        assert!(start.elapsed() < Duration::from_secs(10));
+
+        let result = workspace
+            .run_verification(&Verification {
+                timeout_seconds: Some(30),
+                ..Verification::new("echo fast")
+            })
+            .unwrap();
+        assert!(result.passed);
+        assert_eq!(result.failure_kind, None);
+    }
+
+    #[test]
+    fn test_run_verification_classifies_failures() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-failure-kind");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        let run = |command: &str| {
+            workspace
+                .run_verification(&Verification::new(command))
+                .unwrap()
+        };
+
+        let result = run("definitely-not-a-real-command-xyz");
+        assert_eq!(
+            result.failure_kind,
+            Some(VerificationFailure::CommandNotFound)
+        );
+        assert_eq!(
+            result.summary(),
+            "FAILED (command not found, exit code 127)"
+        );
+
+        let result = run("exit 3");
+        assert_eq!(result.failure_kind, Some(VerificationFailure::ExitCode));
+
+        // A command expected to exit 127 is not "not found"
+        let result = workspace
+            .run_verification(&Verification {
+                success_exit_code: 127,
+                ..Verification::new("exit 127")
+            })
+            .unwrap();
+        assert!(result.passed);
+        assert_eq!(result.failure_kind, None);
+    }
+
+    #[test]
+    fn test_run_verification_checks_stdout_assertions() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-stdout");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        let run = |verification: Verification| workspace.run_verification(&verification).unwrap();
+        let command = "echo 'test result: ok. 12 passed'";
+
+        let result = run(Verification {
+            stdout_contains: Some("12 passed".to_string()),
+            stdout_not_contains: Some("FAILED".to_string()),
+            stdout_matches: Some(r"ok\. \d+ passed".to_string()),
+            ..Verification::new(command)
+        });
+        assert!(result.passed);
+        assert_eq!(result.failed_assertion, None);
+
+        let result = run(Verification {
+            stdout_contains: Some("All tests passed".to_string()),
+            ..Verification::new(command)
+        });
+        assert!(!result.passed);
+        assert_eq!(
+            result.failure_kind,
+            Some(VerificationFailure::OutputMismatch)
+        );
+        assert_eq!(
+            result.summary(),
+            r#"FAILED (stdout does not contain "All tests passed")"#
+        );
+
+        let result = run(Verification {
+            stdout_not_contains: Some("ok.".to_string()),
+            ..Verification::new(command)
+        });
+        assert_eq!(
+            result.failed_assertion.as_deref(),
+            Some(r#"stdout contains "ok.""#)
+        );
+
+        let result = run(Verification {
+            stdout_matches: Some(r"^\d+ failed".to_string()),
+            ..Verification::new(command)
+        });
+        assert_eq!(
+            result.failed_assertion.as_deref(),
+            Some(r"stdout does not match /^\d+ failed/")
+        );
+
+        // Assertions only read stdout
+        let result = run(Verification {
+            stdout_contains: Some("oops".to_string()),
+            ..Verification::new("echo oops >&2")
+        });
+        assert!(!result.passed);
+
+        // A wrong exit code is reported as such, without an assertion
+        let result = run(Verification {
+            stdout_contains: Some("All tests passed".to_string()),
+            ..Verification::new("echo partial; exit 2")
+        });
+        assert_eq!(result.failure_kind, Some(VerificationFailure::ExitCode));
+        assert_eq!(result.failed_assertion, None);
+        assert_eq!(result.summary(), "FAILED (exit code 2, expected 0)");
+    }
+
+    #[test]
+    fn test_run_verification_records_coverage_and_applies_minimum() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-coverage");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        fs::write(
+            workspace.path().join("lcov.info"),
+            "SF:src/lib.rs\nDA:1,1\nLF:8\nLH:6\nend_of_record\n",
+        )
+        .unwrap();
+        let run = |coverage_report: &str, min_coverage: Option<f64>| {
+            let verification = Verification {
+                coverage_report: Some(coverage_report.to_string()),
+                min_coverage,
+                ..Verification::new("true")
+            };
+            workspace.run_verification(&verification).unwrap()
+        };
+
+        let result = run("lcov.info", None);
+        assert!(result.passed);
+        assert!((result.coverage.unwrap() - 75.0).abs() < 1e-9);
+
+        let result = run("lcov.info", Some(70.0));
+        assert!(result.passed);
+
+        let result = run("lcov.info", Some(80.0));
+        assert!(!result.passed);
+        assert_eq!(
+            result.failure_kind,
+            Some(VerificationFailure::CoverageBelowMinimum)
+        );
+        assert_eq!(result.summary(), "FAILED (coverage 75.0% below minimum)");
+
+        // A missing report records no coverage; it only fails with a minimum set
+        let result = run("missing.info", None);
+        assert!(result.passed);
+        assert_eq!(result.coverage, None);
+        let result = run("missing.info", Some(10.0));
+        assert_eq!(
+            result.summary(),
+            "FAILED (coverage report missing or unreadable)"
+        );
+    }
+
     #[test]
     fn test_verification_result_summary() {
         let passed_result = VerificationResult {
@@ -998,6 +1571,9 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            coverage: None,
+            failure_kind: None,
+            failed_assertion: None,
         };
         assert_eq!(passed_result.summary(), "PASSED (exit code 0)");
 
@@ -1007,7 +1583,17 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            coverage: None,
+            failure_kind: Some(VerificationFailure::ExitCode),
+            failed_assertion: None,
         };
         assert_eq!(failed_result.summary(), "FAILED (exit code 1, expected 0)");
+
+        let timed_out = VerificationResult {
+            exit_code: -1,
+            failure_kind: Some(VerificationFailure::TimedOut),
+            ..failed_result
+        };
+        assert_eq!(timed_out.summary(), "FAILED (timed out)");
     }
 }
@@ -28,22 +28,52 @@
 //! }
 //! ```
 
+use serde::{Deserialize, Serialize};
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Configuration for worktree operations.
-#[derive(Debug, Clone)]
+///
+/// Loaded from `features.worktree`:
+/// ```yaml
+/// features:
+///   worktree:
+///     sync_extra: [".env", "node_modules/.cache/**"]
+///     base_ref: main
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorktreeConfig {
     /// Directory where worktrees are created (default: `.worktrees`).
+    ///
+    /// Not read from config: loop management commands always look for
+    /// worktrees in the default directory.
+    #[serde(skip, default = "default_worktree_dir")]
     pub worktree_dir: PathBuf,
+
+    /// Gitignored paths to copy into new worktrees anyway, as globs relative
+    /// to the repo root (e.g. `.env`, `target/cache/**`).
+    #[serde(default)]
+    pub sync_extra: Vec<String>,
+
+    /// Ref (branch, tag or SHA) new worktree branches start from instead of
+    /// HEAD. Worktrees based on a ref don't receive the current working
+    /// tree's untracked files or unstaged changes; `sync_extra` still applies.
+    #[serde(default)]
+    pub base_ref: Option<String>,
+}
+
+fn default_worktree_dir() -> PathBuf {
+    PathBuf::from(".worktrees")
 }
 
 impl Default for WorktreeConfig {
     fn default() -> Self {
         Self {
-            worktree_dir: PathBuf::from(".worktrees"),
+            worktree_dir: default_worktree_dir(),
+            sync_extra: Vec::new(),
+            base_ref: None,
         }
     }
 }
@@ -53,6 +83,7 @@ impl WorktreeConfig {
     pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
         Self {
             worktree_dir: dir.into(),
+            ..Self::default()
         }
     }
 
@@ -93,6 +124,10 @@ pub struct SyncStats {
     pub skipped: usize,
     /// Number of files that failed to copy.
     pub errors: usize,
+    /// Gitignored files copied because they matched `sync_extra`.
+    pub extra_copied: Vec<PathBuf>,
+    /// `sync_extra` patterns that matched no gitignored files.
+    pub extra_missing: Vec<String>,
 }
 
 /// Errors that can occur during worktree operations.
@@ -121,12 +156,17 @@ pub enum WorktreeError {
     /// Branch already exists.
     #[error("Branch already exists: {0}")]
     BranchExists(String),
+
+    /// Configured base ref does not resolve to a commit.
+    #[error("Base ref not found: {0}")]
+    BaseRefNotFound(String),
 }
 
 /// Create a new worktree for a parallel Ralph loop.
 ///
 /// Creates a new branch and worktree at `{config.worktree_dir}/{loop_id}`.
-/// The branch is created from HEAD of the current branch.
+/// The branch is created from `config.base_ref` if set, otherwise from HEAD
+/// of the current branch.
 ///
 /// # Arguments
 ///
@@ -162,16 +202,24 @@ pub fn create_worktree(
         ));
     }
 
+    // Validate the base ref before touching the filesystem
+    if let Some(base_ref) = &config.base_ref {
+        verify_commit_ref(repo_root, base_ref)?;
+    }
+
     // Ensure worktree directory exists
     fs::create_dir_all(&worktree_base)?;
 
     // Create worktree with new branch
-    // git worktree add -b <branch> <path>
-    let output = Command::new("git")
+    // git worktree add -b <branch> <path> [<base_ref>]
+    let mut command = Command::new("git");
+    command
         .args(["worktree", "add", "-b", &branch_name])
-        .arg(&worktree_path)
-        .current_dir(repo_root)
-        .output()?;
+        .arg(&worktree_path);
+    if let Some(base_ref) = &config.base_ref {
+        command.arg(base_ref);
+    }
+    let output = command.current_dir(repo_root).output()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -189,8 +237,15 @@ pub fn create_worktree(
         return Err(WorktreeError::Git(stderr.to_string()));
     }
 
-    // Sync untracked files and unstaged changes
-    let sync_stats = sync_working_directory_to_worktree(repo_root, &worktree_path, config)?;
+    // Sync untracked files and unstaged changes. They belong to HEAD, so a
+    // worktree based on another ref only gets the sync_extra files.
+    let sync_stats = if config.base_ref.is_some() {
+        let mut stats = SyncStats::default();
+        sync_extra_to_worktree(repo_root, &worktree_path, config, &mut stats)?;
+        stats
+    } else {
+        sync_working_directory_to_worktree(repo_root, &worktree_path, config)?
+    };
 
     if sync_stats.errors > 0 {
         tracing::warn!(
@@ -203,11 +258,12 @@ pub fn create_worktree(
     let head = get_head_commit(&worktree_path).ok();
 
     tracing::debug!(
-        "Created worktree at {} on branch {} (synced {} untracked, {} modified files)",
+        "Created worktree at {} on branch {} (synced {} untracked, {} modified, {} extra files)",
         worktree_path.display(),
         branch_name,
         sync_stats.untracked_copied,
-        sync_stats.modified_copied
+        sync_stats.modified_copied,
+        sync_stats.extra_copied.len()
     );
 
     Ok(Worktree {
@@ -479,6 +535,34 @@ pub fn worktree_exists(
     worktree_path.exists()
 }
 
+/// Check if the `ralph/<loop_id>` branch exists, e.g. left behind by a
+/// removed worktree. `create_worktree` refuses to reuse it.
+pub fn loop_branch_exists(repo_root: impl AsRef<Path>, loop_id: &str) -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("refs/heads/ralph/{loop_id}"))
+        .current_dir(repo_root)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Check that `base_ref` resolves to a commit.
+fn verify_commit_ref(repo_root: &Path, base_ref: &str) -> Result<(), WorktreeError> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet", "--end-of-options"])
+        .arg(format!("{base_ref}^{{commit}}"))
+        .current_dir(repo_root)
+        .output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(WorktreeError::BaseRefNotFound(base_ref.to_string()))
+    }
+}
+
 /// Get list of untracked files in the repository.
 ///
 /// Uses `git ls-files --others --exclude-standard` to get files that are:
@@ -526,6 +610,39 @@ fn get_unstaged_modified_files(repo_root: &Path) -> Result<Vec<PathBuf>, Worktre
         .collect())
 }
 
+/// Get gitignored, untracked files matching a `sync_extra` glob.
+///
+/// Uses `git ls-files --others --ignored --exclude-standard` with a
+/// `:(glob)` pathspec, so `*` stays within a directory and `**` crosses them.
+fn get_ignored_files_matching(
+    repo_root: &Path,
+    pattern: &str,
+) -> Result<Vec<PathBuf>, WorktreeError> {
+    let output = Command::new("git")
+        .args([
+            "ls-files",
+            "--others",
+            "--ignored",
+            "--exclude-standard",
+            "--",
+        ])
+        .arg(format!(":(glob){pattern}"))
+        .current_dir(repo_root)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(WorktreeError::Git(stderr.to_string()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
 /// Copy a file from repo to worktree, preserving directory structure.
 ///
 /// Creates parent directories as needed. Handles symlinks on Unix.
@@ -568,6 +685,20 @@ fn copy_file_with_structure(
     Ok(true)
 }
 
+/// Whether a repo-relative path must never be synced: `.git` and the
+/// worktree directory itself.
+fn is_excluded_from_sync(path: &Path, worktree_dir: &Path) -> bool {
+    let path_str = path.to_string_lossy();
+    // Exclude .git directory
+    if path_str.starts_with(".git/") || path_str == ".git" {
+        return true;
+    }
+    // Exclude the worktree directory itself
+    let worktree_dir_str = worktree_dir.to_string_lossy();
+    path_str.starts_with(&*worktree_dir_str)
+        || path_str.starts_with(&format!("{}/", worktree_dir_str))
+}
+
 /// Sync untracked and unstaged files from the main repo to a worktree.
 ///
 /// This copies files that are not committed to git, ensuring that WIP files
@@ -578,6 +709,10 @@ fn copy_file_with_structure(
 /// - `.git/` directory (never copied)
 /// - The worktree directory itself (e.g., `.worktrees/`)
 ///
+/// Gitignored files are skipped unless they match a `config.sync_extra`
+/// glob. Patterns that match nothing are logged and reported in
+/// [`SyncStats::extra_missing`] rather than failing the sync.
+///
 /// # Arguments
 ///
 /// * `repo_root` - Root of the git repository
@@ -594,25 +729,7 @@ pub fn sync_working_directory_to_worktree(
 ) -> Result<SyncStats, WorktreeError> {
     let mut stats = SyncStats::default();
 
-    // Get the worktree directory name for exclusion
-    let worktree_dir = &config.worktree_dir;
-
-    // Helper to check if a path should be excluded
-    let should_exclude = |path: &Path| -> bool {
-        let path_str = path.to_string_lossy();
-        // Exclude .git directory
-        if path_str.starts_with(".git/") || path_str == ".git" {
-            return true;
-        }
-        // Exclude the worktree directory itself
-        let worktree_dir_str = worktree_dir.to_string_lossy();
-        if path_str.starts_with(&*worktree_dir_str)
-            || path_str.starts_with(&format!("{}/", worktree_dir_str))
-        {
-            return true;
-        }
-        false
-    };
+    let should_exclude = |path: &Path| is_excluded_from_sync(path, &config.worktree_dir);
 
     // Get untracked files
     let untracked = get_untracked_files(repo_root)?;
@@ -658,10 +775,13 @@ pub fn sync_working_directory_to_worktree(
         }
     }
 
+    sync_extra_to_worktree(repo_root, worktree_path, config, &mut stats)?;
+
     tracing::debug!(
-        "Synced {} untracked and {} modified files to worktree ({} skipped, {} errors)",
+        "Synced {} untracked, {} modified and {} extra files to worktree ({} skipped, {} errors)",
         stats.untracked_copied,
         stats.modified_copied,
+        stats.extra_copied.len(),
         stats.skipped,
         stats.errors
     );
@@ -669,6 +789,49 @@ pub fn sync_working_directory_to_worktree(
     Ok(stats)
 }
 
+/// Copy gitignored files matching `config.sync_extra` into a worktree.
+///
+/// Patterns that match nothing are logged and recorded in
+/// [`SyncStats::extra_missing`] rather than treated as errors.
+fn sync_extra_to_worktree(
+    repo_root: &Path,
+    worktree_path: &Path,
+    config: &WorktreeConfig,
+    stats: &mut SyncStats,
+) -> Result<(), WorktreeError> {
+    for pattern in &config.sync_extra {
+        let matched: Vec<PathBuf> = get_ignored_files_matching(repo_root, pattern)?
+            .into_iter()
+            .filter(|file| !is_excluded_from_sync(file, &config.worktree_dir))
+            .collect();
+        if matched.is_empty() {
+            tracing::warn!(
+                "worktree.sync_extra pattern '{}' matched no gitignored files",
+                pattern
+            );
+            stats.extra_missing.push(pattern.clone());
+            continue;
+        }
+        for file in matched {
+            match copy_file_with_structure(repo_root, worktree_path, &file) {
+                Ok(true) => {
+                    tracing::trace!("Copied extra file: {}", file.display());
+                    stats.extra_copied.push(file);
+                }
+                Ok(false) => {
+                    stats.skipped += 1;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to copy extra file {}: {}", file.display(), e);
+                    stats.errors += 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -871,10 +1034,12 @@ mod tests {
         let loop_id = "check-exists";
 
         assert!(!worktree_exists(temp_dir.path(), loop_id, &config));
+        assert!(!loop_branch_exists(temp_dir.path(), loop_id));
 
         let _wt = create_worktree(temp_dir.path(), loop_id, &config).unwrap();
 
         assert!(worktree_exists(temp_dir.path(), loop_id, &config));
+        assert!(loop_branch_exists(temp_dir.path(), loop_id));
     }
 
     #[test]
@@ -1034,6 +1199,119 @@ branch refs/heads/ralph/loop-1
         assert!(worktree.path.join("valid.txt").exists());
     }
 
+    #[test]
+    fn test_sync_extra_copies_matching_gitignored_files() {
+        let temp_dir = TempDir::new().unwrap();
+        init_git_repo(temp_dir.path());
+
+        fs::write(temp_dir.path().join(".gitignore"), ".env\ncache/\n*.log\n").unwrap();
+        Command::new("git")
+            .args(["add", ".gitignore"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "Add gitignore"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+
+        fs::write(temp_dir.path().join(".env"), "SECRET=1").unwrap();
+        fs::create_dir_all(temp_dir.path().join("cache/deep")).unwrap();
+        fs::write(temp_dir.path().join("cache/deep/blob.bin"), [0u8, 1, 2]).unwrap();
+        fs::write(temp_dir.path().join("debug.log"), "log content").unwrap();
+
+        let config = WorktreeConfig {
+            sync_extra: vec![
+                ".env".to_string(),
+                "cache/**".to_string(),
+                "missing.toml".to_string(),
+            ],
+            ..WorktreeConfig::default()
+        };
+        let worktree_path = temp_dir.path().join(".worktrees/extra-test");
+        fs::create_dir_all(&worktree_path).unwrap();
+
+        let stats =
+            sync_working_directory_to_worktree(temp_dir.path(), &worktree_path, &config).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(worktree_path.join(".env")).unwrap(),
+            "SECRET=1"
+        );
+        assert!(worktree_path.join("cache/deep/blob.bin").exists());
+        assert!(!worktree_path.join("debug.log").exists(), "not listed");
+        assert_eq!(
+            stats.extra_copied,
+            vec![PathBuf::from(".env"), PathBuf::from("cache/deep/blob.bin")]
+        );
+        assert_eq!(stats.extra_missing, vec!["missing.toml".to_string()]);
+        assert_eq!(stats.errors, 0);
+    }
+
+    #[test]
+    fn test_create_worktree_from_base_ref() {
+        let temp_dir = TempDir::new().unwrap();
+        init_git_repo(temp_dir.path());
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["tag", "v1"]);
+        let v1 = git(&["rev-parse", "HEAD"]);
+
+        fs::write(temp_dir.path().join("later.txt"), "later").unwrap();
+        git(&["add", "later.txt"]);
+        git(&["commit", "-m", "Later commit"]);
+        fs::write(temp_dir.path().join("README.md"), "# Dirty").unwrap();
+        fs::write(temp_dir.path().join("wip.txt"), "wip").unwrap();
+
+        let config = WorktreeConfig {
+            base_ref: Some("v1".to_string()),
+            ..WorktreeConfig::default()
+        };
+        let worktree = create_worktree(temp_dir.path(), "from-tag", &config).unwrap();
+
+        assert_eq!(worktree.head.as_deref(), Some(v1.as_str()));
+        assert!(!worktree.path.join("later.txt").exists());
+        assert_eq!(
+            fs::read_to_string(worktree.path.join("README.md")).unwrap(),
+            "# Test",
+            "working tree changes belong to HEAD, not the base ref"
+        );
+        assert!(!worktree.path.join("wip.txt").exists());
+    }
+
+    #[test]
+    fn test_create_worktree_rejects_unknown_base_ref() {
+        let temp_dir = TempDir::new().unwrap();
+        init_git_repo(temp_dir.path());
+
+        let config = WorktreeConfig {
+            base_ref: Some("no-such-branch".to_string()),
+            ..WorktreeConfig::default()
+        };
+        let err = create_worktree(temp_dir.path(), "bad-base", &config).unwrap_err();
+
+        assert!(
+            matches!(&err, WorktreeError::BaseRefNotFound(r) if r == "no-such-branch"),
+            "{err}"
+        );
+        assert!(!temp_dir.path().join(".worktrees/bad-base").exists());
+    }
+
+    #[test]
+    fn test_worktree_config_parses_sync_extra() {
+        let config: WorktreeConfig = serde_yaml::from_str("sync_extra: ['.env']").unwrap();
+        assert_eq!(config.sync_extra, vec![".env".to_string()]);
+        assert_eq!(config.worktree_dir, PathBuf::from(".worktrees"));
+    }
+
     #[test]
     fn test_sync_excludes_worktrees_directory() {
         let temp_dir = TempDir::new().unwrap();
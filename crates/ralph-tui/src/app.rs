@@ -20,6 +20,7 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use futures::StreamExt;
+use ralph_core::ShutdownPhase;
 use ratatui::{
     Terminal,
     backend::CrosstermBackend,
@@ -305,6 +306,16 @@ impl<W: AsyncWrite + Unpin + Send + 'static> App<W> {
                                     && key.code == KeyCode::Char('c')
                                     && key.modifiers.contains(KeyModifiers::CONTROL) =>
                                 {
+                                    // With a shutdown signal the first Ctrl+C only drains: the
+                                    // loop stops after the current iteration and the footer says so.
+                                    let shutdown = self.state.lock().unwrap().shutdown.clone();
+                                    if self.rpc_writer.is_none()
+                                        && let Some(shutdown) = shutdown
+                                        && shutdown.request() == ShutdownPhase::Draining
+                                    {
+                                        info!("Ctrl+C detected, finishing current iteration");
+                                        continue;
+                                    }
                                     info!("Ctrl+C detected, signaling abort");
                                     if let Some(ref writer) = self.rpc_writer {
                                         // Subprocess mode: send abort via RPC
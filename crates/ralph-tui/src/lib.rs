@@ -246,6 +246,19 @@ impl Tui {
         self
     }
 
+    /// Sets the shared shutdown signal for two-phase Ctrl+C handling.
+    ///
+    /// The first Ctrl+C asks the loop to finish its current iteration and the
+    /// footer shows that it is draining; a second one within the force-quit
+    /// window signals the interrupt channel as before.
+    #[must_use]
+    pub fn with_shutdown_signal(self, signal: ralph_core::ShutdownSignal) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.shutdown = Some(signal);
+        }
+        self
+    }
+
     /// Returns the shared state for external updates.
     pub fn state(&self) -> Arc<Mutex<TuiState>> {
         Arc::clone(&self.state)
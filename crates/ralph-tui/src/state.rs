@@ -304,6 +304,8 @@ pub struct TuiState {
     pub events_path: Option<std::path::PathBuf>,
     /// Path to the urgent-steer marker file used to gate `ralph emit`.
     pub urgent_steer_path: Option<std::path::PathBuf>,
+    /// Shared Ctrl-C phase (in-process mode); drives two-phase shutdown.
+    pub shutdown: Option<ralph_core::ShutdownSignal>,
     /// Brief flash message after attempting to send guidance.
     /// (mode, result, when)
     pub guidance_flash: Option<(GuidanceMode, GuidanceResult, Instant)>,
@@ -377,6 +379,7 @@ impl TuiState {
             guidance_next_queue: Arc::new(Mutex::new(Vec::new())),
             events_path: None,
             urgent_steer_path: None,
+            shutdown: None,
             guidance_flash: None,
             // Subprocess error state
             subprocess_error: None,
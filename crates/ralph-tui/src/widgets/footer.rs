@@ -40,6 +40,24 @@ impl Widget for Footer<'_> {
             return;
         }
 
+        // Draining after the first Ctrl+C
+        if let Some(message) = self
+            .state
+            .shutdown
+            .as_ref()
+            .and_then(|shutdown| shutdown.phase().status_message())
+        {
+            let line = Line::from(vec![
+                Span::raw(" "),
+                Span::styled(
+                    format!("\u{23f8} {message}"),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]);
+            Paragraph::new(line).render(inner_area, buf);
+            return;
+        }
+
         // Guidance flash (brief after attempting send)
         if let Some((mode, result)) = self.state.active_guidance_flash() {
             let (msg, color) = match (mode, result) {
@@ -247,6 +265,21 @@ mod tests {
     // Acceptance Criteria Tests (Task 06)
     // =========================================================================
 
+    #[test]
+    fn footer_shows_draining_status_after_first_ctrl_c() {
+        let mut state = TuiState::new();
+        let shutdown = ralph_core::ShutdownSignal::new();
+        state.shutdown = Some(shutdown.clone());
+        assert!(!render_to_string_with_width(&state, 100).contains("finishing"));
+
+        shutdown.request();
+        let text = render_to_string_with_width(&state, 100);
+        assert!(
+            text.contains("finishing current iteration (press Ctrl-C again to force quit)"),
+            "{text}"
+        );
+    }
+
     #[test]
     fn footer_shows_new_iteration_alert() {
         // Given new_iteration_alert = Some(5) and following_latest = false